@@ -0,0 +1,8 @@
+-- Add migration script here
+ALTER TABLE articles
+ADD COLUMN series TEXT;
+
+ALTER TABLE articles
+ADD COLUMN series_order INTEGER;
+
+CREATE INDEX idx_articles_series ON articles (series, series_order) WHERE series IS NOT NULL;
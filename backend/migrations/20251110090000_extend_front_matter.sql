@@ -0,0 +1,20 @@
+-- Add migration script here
+ALTER TABLE articles
+ADD COLUMN description TEXT,
+ADD COLUMN cover_image TEXT,
+ADD COLUMN canonical_url TEXT,
+ADD COLUMN language TEXT,
+ADD COLUMN license TEXT,
+ADD COLUMN author TEXT,
+ADD COLUMN pinned BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN extra JSONB NOT NULL DEFAULT '{}';
+
+CREATE TABLE ingestion_reports (
+    path TEXT PRIMARY KEY,
+    article_id UUID,
+    status TEXT NOT NULL,
+    issues JSONB NOT NULL DEFAULT '[]',
+    ingested_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_ingestion_reports_status ON ingestion_reports (status);
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE articles
+ADD COLUMN content_html TEXT NOT NULL DEFAULT '';
@@ -0,0 +1,4 @@
+-- Add migration script here
+-- NULL marks rows ingested before TOC extraction; they are extracted on read
+ALTER TABLE articles
+ADD COLUMN toc JSONB;
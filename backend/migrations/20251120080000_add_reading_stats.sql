@@ -0,0 +1,5 @@
+-- Add migration script here
+-- NULL marks rows ingested before reading statistics were computed
+ALTER TABLE articles
+ADD COLUMN word_count INTEGER,
+ADD COLUMN reading_minutes INTEGER;
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE articles
+ADD COLUMN summary_generated BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,8 @@
+-- Add migration script here
+CREATE TABLE article_links (
+    source_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+    target_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+    PRIMARY KEY (source_id, target_id)
+);
+
+CREATE INDEX idx_article_links_target_id ON article_links (target_id);
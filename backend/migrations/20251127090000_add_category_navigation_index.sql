@@ -0,0 +1,4 @@
+-- Add migration script here
+-- Keyset lookups of the previous/next published post within a category
+CREATE INDEX idx_articles_category_created_at ON articles (category, created_at, id)
+WHERE status = 'published';
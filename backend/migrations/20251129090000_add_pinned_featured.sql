@@ -0,0 +1,10 @@
+-- Add migration script here
+ALTER TABLE articles
+ADD COLUMN pinned_weight INTEGER,
+ADD COLUMN featured BOOLEAN NOT NULL DEFAULT FALSE,
+ADD COLUMN featured_weight INTEGER,
+-- Order weights only apply to pinned / featured posts
+ADD CONSTRAINT articles_pinned_weight_check CHECK (pinned OR pinned_weight IS NULL),
+ADD CONSTRAINT articles_featured_weight_check CHECK (featured OR featured_weight IS NULL);
+
+CREATE INDEX idx_articles_featured ON articles (featured_weight, created_at) WHERE featured;
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE articles
+ADD COLUMN images JSONB NOT NULL DEFAULT '[]';
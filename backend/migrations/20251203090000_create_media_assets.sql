@@ -0,0 +1,14 @@
+-- Add migration script here
+CREATE TABLE media_assets (
+    hash TEXT PRIMARY KEY,
+    content_type TEXT NOT NULL,
+    byte_size BIGINT NOT NULL,
+    width INTEGER NOT NULL,
+    height INTEGER NOT NULL,
+    blurhash TEXT,
+    original_hash TEXT REFERENCES media_assets (hash) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_media_assets_original ON media_assets (original_hash)
+WHERE original_hash IS NOT NULL;
@@ -0,0 +1,18 @@
+-- Add migration script here
+CREATE TABLE article_media (
+    article_id UUID NOT NULL REFERENCES articles (id) ON DELETE CASCADE,
+    hash TEXT NOT NULL REFERENCES media_assets (hash),
+    PRIMARY KEY (article_id, hash)
+);
+
+CREATE INDEX idx_article_media_hash ON article_media (hash);
+
+-- Images ingested before references were recorded are still linked from
+-- the stored content and gallery
+INSERT INTO article_media (article_id, hash)
+SELECT a.id, m.hash
+FROM articles a
+JOIN media_assets m
+    ON m.original_hash IS NULL
+    AND (strpos(a.content, m.hash) > 0 OR strpos(a.images::TEXT, m.hash) > 0)
+ON CONFLICT DO NOTHING;
@@ -0,0 +1,8 @@
+-- Add migration script here
+ALTER TABLE articles
+ADD COLUMN keep_location BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Blobs stored before this migration still carry their full EXIF data,
+-- including GPS tags, until their posts are pushed again
+ALTER TABLE media_assets
+ADD COLUMN exif JSONB;
@@ -0,0 +1,5 @@
+-- Add migration script here
+-- Posts created through the Micropub endpoint live only in the database;
+-- everything else is ingested from the content repository
+ALTER TABLE articles
+ADD COLUMN source TEXT NOT NULL DEFAULT 'git' CHECK (source IN ('git', 'micropub'));
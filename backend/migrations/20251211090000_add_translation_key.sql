@@ -0,0 +1,6 @@
+-- Add migration script here
+-- Versions of the same post in different languages share a translation key
+ALTER TABLE articles
+ADD COLUMN translation_key TEXT;
+
+CREATE INDEX idx_articles_translation_key ON articles (translation_key) WHERE translation_key IS NOT NULL;
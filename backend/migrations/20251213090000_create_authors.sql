@@ -0,0 +1,34 @@
+-- Add migration script here
+-- Author profiles, replaced whenever the authors data file of the content
+-- repository changes
+CREATE TABLE authors (
+    id TEXT PRIMARY KEY,
+    name TEXT NOT NULL,
+    avatar TEXT,
+    bio TEXT,
+    links JSONB NOT NULL DEFAULT '[]',
+    github TEXT,
+    emails TEXT[] NOT NULL DEFAULT '{}'
+);
+
+-- Author ids of each article in byline order; ids without a profile are
+-- kept so the post is attributed once the profile is added
+ALTER TABLE articles
+ADD COLUMN authors TEXT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_articles_authors ON articles USING GIN (authors);
+
+-- Byline embedded in listings: id, name and avatar of each author, in
+-- order, with the id standing in for the name of unknown authors
+CREATE FUNCTION article_authors(ids TEXT[]) RETURNS JSONB
+LANGUAGE SQL STABLE AS $$
+    SELECT COALESCE(
+        jsonb_agg(
+            jsonb_build_object('id', u.id, 'name', COALESCE(a.name, u.id), 'avatar', a.avatar)
+            ORDER BY u.position
+        ),
+        '[]'::JSONB
+    )
+    FROM unnest(ids) WITH ORDINALITY AS u(id, position)
+    LEFT JOIN authors a ON a.id = u.id
+$$;
@@ -0,0 +1,9 @@
+-- Add migration script here
+-- Data collections ingested from the data directory of the content
+-- repository, one row per declared collection
+CREATE TABLE collections (
+    name TEXT PRIMARY KEY,
+    path TEXT NOT NULL,
+    data JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -4,10 +4,13 @@ use std::time::Duration;
 
 use crate::application::article_service::ArticleService;
 use crate::config::{AppConfig, Config};
+use crate::domain::media::MediaStore;
+use crate::domain::{categories::CategoryRegistry, tags::TagNormalizer};
 use crate::errors::Result;
 use crate::infrastructure::db::sqlx_repo::SqlxArticleRepository;
 #[cfg(feature = "webhook")]
 use crate::infrastructure::github::api_client::GithubApiClient;
+use crate::infrastructure::media::{local::LocalMediaStore, s3::S3MediaStore};
 use crate::infrastructure::search::index::MeiliSearchService;
 
 /// Default maximum number of database connections in the pool
@@ -59,6 +62,27 @@ impl AppState {
         // Extract JWT secret for later use in authentication
         let jwt_secret = config.jwt_secret;
 
+        // Validate the configured categories (falls back to the built-in set)
+        let categories = CategoryRegistry::new(config.categories)?;
+
+        // Build the tag normalizer from the configured synonym groups
+        let tags = TagNormalizer::new(config.tag_synonyms)?;
+
+        // Reject reading speeds that would divide by zero
+        let reading = config.reading.validate()?;
+        let summary = config.summary.validate()?;
+        let media = config.media.validate()?;
+        let micropub = config.micropub.validate(&categories)?;
+        let collections = config.collections.validate()?;
+        let notebooks = config.notebooks.validate()?;
+
+        // Blobs go to the S3-compatible bucket if one is configured,
+        // otherwise under the local root
+        let media_store: Arc<dyn MediaStore> = match &media.s3 {
+            Some(s3) => Arc::new(S3MediaStore::new(s3)?),
+            None => Arc::new(LocalMediaStore::new(&media.root)),
+        };
+
         // Configure and create the database connection pool
         // The pool manages a set of database connections for efficient reuse
         let pool = PgPoolOptions::new()
@@ -85,6 +109,15 @@ impl AppState {
         // Create application configuration wrapped in Arc for thread-safe sharing
         let app_config = Arc::new(AppConfig::new(
             &jwt_secret,
+            categories,
+            tags,
+            reading,
+            summary,
+            media,
+            micropub,
+            config.micropub_token.as_deref(),
+            collections,
+            notebooks,
             #[cfg(feature = "webhook")]
             &github_webhook_secret,
             #[cfg(feature = "webhook")]
@@ -100,6 +133,7 @@ impl AppState {
             #[cfg(feature = "webhook")]
             Arc::new(github_client),
             Arc::new(search_service),
+            media_store,
             app_config.clone(),
         ));
 
@@ -1,32 +1,96 @@
-#[cfg(feature = "webhook")]
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 #[cfg(feature = "webhook")]
-use gray_matter::{Matter, engine::YAML};
+use futures::{StreamExt, stream};
 #[cfg(feature = "webhook")]
-use octocrab::models::webhook_events::{WebhookEvent, WebhookEventType};
+use gray_matter::{
+    Matter, ParsedEntity,
+    engine::{Engine, JSON, TOML, YAML},
+};
 #[cfg(feature = "webhook")]
+use octocrab::models::webhook_events::{WebhookEvent, WebhookEventType};
+use serde_json::Map;
 use time::OffsetDateTime;
+use tokio::sync::RwLock;
 
 #[cfg(feature = "webhook")]
-use crate::domain::articles::ArticleFrontMatter;
+use crate::domain::asciidoc::convert_asciidoc;
+#[cfg(feature = "webhook")]
+use crate::domain::authors::{
+    Author, AuthorsData, CommitAuthor, authors_data_format, validate_authors,
+};
+#[cfg(feature = "webhook")]
+use crate::domain::collections::{Collection, CollectionSchema};
+#[cfg(feature = "webhook")]
+use crate::domain::front_matter::{
+    FrontMatterFormat, FrontMatterValidation, key_lines, validate_front_matter,
+};
+#[cfg(feature = "webhook")]
+use crate::domain::gallery::GalleryImage;
+#[cfg(feature = "webhook")]
+use crate::domain::ingestion::IngestionIssue;
+#[cfg(feature = "webhook")]
+use crate::domain::links::{LinkEdge, LinkIndex, LinkTarget, resolve_repo_path, split_fragment};
+#[cfg(feature = "webhook")]
+use crate::domain::media::{MediaOptions, MediaReference};
+#[cfg(feature = "webhook")]
+use crate::domain::notebooks::{NotebookFrontMatter, NotebookOptions, convert_notebook};
+#[cfg(feature = "webhook")]
+use crate::domain::org::convert_org;
 #[cfg(feature = "webhook")]
 use crate::domain::repositories::TransactionGuard;
 #[cfg(feature = "webhook")]
+use crate::domain::search::DEFAULT_SEARCH_INDEX;
+#[cfg(feature = "webhook")]
+use crate::domain::source_formats::{ConvertedDocument, SourceFormat};
+#[cfg(feature = "webhook")]
 use crate::infrastructure::github::webhook::FileChange;
 #[cfg(feature = "webhook")]
 use crate::infrastructure::github::{client::GithubClient, webhook::WebhookHandler};
+#[cfg(feature = "webhook")]
+use crate::infrastructure::markdown::{
+    image_sources, inline_image_sources, remove_images, resolve_internal_links,
+    rewrite_image_sources,
+};
+#[cfg(feature = "webhook")]
+use crate::infrastructure::media::processing::{
+    ProcessedImage, decode_data_uri, process_image, strip_metadata,
+};
 
 use crate::{
     config::AppConfig,
     domain::{
-        articles::Article,
+        archives::{ArchiveMonthCount, ArchivePeriod},
+        articles::{Article, ArticleFrontMatter, ArticleId, ArticleSource, PostCategory},
+        authors::byline,
+        categories::Category,
+        ingestion::{IngestionReport, IngestionStatus},
+        languages::negotiate_language,
+        media::{MediaAsset, MediaContent, MediaHash, MediaStore},
+        micropub::{
+            MicropubOutcome, MicropubProperties, MicropubRequest, MicropubUpdate,
+            article_id_from_url, micropub_path, token_matches,
+        },
+        reading::{ContentStats, ReadingSpeeds},
+        related::{ContentVersion, MAX_RELATED_POSTS, RelatedCandidate, rank_related},
         repositories::ArticleRepository,
-        search::{SearchHit, SearchService},
+        search::{
+            ArticleDocument, FILTERABLE_ATTRIBUTES, SEARCHABLE_ATTRIBUTES, SearchHit, SearchService,
+        },
+        summary::SummaryOptions,
+        toc::TocEntry,
+    },
+    errors::{GetPostsError, MediaError, MicropubError, Result, SomeError},
+    infrastructure::markdown::{
+        content_stats, extract_toc, generate_summary, render, render_markdown,
+    },
+    interfaces::http::dtos::{
+        ArchiveMonth, ArchiveYear, AuthorResponse, CollectionResponse, ContentFormat, FeaturedPost,
+        GalleryResponse, GraphResponse, LinkedArticle, MediaInfoResponse, MicropubConfigResponse,
+        MicropubSourceResponse, PostDetailResponse, PostResponse, RelatedPost, SeriesNavigation,
+        SeriesPart, SeriesResponse, TagResponse, Translation,
     },
-    errors::Result,
-    interfaces::http::dtos::PostResponse,
 };
 
 /// Article service layer containing business logic for article operations
@@ -53,8 +117,24 @@ pub struct ArticleService {
     /// Search service for full-text search operations
     search_service: Arc<dyn SearchService>,
 
+    /// Content-addressed store for images collected at ingestion
+    media_store: Arc<dyn MediaStore>,
+
     /// Application configuration including secrets and settings
     config: Arc<AppConfig>,
+
+    /// Related posts computed per article, see [`ArticleService::get_related_posts`]
+    related_cache: RwLock<RelatedCache>,
+}
+
+/// Cached related posts, valid for one version of the published articles
+#[derive(Default)]
+struct RelatedCache {
+    /// Version the cached results were computed against
+    version: Option<ContentVersion>,
+
+    /// Article id -> up to `MAX_RELATED_POSTS` related posts
+    posts: HashMap<ArticleId, Vec<RelatedPost>>,
 }
 
 impl ArticleService {
@@ -65,6 +145,7 @@ impl ArticleService {
     /// * `db_repo` - Article repository implementation for database operations
     /// * `github_client` - GitHub API client (webhook feature only)
     /// * `search_service` - Search service implementation for full-text search
+    /// * `media_store` - Store holding ingested images and their variants
     /// * `config` - Application configuration
     ///
     /// # Returns
@@ -74,6 +155,7 @@ impl ArticleService {
         db_repo: Arc<dyn ArticleRepository>,
         #[cfg(feature = "webhook")] github_client: Arc<dyn GithubClient>,
         search_service: Arc<dyn SearchService>,
+        media_store: Arc<dyn MediaStore>,
         config: Arc<AppConfig>,
     ) -> Self {
         Self {
@@ -81,7 +163,9 @@ impl ArticleService {
             #[cfg(feature = "webhook")]
             github_client,
             search_service,
+            media_store,
             config,
+            related_cache: RwLock::default(),
         }
     }
 
@@ -140,7 +224,7 @@ impl ArticleService {
     ///
     /// Extracts file changes from the push event and processes each changed file
     /// based on its status (added, modified, or removed). Only processes files
-    /// with valid extensions (.md, .mdx).
+    /// with valid extensions (.md, .mdx, .ipynb, .org, .adoc).
     ///
     /// # Arguments
     ///
@@ -152,320 +236,2214 @@ impl ArticleService {
     /// * `Err(SomeError)` - Error occurred during file processing
     #[cfg(feature = "webhook")]
     async fn process_push_event(&self, event: &WebhookEvent) -> Result<()> {
-        use crate::domain::search::DEFAULT_SEARCH_INDEX;
-
         let repo_name = event.get_repository_name()?;
         let owner = event.get_repository_owner()?;
+        let commit = event.get_push_head_commit()?;
 
         tracing::info!("Processing push event for repository: {}", repo_name);
 
-        let (mut added_files, removed_files, modified_files) = event.get_push_file_changes();
+        let (mut added_files, removed_files, mut modified_files) = event.get_push_file_changes();
+        let mut reports = Vec::new();
+
+        // Profiles changed by this push already apply to the posts it changes
+        let authors_update = self
+            .load_authors_data(
+                &owner,
+                &repo_name,
+                &commit,
+                &added_files,
+                &modified_files,
+                &removed_files,
+                &mut reports,
+            )
+            .await;
+        let authors = match &authors_update {
+            Some(authors) => authors.clone(),
+            None => self.db_repo.get_authors().await?,
+        };
+
+        let collections = self
+            .load_collections(
+                &owner,
+                &repo_name,
+                &commit,
+                &added_files,
+                &modified_files,
+                &mut reports,
+            )
+            .await;
+        let removed_collections = self.collection_paths(&removed_files).await;
 
+        modified_files.retain(|f| self.is_valid_file(&f.file_path));
         let modified_articles = self
-            .process_modified_event(&owner, &repo_name, &modified_files)
+            .process_modified_event(
+                &owner,
+                &repo_name,
+                &commit,
+                &modified_files,
+                &authors,
+                &mut reports,
+            )
             .await?;
 
         added_files.retain(|f| self.is_valid_file(&f.file_path));
         let (added, modified, removed) = self
-            .process_added_and_removed_event(&owner, &repo_name, &added_files, &removed_files)
+            .process_added_and_removed_event(
+                &owner,
+                &repo_name,
+                &commit,
+                &added_files,
+                &removed_files,
+                &authors,
+                &mut reports,
+            )
             .await?;
 
-        let upsert_articles: Vec<Article> = modified_articles
+        let mut upsert_articles: Vec<Article> = modified_articles
             .into_iter()
             .chain(modified)
             .chain(added)
             .collect();
+        let links = self
+            .resolve_links(&mut upsert_articles, &removed, &mut reports)
+            .await?;
 
-        let mut tx = self.db_repo.begin_transaction().await?;
-        self.process_upsert_files(&upsert_articles, &mut tx).await?;
-        self.process_deleted_files(&removed, &mut tx).await?;
-        tx.commit().await?;
+        // Images are fetched and encoded before the transaction is opened, so
+        // the media lock is only held while the push is recorded
+        let mut uploaded = Vec::new();
+        let (media, media_references) = self
+            .process_media(
+                &owner,
+                &repo_name,
+                &commit,
+                &mut upsert_articles,
+                &mut reports,
+                &mut uploaded,
+            )
+            .await?;
+
+        let recorded: Result<()> = async {
+            let mut tx = self.db_repo.begin_transaction().await?;
+            if let Some(authors) = &authors_update {
+                tx.replace_authors(authors).await?;
+            }
+            // Deleted first so a collection moved to another extension is kept
+            tx.delete_collections(&removed_collections).await?;
+            tx.upsert_collections(&collections).await?;
+
+            // Held until commit, so garbage collection can't delete a known
+            // blob that this push reuses before it is referenced
+            tx.lock_media().await?;
+            self.check_reused_media(&media, &uploaded).await?;
+            tx.insert_media(&media).await?;
+            self.process_upsert_files(&upsert_articles, &mut tx).await?;
+            self.process_deleted_files(&removed, &mut tx).await?;
+            let sources: Vec<ArticleId> = upsert_articles.iter().map(|a| a.id).collect();
+            tx.replace_links(&sources, &links).await?;
+            tx.replace_media_references(&sources, &media_references)
+                .await?;
+
+            // Reports of removed files go first so a path re-added in the same
+            // push keeps its fresh report
+            let removed_paths: Vec<String> =
+                removed_files.iter().map(|f| f.file_path.clone()).collect();
+            tx.delete_reports(&removed_paths).await?;
+            tx.upsert_reports(&reports).await?;
+            tx.commit().await
+        }
+        .await;
+
+        // Nothing references the blobs stored for a push that wasn't recorded
+        if let Err(e) = recorded {
+            if let Err(e) = self.discard_media(&uploaded).await {
+                tracing::warn!("Failed to delete media of a failed push: {}", e);
+            }
+            return Err(e);
+        }
+
+        // Images dropped by this push are only unreferenced once it is committed
+        if let Err(e) = self.collect_media_garbage().await {
+            tracing::warn!("Failed to collect unreferenced media: {}", e);
+        }
 
         self.create_index(DEFAULT_SEARCH_INDEX).await?;
 
         Ok(())
     }
 
-    /// Check if a file is valid for processing
+    /// Load the authors data file if the push changed it
     ///
-    /// Validates that the file has an allowed extension (.md or .mdx).
-    /// Only markdown files should be processed as articles.
-    ///
-    /// # Arguments
-    ///
-    /// * `file_path` - Path to the file to validate
+    /// The most recently added or modified data file (see
+    /// `AUTHORS_DATA_FILES`) is read from the repository as of the pushed
+    /// `commit` and validated; a
+    /// removed one clears the profiles. The file gets an ingestion report
+    /// like any article.
     ///
     /// # Returns
     ///
-    /// * `true` - File has a valid extension
-    /// * `false` - File extension is not allowed or could not be determined
+    /// * `Some(Vec<Author>)` - Profiles to store in place of the current ones
+    /// * `None` - The data file is unchanged, or it is invalid and the stored
+    ///   profiles are kept
     #[cfg(feature = "webhook")]
-    fn is_valid_file(&self, file_path: &str) -> bool {
-        use std::path::Path;
+    #[allow(clippy::too_many_arguments)]
+    async fn load_authors_data(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        added: &[FileChange],
+        modified: &[FileChange],
+        removed: &[FileChange],
+        reports: &mut Vec<IngestionReport>,
+    ) -> Option<Vec<Author>> {
+        let is_data_file = |f: &&FileChange| authors_data_format(&f.file_path).is_some();
+
+        let Some(changed) = added
+            .iter()
+            .chain(modified)
+            .filter(is_data_file)
+            .max_by_key(|f| f.timestamp)
+        else {
+            return removed.iter().any(|f| is_data_file(&f)).then(Vec::new);
+        };
+
+        let path = changed.file_path.as_str();
+        let format = authors_data_format(path)?;
+        let authors = match self
+            .github_client
+            .get_file_content(owner, repo, commit, path)
+            .await
+            .and_then(|content| parse_data_file(format, &content))
+            .and_then(|data| {
+                serde_json::from_value::<AuthorsData>(data)
+                    .map_err(|e| anyhow::anyhow!("Invalid authors data: {}", e).into())
+            }) {
+            Ok(data) => data.authors,
+            Err(e) => {
+                tracing::warn!("Failed to load authors data {}: {}", path, e);
+                let issue = IngestionIssue::error(None, None, e.to_string());
+                reports.push(IngestionReport::new(path, None, vec![issue]));
+                return None;
+            }
+        };
+
+        let issues = validate_authors(&authors);
+        for issue in &issues {
+            tracing::warn!("Authors data issue in {}: {}", path, issue);
+        }
 
-        let allowed_extensions = ["md", "mdx"];
+        let valid = !issues.iter().any(IngestionIssue::is_error);
+        reports.push(IngestionReport::new(path, None, issues));
 
-        Path::new(file_path)
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .map(|ext| allowed_extensions.contains(&ext))
-            .unwrap_or(false)
+        valid.then_some(authors)
     }
 
+    /// Load the data collections changed by the push
+    ///
+    /// For each declared collection, the most recently added or modified
+    /// file (see `CollectionOptions::collection_file`) is read from the
+    /// repository as of the pushed `commit` and checked against the
+    /// collection's schema. The file
+    /// gets an ingestion report like any article.
+    ///
+    /// # Returns
+    ///
+    /// The valid collections to store; collections with errors keep their
+    /// stored data.
     #[cfg(feature = "webhook")]
-    pub async fn process_added_and_removed_event(
+    async fn load_collections(
         &self,
         owner: &str,
         repo: &str,
+        commit: &str,
         added: &[FileChange],
-        removed: &[FileChange],
-    ) -> Result<(Vec<Article>, Vec<Article>, HashSet<String>)> {
-        use time::OffsetDateTime;
+        modified: &[FileChange],
+        reports: &mut Vec<IngestionReport>,
+    ) -> Vec<Collection> {
+        let options = self.config.collections.read().await.clone();
 
-        use crate::infrastructure::time_utils::chrono_to_offset;
+        let mut latest: HashMap<&str, (&FileChange, &CollectionSchema, FrontMatterFormat)> =
+            HashMap::new();
+        for change in added.iter().chain(modified) {
+            let Some((schema, format)) = options.collection_file(&change.file_path) else {
+                continue;
+            };
+            if latest
+                .get(schema.name.as_str())
+                .is_none_or(|(c, _, _)| c.timestamp <= change.timestamp)
+            {
+                latest.insert(&schema.name, (change, schema, format));
+            }
+        }
 
-        let removed_paths: Vec<String> = removed.into_iter().map(|f| f.file_path.clone()).collect();
-        let mut removed_files_id = self.db_repo.get_by_paths(&removed_paths).await?;
+        let mut collections = Vec::new();
+        for (change, schema, format) in latest.into_values() {
+            let path = change.file_path.as_str();
+            let data = match self
+                .github_client
+                .get_file_content(owner, repo, commit, path)
+                .await
+                .and_then(|content| parse_data_file(format, &content))
+            {
+                Ok(data) => data,
+                Err(e) => {
+                    tracing::warn!("Failed to load collection {}: {}", path, e);
+                    let issue = IngestionIssue::error(None, None, e.to_string());
+                    reports.push(IngestionReport::new(path, None, vec![issue]));
+                    continue;
+                }
+            };
 
-        let mut add = Vec::new();
-        let mut modify = Vec::new();
+            let issues = schema.validate(&data);
+            for issue in &issues {
+                tracing::warn!("Collection issue in {}: {}", path, issue);
+            }
 
-        let added_contents = self.github_client.fetch_files(owner, repo, added).await;
-        for (timestamp, content, file_path) in added_contents {
-            match content {
-                Ok(content) => match self.extract_article(&content) {
-                    Ok((info, content)) => {
-                        let offset_timestamp = chrono_to_offset(timestamp).unwrap_or_else(|_| {
-                            tracing::warn!("Failed to parse timestamp");
-                            OffsetDateTime::now_utc()
-                        });
-
-                        if removed_files_id.contains(&info.id) {
-                            removed_files_id.remove(&info.id);
-
-                            modify.push(build_article(
-                                info,
-                                file_path,
-                                content,
-                                offset_timestamp,
-                                offset_timestamp,
-                            ));
-                        } else {
-                            add.push(build_article(
-                                info,
-                                file_path,
-                                content,
-                                offset_timestamp,
-                                offset_timestamp,
-                            ));
-                        }
-                    }
+            if !issues.iter().any(IngestionIssue::is_error) {
+                collections.push(Collection {
+                    name: schema.name.clone(),
+                    path: path.to_string(),
+                    data,
+                    updated_at: OffsetDateTime::now_utc(),
+                });
+            }
+            reports.push(IngestionReport::new(path, None, issues));
+        }
 
-                    Err(e) => {
-                        tracing::warn!("Failed to extract article: {}", e);
-                    }
-                },
+        collections
+    }
 
-                Err(e) => {
-                    tracing::warn!("Failed to fetch file content: {}", e)
+    /// Paths of the removed files that held a declared collection
+    #[cfg(feature = "webhook")]
+    async fn collection_paths(&self, removed: &[FileChange]) -> Vec<String> {
+        let options = self.config.collections.read().await;
+
+        removed
+            .iter()
+            .filter(|f| options.collection_file(&f.file_path).is_some())
+            .map(|f| f.file_path.clone())
+            .collect()
+    }
+
+    /// Resolve the internal links of the pushed articles
+    ///
+    /// Relative links to article sources (`.md`, `.ipynb`, `.org`, `.adoc`)
+    /// and `[[wiki-style]]` references are rewritten to canonical article
+    /// URLs, matching against the stored articles and the articles of this
+    /// push. The HTML and table of contents of rewritten articles are
+    /// rendered again. Links that can't be resolved are kept as
+    /// written and reported as warnings in the file's ingestion report.
+    ///
+    /// Only pushed articles are resolved: a stored article with a broken link
+    /// is fixed the next time it is pushed, not when its target appears.
+    ///
+    /// # Returns
+    ///
+    /// The link edges starting at the pushed articles, without self-links.
+    #[cfg(feature = "webhook")]
+    async fn resolve_links(
+        &self,
+        articles: &mut [Article],
+        removed: &HashSet<ArticleId>,
+        reports: &mut [IngestionReport],
+    ) -> Result<Vec<LinkEdge>> {
+        let stored = self.db_repo.get_link_targets().await?;
+        let pushed = articles.iter().map(|a| LinkTarget {
+            id: a.id,
+            path: a.path.clone(),
+            title: a.title.clone(),
+            category: a.category.clone(),
+        });
+        // Pushed articles come last so they replace stale rows with the same path
+        let index = LinkIndex::new(
+            stored
+                .into_iter()
+                .filter(|t| !removed.contains(&t.id))
+                .chain(pushed),
+        );
+
+        let mut edges = Vec::new();
+        for article in articles.iter_mut() {
+            let resolved = resolve_internal_links(&article.path, &article.content, &index);
+
+            if resolved.content != article.content {
+                let rendered = render(&resolved.content);
+                article.content_html = rendered.html;
+                article.toc = Some(rendered.toc);
+                article.content = resolved.content;
+            }
+
+            edges.extend(
+                resolved
+                    .targets
+                    .into_iter()
+                    .filter(|target| *target != article.id)
+                    .map(|target| LinkEdge {
+                        source: article.id,
+                        target,
+                    }),
+            );
+
+            if let Some(report) = reports.iter_mut().find(|r| r.path == article.path) {
+                for broken in resolved.broken {
+                    report.push_issue(IngestionIssue::warning(
+                        None,
+                        None,
+                        format!("broken internal link `{}`: {}", broken.link, broken.reason),
+                    ));
                 }
             }
         }
 
-        Ok((add, modify, removed_files_id))
+        Ok(edges)
     }
 
+    /// Collect the images of the pushed articles into the media store
+    ///
+    /// Every repository image embedded in the markdown body or listed in the
+    /// gallery is downloaded, stored under its content hash and run through
+    /// the image pipeline (see [`process_image`]). Image URLs in the content
+    /// are rewritten to the media URL and the HTML is rendered again; gallery
+    /// images get the media URL and, where front matter leaves them out, the
+    /// dimensions and camera metadata read from the file. Images embedded
+    /// inline as `data:` URIs, like notebook outputs, are decoded and stored
+    /// the same way.
+    ///
+    /// Sensitive metadata is removed from the stored files (see
+    /// [`strip_metadata`]). A post setting `keep_location` gets its own copy
+    /// of each image with the GPS position left in, so the same photo can be
+    /// shared by a post that keeps it and one that doesn't.
+    ///
+    /// An image that can't be ingested keeps its original URL and is reported
+    /// as a warning in the file's ingestion report; an inline image is removed
+    /// instead, as its URL is the image itself.
+    ///
+    /// # Returns
+    ///
+    /// The media assets to record, originals before their variants, and the
+    /// images each article uses. Images already recorded by an earlier push
+    /// are not processed again but are referenced all the same. The hashes
+    /// of the blobs written to the media store are added to `uploaded`, so
+    /// they can be deleted if the push isn't recorded.
     #[cfg(feature = "webhook")]
-    pub async fn process_modified_event(
+    async fn process_media(
         &self,
         owner: &str,
         repo: &str,
-        modified: &[FileChange],
-    ) -> Result<Vec<Article>> {
-        use time::OffsetDateTime;
+        commit: &str,
+        articles: &mut [Article],
+        reports: &mut [IngestionReport],
+        uploaded: &mut Vec<MediaHash>,
+    ) -> Result<(Vec<MediaAsset>, Vec<MediaReference>)> {
+        let options = self.config.media.read().await.clone();
 
-        use crate::infrastructure::time_utils::chrono_to_offset;
+        // Gallery paths were resolved against the repository at validation
+        let mut sources: Vec<(String, bool)> = Vec::new();
+        for article in articles.iter() {
+            let embedded = image_sources(&article.content)
+                .into_iter()
+                .filter_map(|src| resolve_repo_path(&article.path, split_fragment(&src).0));
+            let inline = inline_image_sources(&article.content);
+            let gallery = article.images.iter().map(|image| image.src.clone());
+            for path in embedded.chain(inline).chain(gallery) {
+                let source = (path, article.keep_location);
+                if !sources.contains(&source) {
+                    sources.push(source);
+                }
+            }
+        }
 
-        let contents = self
-            .github_client
-            .fetch_files(&owner, &repo, &modified)
+        // Images are encoded on the blocking pool, a few at a time, so a push
+        // with many of them still fits in GitHub's delivery timeout
+        const MAX_CONCURRENT: usize = 4;
+
+        // Each file is fetched once per push, however many posts embed it
+        let stored = std::sync::Mutex::new(Vec::new());
+        let results: Vec<_> = stream::iter(sources)
+            .map(|(path, keep_location)| {
+                let (options, stored) = (&options, &stored);
+                async move {
+                    let result = self
+                        .ingest_image(owner, repo, commit, &path, keep_location, options, stored)
+                        .await;
+                    (path, keep_location, result)
+                }
+            })
+            .buffered(MAX_CONCURRENT)
+            .collect()
             .await;
+        uploaded.extend(stored.into_inner().unwrap_or_else(|e| e.into_inner()));
 
-        let mut articles = Vec::new();
+        let mut ingested: HashMap<(String, bool), std::result::Result<MediaAsset, String>> =
+            HashMap::new();
+        let mut originals = Vec::new();
+        let mut variants = Vec::new();
+        for (path, keep_location, result) in results {
+            let result = match result {
+                Ok((original, resized)) => {
+                    originals.push(original.clone());
+                    variants.extend(resized);
+                    Ok(original)
+                }
+                Err(e) => {
+                    let name = if path.starts_with("data:") {
+                        "inline image"
+                    } else {
+                        &path
+                    };
+                    tracing::warn!("Failed to ingest image {}: {}", name, e);
+                    Err(e.to_string())
+                }
+            };
+            ingested.insert((path, keep_location), result);
+        }
 
-        for (timestamp, content, file_path) in contents {
-            if let Ok(content) = content {
-                let (article_info, content) = match self.extract_article(&content) {
-                    Ok((article_info, content)) => (article_info, content),
-                    Err(err) => {
-                        tracing::warn!(
-                            "Failed to extract article from file {}: {}",
-                            file_path,
-                            err
-                        );
-                        continue;
+        let mut references = HashSet::new();
+        for article in articles.iter_mut() {
+            let mut problems = Vec::new();
+            let mut reference = |asset: &MediaAsset| {
+                references.insert(MediaReference {
+                    article: article.id,
+                    hash: asset.hash.clone(),
+                });
+                options.url(&asset.hash)
+            };
+
+            let mut urls = HashMap::new();
+            for src in image_sources(&article.content) {
+                let result = resolve_repo_path(&article.path, split_fragment(&src).0)
+                    .and_then(|path| ingested.get(&(path, article.keep_location)));
+                match result {
+                    Some(Ok(asset)) => {
+                        urls.insert(src, reference(asset));
+                    }
+                    Some(Err(reason)) => {
+                        problems.push(format!("image `{}` not ingested: {}", src, reason))
+                    }
+                    None => problems.push(format!(
+                        "image `{}` not ingested: outside the repository",
+                        src
+                    )),
+                }
+            }
+            // An inline image that can't be stored is dropped rather than
+            // kept as a `data:` payload in the content
+            let mut dropped = HashSet::new();
+            for (i, src) in inline_image_sources(&article.content)
+                .into_iter()
+                .enumerate()
+            {
+                match ingested.get(&(src.clone(), article.keep_location)) {
+                    Some(Ok(asset)) => {
+                        urls.insert(src, reference(asset));
                     }
-                };
+                    Some(Err(reason)) => {
+                        problems.push(format!(
+                            "inline image {} not ingested and removed: {}",
+                            i + 1,
+                            reason
+                        ));
+                        dropped.insert(src);
+                    }
+                    None => {}
+                }
+            }
 
-                let offset_timestamp = chrono_to_offset(timestamp).unwrap_or_else(|_| {
-                    tracing::warn!("Failed to parse timestamp");
-                    OffsetDateTime::now_utc()
-                });
+            if !urls.is_empty() || !dropped.is_empty() {
+                let content =
+                    remove_images(&rewrite_image_sources(&article.content, &urls), &dropped);
+                if content != article.content {
+                    let rendered = render(&content);
+                    article.content_html = rendered.html;
+                    article.toc = Some(rendered.toc);
+                    article.content = content;
+                }
+            }
 
-                articles.push(build_article(
-                    article_info,
-                    file_path,
-                    content,
-                    offset_timestamp,
-                    offset_timestamp,
-                ));
+            for image in article.images.iter_mut() {
+                match ingested.get(&(image.src.clone(), article.keep_location)) {
+                    Some(Ok(asset)) => {
+                        image.src = reference(asset);
+                        if image.width.is_none() && image.height.is_none() {
+                            image.width = u32::try_from(asset.width).ok();
+                            image.height = u32::try_from(asset.height).ok();
+                        }
+                        if let Some(exif) = &asset.exif {
+                            image.fill_from_exif(exif, article.keep_location);
+                        }
+                    }
+                    Some(Err(reason)) => {
+                        problems.push(format!("image `{}` not ingested: {}", image.src, reason))
+                    }
+                    None => {}
+                }
+            }
+
+            if let Some(report) = reports.iter_mut().find(|r| r.path == article.path) {
+                for problem in problems {
+                    report.push_issue(IngestionIssue::warning(None, None, problem));
+                }
             }
         }
 
-        Ok(articles)
+        originals.extend(variants);
+
+        Ok((originals, references.into_iter().collect()))
     }
 
+    /// Download one image and store it with its variants
+    ///
+    /// `path` is a repository path, read as of the pushed `commit`, or a
+    /// `data:` URI for images embedded inline, such as notebook outputs.
+    ///
+    /// Sensitive metadata is removed first, then the image is hashed: if the
+    /// hash is already recorded the image and its variants were stored by an
+    /// earlier push and are reused as is. Otherwise the hash of every blob
+    /// written to the media store is added to `uploaded`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((original, variants))` - The original and the variants to record;
+    ///   `variants` is empty if the image was already recorded
+    /// * `Err(SomeError)` - The image could not be fetched, is too large, or
+    ///   could not be processed or stored
     #[cfg(feature = "webhook")]
-    pub async fn process_upsert_files(
+    #[allow(clippy::too_many_arguments)]
+    async fn ingest_image(
         &self,
-        upsert_articles: &[Article],
-        tx: &mut TransactionGuard,
-    ) -> Result<()> {
-        if !upsert_articles.is_empty() {
-            tx.upsert_batch(upsert_articles).await?;
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        path: &str,
+        keep_location: bool,
+        options: &MediaOptions,
+        uploaded: &std::sync::Mutex<Vec<MediaHash>>,
+    ) -> Result<(MediaAsset, Vec<MediaAsset>)> {
+        let bytes = if path.starts_with("data:") {
+            decode_data_uri(path)?
+        } else {
+            self.github_client
+                .get_file_bytes(owner, repo, commit, path, options.max_source_bytes)
+                .await?
+        };
+        // Repository files are capped while downloading, inline ones here
+        if bytes.len() > options.max_source_bytes {
+            return Err(anyhow::anyhow!(
+                "{} bytes exceeds the limit of {} bytes",
+                bytes.len(),
+                options.max_source_bytes
+            )
+            .into());
         }
 
-        Ok(())
-    }
-
-    #[cfg(feature = "webhook")]
-    pub async fn process_deleted_files(
-        &self,
-        deleted: &HashSet<String>,
-        tx: &mut TransactionGuard,
-    ) -> Result<()> {
-        if !deleted.is_empty() {
-            tx.delete_batch(deleted).await?;
+        let bytes = strip_metadata(bytes, keep_location)?;
+        let hash = MediaHash::of(&bytes);
+        if let Some(asset) = self.db_repo.get_media_asset(&hash).await? {
+            return Ok((asset, Vec::new()));
         }
 
-        Ok(())
-    }
+        // Decoding and AVIF encoding are CPU bound
+        let pipeline_options = options.clone();
+        let processed =
+            tokio::task::spawn_blocking(move || process_image(bytes, &pipeline_options))
+                .await
+                .map_err(|e| anyhow::anyhow!("Image processing task failed: {}", e))??;
 
-    #[cfg(feature = "webhook")]
-    fn extract_article(&self, content: &str) -> Result<(ArticleFrontMatter, String)> {
-        let matter = Matter::<YAML>::new();
-        let front_matter = matter.parse::<ArticleFrontMatter>(content)?;
-        let info = front_matter
-            .data
-            .ok_or_else(|| anyhow::anyhow!("Extract article info failed."))?;
+        let ProcessedImage { original, variants } = processed;
+        for blob in std::iter::once(&original).chain(&variants) {
+            self.media_store
+                .put(&blob.asset.hash, &blob.bytes, &blob.asset.content_type)
+                .await?;
+            uploaded
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .push(blob.asset.hash.clone());
+        }
 
-        Ok((info, front_matter.content))
+        Ok((
+            original.asset,
+            variants.into_iter().map(|v| v.asset).collect(),
+        ))
     }
 
-    /// Retrieve paginated list of articles by category
+    /// Make sure the known images a push reuses haven't been collected since
     ///
-    /// Fetches articles filtered by category with pagination support.
-    /// Results are typically ordered by creation date in descending order.
+    /// Called with the media lock held: an image found in the database before
+    /// the lock was taken may have lost its last reference and been deleted
+    /// by a garbage collection in between.
     ///
     /// # Arguments
     ///
-    /// * `category` - Category to filter by (e.g., "article", "note", "think")
-    /// * `page_size` - Number of articles to return per page
-    /// * `offset` - Number of articles to skip (for pagination)
+    /// * `media` - The media assets of the push
+    /// * `uploaded` - Hashes of the blobs the push stored itself
     ///
     /// # Returns
     ///
-    /// * `Ok(Vec<PostResponse>)` - List of articles matching the criteria
-    /// * `Err(SomeError)` - Error occurred during database query
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// // Get first page of articles (10 items)
-    /// let articles = service.get_posts_by_category("article", 10, 0).await?;
-    ///
-    /// // Get second page
-    /// let articles = service.get_posts_by_category("article", 10, 10).await?;
-    /// ```
-    pub async fn get_posts_by_category(
-        &self,
-        category: &str,
-        page_size: i64,
-        offset: i64,
-    ) -> Result<Vec<PostResponse>> {
-        self.db_repo
-            .get_posts_by_category(category, page_size, offset)
-            .await
+    /// * `Ok(())` - Every reused image is still recorded
+    /// * `Err(SomeError)` - A reused image was deleted; the push has to be
+    ///   processed again
+    #[cfg(feature = "webhook")]
+    async fn check_reused_media(&self, media: &[MediaAsset], uploaded: &[MediaHash]) -> Result<()> {
+        for asset in media.iter().filter(|a| !uploaded.contains(&a.hash)) {
+            if self.db_repo.get_media_asset(&asset.hash).await?.is_none() {
+                return Err(
+                    anyhow::anyhow!("Media {} was collected during ingestion", asset.hash).into(),
+                );
+            }
+        }
+
+        Ok(())
     }
 
-    /// Retrieve a single article by its ID
+    /// Delete the blobs stored by a push that could not be recorded
+    ///
+    /// Takes the media lock, so a blob recorded in the meantime by another
+    /// ingestion of the same image is kept.
     ///
     /// # Arguments
     ///
-    /// * `id` - Unique identifier of the article
+    /// * `hashes` - Hashes of the blobs the push stored
     ///
     /// # Returns
     ///
-    /// * `Ok(Article)` - The complete article entity
-    /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
-    /// * `Err(SomeError)` - Other error occurred during retrieval
-    pub async fn get_article_by_id(&self, id: &str) -> Result<Article> {
-        self.db_repo.get_post_by_id(id).await
+    /// * `Ok(())` - The unrecorded blobs were deleted, or logged if they
+    ///   could not be
+    /// * `Err(SomeError)` - Database error
+    #[cfg(feature = "webhook")]
+    async fn discard_media(&self, hashes: &[MediaHash]) -> Result<()> {
+        if hashes.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.db_repo.begin_transaction().await?;
+        tx.lock_media().await?;
+        for hash in hashes {
+            if self.db_repo.get_media_asset(hash).await?.is_some() {
+                continue;
+            }
+            if let Err(e) = self.media_store.delete(hash).await {
+                tracing::warn!("Failed to delete media blob {}: {}", hash, e);
+            }
+        }
+        tx.commit().await
     }
 
-    /// Perform full-text search on articles
+    /// Check if a file is valid for processing
     ///
-    /// Searches articles using the search service (Meilisearch) with support
-    /// for pagination, highlighting, and relevance ranking.
+    /// Validates that the file has the extension of a source format (.md,
+    /// .mdx, .ipynb, .org, .adoc or .asciidoc). Only article sources are
+    /// processed as articles.
     ///
     /// # Arguments
     ///
-    /// * `index` - Name of the search index to query (typically "articles")
-    /// * `query` - Search query string
-    /// * `current_page` - Current page number (1-based)
-    /// * `limit` - Number of results per page
+    /// * `file_path` - Path to the file to validate
     ///
     /// # Returns
     ///
-    /// A tuple containing:
-    /// * `Vec<SearchHit>` - List of search results with highlighting
-    /// * `usize` - Total number of matching articles
-    /// * `usize` - Total number of pages
-    /// * `usize` - Current page number
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// let (results, total, pages, current) =
-    ///     service.search("articles", "rust programming", 1, 10).await?;
-    ///
-    /// println!("Found {} results across {} pages", total, pages);
-    /// for hit in results {
-    ///     println!("{}: {}", hit.id, hit.title);
-    /// }
-    /// ```
-    pub async fn search(
-        &self,
-        index: &str,
-        query: &str,
-        current_page: usize,
-        limit: usize,
+    /// * `true` - File has a valid extension
+    /// * `false` - File extension is not allowed or could not be determined
+    #[cfg(feature = "webhook")]
+    fn is_valid_file(&self, file_path: &str) -> bool {
+        SourceFormat::from_path(file_path).is_some()
+    }
+
+    #[cfg(feature = "webhook")]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn process_added_and_removed_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        added: &[FileChange],
+        removed: &[FileChange],
+        authors: &[Author],
+        reports: &mut Vec<IngestionReport>,
+    ) -> Result<(Vec<Article>, Vec<Article>, HashSet<ArticleId>)> {
+        use time::OffsetDateTime;
+
+        use crate::infrastructure::time_utils::chrono_to_offset;
+
+        let removed_paths: Vec<String> = removed.iter().map(|f| f.file_path.clone()).collect();
+        let mut removed_files_id = self.db_repo.get_by_paths(&removed_paths).await?;
+
+        let mut add = Vec::new();
+        let mut modify = Vec::new();
+        let speeds = *self.config.reading.read().await;
+        let summary_options = *self.config.summary.read().await;
+
+        let committers = commit_authors(added);
+        let added_contents = self
+            .github_client
+            .fetch_files(owner, repo, commit, added)
+            .await;
+        for (timestamp, content, file_path) in added_contents {
+            let Some((info, content)) = self
+                .prepare_article(owner, repo, commit, &file_path, content, authors, reports)
+                .await
+            else {
+                continue;
+            };
+
+            let offset_timestamp = chrono_to_offset(timestamp).unwrap_or_else(|_| {
+                tracing::warn!("Failed to parse timestamp");
+                OffsetDateTime::now_utc()
+            });
+
+            let mut article = build_article(
+                info,
+                file_path,
+                content,
+                &speeds,
+                &summary_options,
+                offset_timestamp,
+                offset_timestamp,
+            );
+
+            // A moved file is the same post, so it keeps its byline
+            if removed_files_id.remove(&article.id) {
+                modify.push(article);
+            } else {
+                let committer = committers.get(article.path.as_str()).copied();
+                attribute_to_committer(&mut article, committer, authors);
+                add.push(article);
+            }
+        }
+        self.keep_stored_authors(&mut modify, &committers, authors)
+            .await?;
+
+        Ok((add, modify, removed_files_id))
+    }
+
+    #[cfg(feature = "webhook")]
+    pub async fn process_modified_event(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        modified: &[FileChange],
+        authors: &[Author],
+        reports: &mut Vec<IngestionReport>,
+    ) -> Result<Vec<Article>> {
+        use time::OffsetDateTime;
+
+        use crate::infrastructure::time_utils::chrono_to_offset;
+
+        let committers = commit_authors(modified);
+        let contents = self
+            .github_client
+            .fetch_files(owner, repo, commit, modified)
+            .await;
+
+        let mut articles = Vec::new();
+        let speeds = *self.config.reading.read().await;
+        let summary_options = *self.config.summary.read().await;
+
+        for (timestamp, content, file_path) in contents {
+            let Some((article_info, content)) = self
+                .prepare_article(owner, repo, commit, &file_path, content, authors, reports)
+                .await
+            else {
+                continue;
+            };
+
+            let offset_timestamp = chrono_to_offset(timestamp).unwrap_or_else(|_| {
+                tracing::warn!("Failed to parse timestamp");
+                OffsetDateTime::now_utc()
+            });
+
+            articles.push(build_article(
+                article_info,
+                file_path,
+                content,
+                &speeds,
+                &summary_options,
+                offset_timestamp,
+                offset_timestamp,
+            ));
+        }
+        self.keep_stored_authors(&mut articles, &committers, authors)
+            .await?;
+
+        Ok(articles)
+    }
+
+    /// Keep the bylines of changed posts without `authors`
+    ///
+    /// A post stays attributed to whoever it was credited to when it was
+    /// first ingested, not to the author of the latest commit. A post that
+    /// isn't stored yet, because earlier versions were rejected, is
+    /// attributed like an added one. The stored bylines are read in a single
+    /// query for the whole push.
+    #[cfg(feature = "webhook")]
+    async fn keep_stored_authors(
+        &self,
+        articles: &mut [Article],
+        committers: &HashMap<&str, &CommitAuthor>,
+        authors: &[Author],
+    ) -> Result<()> {
+        let ids: Vec<ArticleId> = articles
+            .iter()
+            .filter(|a| a.authors.is_empty())
+            .map(|a| a.id)
+            .collect();
+        if ids.is_empty() {
+            return Ok(());
+        }
+
+        let stored = self.db_repo.get_article_authors(&ids).await?;
+        for article in articles.iter_mut().filter(|a| a.authors.is_empty()) {
+            match stored.get(&article.id) {
+                Some(byline) => article.authors = byline.clone(),
+                None => {
+                    let committer = committers.get(article.path.as_str()).copied();
+                    attribute_to_committer(article, committer, authors);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate one fetched file and record its ingestion report
+    ///
+    /// Runs the front matter schema validation, checks the category against
+    /// the configured registry, checks that gallery images exist in the
+    /// repository as of the pushed `commit`, warns about authors without a
+    /// profile and normalizes tags. Every file gets a report, including files
+    /// that could not be fetched.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((front_matter, body))` - The file can be ingested
+    /// * `None` - The file was rejected; the reasons are in its report
+    #[cfg(feature = "webhook")]
+    #[allow(clippy::too_many_arguments)]
+    async fn prepare_article(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        file_path: &str,
+        content: Result<String>,
+        authors: &[Author],
+        reports: &mut Vec<IngestionReport>,
+    ) -> Option<(ArticleFrontMatter, String)> {
+        let notebook_options = *self.config.notebooks.read().await;
+        let extracted =
+            content.and_then(|c| self.extract_source(file_path, &c, &notebook_options, authors));
+        let (mut validation, body) = match extracted {
+            Ok(extracted) => extracted,
+            Err(e) => {
+                tracing::warn!("Failed to ingest file {}: {}", file_path, e);
+                let issue = IngestionIssue::error(None, None, e.to_string());
+                reports.push(IngestionReport::new(file_path, None, vec![issue]));
+                return None;
+            }
+        };
+
+        if let Some(category) = validation.front_matter.as_ref().map(|f| f.category.clone())
+            && !self.is_known_category(&category).await
+        {
+            validation.reject("category", format!("unknown category `{}`", category));
+        }
+
+        if let Some(front_matter) = validation.front_matter.as_ref()
+            && front_matter.title.is_empty()
+            && !self.allows_untitled(&front_matter.category).await
+        {
+            validation.reject_missing("title");
+        }
+
+        if let Some(front_matter) = validation.front_matter.as_mut() {
+            let (missing, unchecked) = self
+                .resolve_images(owner, repo, commit, file_path, &mut front_matter.images)
+                .await;
+            for problem in unchecked {
+                validation.warn("images", problem);
+            }
+            for problem in missing {
+                validation.reject("images", problem);
+            }
+        }
+
+        let unknown_authors: Vec<String> = validation
+            .front_matter
+            .iter()
+            .flat_map(|f| &f.authors)
+            .filter(|id| !authors.iter().any(|a| &a.id == *id))
+            .cloned()
+            .collect();
+        for id in unknown_authors {
+            validation.warn(
+                "authors",
+                format!("no profile for author `{}` in the authors data file", id),
+            );
+        }
+
+        for issue in &validation.issues {
+            tracing::warn!("Front matter issue in {}: {}", file_path, issue);
+        }
+
+        reports.push(IngestionReport::new(
+            file_path,
+            validation.article_id,
+            validation.issues,
+        ));
+
+        let mut front_matter = validation.front_matter?;
+        front_matter.tags = self.normalize_tags(&front_matter.tags).await;
+
+        Some((front_matter, body))
+    }
+
+    /// Resolve gallery image paths against the article and check they exist
+    ///
+    /// Each `src` is rewritten to its path from the repository root and
+    /// looked up as of the pushed `commit`. An image that could not be
+    /// looked up, e.g. because the GitHub API failed, is kept: it is fetched
+    /// again at ingestion, which reports it if it is really missing.
+    ///
+    /// # Returns
+    ///
+    /// One message per image that is outside the repository or not a file
+    /// in it, and one per image that could not be checked.
+    #[cfg(feature = "webhook")]
+    async fn resolve_images(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        file_path: &str,
+        images: &mut [GalleryImage],
+    ) -> (Vec<String>, Vec<String>) {
+        let mut missing = Vec::new();
+        let mut unchecked = Vec::new();
+
+        for (i, image) in images.iter_mut().enumerate() {
+            let Some(path) = resolve_repo_path(file_path, image.src.trim()) else {
+                missing.push(format!(
+                    "image {}: `{}` is outside the repository",
+                    i + 1,
+                    image.src
+                ));
+                continue;
+            };
+
+            match self
+                .github_client
+                .file_exists(owner, repo, commit, &path)
+                .await
+            {
+                Ok(true) => image.src = path,
+                Ok(false) => missing.push(format!(
+                    "image {}: `{}` not found in the repository",
+                    i + 1,
+                    path
+                )),
+                Err(e) => {
+                    unchecked.push(format!(
+                        "image {}: could not check `{}`: {}",
+                        i + 1,
+                        path,
+                        e
+                    ));
+                    image.src = path;
+                }
+            }
+        }
+
+        (missing, unchecked)
+    }
+
+    #[cfg(feature = "webhook")]
+    pub async fn process_upsert_files(
+        &self,
+        upsert_articles: &[Article],
+        tx: &mut TransactionGuard,
+    ) -> Result<()> {
+        if !upsert_articles.is_empty() {
+            tx.upsert_batch(upsert_articles).await?;
+        }
+
+        Ok(())
+    }
+
+    #[cfg(feature = "webhook")]
+    pub async fn process_deleted_files(
+        &self,
+        deleted: &HashSet<ArticleId>,
+        tx: &mut TransactionGuard,
+    ) -> Result<()> {
+        if !deleted.is_empty() {
+            tx.delete_batch(deleted).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Normalize front-matter tags and resolve configured synonyms
+    async fn normalize_tags(&self, tags: &[String]) -> Vec<String> {
+        self.config.tags.read().await.normalize_all(tags)
+    }
+
+    /// Check whether posts of a category may leave out their title
+    async fn allows_untitled(&self, category: &PostCategory) -> bool {
+        self.config
+            .categories
+            .read()
+            .await
+            .get(category)
+            .is_some_and(|c| c.optional_title)
+    }
+
+    /// Check whether a front-matter category is configured
+    ///
+    /// Hidden categories are accepted here; only listing is restricted for them.
+    #[cfg(feature = "webhook")]
+    async fn is_known_category(&self, category: &PostCategory) -> bool {
+        self.config.categories.read().await.contains(category)
+    }
+
+    /// Parse a file in its source format and validate its front matter
+    ///
+    /// The format is detected from the file extension. Every format is
+    /// normalized to a front matter validation and a markdown body, so the
+    /// rest of ingestion and the stored article are the same whichever
+    /// format a post was written in.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((validation, body))` - The validation outcome and the markdown body
+    /// * `Err(SomeError)` - The file is not an article source, or could not
+    ///   be parsed
+    #[cfg(feature = "webhook")]
+    fn extract_source(
+        &self,
+        file_path: &str,
+        content: &str,
+        notebook_options: &NotebookOptions,
+        authors: &[Author],
+    ) -> Result<(FrontMatterValidation, String)> {
+        let format = SourceFormat::from_path(file_path)
+            .ok_or_else(|| anyhow::anyhow!("`{}` is not an article source file", file_path))?;
+
+        match format {
+            SourceFormat::Markdown => self.extract_article(content),
+            SourceFormat::Notebook => self.extract_notebook(content, notebook_options),
+            SourceFormat::Org => Ok(self.extract_document(convert_org(content), authors)),
+            SourceFormat::AsciiDoc => Ok(self.extract_document(convert_asciidoc(content), authors)),
+        }
+    }
+
+    /// Parse the front matter of a file and validate it field by field
+    ///
+    /// The format (YAML `---`, TOML `+++` or JSON `;;;`) is detected from the
+    /// delimiter on the first line. Files without front matter produce a
+    /// validation with a "missing front matter" error.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((validation, body))` - The validation outcome and the markdown body
+    /// * `Err(SomeError)` - The front matter could not be parsed; the message
+    ///   names the format that failed
+    #[cfg(feature = "webhook")]
+    fn extract_article(&self, content: &str) -> Result<(FrontMatterValidation, String)> {
+        let Some(format) = FrontMatterFormat::detect(content) else {
+            return Ok((
+                validate_front_matter(None, &Default::default()),
+                content.to_string(),
+            ));
+        };
+
+        let parsed = match format {
+            FrontMatterFormat::Yaml => parse_front_matter::<YAML>(format, content),
+            FrontMatterFormat::Toml => parse_front_matter::<TOML>(format, content),
+            FrontMatterFormat::Json => parse_front_matter::<JSON>(format, content),
+        }
+        .map_err(|e| anyhow::anyhow!("Invalid {} front matter: {}", format, e))?;
+
+        let key_lines = key_lines(content, format);
+        let validation = validate_front_matter(parsed.data.as_ref(), &key_lines);
+
+        Ok((validation, parsed.content))
+    }
+
+    /// Convert a Jupyter notebook and validate its front matter
+    ///
+    /// The front matter comes from a leading raw cell, parsed like the front
+    /// matter of a markdown file, or else from the `front_matter` notebook
+    /// metadata. Lines of front matter issues count from the raw cell.
+    /// Truncated and dropped outputs are reported as warnings.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((validation, body))` - The validation outcome and the converted
+    ///   markdown body
+    /// * `Err(SomeError)` - The notebook or its front matter could not be parsed
+    #[cfg(feature = "webhook")]
+    fn extract_notebook(
+        &self,
+        content: &str,
+        options: &NotebookOptions,
+    ) -> Result<(FrontMatterValidation, String)> {
+        let notebook = convert_notebook(content, options)?;
+
+        let mut validation = match &notebook.front_matter {
+            Some(NotebookFrontMatter::Cell(source)) => self.extract_article(source)?.0,
+            Some(NotebookFrontMatter::Metadata(data)) => {
+                validate_front_matter(Some(data), &Default::default())
+            }
+            None => validate_front_matter(None, &Default::default()),
+        };
+        validation.issues.extend(notebook.issues);
+
+        Ok((validation, notebook.markdown))
+    }
+
+    /// Validate the header of a converted Org-mode or AsciiDoc document
+    ///
+    /// Lines of front matter issues are those of the header entries. Header
+    /// entries that were ignored are reported as warnings. Authors named in
+    /// the header are looked up in `authors`, see
+    /// [`ConvertedDocument::resolve_authors`].
+    #[cfg(feature = "webhook")]
+    fn extract_document(
+        &self,
+        mut document: ConvertedDocument,
+        authors: &[Author],
+    ) -> (FrontMatterValidation, String) {
+        document.resolve_authors(authors);
+        let mut validation =
+            validate_front_matter(document.front_matter.as_ref(), &document.key_lines);
+        validation.issues.extend(document.issues);
+
+        (validation, document.markdown)
+    }
+
+    /// Check the access token of a Micropub request
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Token from the `Authorization: Bearer` header or the
+    ///   `access_token` parameter, if the client sent one
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The token matches the configured one
+    /// * `Err(MicropubError::Unauthorized)` - No token was sent
+    /// * `Err(MicropubError::Forbidden)` - The token is wrong, or no token is
+    ///   configured and Micropub is disabled
+    pub fn authorize_micropub(&self, token: Option<&str>) -> Result<()> {
+        let Some(expected) = self.config.micropub_token.as_deref() else {
+            return Err(MicropubError::Forbidden("Micropub is not enabled".to_string()).into());
+        };
+        let token = token.ok_or(MicropubError::Unauthorized)?;
+
+        if !token_matches(expected, token) {
+            return Err(MicropubError::Forbidden("Invalid access token".to_string()).into());
+        }
+
+        Ok(())
+    }
+
+    /// Describe the Micropub endpoint for `q=config`
+    ///
+    /// # Returns
+    ///
+    /// The single post type Micropub can create, named after the configured
+    /// category, and no syndication targets
+    pub async fn get_micropub_config(&self) -> MicropubConfigResponse {
+        let category = self.config.micropub.read().await.category.clone();
+        let name = self
+            .config
+            .categories
+            .read()
+            .await
+            .get(&category)
+            .map(|c| c.display_name.clone())
+            .unwrap_or_else(|| category.to_string());
+
+        MicropubConfigResponse::new(name)
+    }
+
+    /// Retrieve the editable properties of a post for `q=source`
+    ///
+    /// Posts from the repository can be read too, though only Micropub posts
+    /// can be changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - Public URL of the post
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MicropubSourceResponse)` - The post as an `h-entry`
+    /// * `Err(MicropubError::InvalidRequest)` - The URL names no post
+    /// * `Err(SomeError)` - Database error
+    pub async fn get_micropub_source(&self, url: &str) -> Result<MicropubSourceResponse> {
+        let article = self.find_micropub_target(url).await?;
+
+        Ok(MicropubSourceResponse::from(&MicropubProperties::from(
+            &article,
+        )))
+    }
+
+    /// Create, update or delete a post through Micropub
+    ///
+    /// Posts are stored through the repository like ingested ones, marked with
+    /// [`ArticleSource::Micropub`] and a synthetic path (see
+    /// [`micropub_path`]), so pushes never touch them. Posts from the
+    /// repository can't be changed through Micropub.
+    ///
+    /// New posts go to the configured Micropub category. Tags are normalized
+    /// like front matter tags, and a post without `name` is only accepted if
+    /// its category allows untitled posts.
+    ///
+    /// Only the changed post is added to, replaced in or removed from the
+    /// search index; if that fails the post stays stored and the failure is
+    /// logged, so clients don't post twice.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The parsed Micropub request
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MicropubOutcome)` - What was done, with the URL of a new post
+    /// * `Err(MicropubError::InvalidRequest)` - The properties are invalid or
+    ///   the URL names no post
+    /// * `Err(MicropubError::Forbidden)` - The post comes from the repository
+    /// * `Err(SomeError)` - Database error
+    pub async fn handle_micropub(&self, request: MicropubRequest) -> Result<MicropubOutcome> {
+        match request {
+            MicropubRequest::Create(properties) => self.create_micropub_post(properties).await,
+            MicropubRequest::Update { url, update } => {
+                self.update_micropub_post(&url, &update).await
+            }
+            MicropubRequest::Delete { url } => self.delete_micropub_post(&url).await,
+        }
+    }
+
+    async fn create_micropub_post(
+        &self,
+        properties: MicropubProperties,
+    ) -> Result<MicropubOutcome> {
+        let options = self.config.micropub.read().await.clone();
+        let id = ArticleId::new_random();
+        let now = OffsetDateTime::now_utc();
+        let created_at = properties.published.unwrap_or(now);
+
+        let article = self
+            .build_micropub_post(id, options.category.clone(), properties, created_at, now)
+            .await?;
+
+        let mut tx = self.db_repo.begin_transaction().await?;
+        tx.upsert_batch(std::slice::from_ref(&article)).await?;
+        tx.commit().await?;
+
+        tracing::info!("Created Micropub post {}", id);
+        self.index_micropub_post(&article).await;
+
+        Ok(MicropubOutcome::Created {
+            url: options.post_url(&article.category, &id),
+        })
+    }
+
+    async fn update_micropub_post(
+        &self,
+        url: &str,
+        update: &MicropubUpdate,
+    ) -> Result<MicropubOutcome> {
+        let stored = self.find_micropub_post(url).await?;
+
+        let mut properties = MicropubProperties::from(&stored);
+        update.apply(&mut properties)?;
+
+        let article = self
+            .build_micropub_post(
+                stored.id,
+                stored.category,
+                properties,
+                stored.created_at,
+                OffsetDateTime::now_utc(),
+            )
+            .await?;
+
+        let mut tx = self.db_repo.begin_transaction().await?;
+        tx.upsert_batch(std::slice::from_ref(&article)).await?;
+        tx.commit().await?;
+
+        tracing::info!("Updated Micropub post {}", article.id);
+        self.index_micropub_post(&article).await;
+
+        Ok(MicropubOutcome::Updated)
+    }
+
+    async fn delete_micropub_post(&self, url: &str) -> Result<MicropubOutcome> {
+        let stored = self.find_micropub_post(url).await?;
+
+        let mut tx = self.db_repo.begin_transaction().await?;
+        tx.delete_batch(&HashSet::from([stored.id])).await?;
+        tx.commit().await?;
+
+        tracing::info!("Deleted Micropub post {}", stored.id);
+        if let Err(e) = self.search_service.delete_index_item(&stored).await {
+            tracing::warn!(
+                "Failed to remove Micropub post {} from the search index: {}",
+                stored.id,
+                e
+            );
+        }
+
+        Ok(MicropubOutcome::Deleted)
+    }
+
+    /// Index a post written through Micropub
+    ///
+    /// The post is stored already, so a failure is only logged; the next
+    /// push rebuilds the index.
+    async fn index_micropub_post(&self, article: &Article) {
+        if let Err(e) = self.search_service.update_or_add_index_item(article).await {
+            tracing::warn!(
+                "Failed to index Micropub post {} for search: {}",
+                article.id,
+                e
+            );
+        }
+    }
+
+    /// Look up the post a Micropub request names by URL
+    async fn find_micropub_target(&self, url: &str) -> Result<Article> {
+        let id = article_id_from_url(url)
+            .ok_or_else(|| MicropubError::InvalidRequest(format!("`{}` is not a post URL", url)))?;
+
+        match self.db_repo.get_post_by_id(&id).await {
+            Err(SomeError::GetPosts(GetPostsError::ArticleNotFound)) => {
+                Err(MicropubError::InvalidRequest(format!("no post at `{}`", url)).into())
+            }
+            result => result,
+        }
+    }
+
+    /// Look up a post a Micropub request may change
+    async fn find_micropub_post(&self, url: &str) -> Result<Article> {
+        let article = self.find_micropub_target(url).await?;
+
+        if article.source != ArticleSource::Micropub {
+            return Err(MicropubError::Forbidden(format!(
+                "`{}` is managed in the content repository",
+                url
+            ))
+            .into());
+        }
+
+        Ok(article)
+    }
+
+    /// Build the stored article of a Micropub post
+    async fn build_micropub_post(
+        &self,
+        id: ArticleId,
+        category: PostCategory,
+        properties: MicropubProperties,
+        created_at: OffsetDateTime,
+        updated_at: OffsetDateTime,
+    ) -> Result<Article> {
+        let content = properties
+            .content
+            .filter(|c| !c.trim().is_empty())
+            .ok_or_else(|| MicropubError::InvalidRequest("`content` is required".to_string()))?;
+
+        let title = properties.name.unwrap_or_default().trim().to_string();
+        if title.is_empty() && !self.allows_untitled(&category).await {
+            return Err(MicropubError::InvalidRequest(format!(
+                "`name` is required for `{}` posts",
+                category
+            ))
+            .into());
+        }
+
+        let front_matter = ArticleFrontMatter {
+            id,
+            title,
+            tags: self.normalize_tags(&properties.category).await,
+            category,
+            summary: properties.summary,
+            status: properties
+                .post_status
+                .unwrap_or_else(|| "published".to_string()),
+            series: None,
+            series_order: None,
+            description: None,
+            cover_image: None,
+            canonical_url: None,
+            language: None,
+            translation_key: None,
+            license: None,
+            author: None,
+            authors: Vec::new(),
+            pinned: false,
+            pinned_weight: None,
+            featured: false,
+            featured_weight: None,
+            images: Vec::new(),
+            keep_location: false,
+            extra: Map::new(),
+        };
+
+        let speeds = *self.config.reading.read().await;
+        let summary_options = *self.config.summary.read().await;
+        let article = build_article(
+            front_matter,
+            micropub_path(&id),
+            content,
+            &speeds,
+            &summary_options,
+            created_at,
+            updated_at,
+        );
+
+        Ok(Article {
+            source: ArticleSource::Micropub,
+            ..article
+        })
+    }
+
+    /// Retrieve paginated list of articles by category
+    ///
+    /// Fetches articles filtered by category with pagination support.
+    /// Pinned articles come first (by weight), then the rest by creation date
+    /// in descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Category to filter by (e.g., "article", "note", "think")
+    /// * `language` - Primary language to list, see [`Self::resolve_language`]
+    /// * `page_size` - Number of articles to return per page
+    /// * `offset` - Number of articles to skip (for pagination)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PostResponse>)` - List of articles matching the criteria
+    /// * `Err(SomeError)` - Error occurred during database query
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Get first page of articles (10 items)
+    /// let articles = service.get_posts_by_category("article", None, 10, 0).await?;
+    ///
+    /// // Get second page of English articles
+    /// let articles = service.get_posts_by_category("article", Some("en"), 10, 10).await?;
+    /// ```
+    pub async fn get_posts_by_category(
+        &self,
+        category: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        self.db_repo
+            .get_posts_by_category(category, language, page_size, offset)
+            .await
+    }
+
+    /// Choose the language a listing or search is restricted to
+    ///
+    /// An explicit `lang` parameter wins (`all` lists every language).
+    /// Without one, the most preferred `Accept-Language` entry that some
+    /// published article is written in is used. Articles without a language
+    /// are always included.
+    ///
+    /// # Arguments
+    ///
+    /// * `requested` - The `lang` query parameter, if any
+    /// * `accept_language` - The `Accept-Language` header, if any
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(String))` - Primary language to filter by (e.g., "en")
+    /// * `Ok(None)` - Don't filter by language
+    /// * `Err(GetPostsError::InvalidLanguage)` - `lang` is not a language tag
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn resolve_language(
+        &self,
+        requested: Option<&str>,
+        accept_language: Option<&str>,
+    ) -> Result<Option<String>> {
+        // Only negotiation needs to know which languages have content
+        let available = if requested.is_none() && accept_language.is_some() {
+            self.db_repo.get_languages().await?
+        } else {
+            Vec::new()
+        };
+
+        negotiate_language(requested, accept_language, &available)
+            .map_err(|lang| GetPostsError::InvalidLanguage(lang).into())
+    }
+
+    /// List publicly visible categories in navigation order
+    ///
+    /// # Returns
+    ///
+    /// Snapshot of the visible categories from the current configuration,
+    /// ordered by `sort_order` and then slug
+    pub async fn get_categories(&self) -> Vec<Category> {
+        self.config
+            .categories
+            .read()
+            .await
+            .visible()
+            .cloned()
+            .collect()
+    }
+
+    /// Resolve a category requested by a listing endpoint
+    ///
+    /// # Arguments
+    ///
+    /// * `slug` - Category slug taken from the request
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Category)` - The configured, visible category
+    /// * `Err(GetPostsError::UnknownCategory)` - The category is not configured or hidden
+    pub async fn get_listed_category(&self, slug: &PostCategory) -> Result<Category> {
+        self.config
+            .categories
+            .read()
+            .await
+            .get(slug)
+            .filter(|c| c.visible)
+            .cloned()
+            .ok_or_else(|| GetPostsError::UnknownCategory(slug.to_string()).into())
+    }
+
+    /// Retrieve paginated list of articles carrying a tag
+    ///
+    /// The requested tag goes through the same normalization and synonym
+    /// resolution as ingested tags, so any known spelling matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - Tag as requested by the client (e.g., "Rust-Lang")
+    /// * `language` - Primary language to list, see [`Self::resolve_language`]
+    /// * `page_size` - Number of articles to return per page
+    /// * `offset` - Number of articles to skip (for pagination)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PostResponse>)` - List of articles carrying the tag
+    /// * `Err(GetPostsError::InvalidTag)` - Tag is empty after normalization
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_posts_by_tag(
+        &self,
+        tag: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let normalized = self
+            .config
+            .tags
+            .read()
+            .await
+            .normalize(tag)
+            .ok_or_else(|| GetPostsError::InvalidTag(tag.to_string()))?;
+
+        self.db_repo
+            .get_posts_by_tag(&normalized, language, page_size, offset)
+            .await
+    }
+
+    /// Retrieve an author profile with a page of their posts
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Author id as requested by the client (e.g., "jane")
+    /// * `language` - Primary language to list, see [`Self::resolve_language`]
+    /// * `page_size` - Number of posts to return per page
+    /// * `offset` - Number of posts to skip (for pagination)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(AuthorResponse)` - The profile and its posts, newest first
+    /// * `Err(GetPostsError::AuthorNotFound)` - No profile has the id
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_author(
+        &self,
+        id: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<AuthorResponse> {
+        let author = self
+            .db_repo
+            .get_authors_by_ids(&[id.to_string()])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| GetPostsError::AuthorNotFound(id.to_string()))?;
+
+        let posts = self
+            .db_repo
+            .get_posts_by_author(&author.id, language, page_size, offset)
+            .await?;
+
+        Ok(AuthorResponse { author, posts })
+    }
+
+    /// Retrieve a data collection
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Collection name as requested by the client (e.g., "links")
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CollectionResponse)` - The stored entries of the collection
+    /// * `Err(GetPostsError::CollectionNotFound)` - The collection is not
+    ///   declared in `[collections]` or its file has not been pushed
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_collection(&self, name: &str) -> Result<CollectionResponse> {
+        // Collections dropped from the config are no longer served
+        if self.config.collections.read().await.get(name).is_none() {
+            return Err(GetPostsError::CollectionNotFound(name.to_string()).into());
+        }
+
+        let collection = self
+            .db_repo
+            .get_collection(name)
+            .await?
+            .ok_or_else(|| GetPostsError::CollectionNotFound(name.to_string()))?;
+
+        Ok(collection.into())
+    }
+
+    /// Count published posts per year and month for the archive page
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Only count posts of this (already validated) category
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ArchiveYear>)` - Years with their months, both newest first
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_archives(&self, category: Option<&PostCategory>) -> Result<Vec<ArchiveYear>> {
+        let counts = self
+            .db_repo
+            .get_archive_counts(category.map(PostCategory::as_str))
+            .await?;
+
+        Ok(group_archive_counts(counts))
+    }
+
+    /// Retrieve paginated list of published posts created in an archive month
+    ///
+    /// # Arguments
+    ///
+    /// * `period` - Validated year and month
+    /// * `category` - Only list posts of this (already validated) category
+    /// * `page_size` - Number of posts to return per page
+    /// * `offset` - Number of posts to skip (for pagination)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PostResponse>)` - Posts of the month, newest first (may be empty)
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_archive_posts(
+        &self,
+        period: ArchivePeriod,
+        category: Option<&PostCategory>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let (start, end) = period.bounds();
+
+        self.db_repo
+            .get_posts_by_period(
+                start,
+                end,
+                category.map(PostCategory::as_str),
+                page_size,
+                offset,
+            )
+            .await
+    }
+
+    /// Retrieve the featured articles for the home page hero section
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of articles to return
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<FeaturedPost>)` - Published featured articles, by weight then newest first
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_featured(&self, limit: i64) -> Result<Vec<FeaturedPost>> {
+        self.db_repo.get_featured(limit).await
+    }
+
+    /// List every tag with the number of articles using it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TagResponse>)` - Tags ordered by count (descending), then name
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_tags(&self) -> Result<Vec<TagResponse>> {
+        self.db_repo.get_tags().await
+    }
+
+    /// List the stored ingestion reports, most recently ingested first
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Only list reports with this outcome
+    /// * `page_size` - Number of reports per page
+    /// * `offset` - Number of reports to skip
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<IngestionReport>)` - The latest report of each source path
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_reports(
+        &self,
+        status: Option<IngestionStatus>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<IngestionReport>> {
+        self.db_repo.get_reports(status, page_size, offset).await
+    }
+
+    /// Retrieve a single article by its ID
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Validated unique identifier of the article
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Article)` - The complete article entity
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+    /// * `Err(SomeError)` - Other error occurred during retrieval
+    pub async fn get_article_by_id(&self, id: &ArticleId) -> Result<Article> {
+        self.db_repo.get_post_by_id(id).await
+    }
+
+    /// Retrieve a single article together with its navigation blocks
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Validated unique identifier of the article
+    /// * `format` - Additional body representations to include
+    ///
+    /// Articles stored before HTML rendering, TOC extraction, reading
+    /// statistics or summary generation were introduced lack the
+    /// corresponding fields; these are produced on the fly until the next push
+    /// refreshes the article.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(PostDetailResponse)` - The article with its author profiles, its
+    ///   table of contents, its translations and, if it belongs to a series,
+    ///   its position and previous/next parts
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+    /// * `Err(SomeError)` - Other error occurred during retrieval
+    pub async fn get_article_detail(
+        &self,
+        id: &ArticleId,
+        format: ContentFormat,
+    ) -> Result<PostDetailResponse> {
+        let mut article = self.db_repo.get_post_by_id(id).await?;
+
+        let toc = article
+            .toc
+            .take()
+            .unwrap_or_else(|| extract_toc(&article.content));
+
+        if article.word_count.is_none() || article.reading_minutes.is_none() {
+            let speeds = *self.config.reading.read().await;
+            let (word_count, reading_minutes) =
+                reading_stats(&content_stats(&article.content), &speeds);
+            article.word_count = Some(word_count);
+            article.reading_minutes = Some(reading_minutes);
+        }
+
+        if article.summary.is_none() {
+            let options = *self.config.summary.read().await;
+            (article.summary, article.summary_generated) =
+                summary_or_generated(None, &article.content, &options);
+        }
+
+        let series_navigation = match &article.series {
+            Some(series) => {
+                let parts = self.db_repo.get_series_parts(series).await?;
+                build_series_navigation(series, parts, &article.id)
+            }
+            None => None,
+        };
+
+        let category_navigation = self
+            .db_repo
+            .get_category_neighbours(&article.category, article.created_at, &article.id)
+            .await?;
+
+        let profiles = self.db_repo.get_authors_by_ids(&article.authors).await?;
+        let authors = byline(&article.authors, &profiles);
+
+        let translations: Vec<Translation> = match &article.translation_key {
+            Some(key) => self.db_repo.get_translations(key, &article.id).await?,
+            None => Vec::new(),
+        };
+
+        let content_markdown = format.includes_markdown().then(|| article.content.clone());
+        let content_html = format.includes_html().then(|| {
+            if article.content_html.is_empty() {
+                render_markdown(&article.content)
+            } else {
+                article.content_html.clone()
+            }
+        });
+
+        Ok(PostDetailResponse {
+            article,
+            authors,
+            toc,
+            series_navigation,
+            category_navigation,
+            translations,
+            content_markdown,
+            content_html,
+        })
+    }
+
+    /// Retrieve the table of contents of an article
+    ///
+    /// Reads only the stored heading tree, so it is much lighter than the
+    /// detail endpoint. Articles stored before TOC extraction was introduced
+    /// are extracted on the fly.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Validated unique identifier of the article
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TocEntry>)` - Top-level headings with nested children
+    ///   (empty if the article has no headings)
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+    /// * `Err(SomeError)` - Other error occurred during retrieval
+    pub async fn get_toc(&self, id: &ArticleId) -> Result<Vec<TocEntry>> {
+        match self.db_repo.get_toc(id).await? {
+            Some(toc) => Ok(toc),
+            None => {
+                let article = self.db_repo.get_post_by_id(id).await?;
+                Ok(extract_toc(&article.content))
+            }
+        }
+    }
+
+    /// Retrieve the gallery images of a post
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Validated unique identifier of the post
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GalleryResponse)` - The images in front matter order (empty if
+    ///   the post declares none)
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+    /// * `Err(SomeError)` - Other error occurred during retrieval
+    pub async fn get_gallery(&self, id: &ArticleId) -> Result<GalleryResponse> {
+        let article = self.db_repo.get_post_by_id(id).await?;
+
+        Ok(GalleryResponse::from(article))
+    }
+
+    /// Retrieve a stored media blob with its metadata
+    ///
+    /// Stores that presign reads return a URL to redirect to instead of the
+    /// bytes, see [`MediaStore::presigned_read`].
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Validated content hash of the blob
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((MediaAsset, MediaContent))` - The blob metadata and where to
+    ///   read the blob from
+    /// * `Err(MediaError::NotFound)` - No blob is recorded or stored under the hash
+    /// * `Err(SomeError)` - Database or storage error
+    pub async fn get_media(&self, hash: &MediaHash) -> Result<(MediaAsset, MediaContent)> {
+        let not_found = || MediaError::NotFound(hash.to_string());
+
+        let asset = self
+            .db_repo
+            .get_media_asset(hash)
+            .await?
+            .ok_or_else(not_found)?;
+
+        if let Some(redirect) = self.media_store.presigned_read(hash).await? {
+            return Ok((asset, redirect));
+        }
+
+        let bytes = self.media_store.get(hash).await?.ok_or_else(not_found)?;
+
+        Ok((asset, MediaContent::Bytes(bytes)))
+    }
+
+    /// Delete the media no article references any more
+    ///
+    /// Runs after every push. Rows are deleted first, then the blobs; a blob
+    /// that can't be deleted is logged and left behind, never served again.
+    /// The rows are only gone once the blobs are: an ingestion looking up the
+    /// same image before then reuses it and fails its check under the media
+    /// lock, one looking it up afterwards stores the blob again.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(usize)` - Number of originals and variants deleted
+    /// * `Err(SomeError)` - The unreferenced media could not be deleted
+    pub async fn collect_media_garbage(&self) -> Result<usize> {
+        let mut tx = self.db_repo.begin_transaction().await?;
+        tx.lock_media().await?;
+        let hashes = tx.delete_unreferenced_media().await?;
+
+        for hash in &hashes {
+            if let Err(e) = self.media_store.delete(hash).await {
+                tracing::warn!("Failed to delete media blob {}: {}", hash, e);
+            }
+        }
+        tx.commit().await?;
+
+        if !hashes.is_empty() {
+            tracing::info!("Deleted {} unreferenced media blobs", hashes.len());
+        }
+
+        Ok(hashes.len())
+    }
+
+    /// Describe an ingested image and its responsive variants
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Validated content hash of the original image
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MediaInfoResponse)` - Dimensions, blurhash and variant URLs
+    /// * `Err(MediaError::NotFound)` - No original is recorded under the hash
+    ///   (variants have no info of their own)
+    /// * `Err(SomeError)` - Database error
+    pub async fn get_media_info(&self, hash: &MediaHash) -> Result<MediaInfoResponse> {
+        let original = self
+            .db_repo
+            .get_media_asset(hash)
+            .await?
+            .filter(|asset| asset.original.is_none())
+            .ok_or_else(|| MediaError::NotFound(hash.to_string()))?;
+        let variants = self.db_repo.get_media_variants(hash).await?;
+        let options = self.config.media.read().await;
+
+        Ok(MediaInfoResponse::new(original, variants, &options))
+    }
+
+    /// List the articles linking to an article
+    ///
+    /// Links are recorded at ingestion from resolved relative links to
+    /// article sources and `[[wiki-style]]` references.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Validated unique identifier of the linked article
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<LinkedArticle>)` - Linking articles, newest first
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+    /// * `Err(SomeError)` - Other error occurred during retrieval
+    pub async fn get_backlinks(&self, id: &ArticleId) -> Result<Vec<LinkedArticle>> {
+        let backlinks = self.db_repo.get_backlinks(id).await?;
+
+        Ok(backlinks.into_iter().map(LinkedArticle::from).collect())
+    }
+
+    /// Retrieve the whole internal link graph
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(GraphResponse)` - Every published article as a node and every
+    ///   link between them as an edge
+    /// * `Err(SomeError)` - An error occurred during retrieval
+    pub async fn get_link_graph(&self) -> Result<GraphResponse> {
+        let nodes = self.db_repo.get_published_link_targets().await?;
+        let edges = self.db_repo.get_link_edges().await?;
+
+        Ok(GraphResponse {
+            nodes: nodes.into_iter().map(LinkedArticle::from).collect(),
+            edges,
+        })
+    }
+
+    /// Recommend the posts most related to an article
+    ///
+    /// Published posts are scored by shared tags, same series, same category
+    /// and the overlap of title and summary terms (see
+    /// [`rank_related`]). Results are cached per article and recomputed
+    /// when the published articles change, which is detected by comparing a
+    /// cheap [`ContentVersion`] query against the one the cache was built for.
+    /// Drafts are left out of that version, so their recommendations are
+    /// computed on every request instead of cached.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - Validated unique identifier of the article
+    /// * `limit` - Maximum number of posts, capped at `MAX_RELATED_POSTS`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<RelatedPost>)` - Related posts, most related first (may be empty)
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+    /// * `Err(SomeError)` - Other error occurred during retrieval
+    pub async fn get_related_posts(
+        &self,
+        id: &ArticleId,
+        limit: usize,
+    ) -> Result<Vec<RelatedPost>> {
+        let limit = limit.min(MAX_RELATED_POSTS);
+        let version = self.db_repo.get_content_version().await?;
+
+        {
+            let cache = self.related_cache.read().await;
+            if cache.version == Some(version)
+                && let Some(posts) = cache.posts.get(id)
+            {
+                return Ok(posts.iter().take(limit).cloned().collect());
+            }
+        }
+
+        let candidates = self.db_repo.get_related_candidates().await?;
+        let Some(article) = candidates.iter().find(|c| c.id == *id) else {
+            // Drafts still get recommendations, but never appear in them
+            let article = self.db_repo.get_post_by_id(id).await?;
+            let draft = RelatedCandidate {
+                id: article.id,
+                title: article.title,
+                category: article.category,
+                tags: article.tags,
+                series: article.series,
+                summary: article.summary,
+                created_at: article.created_at,
+            };
+
+            return Ok(rank_related(&draft, &candidates, limit)
+                .into_iter()
+                .map(RelatedPost::from)
+                .collect());
+        };
+
+        let posts: Vec<RelatedPost> = rank_related(article, &candidates, MAX_RELATED_POSTS)
+            .into_iter()
+            .map(RelatedPost::from)
+            .collect();
+
+        let mut cache = self.related_cache.write().await;
+        if cache.version != Some(version) {
+            cache.version = Some(version);
+            cache.posts.clear();
+        }
+        cache.posts.insert(*id, posts.clone());
+
+        Ok(posts.into_iter().take(limit).collect())
+    }
+
+    /// List the published parts of a series in reading order
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Series name as written in front matter
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(SeriesResponse)` - The series with its ordered parts
+    /// * `Err(GetPostsError::SeriesNotFound)` - No published article belongs
+    ///   to the series
+    /// * `Err(SomeError)` - Error occurred during database query
+    pub async fn get_series(&self, name: &str) -> Result<SeriesResponse> {
+        let name = name.trim();
+        let parts = self.db_repo.get_series_parts(name).await?;
+
+        if parts.is_empty() {
+            return Err(GetPostsError::SeriesNotFound(name.to_string()).into());
+        }
+
+        Ok(SeriesResponse {
+            name: name.to_string(),
+            total: parts.len(),
+            parts,
+        })
+    }
+
+    /// Perform full-text search on articles
+    ///
+    /// Searches articles using the search service (Meilisearch) with support
+    /// for pagination, highlighting, and relevance ranking.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Name of the search index to query (typically "articles")
+    /// * `query` - Search query string
+    /// * `language` - Primary language to search, see [`Self::resolve_language`]
+    /// * `current_page` - Current page number (1-based)
+    /// * `limit` - Number of results per page
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing:
+    /// * `Vec<SearchHit>` - List of search results with highlighting
+    /// * `usize` - Total number of matching articles
+    /// * `usize` - Total number of pages
+    /// * `usize` - Current page number
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let (results, total, pages, current) =
+    ///     service.search("articles", "rust programming", None, 1, 10).await?;
+    ///
+    /// println!("Found {} results across {} pages", total, pages);
+    /// for hit in results {
+    ///     println!("{}: {}", hit.id, hit.title);
+    /// }
+    /// ```
+    pub async fn search(
+        &self,
+        index: &str,
+        query: &str,
+        language: Option<&str>,
+        current_page: usize,
+        limit: usize,
     ) -> Result<(Vec<SearchHit>, usize, usize, usize)> {
         self.search_service
-            .search(query, index, current_page, limit)
+            .search(query, index, language, current_page, limit)
             .await
     }
 
     /// Create a search index and populate it with existing articles
     ///
     /// This is typically used during initial setup or when rebuilding the
-    /// search index from scratch. It creates a new index with the specified
-    /// searchable attributes and imports all articles from the database.
+    /// search index from scratch. It creates a new index searching
+    /// [`SEARCHABLE_ATTRIBUTES`], filterable by [`FILTERABLE_ATTRIBUTES`], and
+    /// imports all articles from the database, together with the camera
+    /// metadata of their gallery images and their primary language.
     ///
     /// # Arguments
     ///
     /// * `index` - Name of the search index to create
-    /// * `searchable_attributes` - Fields that should be searchable (e.g., ["title", "content"])
     ///
     /// # Returns
     ///
@@ -474,50 +2452,215 @@ impl ArticleService {
     ///
     /// # Example
     ///
-    /// ```rust
-    /// service.create_index("articles", &["title", "summary", "content"]).await?;
+    /// ```rust,ignore
+    /// service.create_index("articles").await?;
     /// ```
     pub async fn create_index(&self, index: &str) -> Result<()> {
-        let searchable_attributes = ["title", "content", "summary"];
-
         // Create the index with specified searchable attributes
         let client = self
             .search_service
-            .create_index_client(index, &searchable_attributes)
+            .create_index_client(index, SEARCHABLE_ATTRIBUTES, FILTERABLE_ATTRIBUTES)
             .await?;
 
         // Fetch all articles from database
         let db_articles = self.db_repo.get_all().await?;
+        let documents: Vec<ArticleDocument> =
+            db_articles.iter().map(ArticleDocument::from).collect();
 
-        // Import all articles into the search index
+        // Import all articles, with their gallery metadata, into the search index
         client
             .index(index)
-            .add_documents(&db_articles, Some("id"))
+            .add_documents(&documents, Some("id"))
             .await?
-            .wait_for_completion(&client, None, None)
+            .wait_for_completion(client, None, None)
             .await?;
 
         Ok(())
     }
 }
 
+/// Parse a front matter block with the engine matching its format
+#[cfg(feature = "webhook")]
+fn parse_front_matter<E: Engine>(
+    format: FrontMatterFormat,
+    content: &str,
+) -> gray_matter::Result<ParsedEntity<serde_json::Value>> {
+    let mut matter = Matter::<E>::new();
+    matter.delimiter = format.delimiter().to_string();
+
+    matter.parse(content)
+}
+
+/// Parse a data file (authors, collections) with the engine matching its format
+#[cfg(feature = "webhook")]
+fn parse_data_file(format: FrontMatterFormat, content: &str) -> Result<serde_json::Value> {
+    let pod = match format {
+        FrontMatterFormat::Yaml => YAML::parse(content),
+        FrontMatterFormat::Toml => TOML::parse(content),
+        FrontMatterFormat::Json => JSON::parse(content),
+    }
+    .map_err(|e| anyhow::anyhow!("Invalid {} data file: {}", format, e))?;
+
+    let data = pod
+        .deserialize()
+        .map_err(|e| anyhow::anyhow!("Invalid data file: {}", e))?;
+
+    Ok(data)
+}
+
+/// Author of the last commit that changed each file
+#[cfg(feature = "webhook")]
+fn commit_authors(changes: &[FileChange]) -> HashMap<&str, &CommitAuthor> {
+    // Commits are listed oldest first, so later changes replace earlier ones
+    changes
+        .iter()
+        .map(|c| (c.file_path.as_str(), &c.author))
+        .collect()
+}
+
+/// Attribute a new post without `authors` to the author of its last commit
+///
+/// Nothing is recorded if the commit author matches no profile. Changed
+/// posts keep their stored byline instead, see
+/// [`ArticleService::keep_stored_authors`].
 #[cfg(feature = "webhook")]
+fn attribute_to_committer(
+    article: &mut Article,
+    committer: Option<&CommitAuthor>,
+    authors: &[Author],
+) {
+    if article.authors.is_empty()
+        && let Some(id) = committer.and_then(|c| c.author_id(authors))
+    {
+        article.authors.push(id);
+    }
+}
+
+/// Locate `current` within the ordered parts of its series
+///
+/// Returns `None` if the article is not among the parts, either because it
+/// is a draft or because the series changed between the two queries.
+fn build_series_navigation(
+    series: &str,
+    mut parts: Vec<SeriesPart>,
+    current: &ArticleId,
+) -> Option<SeriesNavigation> {
+    let index = parts.iter().position(|p| &p.id == current)?;
+    let total = parts.len();
+
+    let next = (index + 1 < total).then(|| parts.remove(index + 1));
+    let previous = (index > 0).then(|| parts.swap_remove(index - 1));
+
+    Some(SeriesNavigation {
+        name: series.to_string(),
+        position: index + 1,
+        total,
+        previous,
+        next,
+    })
+}
+
+/// Nest monthly post counts under their years
+///
+/// Expects the counts ordered newest first, as returned by the repository.
+fn group_archive_counts(counts: Vec<ArchiveMonthCount>) -> Vec<ArchiveYear> {
+    let mut years: Vec<ArchiveYear> = Vec::new();
+
+    for ArchiveMonthCount { year, month, count } in counts {
+        let month = ArchiveMonth { month, count };
+        match years.last_mut() {
+            Some(last) if last.year == year => {
+                last.count += count;
+                last.months.push(month);
+            }
+            _ => years.push(ArchiveYear {
+                year,
+                count,
+                months: vec![month],
+            }),
+        }
+    }
+
+    years
+}
+
+/// Keep the front matter summary, or generate one from the content if it is
+/// missing or blank
+///
+/// Returns the summary and whether it was generated.
+fn summary_or_generated(
+    summary: Option<String>,
+    content: &str,
+    options: &SummaryOptions,
+) -> (Option<String>, bool) {
+    match summary.filter(|s| !s.trim().is_empty()) {
+        Some(summary) => (Some(summary), false),
+        None => {
+            let generated = generate_summary(content, options);
+            let is_generated = generated.is_some();
+            (generated, is_generated)
+        }
+    }
+}
+
+/// Compute the stored word count and reading time from content statistics
+fn reading_stats(stats: &ContentStats, speeds: &ReadingSpeeds) -> (i32, i32) {
+    let word_count = i32::try_from(stats.word_count()).unwrap_or(i32::MAX);
+    let reading_minutes = i32::try_from(speeds.reading_minutes(stats)).unwrap_or(i32::MAX);
+
+    (word_count, reading_minutes)
+}
+
 fn build_article(
     front_matter: ArticleFrontMatter,
     path: String,
     content: String,
+    speeds: &ReadingSpeeds,
+    summary_options: &SummaryOptions,
     create_at: OffsetDateTime,
     update_at: OffsetDateTime,
 ) -> Article {
+    let rendered = render(&content);
+    let (word_count, reading_minutes) = reading_stats(&rendered.stats, speeds);
+    let (summary, summary_generated) =
+        summary_or_generated(front_matter.summary, &content, summary_options);
+
     Article {
         id: front_matter.id,
-        path: path,
+        path,
         title: front_matter.title,
         tags: front_matter.tags,
         category: front_matter.category,
-        summary: front_matter.summary,
-        content: content,
+        summary,
+        summary_generated,
+        content_html: rendered.html,
+        toc: Some(rendered.toc),
+        word_count: Some(word_count),
+        reading_minutes: Some(reading_minutes),
+        content,
         status: front_matter.status,
+        // Blank series names are treated as "not part of a series"
+        series: front_matter
+            .series
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty()),
+        series_order: front_matter.series_order,
+        description: front_matter.description,
+        cover_image: front_matter.cover_image,
+        canonical_url: front_matter.canonical_url,
+        language: front_matter.language,
+        translation_key: front_matter.translation_key,
+        license: front_matter.license,
+        author: front_matter.author,
+        authors: front_matter.authors,
+        pinned: front_matter.pinned,
+        pinned_weight: front_matter.pinned_weight,
+        featured: front_matter.featured,
+        featured_weight: front_matter.featured_weight,
+        images: front_matter.images,
+        keep_location: front_matter.keep_location,
+        source: ArticleSource::Git,
+        extra: front_matter.extra,
         created_at: create_at,
         updated_at: update_at,
     }
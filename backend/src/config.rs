@@ -3,10 +3,21 @@ use std::collections::HashSet;
 
 use config::{Config as ConfigLoader, Environment, File};
 use serde::Deserialize;
-#[cfg(feature = "webhook")]
 use tokio::sync::RwLock;
 
-use crate::errors::Result;
+use crate::{
+    domain::{
+        categories::{Category, CategoryRegistry},
+        collections::CollectionOptions,
+        media::MediaOptions,
+        micropub::MicropubOptions,
+        notebooks::NotebookOptions,
+        reading::ReadingSpeeds,
+        summary::SummaryOptions,
+        tags::{TagNormalizer, TagSynonym},
+    },
+    errors::Result,
+};
 
 /// Application configuration structure
 ///
@@ -25,6 +36,15 @@ use crate::errors::Result;
 /// * `jwt_secret` - Secret key for JWT token generation and validation
 /// * `host` - Optional server host address (defaults to 0.0.0.0 if not set)
 /// * `port` - Optional server port number (defaults to 8124 if not set)
+/// * `categories` - Content categories (defaults to the built-in set if empty)
+/// * `tag_synonyms` - Groups of tag spellings merged into one canonical tag
+/// * `reading` - Reading speeds used to estimate reading time
+/// * `summary` - Options for summaries generated when front matter has none
+/// * `media` - Media store location and responsive image settings
+/// * `micropub` - Category and site URL of posts created through Micropub
+/// * `micropub_token` - Access token of the Micropub endpoint (disabled if not set)
+/// * `collections` - Directory and schemas of the data collections
+/// * `notebooks` - Limits on the cell outputs of ingested Jupyter notebooks
 /// * `github_webhook_secret` - Secret for validating GitHub webhook signatures (webhook feature only)
 /// * `allowed_repositories` - Set of repository names allowed to trigger webhooks (webhook feature only)
 /// * `github_token` - GitHub personal access token for API access (webhook feature only)
@@ -52,6 +72,45 @@ pub struct Config {
     /// If not provided, defaults to 8124
     pub port: Option<u16>,
 
+    /// Content categories declared as `[[categories]]` in config.toml
+    /// If empty, the built-in categories (article, note, think, pictures, talk) are used
+    #[serde(default)]
+    pub categories: Vec<Category>,
+
+    /// Tag synonym groups declared as `[[tag_synonyms]]` in config.toml
+    #[serde(default)]
+    pub tag_synonyms: Vec<TagSynonym>,
+
+    /// Reading speeds declared as `[reading]` in config.toml
+    /// Omitted fields fall back to their defaults
+    #[serde(default)]
+    pub reading: ReadingSpeeds,
+
+    /// Summary generation options declared as `[summary]` in config.toml
+    #[serde(default)]
+    pub summary: SummaryOptions,
+
+    /// Image pipeline settings declared as `[media]` in config.toml
+    #[serde(default)]
+    pub media: MediaOptions,
+
+    /// Micropub settings declared as `[micropub]` in config.toml
+    #[serde(default)]
+    pub micropub: MicropubOptions,
+
+    /// Access token Micropub clients must present
+    /// If not set, the Micropub endpoint rejects every request
+    #[serde(default)]
+    pub micropub_token: Option<String>,
+
+    /// Data collection settings declared as `[collections]` in config.toml
+    #[serde(default)]
+    pub collections: CollectionOptions,
+
+    /// Notebook output limits declared as `[notebooks]` in config.toml
+    #[serde(default)]
+    pub notebooks: NotebookOptions,
+
     /// GitHub webhook secret for signature verification
     /// Only available when the "webhook" feature is enabled
     #[cfg(feature = "webhook")]
@@ -92,7 +151,7 @@ impl Config {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// use backend::config::Config;
     ///
     /// let config = Config::new().expect("Failed to load configuration");
@@ -122,12 +181,41 @@ impl Config {
 /// Unlike `Config`, which is loaded once at startup, `AppConfig` can be modified
 /// during application execution (particularly for webhook-related settings).
 ///
-/// Fields that may change at runtime (like `allowed_repositories`) are wrapped
-/// in `RwLock` to allow safe concurrent access and modification.
+/// Fields that may change at runtime (like `allowed_repositories` and
+/// `categories`) are wrapped in `RwLock` to allow safe concurrent access and
+/// modification.
 pub struct AppConfig {
     /// JWT secret key for token operations
     pub jwt_secret: String,
 
+    /// Configured content categories, wrapped in RwLock for runtime updates
+    pub categories: RwLock<CategoryRegistry>,
+
+    /// Tag normalizer with configured synonyms, wrapped in RwLock for runtime updates
+    pub tags: RwLock<TagNormalizer>,
+
+    /// Reading speeds for reading time estimates, wrapped in RwLock for runtime updates
+    pub reading: RwLock<ReadingSpeeds>,
+
+    /// Summary generation options, wrapped in RwLock for runtime updates
+    pub summary: RwLock<SummaryOptions>,
+
+    /// Image pipeline settings, wrapped in RwLock for runtime updates of
+    /// everything but the store location
+    pub media: RwLock<MediaOptions>,
+
+    /// Micropub settings, wrapped in RwLock for runtime updates
+    pub micropub: RwLock<MicropubOptions>,
+
+    /// Access token of the Micropub endpoint, `None` if it is disabled
+    pub micropub_token: Option<String>,
+
+    /// Data collection settings, wrapped in RwLock for runtime updates
+    pub collections: RwLock<CollectionOptions>,
+
+    /// Notebook output limits, wrapped in RwLock for runtime updates
+    pub notebooks: RwLock<NotebookOptions>,
+
     /// GitHub webhook secret for signature verification
     #[cfg(feature = "webhook")]
     pub github_webhook_secret: String,
@@ -148,6 +236,15 @@ impl AppConfig {
     /// # Arguments
     ///
     /// * `jwt_secret` - Secret key for JWT operations
+    /// * `categories` - Validated registry of content categories
+    /// * `tags` - Tag normalizer built from the configured synonyms
+    /// * `reading` - Validated reading speeds
+    /// * `summary` - Validated summary generation options
+    /// * `media` - Validated image pipeline settings
+    /// * `micropub` - Validated Micropub settings
+    /// * `micropub_token` - Access token of the Micropub endpoint, if enabled
+    /// * `collections` - Validated data collection settings
+    /// * `notebooks` - Validated notebook output limits
     /// * `github_webhook_secret` - GitHub webhook verification secret (webhook feature only)
     /// * `github_token` - GitHub API access token (webhook feature only)
     /// * `allowed_repositories` - Initial set of allowed repositories (webhook feature only)
@@ -160,10 +257,24 @@ impl AppConfig {
     ///
     /// ```rust
     /// use backend::config::AppConfig;
+    /// use backend::domain::{
+    ///     categories::CategoryRegistry, collections::CollectionOptions, media::MediaOptions,
+    ///     micropub::MicropubOptions, notebooks::NotebookOptions, reading::ReadingSpeeds,
+    ///     summary::SummaryOptions, tags::TagNormalizer,
+    /// };
     /// use std::collections::HashSet;
     ///
     /// let config = AppConfig::new(
     ///     "my_jwt_secret",
+    ///     CategoryRegistry::default(),
+    ///     TagNormalizer::default(),
+    ///     ReadingSpeeds::default(),
+    ///     SummaryOptions::default(),
+    ///     MediaOptions::default(),
+    ///     MicropubOptions::default(),
+    ///     Some("my_micropub_token"),
+    ///     CollectionOptions::default(),
+    ///     NotebookOptions::default(),
     ///     #[cfg(feature = "webhook")]
     ///     "my_webhook_secret",
     ///     #[cfg(feature = "webhook")]
@@ -172,14 +283,37 @@ impl AppConfig {
     ///     HashSet::new()
     /// );
     /// ```
+    // One argument per runtime setting; the webhook settings are feature gated
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         jwt_secret: &str,
+        categories: CategoryRegistry,
+        tags: TagNormalizer,
+        reading: ReadingSpeeds,
+        summary: SummaryOptions,
+        media: MediaOptions,
+        micropub: MicropubOptions,
+        micropub_token: Option<&str>,
+        collections: CollectionOptions,
+        notebooks: NotebookOptions,
         #[cfg(feature = "webhook")] github_webhook_secret: &str,
         #[cfg(feature = "webhook")] github_token: &str,
         #[cfg(feature = "webhook")] allowed_repositories: HashSet<String>,
     ) -> Self {
         Self {
             jwt_secret: jwt_secret.to_string(),
+            categories: RwLock::new(categories),
+            tags: RwLock::new(tags),
+            reading: RwLock::new(reading),
+            summary: RwLock::new(summary),
+            media: RwLock::new(media),
+            micropub: RwLock::new(micropub),
+            // An empty token would let anyone post
+            micropub_token: micropub_token
+                .filter(|token| !token.is_empty())
+                .map(str::to_string),
+            collections: RwLock::new(collections),
+            notebooks: RwLock::new(notebooks),
             #[cfg(feature = "webhook")]
             github_webhook_secret: github_webhook_secret.to_string(),
             #[cfg(feature = "webhook")]
@@ -1,3 +1,24 @@
+pub mod archives;
 pub mod articles;
+pub mod asciidoc;
+pub mod authors;
+pub mod categories;
+pub mod collections;
+pub mod exif;
+pub mod front_matter;
+pub mod gallery;
+pub mod ingestion;
+pub mod languages;
+pub mod links;
+pub mod media;
+pub mod micropub;
+pub mod notebooks;
+pub mod org;
+pub mod reading;
+pub mod related;
 pub mod repositories;
 pub mod search;
+pub mod source_formats;
+pub mod summary;
+pub mod tags;
+pub mod toc;
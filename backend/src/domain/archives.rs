@@ -0,0 +1,81 @@
+use time::{Date, Month, OffsetDateTime};
+
+use crate::errors::{GetPostsError, Result};
+
+/// Default number of posts per page for `/archives/{year}/{month}`
+pub const DEFAULT_ARCHIVE_PAGE_SIZE: i64 = 20;
+
+/// Number of published posts created in one month
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveMonthCount {
+    /// Calendar year (UTC)
+    pub year: i32,
+
+    /// Month of the year, 1 to 12 (UTC)
+    pub month: i32,
+
+    /// Number of posts created in the month
+    pub count: i64,
+}
+
+/// A calendar month of the archive
+///
+/// Months are in UTC, matching how `created_at` is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchivePeriod {
+    year: i32,
+    month: Month,
+}
+
+impl ArchivePeriod {
+    /// Validate a year and month taken from the request path
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetPostsError::InvalidArchivePeriod` if the month is not
+    /// between 1 and 12 or the year is outside 1..=9999.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::archives::ArchivePeriod;
+    ///
+    /// let period = ArchivePeriod::new(2025, 12).unwrap();
+    /// let (start, end) = period.bounds();
+    /// assert_eq!(start.to_string(), "2025-12-01 0:00:00.0 +00:00:00");
+    /// assert_eq!(end.to_string(), "2026-01-01 0:00:00.0 +00:00:00");
+    ///
+    /// assert!(ArchivePeriod::new(2025, 13).is_err());
+    /// assert!(ArchivePeriod::new(0, 1).is_err());
+    /// ```
+    pub fn new(year: i32, month: u8) -> Result<Self> {
+        let invalid = || GetPostsError::InvalidArchivePeriod { year, month };
+
+        if !(1..=9999).contains(&year) {
+            return Err(invalid().into());
+        }
+        let month = Month::try_from(month).map_err(|_| invalid())?;
+
+        Ok(Self { year, month })
+    }
+
+    /// Start (inclusive) and end (exclusive) of the month
+    ///
+    /// Used as a half-open `created_at` range so the lookup can walk
+    /// `idx_articles_created_at`.
+    pub fn bounds(&self) -> (OffsetDateTime, OffsetDateTime) {
+        let first_day = |year, month| {
+            Date::from_calendar_date(year, month, 1)
+                .expect("the first day of a month is always valid")
+                .midnight()
+                .assume_utc()
+        };
+
+        let end = match self.month {
+            Month::December => first_day(self.year + 1, Month::January),
+            month => first_day(self.year, month.next()),
+        };
+
+        (first_day(self.year, self.month), end)
+    }
+}
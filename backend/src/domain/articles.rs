@@ -1,8 +1,98 @@
 use serde::{Deserialize, Serialize};
-use sqlx::prelude::FromRow;
-use std::str::FromStr;
+use serde_json::{Map, Value};
+use sqlx::{prelude::FromRow, types::Uuid};
+use std::{fmt, str::FromStr};
 use time::OffsetDateTime;
 
+use crate::domain::{gallery::GalleryImage, toc::TocEntry};
+use crate::errors::GetPostsError;
+
+/// Validated unique identifier of an article
+///
+/// The `articles.id` column is a `UUID`, so every id entering the system
+/// (front matter, URL path parameters, repository lookups) is parsed into this
+/// newtype at the boundary. Malformed ids are rejected with
+/// `GetPostsError::InvalidArticleId` instead of reaching the database.
+///
+/// # Serialization
+///
+/// Serialized as the hyphenated UUID string in JSON and search documents, and
+/// stored as a native `UUID` in PostgreSQL.
+///
+/// # Examples
+///
+/// ```
+/// use backend::domain::articles::ArticleId;
+///
+/// let id: ArticleId = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+/// assert_eq!(id.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+///
+/// assert!("not-a-uuid".parse::<ArticleId>().is_err());
+/// ```
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type,
+)]
+#[serde(try_from = "String", into = "String")]
+#[sqlx(transparent)]
+pub struct ArticleId(Uuid);
+
+impl ArticleId {
+    /// Generate a random id for a post that has no front matter to take
+    /// one from
+    pub fn new_random() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    /// Wrap an already validated UUID
+    pub fn from_uuid(uuid: Uuid) -> Self {
+        Self(uuid)
+    }
+
+    /// Access the underlying UUID, e.g. for binding UUID arrays in queries
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl FromStr for ArticleId {
+    type Err = GetPostsError;
+
+    /// Parse an article id, accepting any textual UUID representation
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetPostsError::InvalidArticleId` carrying the rejected input
+    /// and the reason reported by the UUID parser.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s.trim())
+            .map(Self)
+            .map_err(|e| GetPostsError::InvalidArticleId {
+                id: s.to_string(),
+                reason: e.to_string(),
+            })
+    }
+}
+
+impl TryFrom<String> for ArticleId {
+    type Error = GetPostsError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<ArticleId> for String {
+    fn from(id: ArticleId) -> Self {
+        id.to_string()
+    }
+}
+
+impl fmt::Display for ArticleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.hyphenated().fmt(f)
+    }
+}
+
 /// Article domain entity representing a blog post or article
 ///
 /// This struct maps directly to the `articles` table in the database
@@ -11,39 +101,166 @@ use time::OffsetDateTime;
 /// # Fields
 ///
 /// * `id` - Unique identifier for the article
-/// * `title` - Article title
+/// * `title` - Article title, empty for untitled posts
 /// * `tags` - List of tags associated with the article
-/// * `category` - Category classification (article, note, think, etc.)
+/// * `category` - Category slug, one of the configured categories
 /// * `summary` - Brief summary or excerpt of the article
+/// * `summary_generated` - Whether `summary` was generated from the content
 /// * `content` - Full article content (markdown format)
+/// * `content_html` - Sanitized HTML rendered from `content` at ingestion
+/// * `toc` - Heading tree extracted from `content` at ingestion
+/// * `word_count` - Number of words (CJK characters count individually)
+/// * `reading_minutes` - Estimated reading time in minutes
 /// * `status` - Publication status (draft, published, archived, etc.)
+/// * `series` - Name of the multi-part series the article belongs to, if any
+/// * `series_order` - Explicit position of the article within its series
+/// * `description` - Meta description for previews and SEO
+/// * `cover_image` - Cover image path or URL
+/// * `canonical_url` - Canonical URL if the article was first published elsewhere
+/// * `language` - Language tag of the content (e.g., "en", "zh-CN")
+/// * `translation_key` - Key shared by the translations of the same post
+/// * `license` - License of the content (e.g., "CC BY-SA 4.0")
+/// * `author` - Author name
+/// * `authors` - Ids of the author profiles, in byline order
+/// * `pinned` - Whether the article is pinned
+/// * `pinned_weight` - Order among pinned articles, lower first
+/// * `featured` - Whether the article is featured on the home page
+/// * `featured_weight` - Order among featured articles, lower first
+/// * `images` - Gallery images declared in front matter, in order
+/// * `keep_location` - Whether photos keep their GPS position
+/// * `source` - Where the article was authored (repository or Micropub)
+/// * `extra` - Free-form metadata from front matter
 /// * `created_at` - Timestamp when the article was created
 /// * `updated_at` - Timestamp when the article was last modified
 #[derive(Debug, Clone, Deserialize, Serialize, FromRow)]
 pub struct Article {
     /// Unique identifier for the article
-    pub id: String,
+    pub id: ArticleId,
 
     pub path: String,
 
-    /// Article title
+    /// Article title, empty for posts of categories where it is optional
     pub title: String,
 
     /// List of tags for categorization and search
     pub tags: Vec<String>,
 
-    /// Article category (article, note, think, pictures, talk)
+    /// Article category slug (e.g., article, note, think)
     pub category: PostCategory,
 
     /// Brief summary or excerpt of the article
     pub summary: Option<String>,
 
+    /// Whether `summary` was generated from the content because the front
+    /// matter had none
+    pub summary_generated: bool,
+
     /// Full article content in markdown format
     pub content: String,
 
+    /// Sanitized HTML rendered from `content` at ingestion
+    ///
+    /// Kept out of serialized output (and therefore out of search documents);
+    /// the detail endpoint exposes it on request as `content_html`.
+    #[serde(default, skip_serializing)]
+    pub content_html: String,
+
+    /// Heading tree extracted from `content` at ingestion
+    ///
+    /// `None` for articles stored before TOC extraction was introduced.
+    /// Exposed through the detail response and the `/toc` endpoint rather
+    /// than serialized with the article.
+    #[sqlx(json(nullable))]
+    #[serde(default, skip_serializing)]
+    pub toc: Option<Vec<TocEntry>>,
+
+    /// Number of words in the prose, with CJK characters counted individually
+    ///
+    /// `None` for articles stored before reading statistics were computed.
+    pub word_count: Option<i32>,
+
+    /// Estimated reading time in minutes, based on the configured reading speeds
+    pub reading_minutes: Option<i32>,
+
     /// Publication status (e.g., "draft", "published", "archived")
     pub status: String,
 
+    /// Name of the series this article is a part of
+    pub series: Option<String>,
+
+    /// Position within the series; parts without one follow the numbered
+    /// parts in creation order
+    pub series_order: Option<i32>,
+
+    /// Meta description for link previews and SEO
+    pub description: Option<String>,
+
+    /// Cover image path or URL
+    pub cover_image: Option<String>,
+
+    /// Canonical URL if the article was first published elsewhere
+    pub canonical_url: Option<String>,
+
+    /// Language tag of the content (e.g., "en", "zh-CN"), from the `lang`
+    /// front matter field
+    pub language: Option<String>,
+
+    /// Key shared by the versions of the same post in different languages
+    ///
+    /// The detail endpoint links an article to the other articles with the
+    /// same key as its `translations`.
+    pub translation_key: Option<String>,
+
+    /// License of the content (e.g., "CC BY-SA 4.0")
+    pub license: Option<String>,
+
+    /// Author name
+    pub author: Option<String>,
+
+    /// Ids of the authors, in byline order
+    ///
+    /// Taken from the `authors` front matter field, or the author of the
+    /// commit that last changed the file. Exposed as embedded profiles
+    /// (see `domain::authors::Author`) rather than serialized as ids.
+    #[serde(default, skip_serializing)]
+    pub authors: Vec<String>,
+
+    /// Whether the article is pinned to the top of its category listing
+    pub pinned: bool,
+
+    /// Order among pinned articles, lower first; unweighted ones come last
+    pub pinned_weight: Option<i32>,
+
+    /// Whether the article is featured in the home page hero section
+    pub featured: bool,
+
+    /// Order among featured articles, lower first; unweighted ones come last
+    pub featured_weight: Option<i32>,
+
+    /// Gallery images declared in front matter, in display order
+    ///
+    /// Image paths are resolved to repository paths at ingestion. Exposed
+    /// through the `/gallery` endpoint rather than serialized with the article.
+    #[sqlx(json)]
+    #[serde(default, skip_serializing)]
+    pub images: Vec<GalleryImage>,
+
+    /// Whether the images of the article keep their GPS position
+    ///
+    /// By default the location is removed from served image files and not
+    /// exposed in the gallery.
+    pub keep_location: bool,
+
+    /// Where the article was authored
+    ///
+    /// Micropub posts exist only in the database: pushes never touch them and
+    /// the Micropub endpoint refuses to change posts from the repository.
+    pub source: ArticleSource,
+
+    /// Free-form metadata from the `extra` front matter field
+    #[sqlx(json)]
+    pub extra: Map<String, Value>,
+
     /// Timestamp when the article was created
     pub created_at: OffsetDateTime,
 
@@ -51,20 +268,38 @@ pub struct Article {
     pub updated_at: OffsetDateTime,
 }
 
+/// Origin of an article
+///
+/// Stored as TEXT in `articles.source` and serialized as the lowercase name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[serde(rename_all = "lowercase")]
+#[sqlx(type_name = "text", rename_all = "lowercase")]
+pub enum ArticleSource {
+    /// Markdown file ingested from the content repository
+    #[default]
+    Git,
+
+    /// Entry created through the Micropub endpoint
+    Micropub,
+}
+
 /// Front matter structure for articles loaded from markdown files
 ///
 /// This struct is used when processing webhook events that include
 /// markdown files with YAML front matter. It represents the metadata
 /// extracted from the front matter section.
 ///
-/// Only available when the "webhook" feature is enabled.
-#[cfg(feature = "webhook")]
-#[derive(Debug, Clone, Deserialize)]
+/// It is built by `domain::front_matter::validate_front_matter`, which
+/// reports field-level errors instead of failing on the first problem.
+#[derive(Debug, Clone, PartialEq)]
 pub struct ArticleFrontMatter {
-    /// Unique identifier from front matter
-    pub id: String,
+    /// Unique identifier from front matter, validated as a UUID
+    pub id: ArticleId,
 
-    /// Article title from front matter
+    /// Article title from front matter, empty if left out
+    ///
+    /// Whether a title is required depends on the category, see
+    /// `Category::optional_title`.
     pub title: String,
 
     /// List of tags from front matter
@@ -78,77 +313,107 @@ pub struct ArticleFrontMatter {
 
     /// Publication status from front matter
     pub status: String,
+
+    /// Optional series name from front matter
+    pub series: Option<String>,
+
+    /// Optional position within the series from front matter
+    pub series_order: Option<i32>,
+
+    /// Optional meta description
+    pub description: Option<String>,
+
+    /// Optional cover image path or URL
+    pub cover_image: Option<String>,
+
+    /// Optional canonical URL (absolute http(s) URL)
+    pub canonical_url: Option<String>,
+
+    /// Optional language tag, from `lang` (or its long form `language`)
+    pub language: Option<String>,
+
+    /// Optional key linking the translations of a post
+    pub translation_key: Option<String>,
+
+    /// Optional content license
+    pub license: Option<String>,
+
+    /// Optional author name
+    pub author: Option<String>,
+
+    /// Author ids (defaults to none, attributing the post to the commit author)
+    pub authors: Vec<String>,
+
+    /// Whether the article is pinned (defaults to false)
+    pub pinned: bool,
+
+    /// Optional order among pinned articles
+    pub pinned_weight: Option<i32>,
+
+    /// Whether the article is featured (defaults to false)
+    pub featured: bool,
+
+    /// Optional order among featured articles
+    pub featured_weight: Option<i32>,
+
+    /// Gallery images (defaults to none)
+    pub images: Vec<GalleryImage>,
+
+    /// Whether images keep their GPS position (defaults to false)
+    pub keep_location: bool,
+
+    /// Free-form metadata (defaults to an empty map)
+    pub extra: Map<String, Value>,
 }
 
-/// Enumeration of article categories
+/// Category slug of an article
 ///
-/// This enum represents the different types of content that can be published.
-/// Each variant corresponds to a different content category with specific
-/// characteristics and presentation styles.
+/// Categories are no longer fixed in code: the set of known categories,
+/// together with their display metadata, comes from configuration (see
+/// `domain::categories::CategoryRegistry`). This type only guarantees that the
+/// slug is well formed; whether it names a configured category is checked
+/// against the registry during ingestion and when listing posts.
 ///
-/// # Variants
+/// # Format
 ///
-/// * `Article` - Long-form articles and tutorials
-/// * `Note` - Short notes and quick thoughts
-/// * `Think` - Reflective pieces and opinions
-/// * `Pictures` - Photo galleries and image-focused content
-/// * `Talk` - Talks, presentations, and speeches
+/// A slug is 1 to 64 characters of lowercase ASCII letters, digits, `-` or
+/// `_` (e.g., "article", "note", "reading-list").
 ///
 /// # Serialization
 ///
-/// The enum is serialized to lowercase strings in both JSON and database
-/// representations (e.g., "article", "note", "think").
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, sqlx::Type)]
-#[sqlx(rename_all = "lowercase")]
-#[serde(rename_all = "lowercase")]
-pub enum PostCategory {
-    /// Long-form articles and tutorials
-    Article,
-
-    /// Short notes and quick thoughts
-    Note,
-
-    /// Reflective pieces and opinions
-    Think,
+/// Serialized as the plain slug string in JSON, search documents and the
+/// `articles.category` TEXT column.
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type,
+)]
+#[serde(try_from = "String", into = "String")]
+#[sqlx(transparent)]
+pub struct PostCategory(String);
 
-    /// Photo galleries and image-focused content
-    Pictures,
-
-    /// Talks, presentations, and speeches
-    Talk,
-}
+/// Maximum length of a category slug
+const MAX_CATEGORY_SLUG_LEN: usize = 64;
 
 impl PostCategory {
-    /// Convert the category enum to its string representation
-    ///
-    /// # Returns
-    ///
-    /// A static string slice representing the category in lowercase
+    /// Get the slug of the category
     ///
     /// # Examples
     ///
     /// ```
     /// use backend::domain::articles::PostCategory;
     ///
-    /// assert_eq!(PostCategory::Article.as_str(), "article");
-    /// assert_eq!(PostCategory::Note.as_str(), "note");
+    /// let category: PostCategory = "article".parse().unwrap();
+    /// assert_eq!(category.as_str(), "article");
     /// ```
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            PostCategory::Article => "article",
-            PostCategory::Note => "note",
-            PostCategory::Pictures => "pictures",
-            PostCategory::Talk => "talk",
-            PostCategory::Think => "think",
-        }
+    pub fn as_str(&self) -> &str {
+        &self.0
     }
 }
 
 /// Implement FromStr trait for parsing strings into PostCategory
 ///
-/// This allows converting string representations (e.g., from URL parameters)
-/// into the PostCategory enum. The conversion is case-sensitive and only
-/// accepts lowercase strings.
+/// This allows converting string representations (e.g., from URL parameters
+/// or front matter) into a category slug. The conversion is case-sensitive
+/// and only accepts lowercase slugs.
 impl FromStr for PostCategory {
     type Err = String;
 
@@ -156,12 +421,12 @@ impl FromStr for PostCategory {
     ///
     /// # Arguments
     ///
-    /// * `s` - The string to parse (must be lowercase)
+    /// * `s` - The slug to parse (must be lowercase)
     ///
     /// # Returns
     ///
-    /// * `Ok(PostCategory)` - Successfully parsed category
-    /// * `Err(String)` - Error message if the string is not a valid category
+    /// * `Ok(PostCategory)` - Successfully parsed slug
+    /// * `Err(String)` - Error message if the string is not a well-formed slug
     ///
     /// # Examples
     ///
@@ -169,24 +434,46 @@ impl FromStr for PostCategory {
     /// use std::str::FromStr;
     /// use backend::domain::articles::PostCategory;
     ///
-    /// let category = PostCategory::from_str("article").unwrap();
-    /// assert_eq!(category, PostCategory::Article);
+    /// let category = PostCategory::from_str("reading").unwrap();
+    /// assert_eq!(category.as_str(), "reading");
     ///
-    /// let invalid = PostCategory::from_str("invalid");
-    /// assert!(invalid.is_err());
+    /// assert!(PostCategory::from_str("Reading").is_err());
+    /// assert!(PostCategory::from_str("").is_err());
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "article" => Ok(PostCategory::Article),
-            "note" => Ok(PostCategory::Note),
-            "pictures" => Ok(PostCategory::Pictures),
-            "talk" => Ok(PostCategory::Talk),
-            "think" => Ok(PostCategory::Think),
-            _ => Err(format!("Invalid category: {}", s)),
+        let is_valid = !s.is_empty()
+            && s.len() <= MAX_CATEGORY_SLUG_LEN
+            && s.bytes()
+                .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_');
+
+        if is_valid {
+            Ok(PostCategory(s.to_string()))
+        } else {
+            Err(format!("Invalid category: {}", s))
         }
     }
 }
 
+impl TryFrom<String> for PostCategory {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<PostCategory> for String {
+    fn from(category: PostCategory) -> Self {
+        category.0
+    }
+}
+
+impl fmt::Display for PostCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 /// Query parameters for fetching paginated lists of articles
 ///
 /// This struct represents the query parameters that can be provided
@@ -197,12 +484,15 @@ impl FromStr for PostCategory {
 ///
 /// * `category` - Filter articles by category
 /// * `page` - Page number (1-based indexing, defaults to 1)
-/// * `page_size` - Number of items per page (defaults to 20)
+/// * `page_size` - Number of items per page (defaults to the category's
+///   configured listing page size)
+/// * `lang` - Language to list, `all` for every language (defaults to the
+///   best match for `Accept-Language`)
 ///
 /// # Example Query String
 ///
 /// ```text
-/// /posts?category=article&page=2&page_size=10
+/// /posts?category=article&page=2&page_size=10&lang=en
 /// ```
 #[derive(Deserialize, Clone)]
 pub struct PostParams {
@@ -213,21 +503,18 @@ pub struct PostParams {
     #[serde(default = "default_page")]
     pub page: i64,
 
-    /// Number of articles per page (defaults to 20)
-    #[serde(default = "default_page_size")]
-    pub page_size: i64,
+    /// Number of articles per page, falling back to the category's page size
+    #[serde(default)]
+    pub page_size: Option<i64>,
+
+    /// Language to list, negotiated from `Accept-Language` when omitted
+    #[serde(default)]
+    pub lang: Option<String>,
 }
 
 /// Default page number for pagination
 ///
 /// Returns 1 as the default starting page for pagination queries
-fn default_page() -> i64 {
+pub(crate) fn default_page() -> i64 {
     1
 }
-
-/// Default page size for pagination
-///
-/// Returns 20 as the default number of items per page
-fn default_page_size() -> i64 {
-    20
-}
@@ -0,0 +1,601 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::domain::{
+    front_matter::KNOWN_FIELDS,
+    source_formats::{
+        ConvertedDocument, HeaderFields, blockquote, fenced, join_markdown, link_destination,
+    },
+};
+
+/// Deepest markdown heading level
+const MAX_HEADING_LEVEL: usize = 6;
+
+/// Admonition labels, as written in the source and as shown in the post
+const ADMONITIONS: &[(&str, &str)] = &[
+    ("NOTE", "Note"),
+    ("TIP", "Tip"),
+    ("IMPORTANT", "Important"),
+    ("WARNING", "Warning"),
+    ("CAUTION", "Caution"),
+];
+
+/// Macros whose target is a link destination
+const LINK_MACROS: &[&str] = &["link:", "xref:", "https://", "http://", "mailto:"];
+
+/// Convert an AsciiDoc document to a markdown article
+///
+/// The document header forms the front matter:
+///
+/// * `= Title` - `title`
+/// * The author line below the title - `authors`, by name and without email
+///   addresses; names are turned into profile ids by
+///   [`ConvertedDocument::resolve_authors`]
+/// * `:name: value` attribute entries named after a front matter field -
+///   that field, typed as described for
+///   [`header_value`](crate::domain::source_formats::header_value)
+/// * `:keywords:` - `tags`
+///
+/// Other attributes, such as `:toc:`, are rendering settings and are not
+/// front matter; they are still substituted for `{name}` references in
+/// the body. The revision line is ignored.
+///
+/// The body is converted to markdown: sections become headings of the
+/// same depth, listing and literal blocks become fenced code (in the
+/// language of a `[source,lang]` block), quote blocks and admonitions
+/// become block quotes, and links, images, cross references, lists and
+/// tables with one row per line are rewritten. Comments are dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::asciidoc::convert_asciidoc;
+/// use serde_json::json;
+///
+/// let source = "= Hello AsciiDoc\n:status: published\n:toc:\n\n== Intro\nSome *bold* text.\n";
+/// let converted = convert_asciidoc(source);
+///
+/// assert_eq!(
+///     converted.front_matter,
+///     Some(json!({ "title": "Hello AsciiDoc", "status": "published" }))
+/// );
+/// assert_eq!(converted.markdown, "## Intro\nSome **bold** text.\n");
+/// ```
+pub fn convert_asciidoc(source: &str) -> ConvertedDocument {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut header = HeaderFields::default();
+    let mut attributes = HashMap::new();
+    let mut i = 0;
+
+    while lines
+        .get(i)
+        .is_some_and(|l| l.trim().is_empty() || is_line_comment(l))
+    {
+        i += 1;
+    }
+
+    if let Some(title) = lines.get(i).and_then(|l| l.strip_prefix("= ")) {
+        header.insert("title", title, i + 1, "document title");
+        i += 1;
+
+        // The author line, then the revision line, if present
+        for is_author in [true, false] {
+            match lines.get(i) {
+                Some(line)
+                    if !line.trim().is_empty()
+                        && !line.starts_with(':')
+                        && !is_line_comment(line) =>
+                {
+                    if is_author {
+                        header.insert_value("authors", author_names(line), i + 1, "author line");
+                    }
+                    i += 1;
+                }
+                _ => break,
+            }
+        }
+    }
+
+    while let Some(line) = lines.get(i) {
+        if is_line_comment(line) {
+            i += 1;
+            continue;
+        }
+        let Some((name, value, consumed)) = attribute_entry(&lines[i..]) else {
+            break;
+        };
+
+        if let Some(value) = value {
+            let field = match name {
+                "keywords" => Some("tags"),
+                name if KNOWN_FIELDS.contains(&name) => Some(name),
+                _ => None,
+            };
+            if let Some(field) = field {
+                header.insert(field, &value, i + 1, &format!(":{}:", name));
+            }
+            attributes.insert(name.to_string(), value);
+        }
+        i += consumed;
+    }
+
+    let converter = Converter { attributes };
+    header.into_document(join_markdown(&converter.convert_lines(&lines[i..])))
+}
+
+/// Check whether a line is a `// comment`, and not a comment block delimiter
+fn is_line_comment(line: &str) -> bool {
+    line.starts_with("//") && !line.starts_with("///")
+}
+
+/// Names of the authors of an author line, without email addresses
+///
+/// `Jane Doe <jane@example.com>; Kim Lee` gives `["Jane Doe", "Kim Lee"]`.
+fn author_names(line: &str) -> Value {
+    line.split(';')
+        .map(|author| author.split('<').next().unwrap_or_default().trim())
+        .filter(|name| !name.is_empty())
+        .map(|name| Value::String(name.to_string()))
+        .collect()
+}
+
+/// Parse the `:name: value` attribute entry at the start of `lines`
+///
+/// Values continued with a trailing ` \` are joined with the next line.
+///
+/// # Returns
+///
+/// The name, the value (`None` for `:name!:`, which unsets the attribute)
+/// and the number of lines read, or `None` if the first line is not an
+/// attribute entry.
+fn attribute_entry<'a>(lines: &[&'a str]) -> Option<(&'a str, Option<String>, usize)> {
+    let (name, value) = lines.first()?.strip_prefix(':')?.split_once(':')?;
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return None;
+    }
+
+    if let Some(name) = name.strip_suffix('!').or_else(|| name.strip_prefix('!')) {
+        return Some((name, None, 1));
+    }
+
+    let mut value = value.trim().to_string();
+    let mut consumed = 1;
+    while let Some(joined) = value.strip_suffix(" \\").map(str::to_string)
+        && let Some(next) = lines.get(consumed)
+    {
+        value = format!("{} {}", joined.trim_end(), next.trim());
+        consumed += 1;
+    }
+
+    Some((name, Some(value), consumed))
+}
+
+/// Character of a delimiter line of at least four, e.g. `----` or `____`
+fn block_delimiter(line: &str) -> Option<char> {
+    let first = line.chars().next()?;
+
+    (line.len() >= 4 && "-._=*+/".contains(first) && line.chars().all(|c| c == first))
+        .then_some(first)
+}
+
+/// Positional attributes of a `[style,positional]` block attribute line
+fn positional(block_attributes: &str) -> Vec<&str> {
+    block_attributes
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.contains('='))
+        .collect()
+}
+
+/// Converts body lines with the attributes of the header
+struct Converter {
+    attributes: HashMap<String, String>,
+}
+
+impl Converter {
+    /// Convert body lines to markdown lines
+    fn convert_lines(&self, lines: &[&str]) -> Vec<String> {
+        let mut out = Vec::new();
+        let mut pending: Option<&str> = None;
+        let mut i = 0;
+
+        while i < lines.len() {
+            let line = lines[i].trim_end();
+            let style = pending.take();
+            i += 1;
+
+            if let Some(delimiter) = block_delimiter(line) {
+                let body: Vec<&str> = lines[i..]
+                    .iter()
+                    .take_while(|l| l.trim_end() != line)
+                    .copied()
+                    .collect();
+                i += body.len() + 1;
+                out.extend(self.delimited_block(delimiter, &body, style));
+                continue;
+            }
+
+            if let Some(inner) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                // `[[anchor]]` and `[#anchor]` have no markdown equivalent
+                if !inner.starts_with('[') && !inner.starts_with('#') {
+                    pending = Some(inner);
+                }
+                continue;
+            }
+
+            if is_line_comment(line) || line == "--" || line == "<<<" {
+                pending = style;
+                continue;
+            }
+
+            // `.Block title`, kept for the block below it
+            if let Some(title) = line.strip_prefix('.')
+                && title.starts_with(|c: char| !c.is_whitespace() && c != '.')
+            {
+                out.push(format!("*{}*", self.inline(title)));
+                pending = style;
+                continue;
+            }
+
+            if line == "|===" {
+                let body: Vec<&str> = lines[i..]
+                    .iter()
+                    .take_while(|l| l.trim_end() != "|===")
+                    .copied()
+                    .collect();
+                i += body.len() + 1;
+                out.extend(self.table(&body));
+                continue;
+            }
+
+            if line == "'''" {
+                out.push("---".to_string());
+                continue;
+            }
+
+            if line == "+" {
+                out.push(String::new());
+                continue;
+            }
+
+            out.push(
+                self.section(line)
+                    .or_else(|| self.block_image(line))
+                    .or_else(|| self.admonition(line))
+                    .or_else(|| self.list_item(line))
+                    .unwrap_or_else(|| self.inline(line)),
+            );
+        }
+
+        out
+    }
+
+    /// Convert a delimited block, using the block attributes above it
+    fn delimited_block(&self, delimiter: char, body: &[&str], style: Option<&str>) -> Vec<String> {
+        let positional = style.map(positional).unwrap_or_default();
+        let first = positional.first().copied().unwrap_or_default();
+
+        match delimiter {
+            '-' => {
+                let language = match first {
+                    "source" | "" => positional.get(1).copied().unwrap_or_default(),
+                    _ => "",
+                };
+                vec![fenced(&body.join("\n"), language)]
+            }
+            '.' => vec![fenced(&body.join("\n"), "text")],
+            '/' => Vec::new(),
+            '+' => body.iter().map(|l| l.to_string()).collect(),
+            '_' => {
+                let mut quoted = self.convert_lines(body);
+                if first == "quote"
+                    && let Some(attribution) = positional.get(1)
+                {
+                    quoted.push(String::new());
+                    quoted.push(format!("— {}", attribution));
+                }
+                blockquote(&quoted)
+            }
+            _ => match admonition_label(first) {
+                Some(label) => {
+                    let mut quoted = vec![format!("**{}:**", label), String::new()];
+                    quoted.extend(self.convert_lines(body));
+                    blockquote(&quoted)
+                }
+                // Example blocks and sidebars keep their content
+                None => self.convert_lines(body),
+            },
+        }
+    }
+
+    /// Convert a `== Section` title to a heading of the same depth
+    fn section(&self, line: &str) -> Option<String> {
+        let level = line.chars().take_while(|c| *c == '=').count();
+        let title = line[level..].strip_prefix(' ')?;
+        if level == 0 {
+            return None;
+        }
+
+        Some(format!(
+            "{} {}",
+            "#".repeat(level.min(MAX_HEADING_LEVEL)),
+            self.inline(title.trim())
+        ))
+    }
+
+    /// Convert an `image::target[alt]` block image
+    fn block_image(&self, line: &str) -> Option<String> {
+        let (target, attributes) = line.strip_prefix("image::")?.split_once('[')?;
+        let alt = attributes
+            .strip_suffix(']')?
+            .split(',')
+            .next()
+            .unwrap_or_default();
+
+        Some(format!(
+            "![{}]({})",
+            alt.trim(),
+            link_destination(&self.substitute(target))
+        ))
+    }
+
+    /// Convert a `NOTE: text` admonition paragraph
+    fn admonition(&self, line: &str) -> Option<String> {
+        let (name, text) = line.split_once(": ")?;
+        let label = admonition_label(name)?;
+
+        Some(format!("> **{}:** {}", label, self.inline(text)))
+    }
+
+    /// Convert `* item`, `- item`, `. item` and `term:: description` items
+    fn list_item(&self, line: &str) -> Option<String> {
+        let content = line.trim_start();
+
+        for (marker, markdown) in [('*', "-"), ('.', "1.")] {
+            let depth = content.chars().take_while(|c| *c == marker).count();
+            if depth > 0
+                && let Some(item) = content[depth..].strip_prefix(' ')
+            {
+                let indent = " ".repeat((depth - 1) * (markdown.len() + 1));
+                return Some(format!(
+                    "{}{} {}",
+                    indent,
+                    markdown,
+                    self.inline(item.trim())
+                ));
+            }
+        }
+
+        if let Some(item) = content.strip_prefix("- ") {
+            return Some(format!("- {}", self.inline(item.trim())));
+        }
+
+        let (term, description) = content
+            .split_once(":: ")
+            .or_else(|| content.strip_suffix("::").map(|term| (term, "")))?;
+        if term.is_empty() || term.ends_with(':') {
+            return None;
+        }
+
+        Some(
+            format!("**{}**: {}", self.inline(term), self.inline(description))
+                .trim_end()
+                .to_string(),
+        )
+    }
+
+    /// Convert a table, using its first row as the header
+    ///
+    /// The cells of the first line set the number of columns; the cells of
+    /// the other lines fill the rows in order, so one cell per line works
+    /// too. Lines without a leading `|` continue the cell above.
+    fn table(&self, body: &[&str]) -> Vec<String> {
+        let mut columns = 0;
+        let mut cells: Vec<String> = Vec::new();
+
+        for line in body.iter().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+            match line.strip_prefix('|') {
+                Some(row) => {
+                    let row: Vec<String> = row.split('|').map(|c| self.inline(c.trim())).collect();
+                    if columns == 0 {
+                        columns = row.len();
+                    }
+                    cells.extend(row);
+                }
+                None => {
+                    if let Some(cell) = cells.last_mut() {
+                        cell.push(' ');
+                        cell.push_str(&self.inline(line));
+                    }
+                }
+            }
+        }
+
+        let mut out = Vec::new();
+        for (n, row) in cells.chunks(columns.max(1)).enumerate() {
+            out.push(format!("| {} |", row.join(" | ")));
+            if n == 0 {
+                out.push(format!("|{}", " --- |".repeat(row.len())));
+            }
+        }
+
+        out
+    }
+
+    /// Replace `{name}` references to attributes defined in the header
+    fn substitute(&self, text: &str) -> String {
+        let mut out = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(open) = rest.find('{') {
+            out.push_str(&rest[..open]);
+            let reference = &rest[open + 1..];
+            match reference
+                .find('}')
+                .and_then(|close| Some((close, self.attributes.get(&reference[..close])?)))
+            {
+                Some((close, value)) => {
+                    out.push_str(value);
+                    rest = &reference[close + 1..];
+                }
+                None => {
+                    out.push('{');
+                    rest = reference;
+                }
+            }
+        }
+        out.push_str(rest);
+
+        out
+    }
+
+    /// Convert inline markup: constrained bold, links, images and cross
+    /// references
+    ///
+    /// Code spans are copied as written. Italic (`_text_`) and unconstrained
+    /// bold (`**text**`) already mean the same in markdown.
+    fn inline(&self, text: &str) -> String {
+        let text = self.substitute(text);
+        let chars: Vec<char> = text.chars().collect();
+        let mut out = String::with_capacity(text.len());
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+            let rest: String = if "<lxhmi".contains(c) {
+                chars[i..].iter().collect()
+            } else {
+                String::new()
+            };
+            let at_word_start = i == 0 || !chars[i - 1].is_alphanumeric();
+
+            if c == '`'
+                && let Some(len) = chars[i + 1..].iter().position(|c| *c == '`')
+            {
+                let code: String = chars[i + 1..i + 1 + len].iter().collect();
+                let code = code
+                    .strip_prefix('+')
+                    .and_then(|c| c.strip_suffix('+'))
+                    .unwrap_or(&code);
+                out.push_str(&format!("`{}`", code));
+                i += len + 2;
+                continue;
+            }
+
+            if c == '*'
+                && at_word_start
+                && chars
+                    .get(i + 1)
+                    .is_some_and(|a| !a.is_whitespace() && *a != '*')
+                && let Some(close) = (i + 2..chars.len()).find(|&j| {
+                    chars[j] == '*'
+                        && !chars[j - 1].is_whitespace()
+                        && chars
+                            .get(j + 1)
+                            .is_none_or(|a| !a.is_alphanumeric() && *a != '*')
+                })
+            {
+                let inner: String = chars[i + 1..close].iter().collect();
+                out.push_str(&format!("**{}**", self.inline(&inner)));
+                i = close + 1;
+                continue;
+            }
+
+            if c == '<'
+                && rest.starts_with("<<")
+                && let Some(end) = rest.find(">>")
+            {
+                let (target, text) = rest[2..end].split_once(',').unwrap_or((&rest[2..end], ""));
+                let dest = match target.split_once('#') {
+                    Some((document, anchor)) if !document.is_empty() && anchor.is_empty() => {
+                        document.to_string()
+                    }
+                    Some((document, _)) if !document.is_empty() => target.to_string(),
+                    _ => format!("#{}", target.trim_start_matches('#')),
+                };
+                let text = if text.trim().is_empty() {
+                    target
+                } else {
+                    text.trim()
+                };
+                out.push_str(&format!("[{}]({})", text, link_destination(&dest)));
+                i += rest[..end + 2].chars().count();
+                continue;
+            }
+
+            if at_word_start && let Some((markdown, len)) = self.link_macro(&rest) {
+                out.push_str(&markdown);
+                i += len;
+                continue;
+            }
+
+            out.push(c);
+            i += 1;
+        }
+
+        out
+    }
+
+    /// Convert the link, URL or inline image macro at the start of `text`
+    ///
+    /// # Returns
+    ///
+    /// The markdown and the number of characters read, or `None` if `text`
+    /// doesn't start with a macro.
+    fn link_macro(&self, text: &str) -> Option<(String, usize)> {
+        let is_image = text.starts_with("image:");
+        let prefix = LINK_MACROS.iter().find(|p| text.starts_with(**p)).copied();
+        if !is_image && prefix.is_none() {
+            return None;
+        }
+
+        let target_len = text
+            .find(|c: char| c.is_whitespace() || c == '[')
+            .unwrap_or(text.len());
+        let target = match prefix {
+            Some("link:") | Some("xref:") => &text[5..target_len],
+            Some(_) => &text[..target_len],
+            None => &text["image:".len()..target_len],
+        };
+
+        let Some(attributes) = text[target_len..]
+            .strip_prefix('[')
+            .and_then(|rest| rest.find(']').map(|end| &rest[..end]))
+        else {
+            // A bare URL is already a link in markdown; copy it untouched
+            return (prefix.is_some_and(|p| p.ends_with("//"))).then(|| {
+                (
+                    text[..target_len].to_string(),
+                    text[..target_len].chars().count(),
+                )
+            });
+        };
+        let len = text[..target_len + attributes.len() + 2].chars().count();
+        let label = attributes
+            .split(',')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .trim_end_matches('^');
+        let dest = link_destination(target);
+
+        let markdown = if is_image {
+            format!("![{}]({})", label, dest)
+        } else if label.is_empty() {
+            format!("[{}]({})", target, dest)
+        } else {
+            format!("[{}]({})", self.inline(label), dest)
+        };
+
+        Some((markdown, len))
+    }
+}
+
+/// Label of an admonition style such as `NOTE`
+fn admonition_label(style: &str) -> Option<&'static str> {
+    ADMONITIONS
+        .iter()
+        .find(|(name, _)| *name == style)
+        .map(|(_, label)| *label)
+}
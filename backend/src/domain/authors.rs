@@ -0,0 +1,324 @@
+use serde::{Deserialize, Serialize};
+use sqlx::prelude::FromRow;
+
+use crate::domain::{
+    front_matter::{FrontMatterFormat, is_http_url},
+    ingestion::IngestionIssue,
+};
+
+/// Listing page size used by `/authors/{id}` when none is requested
+pub const DEFAULT_AUTHOR_PAGE_SIZE: i64 = 20;
+
+/// Paths of the authors data file in the content repository
+///
+/// The format follows the extension. Only one of them is expected to exist;
+/// if several change in the same push, the last one listed by the push wins.
+pub const AUTHORS_DATA_FILES: &[&str] = &[
+    "data/authors.yaml",
+    "data/authors.yml",
+    "data/authors.toml",
+    "data/authors.json",
+];
+
+/// Maximum length of an author id
+const MAX_AUTHOR_ID_LEN: usize = 64;
+
+/// Format of the authors data file at `path`
+///
+/// # Returns
+///
+/// * `Some(FrontMatterFormat)` - `path` is one of [`AUTHORS_DATA_FILES`]
+/// * `None` - `path` is any other file
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::{authors::authors_data_format, front_matter::FrontMatterFormat};
+///
+/// assert_eq!(authors_data_format("data/authors.yml"), Some(FrontMatterFormat::Yaml));
+/// assert_eq!(authors_data_format("posts/authors.yml"), None);
+/// ```
+pub fn authors_data_format(path: &str) -> Option<FrontMatterFormat> {
+    if !AUTHORS_DATA_FILES.contains(&path) {
+        return None;
+    }
+
+    match path.rsplit_once('.')?.1 {
+        "yaml" | "yml" => Some(FrontMatterFormat::Yaml),
+        "toml" => Some(FrontMatterFormat::Toml),
+        "json" => Some(FrontMatterFormat::Json),
+        _ => None,
+    }
+}
+
+/// Check that a string is a well-formed author id
+///
+/// Author ids follow the same rules as category slugs: 1 to 64 characters
+/// of lowercase ASCII letters, digits, `-` or `_`.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::authors::is_author_id;
+///
+/// assert!(is_author_id("jane-doe"));
+/// assert!(!is_author_id("Jane Doe"));
+/// ```
+pub fn is_author_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_AUTHOR_ID_LEN
+        && id
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_')
+}
+
+/// A link shown on an author profile (website, Mastodon, GitHub, ...)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuthorLink {
+    /// Label of the link (e.g., "Website")
+    pub name: String,
+
+    /// Absolute http(s) URL
+    pub url: String,
+}
+
+/// Profile of a person writing posts
+///
+/// Profiles are declared in the authors data file of the content repository
+/// (see [`AUTHORS_DATA_FILES`]) and stored in the `authors` table, which is
+/// replaced whenever the file changes. Posts reference authors by `id` in
+/// their `authors` front matter field.
+///
+/// # Example Data File
+///
+/// ```yaml
+/// authors:
+///   - id: jane
+///     name: Jane Doe
+///     avatar: https://example.com/jane.png
+///     bio: Writes about Rust and distributed systems.
+///     links:
+///       - name: Website
+///         url: https://jane.example.com
+///     github: janedoe
+///     emails: [jane@example.com]
+/// ```
+///
+/// `github` and `emails` attribute posts without `authors` to the author
+/// of the commit that changed them; they are never exposed by the API.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct Author {
+    /// Id referenced from front matter and used in `/authors/{id}`
+    pub id: String,
+
+    /// Display name
+    pub name: String,
+
+    /// Avatar image path or URL
+    #[serde(default)]
+    pub avatar: Option<String>,
+
+    /// Short biography
+    #[serde(default)]
+    pub bio: Option<String>,
+
+    /// Profile links, in display order
+    #[sqlx(json)]
+    #[serde(default)]
+    pub links: Vec<AuthorLink>,
+
+    /// GitHub username, matched against the author of pushed commits
+    #[serde(default, skip_serializing)]
+    pub github: Option<String>,
+
+    /// Commit email addresses, matched against the author of pushed commits
+    #[serde(default, skip_serializing)]
+    pub emails: Vec<String>,
+}
+
+impl Author {
+    /// Stand-in profile for an id that is not declared in the data file
+    ///
+    /// The id doubles as the display name so bylines stay readable until
+    /// the profile is added.
+    pub fn placeholder(id: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            name: id.to_string(),
+            avatar: None,
+            bio: None,
+            links: Vec::new(),
+            github: None,
+            emails: Vec::new(),
+        }
+    }
+}
+
+/// Top-level structure of the authors data file
+#[derive(Debug, Deserialize)]
+pub struct AuthorsData {
+    /// Every author profile, in any order
+    #[serde(default)]
+    pub authors: Vec<Author>,
+}
+
+/// Check the profiles of an authors data file
+///
+/// Every profile is checked so a single push reports all problems at once.
+/// Issues name the profile by its 1-based position in the file.
+///
+/// # Returns
+///
+/// One error per invalid or duplicate id, empty name and malformed link.
+/// The profiles must only be stored if the list is empty.
+pub fn validate_authors(authors: &[Author]) -> Vec<IngestionIssue> {
+    let mut issues = Vec::new();
+    let mut error = |i: usize, message: String| {
+        issues.push(IngestionIssue::error(
+            Some("authors"),
+            None,
+            format!("author {}: {}", i + 1, message),
+        ));
+    };
+
+    for (i, author) in authors.iter().enumerate() {
+        if !is_author_id(&author.id) {
+            error(i, format!("`{}` is not a valid author id", author.id));
+        } else if authors[..i].iter().any(|a| a.id == author.id) {
+            error(i, format!("duplicate id `{}`", author.id));
+        }
+
+        if author.name.trim().is_empty() {
+            error(i, "`name` must not be empty".to_string());
+        }
+
+        for link in &author.links {
+            if !is_http_url(&link.url) {
+                error(
+                    i,
+                    format!("link `{}` is not an absolute http(s) URL", link.url),
+                );
+            }
+        }
+    }
+
+    issues
+}
+
+/// Author of a pushed commit, as reported by the webhook payload
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitAuthor {
+    /// Name from the commit
+    pub name: String,
+
+    /// Email address from the commit
+    pub email: Option<String>,
+
+    /// GitHub username, if GitHub could match the commit to an account
+    pub username: Option<String>,
+}
+
+impl CommitAuthor {
+    /// Find the profile a commit is attributed to
+    ///
+    /// A profile matches if its `github` username or one of its `emails`
+    /// equals the commit's (ignoring case), or if its id is the lowercased
+    /// GitHub username.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - Id of the first matching profile
+    /// * `None` - No profile matches the commit author
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::authors::{Author, CommitAuthor};
+    ///
+    /// let authors = vec![Author {
+    ///     github: Some("JaneDoe".to_string()),
+    ///     ..Author::placeholder("jane")
+    /// }];
+    /// let commit = CommitAuthor {
+    ///     name: "Jane".to_string(),
+    ///     email: None,
+    ///     username: Some("janedoe".to_string()),
+    /// };
+    ///
+    /// assert_eq!(commit.author_id(&authors).as_deref(), Some("jane"));
+    /// ```
+    pub fn author_id(&self, authors: &[Author]) -> Option<String> {
+        let username = self.username.as_deref();
+        let email = self.email.as_deref();
+
+        authors
+            .iter()
+            .find(|author| {
+                username.is_some_and(|u| {
+                    author.id == u.to_ascii_lowercase()
+                        || author
+                            .github
+                            .as_deref()
+                            .is_some_and(|g| g.eq_ignore_ascii_case(u))
+                }) || email.is_some_and(|e| author.emails.iter().any(|m| m.eq_ignore_ascii_case(e)))
+            })
+            .map(|author| author.id.clone())
+    }
+}
+
+/// Find the profile a document header names as an author
+///
+/// Org-mode and AsciiDoc headers name authors (`#+AUTHOR: Jane Doe`) rather
+/// than giving their ids. A name matches the profile with that id or,
+/// ignoring case, that display name.
+///
+/// # Returns
+///
+/// * `Some(String)` - Id of the first matching profile
+/// * `None` - No profile has that id or name
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::authors::{Author, author_id_by_name};
+///
+/// let authors = vec![Author {
+///     name: "Jane Doe".to_string(),
+///     ..Author::placeholder("jane")
+/// }];
+///
+/// assert_eq!(author_id_by_name("jane doe", &authors).as_deref(), Some("jane"));
+/// assert_eq!(author_id_by_name("jane", &authors).as_deref(), Some("jane"));
+/// assert_eq!(author_id_by_name("Kim Lee", &authors), None);
+/// ```
+pub fn author_id_by_name(name: &str, authors: &[Author]) -> Option<String> {
+    let name = name.trim();
+
+    authors
+        .iter()
+        .find(|author| author.id == name || author.name.eq_ignore_ascii_case(name))
+        .map(|author| author.id.clone())
+}
+
+/// Order the profiles of a post's authors like its byline
+///
+/// # Arguments
+///
+/// * `ids` - Author ids of the post, in byline order
+/// * `profiles` - Stored profiles of (some of) these ids, in any order
+///
+/// # Returns
+///
+/// One profile per id, with [`Author::placeholder`] for ids that have no
+/// stored profile.
+pub fn byline(ids: &[String], profiles: &[Author]) -> Vec<Author> {
+    ids.iter()
+        .map(|id| {
+            profiles
+                .iter()
+                .find(|p| &p.id == id)
+                .cloned()
+                .unwrap_or_else(|| Author::placeholder(id))
+        })
+        .collect()
+}
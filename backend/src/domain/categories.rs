@@ -0,0 +1,179 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{domain::articles::PostCategory, errors::Result};
+
+/// Listing page size used when a category does not configure one
+pub const DEFAULT_CATEGORY_PAGE_SIZE: i64 = 20;
+
+/// Configuration of a single content category
+///
+/// Categories are declared in `config.toml` under `[[categories]]` and
+/// describe how a group of posts is presented by the site. Posts reference a
+/// category by its `slug` in front matter.
+///
+/// # Example Configuration
+///
+/// ```toml
+/// [[categories]]
+/// slug = "reading"
+/// display_name = "Reading"
+/// description = "Notes on books and papers"
+/// sort_order = 60
+/// page_size = 12
+/// visible = true
+/// optional_title = false
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Category {
+    /// Slug used in front matter, URLs and the database (e.g., "article")
+    pub slug: PostCategory,
+
+    /// Human readable name shown in navigation
+    pub display_name: String,
+
+    /// Optional longer description of the category
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Position of the category in navigation (ascending)
+    #[serde(default)]
+    pub sort_order: i32,
+
+    /// Default number of posts per listing page
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+
+    /// Whether the category is listed publicly
+    ///
+    /// Hidden categories still accept posts during ingestion and their
+    /// articles stay reachable by id, but they are omitted from
+    /// `/api/categories` and cannot be browsed through `/api/posts`.
+    #[serde(default = "default_visible")]
+    pub visible: bool,
+
+    /// Whether posts may leave out `title`
+    ///
+    /// Meant for short status updates. Untitled posts are stored with an
+    /// empty title.
+    #[serde(default)]
+    pub optional_title: bool,
+}
+
+fn default_page_size() -> i64 {
+    DEFAULT_CATEGORY_PAGE_SIZE
+}
+
+fn default_visible() -> bool {
+    true
+}
+
+/// Validated set of configured categories
+///
+/// The registry is the single source of truth for which categories exist.
+/// It is built from configuration at startup (and rebuilt when the config
+/// file changes) and consulted during ingestion and when serving listings.
+///
+/// Categories are kept ordered by `sort_order`, then by slug, so iteration
+/// order matches navigation order.
+#[derive(Debug, Clone)]
+pub struct CategoryRegistry {
+    categories: Vec<Category>,
+}
+
+impl CategoryRegistry {
+    /// Build a registry from configured categories
+    ///
+    /// An empty list falls back to the built-in defaults so existing
+    /// deployments keep working without any configuration.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a slug is declared twice or a page size is not
+    /// positive.
+    pub fn new(mut categories: Vec<Category>) -> Result<Self> {
+        if categories.is_empty() {
+            return Ok(Self::default());
+        }
+
+        categories.sort_by(|a, b| {
+            a.sort_order
+                .cmp(&b.sort_order)
+                .then_with(|| a.slug.cmp(&b.slug))
+        });
+
+        for (i, category) in categories.iter().enumerate() {
+            if categories[..i].iter().any(|c| c.slug == category.slug) {
+                return Err(anyhow::anyhow!(
+                    "Invalid category configuration: duplicate slug `{}`",
+                    category.slug
+                )
+                .into());
+            }
+
+            if category.page_size < 1 {
+                return Err(anyhow::anyhow!(
+                    "Invalid category configuration: page_size of `{}` must be positive",
+                    category.slug
+                )
+                .into());
+            }
+        }
+
+        Ok(Self { categories })
+    }
+
+    /// Look up a category by slug, including hidden ones
+    pub fn get(&self, slug: &PostCategory) -> Option<&Category> {
+        self.categories.iter().find(|c| &c.slug == slug)
+    }
+
+    /// Check whether a slug names a configured category
+    pub fn contains(&self, slug: &PostCategory) -> bool {
+        self.get(slug).is_some()
+    }
+
+    /// Iterate over publicly visible categories in navigation order
+    pub fn visible(&self) -> impl Iterator<Item = &Category> {
+        self.categories.iter().filter(|c| c.visible)
+    }
+
+    /// Iterate over all categories in navigation order
+    pub fn iter(&self) -> impl Iterator<Item = &Category> {
+        self.categories.iter()
+    }
+}
+
+/// Built-in categories matching the site's original sections
+///
+/// Only `talk` posts may be untitled.
+impl Default for CategoryRegistry {
+    fn default() -> Self {
+        let defaults = [
+            ("article", "Articles", "Long-form articles and tutorials"),
+            ("note", "Notes", "Short notes and quick thoughts"),
+            ("think", "Thinks", "Reflective pieces and opinions"),
+            (
+                "pictures",
+                "Pictures",
+                "Photo galleries and image-focused content",
+            ),
+            ("talk", "Talks", "Talks, presentations, and speeches"),
+        ];
+
+        let categories = defaults
+            .into_iter()
+            .zip(0..)
+            .map(|((slug, display_name, description), i)| Category {
+                slug: slug.parse().expect("built-in category slugs are valid"),
+                display_name: display_name.to_string(),
+                description: Some(description.to_string()),
+                sort_order: i * 10,
+                page_size: DEFAULT_CATEGORY_PAGE_SIZE,
+                visible: true,
+                optional_title: slug == "talk",
+            })
+            .collect();
+
+        Self { categories }
+    }
+}
@@ -0,0 +1,394 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::prelude::FromRow;
+use time::{
+    Date, OffsetDateTime,
+    format_description::well_known::{Iso8601, Rfc3339},
+};
+
+use crate::{
+    domain::{
+        authors::AUTHORS_DATA_FILES,
+        front_matter::{FrontMatterFormat, is_http_url},
+        ingestion::IngestionIssue,
+    },
+    errors::Result,
+};
+
+/// Maximum length of a collection name
+const MAX_COLLECTION_NAME_LEN: usize = 64;
+
+/// Check that a string is a well-formed collection name
+///
+/// Collection names follow the same rules as category slugs: 1 to 64
+/// characters of lowercase ASCII letters, digits, `-` or `_`.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::collections::is_collection_name;
+///
+/// assert!(is_collection_name("links"));
+/// assert!(!is_collection_name("Friend Links"));
+/// ```
+pub fn is_collection_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_COLLECTION_NAME_LEN
+        && name
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'_')
+}
+
+/// Type of a field declared in a collection schema
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FieldType {
+    /// Any string
+    String,
+
+    /// Absolute http(s) URL
+    Url,
+
+    /// Whole number
+    Integer,
+
+    /// Any number
+    Number,
+
+    /// `true` or `false`
+    Boolean,
+
+    /// `YYYY-MM-DD` date or RFC 3339 timestamp
+    Date,
+
+    /// List of values of any type
+    List,
+
+    /// Mapping of fields of any type
+    Object,
+}
+
+impl FieldType {
+    /// Check whether a value has this type
+    fn accepts(self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::Url => value.as_str().is_some_and(is_http_url),
+            Self::Integer => value.is_i64() || value.is_u64(),
+            Self::Number => value.is_number(),
+            Self::Boolean => value.is_boolean(),
+            Self::Date => value.as_str().is_some_and(is_date),
+            Self::List => value.is_array(),
+            Self::Object => value.is_object(),
+        }
+    }
+
+    /// How the type is described in issue messages
+    fn describe(self) -> &'static str {
+        match self {
+            Self::String => "a string",
+            Self::Url => "an absolute http(s) URL",
+            Self::Integer => "an integer",
+            Self::Number => "a number",
+            Self::Boolean => "a boolean",
+            Self::Date => "a YYYY-MM-DD date or RFC 3339 timestamp",
+            Self::List => "a list",
+            Self::Object => "a mapping of fields",
+        }
+    }
+}
+
+/// Check whether a string is a `YYYY-MM-DD` date or an RFC 3339 timestamp
+fn is_date(value: &str) -> bool {
+    Date::parse(value, &Iso8601::DATE).is_ok() || OffsetDateTime::parse(value, &Rfc3339).is_ok()
+}
+
+/// A field of the entries of a collection
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionField {
+    /// Key of the field in each entry
+    pub name: String,
+
+    /// Expected type of the value
+    #[serde(rename = "type")]
+    pub kind: FieldType,
+
+    /// Whether every entry must set the field
+    #[serde(default)]
+    pub required: bool,
+}
+
+/// Schema of a data collection declared in config.toml
+///
+/// A collection file holds either a list of entries (e.g., friend links)
+/// or a single entry (e.g., the about page). Every entry is a mapping
+/// checked against `fields`; keys that are not declared are kept but
+/// reported as warnings, since they are usually typos.
+///
+/// # Example Configuration
+///
+/// ```toml
+/// [[collections.schemas]]
+/// name = "links"
+/// fields = [
+///     { name = "name", type = "string", required = true },
+///     { name = "url", type = "url", required = true },
+///     { name = "avatar", type = "url" },
+///     { name = "description", type = "string" },
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollectionSchema {
+    /// Name of the collection, used for the file name and in URLs
+    pub name: String,
+
+    /// Fields of each entry
+    #[serde(default)]
+    pub fields: Vec<CollectionField>,
+}
+
+impl CollectionSchema {
+    /// Check the content of a collection file against the schema
+    ///
+    /// Every entry is checked so a single push reports all problems at once.
+    /// Issues of a list name the entry by its 1-based position.
+    ///
+    /// # Returns
+    ///
+    /// One error per missing required field, mistyped value and non-mapping
+    /// entry, and one warning per undeclared key. The data must only be
+    /// stored if there are no errors.
+    pub fn validate(&self, data: &Value) -> Vec<IngestionIssue> {
+        let mut issues = Vec::new();
+
+        match data {
+            Value::Array(entries) => {
+                for (i, entry) in entries.iter().enumerate() {
+                    let position = format!("entry {}: ", i + 1);
+                    match entry {
+                        Value::Object(fields) => self.check_entry(fields, &position, &mut issues),
+                        _ => issues.push(IngestionIssue::error(
+                            None,
+                            None,
+                            format!("{}must be a mapping of fields", position),
+                        )),
+                    }
+                }
+            }
+            Value::Object(fields) => self.check_entry(fields, "", &mut issues),
+            _ => issues.push(IngestionIssue::error(
+                None,
+                None,
+                "collection must be a list of entries or a mapping of fields",
+            )),
+        }
+
+        issues
+    }
+
+    /// Check a single entry, prefixing messages with its position
+    fn check_entry(
+        &self,
+        entry: &Map<String, Value>,
+        position: &str,
+        issues: &mut Vec<IngestionIssue>,
+    ) {
+        for field in &self.fields {
+            match entry.get(&field.name) {
+                None | Some(Value::Null) if field.required => {
+                    issues.push(IngestionIssue::error(
+                        Some(&field.name),
+                        None,
+                        format!("{}missing required field `{}`", position, field.name),
+                    ));
+                }
+                Some(value) if !value.is_null() && !field.kind.accepts(value) => {
+                    issues.push(IngestionIssue::error(
+                        Some(&field.name),
+                        None,
+                        format!(
+                            "{}`{}` must be {}",
+                            position,
+                            field.name,
+                            field.kind.describe()
+                        ),
+                    ));
+                }
+                _ => {}
+            }
+        }
+
+        for key in entry
+            .keys()
+            .filter(|k| !self.fields.iter().any(|f| &f.name == *k))
+        {
+            issues.push(IngestionIssue::warning(
+                Some(key),
+                None,
+                format!("{}field `{}` is not declared in the schema", position, key),
+            ));
+        }
+    }
+}
+
+/// Data collection settings declared as `[collections]` in config.toml
+///
+/// Files in `dir` named after a declared collection, with a `.yaml`,
+/// `.yml`, `.toml` or `.json` extension, are ingested when pushed. Other
+/// files in `dir`, including the authors data file, are not collections.
+///
+/// # Example
+///
+/// ```toml
+/// [collections]
+/// dir = "data"
+///
+/// [[collections.schemas]]
+/// name = "projects"
+/// fields = [
+///     { name = "title", type = "string", required = true },
+///     { name = "repository", type = "url" },
+///     { name = "tags", type = "list" },
+/// ]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct CollectionOptions {
+    /// Directory of the collection files, relative to the repository root
+    pub dir: String,
+
+    /// Declared collections; files of undeclared collections are ignored
+    pub schemas: Vec<CollectionSchema>,
+}
+
+impl Default for CollectionOptions {
+    fn default() -> Self {
+        Self {
+            dir: "data".to_string(),
+            schemas: Vec::new(),
+        }
+    }
+}
+
+impl CollectionOptions {
+    /// Reject schemas that can't be matched to files or checked
+    ///
+    /// Leading and trailing slashes of `dir` are removed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` is empty, a collection name is malformed or
+    /// declared twice, or a field is unnamed or declared twice.
+    pub fn validate(mut self) -> Result<Self> {
+        self.dir = self.dir.trim_matches('/').to_string();
+        if self.dir.is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid collections configuration: `dir` must not be empty"
+            )
+            .into());
+        }
+
+        for (i, schema) in self.schemas.iter().enumerate() {
+            if !is_collection_name(&schema.name) {
+                return Err(anyhow::anyhow!(
+                    "Invalid collections configuration: `{}` is not a valid collection name",
+                    schema.name
+                )
+                .into());
+            }
+
+            if self.schemas[..i].iter().any(|s| s.name == schema.name) {
+                return Err(anyhow::anyhow!(
+                    "Invalid collections configuration: duplicate collection `{}`",
+                    schema.name
+                )
+                .into());
+            }
+
+            for (j, field) in schema.fields.iter().enumerate() {
+                if field.name.trim().is_empty() {
+                    return Err(anyhow::anyhow!(
+                        "Invalid collections configuration: unnamed field in `{}`",
+                        schema.name
+                    )
+                    .into());
+                }
+
+                if schema.fields[..j].iter().any(|f| f.name == field.name) {
+                    return Err(anyhow::anyhow!(
+                        "Invalid collections configuration: duplicate field `{}` in `{}`",
+                        field.name,
+                        schema.name
+                    )
+                    .into());
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Look up a declared collection by name
+    pub fn get(&self, name: &str) -> Option<&CollectionSchema> {
+        self.schemas.iter().find(|s| s.name == name)
+    }
+
+    /// Find the collection a repository file holds
+    ///
+    /// # Returns
+    ///
+    /// * `Some((schema, format))` - `path` is a file of a declared collection
+    /// * `None` - `path` is any other file
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::collections::{CollectionOptions, CollectionSchema};
+    /// use backend::domain::front_matter::FrontMatterFormat;
+    ///
+    /// let options = CollectionOptions {
+    ///     schemas: vec![CollectionSchema { name: "links".to_string(), fields: vec![] }],
+    ///     ..CollectionOptions::default()
+    /// };
+    ///
+    /// let (schema, format) = options.collection_file("data/links.yaml").unwrap();
+    /// assert_eq!((schema.name.as_str(), format), ("links", FrontMatterFormat::Yaml));
+    /// assert!(options.collection_file("data/projects.yaml").is_none());
+    /// assert!(options.collection_file("posts/links.yaml").is_none());
+    /// ```
+    pub fn collection_file(&self, path: &str) -> Option<(&CollectionSchema, FrontMatterFormat)> {
+        if AUTHORS_DATA_FILES.contains(&path) {
+            return None;
+        }
+
+        let file = path.strip_prefix(&self.dir)?.strip_prefix('/')?;
+        let (name, extension) = file.rsplit_once('.')?;
+        let format = match extension {
+            "yaml" | "yml" => FrontMatterFormat::Yaml,
+            "toml" => FrontMatterFormat::Toml,
+            "json" => FrontMatterFormat::Json,
+            _ => return None,
+        };
+
+        Some((self.get(name)?, format))
+    }
+}
+
+/// A data collection ingested from the content repository
+///
+/// Stored in the `collections` table, one row per collection, and replaced
+/// whenever its file is pushed.
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct Collection {
+    /// Name of the declared collection
+    pub name: String,
+
+    /// Repository path of the file the data was read from
+    pub path: String,
+
+    /// Content of the file, as checked against the schema
+    pub data: Value,
+
+    /// Timestamp when the collection was last pushed
+    pub updated_at: OffsetDateTime,
+}
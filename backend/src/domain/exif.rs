@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use sqlx::{
+    Decode, Postgres, Type,
+    error::BoxDynError,
+    postgres::{PgTypeInfo, PgValueRef},
+    types::Json,
+};
+use time::OffsetDateTime;
+
+/// Camera metadata read from the EXIF tags of a photo
+///
+/// Extracted at ingestion from the file as it is served, after sensitive
+/// tags were removed: `location` is only present when the post opted in to
+/// keeping it with `keep_location`. Stored as JSONB in `media_assets.exif`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExifMetadata {
+    /// Camera make and model, e.g. `FUJIFILM X100V`
+    pub camera: Option<String>,
+
+    /// Lens make and model
+    pub lens: Option<String>,
+
+    /// Exposure settings
+    pub exposure: Option<Exposure>,
+
+    /// When the photo was taken
+    ///
+    /// Read from `DateTimeOriginal` and `OffsetTimeOriginal`; cameras that
+    /// don't record an offset are assumed to be set to UTC.
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub taken_at: Option<OffsetDateTime>,
+
+    /// Where the photo was taken
+    pub location: Option<GeoLocation>,
+}
+
+impl ExifMetadata {
+    /// Whether no field could be read
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+}
+
+impl Type<Postgres> for ExifMetadata {
+    fn type_info() -> PgTypeInfo {
+        <Json<Self> as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <Json<Self> as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for ExifMetadata {
+    fn decode(value: PgValueRef<'r>) -> std::result::Result<Self, BoxDynError> {
+        Ok(<Json<Self> as Decode<'r, Postgres>>::decode(value)?.0)
+    }
+}
+
+/// Exposure settings of a photo
+///
+/// # Example Front Matter
+///
+/// ```yaml
+/// exposure:
+///   shutter_speed: 1/250
+///   aperture: 2.8
+///   iso: 400
+///   focal_length: 23
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Exposure {
+    /// Exposure time in seconds, written as on the camera: `1/250`, `2.5`
+    #[serde(default)]
+    pub shutter_speed: Option<String>,
+
+    /// F-number of the aperture
+    #[serde(default)]
+    pub aperture: Option<f64>,
+
+    /// ISO sensitivity
+    #[serde(default)]
+    pub iso: Option<u32>,
+
+    /// Focal length in millimetres
+    #[serde(default)]
+    pub focal_length: Option<f64>,
+}
+
+/// GPS position of a photo, in decimal degrees
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GeoLocation {
+    /// Latitude, positive north of the equator
+    pub latitude: f64,
+
+    /// Longitude, positive east of Greenwich
+    pub longitude: f64,
+}
+
+impl GeoLocation {
+    /// Whether both coordinates are in range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::exif::GeoLocation;
+    ///
+    /// assert!(GeoLocation { latitude: 34.967, longitude: 135.773 }.is_valid());
+    /// assert!(!GeoLocation { latitude: 135.773, longitude: 34.967 }.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        (-90.0..=90.0).contains(&self.latitude) && (-180.0..=180.0).contains(&self.longitude)
+    }
+}
@@ -0,0 +1,629 @@
+use std::{collections::HashMap, fmt};
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::domain::{
+    articles::{ArticleFrontMatter, ArticleId},
+    authors::is_author_id,
+    gallery::GalleryImage,
+    ingestion::IngestionIssue,
+    languages::is_language_tag,
+};
+
+/// Front matter fields understood by the ingestion pipeline
+///
+/// Any other top-level key is reported as a warning and dropped; custom
+/// metadata belongs under `extra`.
+pub const KNOWN_FIELDS: &[&str] = &[
+    "id",
+    "title",
+    "tags",
+    "category",
+    "summary",
+    "status",
+    "series",
+    "series_order",
+    "description",
+    "cover_image",
+    "canonical_url",
+    "lang",
+    "language",
+    "translation_key",
+    "license",
+    "author",
+    "authors",
+    "pinned",
+    "pinned_weight",
+    "featured",
+    "featured_weight",
+    "images",
+    "keep_location",
+    "extra",
+];
+
+/// Line of the opening front matter delimiter, used for file-level issues
+const FRONT_MATTER_LINE: usize = 1;
+
+/// Outcome of validating the front matter of one file
+#[derive(Debug, Clone)]
+pub struct FrontMatterValidation {
+    /// The validated front matter, `None` if any error was found
+    pub front_matter: Option<ArticleFrontMatter>,
+
+    /// Article id, if it could be read even though other fields failed
+    pub article_id: Option<ArticleId>,
+
+    /// Errors and warnings found, in field order
+    pub issues: Vec<IngestionIssue>,
+
+    /// Line of each top-level key, kept for checks done after validation
+    key_lines: HashMap<String, usize>,
+}
+
+/// Serialization format of a front matter block
+///
+/// The format is detected from the delimiter on the first line of the file:
+///
+/// | Delimiter | Format |
+/// |-----------|--------|
+/// | `---`     | YAML   |
+/// | `+++`     | TOML (Hugo style) |
+/// | `;;;`     | JSON   |
+///
+/// All formats are deserialized into the same front matter structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrontMatterFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl FrontMatterFormat {
+    /// Detect the front matter format from the opening delimiter
+    ///
+    /// # Returns
+    ///
+    /// * `Some(format)` - The first line is a known delimiter
+    /// * `None` - The file has no front matter
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::front_matter::FrontMatterFormat;
+    ///
+    /// assert_eq!(FrontMatterFormat::detect("+++\ntitle = \"Hi\"\n+++\n"), Some(FrontMatterFormat::Toml));
+    /// assert_eq!(FrontMatterFormat::detect("# Just markdown"), None);
+    /// ```
+    pub fn detect(source: &str) -> Option<Self> {
+        let first = source.lines().next()?.trim_end();
+
+        [Self::Yaml, Self::Toml, Self::Json]
+            .into_iter()
+            .find(|format| format.delimiter() == first)
+    }
+
+    /// Delimiter line that opens and closes the block
+    pub fn delimiter(&self) -> &'static str {
+        match self {
+            FrontMatterFormat::Yaml => "---",
+            FrontMatterFormat::Toml => "+++",
+            FrontMatterFormat::Json => ";;;",
+        }
+    }
+
+    /// Human readable format name, used in error messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            FrontMatterFormat::Yaml => "YAML",
+            FrontMatterFormat::Toml => "TOML",
+            FrontMatterFormat::Json => "JSON",
+        }
+    }
+}
+
+impl fmt::Display for FrontMatterFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Map top-level front matter keys to their 1-based line in the file
+///
+/// Only the block between the opening delimiter on the first line and the
+/// closing delimiter is scanned. Nested keys and comments are ignored; if a
+/// key appears twice the first occurrence wins. For TOML, a `[table]` header
+/// counts as the line of its top-level key.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::front_matter::{FrontMatterFormat, key_lines};
+///
+/// let source = "---\nid: 1\ntitle: Hello\n---\nBody";
+/// let lines = key_lines(source, FrontMatterFormat::Yaml);
+///
+/// assert_eq!(lines.get("title"), Some(&3));
+/// ```
+pub fn key_lines(source: &str, format: FrontMatterFormat) -> HashMap<String, usize> {
+    let mut iter = source.lines().enumerate();
+
+    match iter.next() {
+        Some((_, first)) if first.trim_end() == format.delimiter() => {}
+        _ => return HashMap::new(),
+    }
+
+    let block = iter
+        .take_while(|(_, line)| line.trim_end() != format.delimiter())
+        .map(|(index, line)| (index + 1, line));
+
+    match format {
+        FrontMatterFormat::Yaml => yaml_key_lines(block),
+        FrontMatterFormat::Toml => toml_key_lines(block),
+        FrontMatterFormat::Json => json_key_lines(block),
+    }
+}
+
+/// Strip surrounding quotes from a key
+fn unquote(key: &str) -> &str {
+    key.trim().trim_matches(|c| c == '"' || c == '\'')
+}
+
+fn yaml_key_lines<'a>(block: impl Iterator<Item = (usize, &'a str)>) -> HashMap<String, usize> {
+    let mut lines = HashMap::new();
+
+    for (line_no, line) in block {
+        if line.starts_with(char::is_whitespace) || line.starts_with('#') || line.starts_with('-') {
+            continue;
+        }
+
+        if let Some((key, _)) = line.split_once(':') {
+            lines.entry(unquote(key).to_string()).or_insert(line_no);
+        }
+    }
+
+    lines
+}
+
+fn toml_key_lines<'a>(block: impl Iterator<Item = (usize, &'a str)>) -> HashMap<String, usize> {
+    let mut lines = HashMap::new();
+    let mut in_table = false;
+
+    for (line_no, line) in block {
+        let trimmed = line.trim();
+
+        if let Some(header) = trimmed.strip_prefix('[') {
+            // `[extra]`, `[extra.links]` and `[[extra.items]]` all belong to `extra`
+            let name = header.trim_start_matches('[').split([']', '.']).next();
+            if let Some(name) = name {
+                lines.entry(unquote(name).to_string()).or_insert(line_no);
+            }
+            in_table = true;
+            continue;
+        }
+
+        if in_table || trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+
+        if let Some((key, _)) = trimmed.split_once('=') {
+            // Dotted keys (`extra.mood = 1`) belong to their first segment
+            let key = key.split('.').next().unwrap_or(key);
+            lines.entry(unquote(key).to_string()).or_insert(line_no);
+        }
+    }
+
+    lines
+}
+
+fn json_key_lines<'a>(block: impl Iterator<Item = (usize, &'a str)>) -> HashMap<String, usize> {
+    let mut lines = HashMap::new();
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut current = String::new();
+    let mut last_string: Option<(String, usize)> = None;
+
+    for (line_no, line) in block {
+        for c in line.chars() {
+            if in_string {
+                match c {
+                    _ if escaped => {
+                        escaped = false;
+                        current.push(c);
+                    }
+                    '\\' => escaped = true,
+                    '"' => {
+                        in_string = false;
+                        last_string = Some((std::mem::take(&mut current), line_no));
+                    }
+                    _ => current.push(c),
+                }
+                continue;
+            }
+
+            match c {
+                '"' => in_string = true,
+                '{' | '[' => {
+                    depth += 1;
+                    last_string = None;
+                }
+                '}' | ']' => {
+                    depth = depth.saturating_sub(1);
+                    last_string = None;
+                }
+                // A string directly followed by `:` at depth 1 is a top-level key
+                ':' => {
+                    if let Some((key, key_line)) = last_string.take()
+                        && depth == 1
+                    {
+                        lines.entry(key).or_insert(key_line);
+                    }
+                }
+                c if c.is_whitespace() => {}
+                _ => last_string = None,
+            }
+        }
+    }
+
+    lines
+}
+
+/// Validate parsed front matter against the article schema
+///
+/// Every field is checked independently so a single run reports all
+/// problems at once: missing required fields, mistyped values, malformed
+/// values (e.g. a canonical URL without scheme) and unknown keys.
+///
+/// A missing `title` is accepted as an empty one here; the caller rejects
+/// it with [`FrontMatterValidation::reject_missing`] unless the category
+/// allows untitled posts.
+///
+/// # Arguments
+///
+/// * `data` - Front matter parsed into a generic value, `None` if the file has none
+/// * `key_lines` - Line of each top-level key in the source file
+///
+/// # Returns
+///
+/// A [`FrontMatterValidation`] with the front matter if there were no
+/// errors, and every issue found.
+pub fn validate_front_matter(
+    data: Option<&Value>,
+    key_lines: &HashMap<String, usize>,
+) -> FrontMatterValidation {
+    let fields = match data {
+        Some(Value::Object(fields)) => fields,
+        Some(_) => {
+            return FrontMatterValidation::failed(IngestionIssue::error(
+                None,
+                Some(FRONT_MATTER_LINE),
+                "front matter must be a mapping of fields",
+            ));
+        }
+        None => {
+            return FrontMatterValidation::failed(IngestionIssue::error(
+                None,
+                Some(FRONT_MATTER_LINE),
+                "missing front matter",
+            ));
+        }
+    };
+
+    let mut reader = FieldReader {
+        fields,
+        key_lines,
+        issues: Vec::new(),
+    };
+
+    let id = reader.required::<ArticleId>("id");
+    // Whether the title may be left out depends on the category, which is
+    // checked against the configured registry after validation
+    let title = match reader.optional::<String>("title") {
+        Some(title) => reader.non_empty("title", title),
+        None => Some(String::new()),
+    };
+    let tags = reader.optional::<Vec<String>>("tags").unwrap_or_default();
+    let category = reader.required("category");
+    let summary = reader.optional("summary");
+    let status = reader
+        .required::<String>("status")
+        .and_then(|s| reader.non_empty("status", s));
+    let series = reader.optional("series");
+    let series_order = reader.optional("series_order");
+    let description = reader.optional("description");
+    let cover_image = reader
+        .optional::<String>("cover_image")
+        .and_then(|c| reader.non_empty("cover_image", c));
+    let canonical_url = reader
+        .optional::<String>("canonical_url")
+        .and_then(|u| reader.check_url("canonical_url", u));
+    // `lang` is the short spelling of `language`
+    let language_field = if fields.contains_key("lang") {
+        "lang"
+    } else {
+        "language"
+    };
+    if language_field == "lang" && fields.contains_key("language") {
+        reader.error("language", "duplicates `lang`; keep only one of them");
+    }
+    let language = reader
+        .optional::<String>(language_field)
+        .and_then(|l| reader.check_language(language_field, l));
+    let translation_key = reader
+        .optional::<String>("translation_key")
+        .and_then(|k| reader.non_empty("translation_key", k))
+        .map(|k| k.trim().to_string());
+    if translation_key.is_some() && !fields.contains_key(language_field) {
+        let line = reader.line("translation_key");
+        reader.issues.push(IngestionIssue::warning(
+            Some("translation_key"),
+            line,
+            "set `lang` too, so readers can tell the translations apart",
+        ));
+    }
+    let license = reader.optional("license");
+    let author = reader.optional("author");
+    let authors = reader
+        .optional::<Vec<String>>("authors")
+        .and_then(|a| reader.check_authors("authors", a))
+        .unwrap_or_default();
+    let pinned = reader.optional("pinned").unwrap_or(false);
+    let pinned_weight = reader.optional("pinned_weight");
+    let pinned_weight = reader.check_weight("pinned_weight", pinned_weight, "pinned", pinned);
+    let featured = reader.optional("featured").unwrap_or(false);
+    let featured_weight = reader.optional("featured_weight");
+    let featured_weight =
+        reader.check_weight("featured_weight", featured_weight, "featured", featured);
+    let images = reader
+        .optional::<Vec<GalleryImage>>("images")
+        .and_then(|i| reader.check_images("images", i))
+        .unwrap_or_default();
+    let keep_location = reader.optional("keep_location").unwrap_or(false);
+    let extra = reader
+        .optional::<Map<String, Value>>("extra")
+        .unwrap_or_default();
+
+    for key in fields
+        .keys()
+        .filter(|k| !KNOWN_FIELDS.contains(&k.as_str()))
+    {
+        let line = reader.line(key);
+        reader.issues.push(IngestionIssue::warning(
+            Some(key),
+            line,
+            "unknown field ignored; put custom metadata under `extra`",
+        ));
+    }
+
+    let has_errors = reader.issues.iter().any(IngestionIssue::is_error);
+    let front_matter = match (id, title, category, status) {
+        (Some(id), Some(title), Some(category), Some(status)) if !has_errors => {
+            Some(ArticleFrontMatter {
+                id,
+                title,
+                tags,
+                category,
+                summary,
+                status,
+                series,
+                series_order,
+                description,
+                cover_image,
+                canonical_url,
+                language,
+                translation_key,
+                license,
+                author,
+                authors,
+                pinned,
+                pinned_weight,
+                featured,
+                featured_weight,
+                images,
+                keep_location,
+                extra,
+            })
+        }
+        _ => None,
+    };
+
+    FrontMatterValidation {
+        front_matter,
+        article_id: id,
+        issues: reader.issues,
+        key_lines: key_lines.clone(),
+    }
+}
+
+impl FrontMatterValidation {
+    fn failed(issue: IngestionIssue) -> Self {
+        Self {
+            front_matter: None,
+            article_id: None,
+            issues: vec![issue],
+            key_lines: HashMap::new(),
+        }
+    }
+
+    /// Reject a field for a reason only known outside the schema
+    ///
+    /// Used for checks that need application state, such as whether the
+    /// category is configured. The error points at the field's line and
+    /// the front matter is discarded.
+    pub fn reject(&mut self, field: &str, message: impl Into<String>) {
+        let line = self.key_lines.get(field).copied();
+        self.issues
+            .push(IngestionIssue::error(Some(field), line, message));
+        self.front_matter = None;
+    }
+
+    /// Warn about a field for a reason only known outside the schema
+    ///
+    /// Unlike [`Self::reject`], the front matter is kept.
+    pub fn warn(&mut self, field: &str, message: impl Into<String>) {
+        let line = self.key_lines.get(field).copied();
+        self.issues
+            .push(IngestionIssue::warning(Some(field), line, message));
+    }
+
+    /// Reject a field that is only required under a condition checked
+    /// outside the schema, reporting it like any missing required field
+    pub fn reject_missing(&mut self, field: &str) {
+        self.issues.push(IngestionIssue::error(
+            Some(field),
+            Some(FRONT_MATTER_LINE),
+            "missing required field",
+        ));
+        self.front_matter = None;
+    }
+}
+
+/// Reads typed fields out of a front matter mapping, collecting issues
+struct FieldReader<'a> {
+    fields: &'a Map<String, Value>,
+    key_lines: &'a HashMap<String, usize>,
+    issues: Vec<IngestionIssue>,
+}
+
+impl FieldReader<'_> {
+    fn line(&self, field: &str) -> Option<usize> {
+        self.key_lines.get(field).copied()
+    }
+
+    fn error(&mut self, field: &str, message: impl Into<String>) {
+        let line = self.line(field);
+        self.issues
+            .push(IngestionIssue::error(Some(field), line, message));
+    }
+
+    /// Read a field that must be present and non-null
+    fn required<T: DeserializeOwned>(&mut self, field: &str) -> Option<T> {
+        match self.fields.get(field) {
+            None | Some(Value::Null) => {
+                self.issues.push(IngestionIssue::error(
+                    Some(field),
+                    Some(FRONT_MATTER_LINE),
+                    "missing required field",
+                ));
+                None
+            }
+            Some(value) => self.convert(field, value),
+        }
+    }
+
+    /// Read a field that may be absent or null
+    fn optional<T: DeserializeOwned>(&mut self, field: &str) -> Option<T> {
+        match self.fields.get(field) {
+            None | Some(Value::Null) => None,
+            Some(value) => self.convert(field, value),
+        }
+    }
+
+    fn convert<T: DeserializeOwned>(&mut self, field: &str, value: &Value) -> Option<T> {
+        match T::deserialize(value) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                self.error(field, e.to_string());
+                None
+            }
+        }
+    }
+
+    /// Drop an order weight whose flag is not set, with a warning, so it
+    /// can't affect the order of unflagged posts
+    fn check_weight(
+        &mut self,
+        field: &str,
+        weight: Option<i32>,
+        flag_field: &str,
+        flag: bool,
+    ) -> Option<i32> {
+        if weight.is_some() && !flag {
+            let line = self.line(field);
+            self.issues.push(IngestionIssue::warning(
+                Some(field),
+                line,
+                format!("ignored because `{}` is not true", flag_field),
+            ));
+            return None;
+        }
+
+        weight
+    }
+
+    /// Report every invalid image by its 1-based position in the list
+    fn check_images(
+        &mut self,
+        field: &str,
+        images: Vec<GalleryImage>,
+    ) -> Option<Vec<GalleryImage>> {
+        let problems: Vec<String> = images
+            .iter()
+            .enumerate()
+            .filter_map(|(i, image)| image.problem().map(|p| format!("image {}: {}", i + 1, p)))
+            .collect();
+
+        if problems.is_empty() {
+            return Some(images);
+        }
+        for problem in problems {
+            self.error(field, problem);
+        }
+        None
+    }
+
+    fn non_empty(&mut self, field: &str, value: String) -> Option<String> {
+        if value.trim().is_empty() {
+            self.error(field, "must not be empty");
+            None
+        } else {
+            Some(value)
+        }
+    }
+
+    fn check_url(&mut self, field: &str, value: String) -> Option<String> {
+        if is_http_url(&value) {
+            Some(value)
+        } else {
+            self.error(field, format!("`{}` is not an absolute http(s) URL", value));
+            None
+        }
+    }
+
+    /// Report every malformed author id and drop repeated ones
+    fn check_authors(&mut self, field: &str, authors: Vec<String>) -> Option<Vec<String>> {
+        let mut ids: Vec<String> = Vec::new();
+        let mut valid = true;
+
+        for id in authors {
+            let id = id.trim().to_string();
+            if !is_author_id(&id) {
+                self.error(field, format!("`{}` is not a valid author id", id));
+                valid = false;
+            } else if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+
+        valid.then_some(ids)
+    }
+
+    /// Accept BCP 47 style tags such as `en`, `zh-CN` or `zh-Hant-TW`
+    fn check_language(&mut self, field: &str, value: String) -> Option<String> {
+        if is_language_tag(&value) {
+            Some(value)
+        } else {
+            self.error(field, format!("`{}` is not a valid language tag", value));
+            None
+        }
+    }
+}
+
+/// Check that a string is an absolute http(s) URL with a host
+pub(crate) fn is_http_url(value: &str) -> bool {
+    value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+        .is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'))
+}
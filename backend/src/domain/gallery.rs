@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+use crate::domain::{
+    exif::{ExifMetadata, Exposure, GeoLocation},
+    links::is_external_url,
+};
+
+/// One image of a gallery, declared in the `images` front matter field
+///
+/// Pictures posts list their images in front matter instead of embedding
+/// them in the markdown body, so the gallery page gets captions, alt text
+/// and dimensions without parsing the content.
+///
+/// Dimensions, camera, lens, exposure and taken-at time that front matter
+/// leaves out are filled in from the image file at ingestion. The location
+/// is only read from the file when the post sets `keep_location`.
+///
+/// # Example Front Matter
+///
+/// ```yaml
+/// images:
+///   - src: ./kyoto/fushimi.jpg
+///     alt: Vermilion torii gates climbing the hill
+///     caption: Fushimi Inari at dawn
+///     width: 4000
+///     height: 3000
+///     taken_at: 2025-04-02T05:48:00+09:00
+///     camera: Nikon FM2
+///     lens: Nikkor 50mm f/1.4
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GalleryImage {
+    /// Repository path of the image file
+    ///
+    /// Written relative to the post (or to the repository root with a
+    /// leading `/`); replaced with the media URL of the image at ingestion.
+    pub src: String,
+
+    /// Alternative text for screen readers, required
+    pub alt: String,
+
+    /// Caption shown under the image
+    #[serde(default)]
+    pub caption: Option<String>,
+
+    /// Width in pixels
+    #[serde(default)]
+    pub width: Option<u32>,
+
+    /// Height in pixels
+    #[serde(default)]
+    pub height: Option<u32>,
+
+    /// When the photo was taken, as an RFC 3339 timestamp
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub taken_at: Option<OffsetDateTime>,
+
+    /// Camera make and model
+    #[serde(default)]
+    pub camera: Option<String>,
+
+    /// Lens make and model
+    #[serde(default)]
+    pub lens: Option<String>,
+
+    /// Exposure settings
+    #[serde(default)]
+    pub exposure: Option<Exposure>,
+
+    /// Where the photo was taken
+    #[serde(default)]
+    pub location: Option<GeoLocation>,
+}
+
+impl GalleryImage {
+    /// Check the fields serde can't, returning a reason if the image is invalid
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::gallery::GalleryImage;
+    ///
+    /// let mut image = GalleryImage {
+    ///     src: "photos/lake.jpg".to_string(),
+    ///     alt: "A frozen lake".to_string(),
+    ///     caption: None,
+    ///     width: Some(1600),
+    ///     height: Some(900),
+    ///     taken_at: None,
+    ///     camera: None,
+    ///     lens: None,
+    ///     exposure: None,
+    ///     location: None,
+    /// };
+    /// assert_eq!(image.problem(), None);
+    ///
+    /// image.src = "https://example.com/lake.jpg".to_string();
+    /// assert!(image.problem().is_some());
+    /// ```
+    pub fn problem(&self) -> Option<String> {
+        let src = self.src.trim();
+
+        if src.is_empty() {
+            return Some("`src` must not be empty".to_string());
+        }
+        if is_external_url(src) {
+            return Some(format!("`src` must be a repository path, got `{}`", src));
+        }
+        if self.alt.trim().is_empty() {
+            return Some("`alt` must not be empty".to_string());
+        }
+        match (self.width, self.height) {
+            (Some(0), _) | (_, Some(0)) => {
+                Some("`width` and `height` must be positive".to_string())
+            }
+            (Some(_), None) | (None, Some(_)) => {
+                Some("`width` and `height` must be given together".to_string())
+            }
+            _ if self.location.is_some_and(|l| !l.is_valid()) => {
+                Some("`location` is out of range".to_string())
+            }
+            _ => None,
+        }
+    }
+
+    /// Fill the fields front matter left out from the metadata of the file
+    ///
+    /// Front matter always wins, field by field, so film scans and edited
+    /// exports can be described by hand.
+    ///
+    /// # Arguments
+    ///
+    /// * `exif` - Metadata read from the served file
+    /// * `keep_location` - Whether the post opted in to showing where its
+    ///   photos were taken
+    pub fn fill_from_exif(&mut self, exif: &ExifMetadata, keep_location: bool) {
+        if self.taken_at.is_none() {
+            self.taken_at = exif.taken_at;
+        }
+        if self.camera.is_none() {
+            self.camera = exif.camera.clone();
+        }
+        if self.lens.is_none() {
+            self.lens = exif.lens.clone();
+        }
+        if self.exposure.is_none() {
+            self.exposure = exif.exposure.clone();
+        }
+        if self.location.is_none() && keep_location {
+            self.location = exif.location;
+        }
+    }
+}
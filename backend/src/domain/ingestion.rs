@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, str::FromStr};
+use time::OffsetDateTime;
+
+use crate::domain::articles::ArticleId;
+
+/// Severity of a single ingestion issue
+///
+/// Errors prevent the file from being ingested; warnings are recorded but
+/// the article is still stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IssueSeverity {
+    Warning,
+    Error,
+}
+
+/// A problem found while ingesting a single file
+///
+/// Issues are field level where possible, so authors can fix the front
+/// matter without guessing.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "severity": "error",
+///   "field": "title",
+///   "line": 3,
+///   "message": "invalid type: integer `42`, expected a string"
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IngestionIssue {
+    /// Whether the issue blocks ingestion
+    pub severity: IssueSeverity,
+
+    /// Front matter field the issue refers to, if any
+    pub field: Option<String>,
+
+    /// 1-based line number in the source file, if known
+    pub line: Option<usize>,
+
+    /// Human readable description of the problem
+    pub message: String,
+}
+
+impl IngestionIssue {
+    /// Create a blocking issue
+    pub fn error(field: Option<&str>, line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Error,
+            field: field.map(str::to_string),
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Create a non-blocking issue
+    pub fn warning(field: Option<&str>, line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            severity: IssueSeverity::Warning,
+            field: field.map(str::to_string),
+            line,
+            message: message.into(),
+        }
+    }
+
+    /// Check whether the issue blocks ingestion
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl fmt::Display for IngestionIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, "`{}`: ", field)?;
+        }
+        f.write_str(&self.message)
+    }
+}
+
+/// Outcome of ingesting a single file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IngestionStatus {
+    /// Article stored without issues
+    Ingested,
+
+    /// Article stored, but some issues were reported
+    IngestedWithWarnings,
+
+    /// Article not stored because of blocking issues
+    Failed,
+}
+
+impl IngestionStatus {
+    /// Get the value stored in the `ingestion_reports.status` column
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IngestionStatus::Ingested => "ingested",
+            IngestionStatus::IngestedWithWarnings => "ingested_with_warnings",
+            IngestionStatus::Failed => "failed",
+        }
+    }
+}
+
+impl FromStr for IngestionStatus {
+    type Err = String;
+
+    /// Parse a value of the `ingestion_reports.status` column
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ingested" => Ok(IngestionStatus::Ingested),
+            "ingested_with_warnings" => Ok(IngestionStatus::IngestedWithWarnings),
+            "failed" => Ok(IngestionStatus::Failed),
+            _ => Err(format!("unknown ingestion status `{}`", s)),
+        }
+    }
+}
+
+/// Per-file ingestion report
+///
+/// One report is kept per source path and replaced on every push that
+/// touches the file, so it always describes the latest ingestion attempt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestionReport {
+    /// Path of the source file in the repository
+    pub path: String,
+
+    /// Id of the article, if the front matter got far enough to provide one
+    pub article_id: Option<ArticleId>,
+
+    /// Overall outcome
+    pub status: IngestionStatus,
+
+    /// Issues found while ingesting the file
+    pub issues: Vec<IngestionIssue>,
+
+    /// When the file was ingested
+    #[serde(with = "time::serde::rfc3339")]
+    pub ingested_at: OffsetDateTime,
+}
+
+impl IngestionReport {
+    /// Build a report, deriving the status from the issues
+    ///
+    /// A report is `Failed` if any issue is an error, even if an article id
+    /// could be read.
+    pub fn new(path: &str, article_id: Option<ArticleId>, issues: Vec<IngestionIssue>) -> Self {
+        Self {
+            path: path.to_string(),
+            article_id,
+            status: status_of(&issues),
+            issues,
+            ingested_at: OffsetDateTime::now_utc(),
+        }
+    }
+
+    /// Add an issue found after the report was built, updating the status
+    pub fn push_issue(&mut self, issue: IngestionIssue) {
+        self.issues.push(issue);
+        self.status = status_of(&self.issues);
+    }
+
+    /// Fold a later report for the same path into this one
+    ///
+    /// Issues of both reports are kept; the later article id (if any) and
+    /// ingestion time win.
+    pub fn merge(&mut self, later: IngestionReport) {
+        self.article_id = later.article_id.or(self.article_id);
+        self.issues.extend(later.issues);
+        self.status = status_of(&self.issues);
+        self.ingested_at = later.ingested_at;
+    }
+}
+
+/// Collapse the reports of a push to one per path
+///
+/// A path can be reported more than once in a push (for example when a
+/// push both adds and modifies it), but only one report per path is kept.
+/// Reports are merged in order, and the first occurrence keeps its position.
+pub fn dedupe_reports(reports: &[IngestionReport]) -> Vec<IngestionReport> {
+    let mut deduped: Vec<IngestionReport> = Vec::with_capacity(reports.len());
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+
+    for report in reports {
+        match positions.get(report.path.as_str()) {
+            Some(&i) => deduped[i].merge(report.clone()),
+            None => {
+                positions.insert(&report.path, deduped.len());
+                deduped.push(report.clone());
+            }
+        }
+    }
+
+    deduped
+}
+
+/// Derive the overall status from the issues of a file
+fn status_of(issues: &[IngestionIssue]) -> IngestionStatus {
+    if issues.iter().any(IngestionIssue::is_error) {
+        IngestionStatus::Failed
+    } else if issues.is_empty() {
+        IngestionStatus::Ingested
+    } else {
+        IngestionStatus::IngestedWithWarnings
+    }
+}
@@ -0,0 +1,148 @@
+/// Value of the `lang` query parameter that turns language filtering off
+pub const ALL_LANGUAGES: &str = "all";
+
+/// Check that a string is a BCP 47 style language tag
+///
+/// Accepts a 2 or 3 letter primary subtag followed by any number of 1 to 8
+/// character alphanumeric subtags, such as `en`, `zh-CN` or `zh-Hant-TW`.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::languages::is_language_tag;
+///
+/// assert!(is_language_tag("zh-Hant-TW"));
+/// assert!(!is_language_tag("english"));
+/// assert!(!is_language_tag("en-"));
+/// ```
+pub fn is_language_tag(tag: &str) -> bool {
+    let mut parts = tag.split('-');
+    let primary_ok = parts
+        .next()
+        .is_some_and(|p| (2..=3).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphabetic()));
+
+    primary_ok
+        && parts.all(|p| (1..=8).contains(&p.len()) && p.chars().all(|c| c.is_ascii_alphanumeric()))
+}
+
+/// Language an article is listed and searched under
+///
+/// Regional variants share their primary language, so `zh-CN` and `zh-TW`
+/// posts are both listed for `zh`.
+///
+/// # Returns
+///
+/// * `Some(String)` - The lowercased primary subtag
+/// * `None` - The string is not a valid language tag
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::languages::primary_language;
+///
+/// assert_eq!(primary_language("zh-CN").as_deref(), Some("zh"));
+/// assert_eq!(primary_language("EN").as_deref(), Some("en"));
+/// assert_eq!(primary_language("*"), None);
+/// ```
+pub fn primary_language(tag: &str) -> Option<String> {
+    let tag = tag.trim();
+    if !is_language_tag(tag) {
+        return None;
+    }
+
+    tag.split('-').next().map(str::to_ascii_lowercase)
+}
+
+/// Primary languages of an `Accept-Language` header, most preferred first
+///
+/// Entries are ordered by their `q` weight, keeping the header order for
+/// equal weights. Wildcards, invalid tags and entries with `q=0` are
+/// skipped, as are repeated primary languages.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::languages::accept_language;
+///
+/// assert_eq!(
+///     accept_language("en-US;q=0.8, zh-CN, zh;q=0.9, fr;q=0, *;q=0.1"),
+///     ["zh", "en"]
+/// );
+/// ```
+pub fn accept_language(header: &str) -> Vec<String> {
+    let mut ranges: Vec<(f32, String)> = header
+        .split(',')
+        .filter_map(|entry| {
+            let mut params = entry.split(';');
+            let language = primary_language(params.next()?)?;
+            let weight = params
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .map_or(Some(1.0), |q| q.trim().parse::<f32>().ok())?;
+
+            (weight > 0.0).then_some((weight, language))
+        })
+        .collect();
+
+    // Stable sort, so equal weights keep the header order
+    ranges.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut languages: Vec<String> = Vec::new();
+    for (_, language) in ranges {
+        if !languages.contains(&language) {
+            languages.push(language);
+        }
+    }
+
+    languages
+}
+
+/// Choose the language of a listing or search
+///
+/// An explicit request wins; [`ALL_LANGUAGES`] turns filtering off.
+/// Otherwise the most preferred `Accept-Language` entry that some article
+/// is written in is used, and nothing is filtered if there is none.
+///
+/// # Arguments
+///
+/// * `requested` - The `lang` query parameter
+/// * `accept_language` - The `Accept-Language` header
+/// * `available` - Primary languages of the published articles
+///
+/// # Returns
+///
+/// * `Ok(Some(String))` - Primary language to filter by
+/// * `Ok(None)` - Don't filter by language
+/// * `Err(String)` - The requested language is not a valid language tag
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::languages::negotiate_language;
+///
+/// let available = ["en".to_string(), "zh".to_string()];
+///
+/// assert_eq!(negotiate_language(Some("en-GB"), None, &available), Ok(Some("en".to_string())));
+/// assert_eq!(negotiate_language(Some("all"), Some("zh"), &available), Ok(None));
+/// assert_eq!(negotiate_language(None, Some("fr, zh;q=0.5"), &available), Ok(Some("zh".to_string())));
+/// assert_eq!(negotiate_language(None, Some("fr"), &available), Ok(None));
+/// ```
+pub fn negotiate_language(
+    requested: Option<&str>,
+    accept_language: Option<&str>,
+    available: &[String],
+) -> Result<Option<String>, String> {
+    if let Some(requested) = requested {
+        if requested.eq_ignore_ascii_case(ALL_LANGUAGES) {
+            return Ok(None);
+        }
+        return primary_language(requested)
+            .map(Some)
+            .ok_or_else(|| requested.to_string());
+    }
+
+    Ok(accept_language.and_then(|header| {
+        self::accept_language(header)
+            .into_iter()
+            .find(|language| available.contains(language))
+    }))
+}
@@ -0,0 +1,307 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::{
+    articles::{ArticleId, PostCategory},
+    source_formats::SourceFormat,
+};
+
+/// Canonical site URL of an article
+///
+/// Internal links are rewritten to this form at ingestion, so the stored
+/// content no longer depends on where the file lives in the repository.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::links::article_url;
+///
+/// let id = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+/// let category = "note".parse().unwrap();
+/// assert_eq!(
+///     article_url(&category, &id),
+///     "/note/67e55044-10b1-426f-9247-bb680e5fe0c8"
+/// );
+/// ```
+pub fn article_url(category: &PostCategory, id: &ArticleId) -> String {
+    format!("/{}/{}", category, id)
+}
+
+/// An article that internal links can point at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinkTarget {
+    /// Id of the article
+    pub id: ArticleId,
+
+    /// Path of the source file in the repository
+    pub path: String,
+
+    /// Article title
+    pub title: String,
+
+    /// Article category, part of the canonical URL
+    pub category: PostCategory,
+}
+
+impl LinkTarget {
+    /// Canonical site URL of the article
+    pub fn url(&self) -> String {
+        article_url(&self.category, &self.id)
+    }
+}
+
+/// A directed link from one article to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct LinkEdge {
+    /// Article containing the link
+    pub source: ArticleId,
+
+    /// Article being linked to
+    pub target: ArticleId,
+}
+
+/// Why an internal link could not be resolved
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BrokenLinkReason {
+    /// No article matches the link
+    NotFound,
+
+    /// Several articles match a `[[wiki-style]]` reference
+    Ambiguous,
+}
+
+impl fmt::Display for BrokenLinkReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BrokenLinkReason::NotFound => f.write_str("no article matches"),
+            BrokenLinkReason::Ambiguous => f.write_str("several articles match"),
+        }
+    }
+}
+
+/// An internal link that could not be resolved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrokenLink {
+    /// The link as written (destination or wiki name)
+    pub link: String,
+
+    /// Why it could not be resolved
+    pub reason: BrokenLinkReason,
+}
+
+/// Result of resolving the internal links of one article
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResolvedLinks {
+    /// Content with internal links rewritten to canonical URLs
+    pub content: String,
+
+    /// Articles linked to, in order of first occurrence, without duplicates
+    pub targets: Vec<ArticleId>,
+
+    /// Links left untouched because they could not be resolved
+    pub broken: Vec<BrokenLink>,
+}
+
+/// Lookup table used to resolve internal links during ingestion
+///
+/// Markdown links are matched by repository path. `[[wiki-style]]`
+/// references are matched, case-insensitively, against the article title,
+/// the file name without extension, or the article id.
+#[derive(Debug, Default)]
+pub struct LinkIndex {
+    /// Repository path -> article
+    by_path: HashMap<String, LinkTarget>,
+
+    /// Normalized wiki name -> article, `None` when the name is ambiguous
+    by_name: HashMap<String, Option<LinkTarget>>,
+}
+
+impl LinkIndex {
+    /// Build the index from every linkable article
+    ///
+    /// Later entries with the same path replace earlier ones, so the articles
+    /// of the current push can be chained after the stored ones.
+    pub fn new(targets: impl IntoIterator<Item = LinkTarget>) -> Self {
+        let mut by_path = HashMap::new();
+        for target in targets {
+            by_path.insert(target.path.clone(), target);
+        }
+
+        let mut by_name: HashMap<String, Option<LinkTarget>> = HashMap::new();
+        for target in by_path.values() {
+            let stem = file_stem(&target.path);
+            let names =
+                [target.title.as_str(), stem, &target.id.to_string()].map(normalize_wiki_name);
+
+            for name in names.into_iter().filter(|n| !n.is_empty()) {
+                match by_name.get_mut(&name) {
+                    Some(Some(existing)) if existing.id != target.id => {
+                        by_name.insert(name, None);
+                    }
+                    Some(_) => {}
+                    None => {
+                        by_name.insert(name, Some(target.clone()));
+                    }
+                }
+            }
+        }
+
+        Self { by_path, by_name }
+    }
+
+    /// Resolve a relative markdown link destination (e.g. `../notes/a.md`)
+    ///
+    /// # Arguments
+    ///
+    /// * `source_path` - Repository path of the file containing the link
+    /// * `dest` - Link destination without fragment; a leading `/` is
+    ///   relative to the repository root
+    pub fn resolve_path(
+        &self,
+        source_path: &str,
+        dest: &str,
+    ) -> Result<&LinkTarget, BrokenLinkReason> {
+        resolve_repo_path(source_path, dest)
+            .and_then(|path| self.by_path.get(&path))
+            .ok_or(BrokenLinkReason::NotFound)
+    }
+
+    /// Resolve the target of a `[[wiki-style]]` reference
+    pub fn resolve_wiki(&self, name: &str) -> Result<&LinkTarget, BrokenLinkReason> {
+        match self.by_name.get(&normalize_wiki_name(name)) {
+            Some(Some(target)) => Ok(target),
+            Some(None) => Err(BrokenLinkReason::Ambiguous),
+            None => Err(BrokenLinkReason::NotFound),
+        }
+    }
+}
+
+/// Check whether a link destination points at an article source in the repository
+///
+/// Links to any [`SourceFormat`] count, so markdown posts can link to
+/// notebooks, Org-mode and AsciiDoc posts and the other way around.
+///
+/// Destinations with a URL scheme (`https:`, `mailto:`), protocol-relative
+/// URLs and pure fragments are external or page-local.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::links::is_internal_markdown_link;
+///
+/// assert!(is_internal_markdown_link("../notes/ownership.md#borrowing"));
+/// assert!(is_internal_markdown_link("intro.MDX"));
+/// assert!(is_internal_markdown_link("notes/emacs.org"));
+/// assert!(!is_internal_markdown_link("https://example.com/readme.md"));
+/// assert!(!is_internal_markdown_link("#section"));
+/// assert!(!is_internal_markdown_link("image.png"));
+/// ```
+pub fn is_internal_markdown_link(dest: &str) -> bool {
+    let path = split_fragment(dest).0;
+
+    !is_external_url(path) && SourceFormat::from_path(path).is_some()
+}
+
+/// Check whether a destination is a URL rather than a repository path
+///
+/// URLs with a scheme (`https:`, `mailto:`, `data:`) and protocol-relative
+/// URLs (`//cdn.example.com/…`) are external.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::links::is_external_url;
+///
+/// assert!(is_external_url("https://example.com/a.png"));
+/// assert!(is_external_url("//cdn.example.com/a.png"));
+/// assert!(is_external_url("data:image/png;base64,AAAA"));
+/// assert!(!is_external_url("../images/a.png"));
+/// assert!(!is_external_url("/assets/a:b.png"));
+/// ```
+pub fn is_external_url(dest: &str) -> bool {
+    let has_scheme = dest
+        .split_once(':')
+        .is_some_and(|(scheme, _)| !scheme.is_empty() && !scheme.contains('/'));
+
+    has_scheme || dest.starts_with("//")
+}
+
+/// Split a link destination into its path and optional `#fragment`
+///
+/// A `?query` on the path is dropped.
+pub fn split_fragment(dest: &str) -> (&str, Option<&str>) {
+    let (path, fragment) = match dest.split_once('#') {
+        Some((path, fragment)) => (path, Some(fragment)),
+        None => (dest, None),
+    };
+    let path = path.split_once('?').map_or(path, |(path, _)| path);
+
+    (path, fragment)
+}
+
+/// Resolve a path written in a file against the file's location
+///
+/// Relative paths start from the directory of `source_path`; a leading `/`
+/// starts from the repository root. Returns `None` if the path climbs above
+/// the repository root.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::links::resolve_repo_path;
+///
+/// assert_eq!(
+///     resolve_repo_path("posts/rust/intro.md", "../images/crab.png").as_deref(),
+///     Some("posts/images/crab.png")
+/// );
+/// assert_eq!(
+///     resolve_repo_path("posts/intro.md", "/assets/logo.svg").as_deref(),
+///     Some("assets/logo.svg")
+/// );
+/// assert_eq!(resolve_repo_path("intro.md", "../secret"), None);
+/// ```
+pub fn resolve_repo_path(source_path: &str, dest: &str) -> Option<String> {
+    let base = source_path.rsplit_once('/').map_or("", |(dir, _)| dir);
+
+    join_repo_path(base, dest)
+}
+
+/// Join a relative link onto a repository directory, resolving `.` and `..`
+///
+/// Returns `None` if the link climbs above the repository root.
+fn join_repo_path(base_dir: &str, dest: &str) -> Option<String> {
+    let mut parts: Vec<&str> = Vec::new();
+    let (start, dest) = match dest.strip_prefix('/') {
+        Some(rooted) => ("", rooted),
+        None => (base_dir, dest),
+    };
+
+    for part in start.split('/').chain(dest.split('/')) {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            part => parts.push(part),
+        }
+    }
+
+    Some(parts.join("/"))
+}
+
+/// File name of a repository path without its extension
+fn file_stem(path: &str) -> &str {
+    let name = path.rsplit_once('/').map_or(path, |(_, name)| name);
+
+    name.rsplit_once('.').map_or(name, |(stem, _)| stem)
+}
+
+/// Normalize a wiki name for case- and spacing-insensitive matching
+fn normalize_wiki_name(name: &str) -> String {
+    name.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
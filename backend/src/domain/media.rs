@@ -0,0 +1,436 @@
+use std::{fmt, str::FromStr};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{articles::ArticleId, exif::ExifMetadata},
+    errors::{MediaError, Result},
+};
+
+/// Length of a hex encoded SHA-256 digest
+const HASH_LEN: usize = 64;
+
+/// Longest lifetime S3 accepts for a presigned URL, one week
+pub const MAX_PRESIGN_SECONDS: u32 = 7 * 24 * 60 * 60;
+
+/// Content hash identifying a stored media blob
+///
+/// Blobs are content addressed: the hash is the lowercase hex SHA-256 of the
+/// stored bytes, so the same image referenced from several posts (or pushed
+/// twice) is stored once, and the URL of a blob never changes meaning. This is
+/// what allows `/media/{hash}` to be served with immutable caching headers.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::media::MediaHash;
+///
+/// let hash: MediaHash = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(hash.as_str().len(), 64);
+///
+/// assert!("9F86D081".parse::<MediaHash>().is_err());
+/// assert!("../../etc/passwd".parse::<MediaHash>().is_err());
+/// ```
+#[derive(
+    Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type,
+)]
+#[serde(try_from = "String", into = "String")]
+#[sqlx(transparent)]
+pub struct MediaHash(String);
+
+impl MediaHash {
+    /// Hash the bytes of a blob (webhook feature only)
+    #[cfg(feature = "webhook")]
+    pub fn of(bytes: &[u8]) -> Self {
+        use sha2::{Digest, Sha256};
+
+        Self(hex::encode(Sha256::digest(bytes)))
+    }
+
+    /// Get the hex digest
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for MediaHash {
+    type Err = MediaError;
+
+    /// Parse a hash taken from a URL or the database
+    ///
+    /// Only 64 lowercase hex characters are accepted, which also guarantees
+    /// the hash is safe to use as a file name.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let is_valid = s.len() == HASH_LEN
+            && s.bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+
+        if is_valid {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(MediaError::InvalidHash(s.to_string()))
+        }
+    }
+}
+
+impl TryFrom<String> for MediaHash {
+    type Error = MediaError;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<MediaHash> for String {
+    fn from(hash: MediaHash) -> Self {
+        hash.0
+    }
+}
+
+impl fmt::Display for MediaHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Encoding of a responsive image variant
+///
+/// Both formats are lossy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariantFormat {
+    /// WebP, widely supported, including by browsers and feed readers
+    /// without AVIF support
+    Webp,
+
+    /// AVIF, smaller but slower to encode
+    Avif,
+}
+
+impl VariantFormat {
+    /// MIME type the variant is served with
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            VariantFormat::Webp => "image/webp",
+            VariantFormat::Avif => "image/avif",
+        }
+    }
+}
+
+/// A stored media blob: an original image or one of its variants
+///
+/// Maps to a row of the `media_assets` table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaAsset {
+    /// Content hash of the blob
+    pub hash: MediaHash,
+
+    /// MIME type the blob is served with
+    pub content_type: String,
+
+    /// Size of the blob in bytes
+    pub byte_size: i64,
+
+    /// Width in pixels
+    pub width: i32,
+
+    /// Height in pixels
+    pub height: i32,
+
+    /// Blurhash placeholder, computed for originals only
+    pub blurhash: Option<String>,
+
+    /// Hash of the original this blob is a resized variant of
+    pub original: Option<MediaHash>,
+
+    /// Camera metadata of an original photo; variants carry no EXIF data
+    pub exif: Option<ExifMetadata>,
+}
+
+/// An article using an ingested image, recorded in `article_media`
+///
+/// Blobs no article references any more are garbage collected.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MediaReference {
+    /// Article embedding the image or listing it in its gallery
+    pub article: ArticleId,
+
+    /// Hash of the original image; variants follow their original
+    pub hash: MediaHash,
+}
+
+/// Where a media blob can be read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MediaContent {
+    /// The blob itself, proxied through the API
+    Bytes(Vec<u8>),
+
+    /// A presigned URL the client can fetch the blob from directly
+    Redirect {
+        /// Presigned URL of the blob
+        url: String,
+
+        /// Seconds the URL stays valid
+        expires_in: u32,
+    },
+}
+
+/// Content-addressed storage for media blobs
+///
+/// Blobs are written once under their [`MediaHash`] and never modified, so
+/// implementations don't need to handle concurrent writers beyond making a
+/// write atomic: two writes of the same hash always carry the same bytes.
+///
+/// # Implementations
+///
+/// * `LocalMediaStore` - A directory on the local filesystem
+/// * `S3MediaStore` - An S3-compatible bucket (AWS S3, MinIO, R2, …)
+#[async_trait]
+pub trait MediaStore: Send + Sync {
+    /// Store a blob under its hash
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Hash of `bytes`, as computed by the caller
+    /// * `bytes` - Content of the blob
+    /// * `content_type` - MIME type the blob is served with
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The blob is stored (or already was)
+    /// * `Err(SomeError)` - The blob could not be written
+    async fn put(&self, hash: &MediaHash, bytes: &[u8], content_type: &str) -> Result<()>;
+
+    /// Read a blob
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(bytes))` - Content of the blob
+    /// * `Ok(None)` - No blob is stored under the hash
+    /// * `Err(SomeError)` - The blob could not be read
+    async fn get(&self, hash: &MediaHash) -> Result<Option<Vec<u8>>>;
+
+    /// Remove a blob
+    ///
+    /// Removing a blob that doesn't exist is not an error.
+    async fn delete(&self, hash: &MediaHash) -> Result<()>;
+
+    /// Presign a URL clients can read the blob from without going through the API
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(MediaContent::Redirect { .. }))` - A URL to redirect to
+    /// * `Ok(None)` - The store doesn't presign reads; serve the bytes from
+    ///   [`MediaStore::get`] instead
+    /// * `Err(SomeError)` - The URL could not be signed
+    async fn presigned_read(&self, hash: &MediaHash) -> Result<Option<MediaContent>>;
+}
+
+/// Image pipeline settings declared as `[media]` in config.toml
+///
+/// Blobs are kept in a local directory unless a `[media.s3]` table is given.
+///
+/// # Example
+///
+/// ```toml
+/// [media]
+/// root = "/var/lib/mizu/media"
+/// public_path = "/api/media"
+/// widths = [480, 960, 1600]
+/// formats = ["webp", "avif"]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct MediaOptions {
+    /// Directory of the local media store, only read at startup
+    pub root: String,
+
+    /// S3-compatible bucket used instead of the local directory, only read
+    /// at startup
+    pub s3: Option<S3Options>,
+
+    /// URL prefix under which `/media/{hash}` is reachable by browsers;
+    /// rewritten image URLs are `{public_path}/{hash}`
+    pub public_path: String,
+
+    /// Widths in pixels of the resized variants; widths not smaller than
+    /// the original are skipped, images are never upscaled
+    pub widths: Vec<u32>,
+
+    /// Encodings generated for every variant width
+    pub formats: Vec<VariantFormat>,
+
+    /// Largest source image accepted, in bytes
+    pub max_source_bytes: usize,
+}
+
+impl Default for MediaOptions {
+    fn default() -> Self {
+        Self {
+            root: "media".to_string(),
+            s3: None,
+            public_path: "/api/media".to_string(),
+            widths: vec![480, 960, 1600],
+            formats: vec![VariantFormat::Webp, VariantFormat::Avif],
+            max_source_bytes: 20 * 1024 * 1024,
+        }
+    }
+}
+
+impl MediaOptions {
+    /// Reject options the pipeline can't work with
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the root or public path is empty, a variant width
+    /// is zero, the size limit is zero, or the S3 options are invalid.
+    pub fn validate(mut self) -> Result<Self> {
+        if self.root.trim().is_empty() {
+            return Err(
+                anyhow::anyhow!("Invalid media configuration: `root` must not be empty").into(),
+            );
+        }
+        if self.public_path.trim().trim_end_matches('/').is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid media configuration: `public_path` must not be empty"
+            )
+            .into());
+        }
+        if self.widths.contains(&0) {
+            return Err(anyhow::anyhow!(
+                "Invalid media configuration: `widths` must be greater than 0"
+            )
+            .into());
+        }
+        if self.max_source_bytes == 0 {
+            return Err(anyhow::anyhow!(
+                "Invalid media configuration: `max_source_bytes` must be greater than 0"
+            )
+            .into());
+        }
+
+        if let Some(s3) = &self.s3 {
+            s3.validate()?;
+        }
+
+        self.widths.sort_unstable();
+        self.widths.dedup();
+        self.formats.dedup();
+
+        Ok(self)
+    }
+
+    /// Public URL of a stored blob
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::media::{MediaHash, MediaOptions};
+    ///
+    /// let hash: MediaHash = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+    ///     .parse()
+    ///     .unwrap();
+    /// assert_eq!(
+    ///     MediaOptions::default().url(&hash),
+    ///     "/api/media/9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+    /// );
+    /// ```
+    pub fn url(&self, hash: &MediaHash) -> String {
+        format!("{}/{}", self.public_path.trim_end_matches('/'), hash)
+    }
+}
+
+/// S3-compatible bucket settings declared as `[media.s3]` in config.toml
+///
+/// Works with AWS S3 and with S3-compatible services such as MinIO or
+/// Cloudflare R2 by setting `endpoint` (and usually `path_style`).
+///
+/// # Example
+///
+/// ```toml
+/// [media.s3]
+/// bucket = "mizu-media"
+/// region = "us-east-1"
+/// endpoint = "http://localhost:9000"
+/// path_style = true
+/// access_key = "minioadmin"
+/// secret_key = "minioadmin"
+/// prefix = "media"
+/// presign_seconds = 3600
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct S3Options {
+    /// Bucket holding the blobs
+    pub bucket: String,
+
+    /// Region of the bucket; any name works with a custom endpoint
+    #[serde(default = "default_s3_region")]
+    pub region: String,
+
+    /// Endpoint of an S3-compatible service, e.g. `http://localhost:9000`;
+    /// AWS S3 when omitted
+    #[serde(default)]
+    pub endpoint: Option<String>,
+
+    /// Address the bucket as `{endpoint}/{bucket}` instead of
+    /// `{bucket}.{endpoint}`, as most self-hosted services expect
+    #[serde(default)]
+    pub path_style: bool,
+
+    /// Access key id; read from the standard AWS environment variables
+    /// and profiles when omitted
+    #[serde(default)]
+    pub access_key: Option<String>,
+
+    /// Secret access key, given together with `access_key`
+    #[serde(default)]
+    pub secret_key: Option<String>,
+
+    /// Key prefix the blobs are stored under, so a bucket can be shared
+    #[serde(default)]
+    pub prefix: String,
+
+    /// Lifetime of presigned read URLs in seconds; 0 proxies reads through
+    /// the API instead, for private buckets behind the site's own cache
+    #[serde(default)]
+    pub presign_seconds: u32,
+}
+
+fn default_s3_region() -> String {
+    "us-east-1".to_string()
+}
+
+impl S3Options {
+    /// Reject settings no S3 service would accept
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bucket is empty, only one of the access key
+    /// and secret key is given, or presigned URLs would outlive one week.
+    pub fn validate(&self) -> Result<()> {
+        if self.bucket.trim().is_empty() {
+            return Err(anyhow::anyhow!(
+                "Invalid media configuration: `s3.bucket` must not be empty"
+            )
+            .into());
+        }
+        if self.access_key.is_some() != self.secret_key.is_some() {
+            return Err(anyhow::anyhow!(
+                "Invalid media configuration: `s3.access_key` and `s3.secret_key` must be given together"
+            )
+            .into());
+        }
+        if self.presign_seconds > MAX_PRESIGN_SECONDS {
+            return Err(anyhow::anyhow!(
+                "Invalid media configuration: `s3.presign_seconds` must be at most {}",
+                MAX_PRESIGN_SECONDS
+            )
+            .into());
+        }
+
+        Ok(())
+    }
+}
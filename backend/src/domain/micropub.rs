@@ -0,0 +1,641 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+
+use crate::{
+    domain::{
+        articles::{Article, ArticleId, PostCategory},
+        categories::CategoryRegistry,
+        links::article_url,
+    },
+    errors::{MicropubError, Result, SomeError},
+};
+
+/// Prefix of the `path` stored for Micropub posts
+///
+/// Micropub posts have no file in the repository; their path is this prefix
+/// followed by the article id, which no pushed file can collide with.
+pub const MICROPUB_PATH_PREFIX: &str = "micropub:";
+
+/// Values accepted for the `post-status` property
+const POST_STATUSES: &[&str] = &["published", "draft"];
+
+/// Stored `path` of a Micropub post
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::micropub::micropub_path;
+///
+/// let id = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+/// assert_eq!(micropub_path(&id), "micropub:67e55044-10b1-426f-9247-bb680e5fe0c8");
+/// ```
+pub fn micropub_path(id: &ArticleId) -> String {
+    format!("{}{}", MICROPUB_PATH_PREFIX, id)
+}
+
+/// Read the article id from a post URL sent by a Micropub client
+///
+/// The id is the last path segment of the canonical post URL, see
+/// [`article_url`]. The host is not checked, so relative URLs work too.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::micropub::article_id_from_url;
+///
+/// let id = article_id_from_url("https://example.com/talk/67e55044-10b1-426f-9247-bb680e5fe0c8/");
+/// assert_eq!(id.unwrap().to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+///
+/// assert!(article_id_from_url("https://example.com/about").is_none());
+/// ```
+pub fn article_id_from_url(url: &str) -> Option<ArticleId> {
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+
+    path.trim_end_matches('/').rsplit('/').next()?.parse().ok()
+}
+
+/// Compare an access token with the configured one
+///
+/// Runs in time independent of where the tokens differ, so the token can't
+/// be guessed byte by byte from response times.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::micropub::token_matches;
+///
+/// assert!(token_matches("s3cret", "s3cret"));
+/// assert!(!token_matches("s3cret", "s3creT"));
+/// assert!(!token_matches("s3cret", "s3"));
+/// ```
+pub fn token_matches(expected: &str, given: &str) -> bool {
+    expected.len() == given.len()
+        && expected
+            .bytes()
+            .zip(given.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+/// Micropub settings declared as `[micropub]` in config.toml
+///
+/// The endpoint is only enabled when an access token is configured, see
+/// `Config::micropub_token`.
+///
+/// # Example
+///
+/// ```toml
+/// [micropub]
+/// category = "talk"
+/// site_url = "https://example.com"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct MicropubOptions {
+    /// Category of the posts created through Micropub
+    pub category: PostCategory,
+
+    /// Origin of the public site; post URLs returned to clients are
+    /// relative to the site when it is not set
+    pub site_url: Option<String>,
+}
+
+impl Default for MicropubOptions {
+    fn default() -> Self {
+        Self {
+            category: "talk".parse().expect("built-in category slugs are valid"),
+            site_url: None,
+        }
+    }
+}
+
+impl MicropubOptions {
+    /// Reject options the endpoint can't work with
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the category is not configured or the site URL
+    /// is not an absolute http(s) URL.
+    pub fn validate(mut self, categories: &CategoryRegistry) -> Result<Self> {
+        if !categories.contains(&self.category) {
+            return Err(anyhow::anyhow!(
+                "Invalid micropub configuration: unknown category `{}`",
+                self.category
+            )
+            .into());
+        }
+
+        if let Some(site_url) = &self.site_url {
+            let site_url = site_url.trim().trim_end_matches('/');
+            let host = site_url
+                .strip_prefix("https://")
+                .or_else(|| site_url.strip_prefix("http://"));
+            if host.is_none_or(str::is_empty) {
+                return Err(anyhow::anyhow!(
+                    "Invalid micropub configuration: `site_url` must be an absolute http(s) URL"
+                )
+                .into());
+            }
+            self.site_url = Some(site_url.to_string());
+        }
+
+        Ok(self)
+    }
+
+    /// Public URL of a post, as returned to clients
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::micropub::MicropubOptions;
+    ///
+    /// let options = MicropubOptions {
+    ///     site_url: Some("https://example.com".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let id = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+    /// assert_eq!(
+    ///     options.post_url(&options.category, &id),
+    ///     "https://example.com/talk/67e55044-10b1-426f-9247-bb680e5fe0c8"
+    /// );
+    /// ```
+    pub fn post_url(&self, category: &PostCategory, id: &ArticleId) -> String {
+        format!(
+            "{}{}",
+            self.site_url.as_deref().unwrap_or_default(),
+            article_url(category, id)
+        )
+    }
+}
+
+/// Properties of a Micropub `h-entry`
+///
+/// Only the properties that map onto a post are kept; others, including
+/// the `mp-*` server commands, are ignored as the specification allows.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MicropubProperties {
+    /// Post body, markdown or HTML
+    pub content: Option<String>,
+
+    /// Post title
+    pub name: Option<String>,
+
+    /// Tags
+    pub category: Vec<String>,
+
+    /// Summary
+    pub summary: Option<String>,
+
+    /// Publication status, `published` or `draft`
+    pub post_status: Option<String>,
+
+    /// Publication time
+    pub published: Option<OffsetDateTime>,
+}
+
+impl MicropubProperties {
+    /// Read properties from a map of property names to value arrays
+    ///
+    /// # Errors
+    ///
+    /// Returns `MicropubError::InvalidRequest` if a value is not an array or
+    /// has the wrong type.
+    pub fn from_map(properties: &Map<String, Value>) -> Result<Self> {
+        let mut parsed = Self::default();
+        for (name, values) in properties {
+            parsed.replace(name, values)?;
+        }
+
+        Ok(parsed)
+    }
+
+    /// Properties in the Micropub JSON format, for `q=source`
+    pub fn to_map(&self) -> Map<String, Value> {
+        let mut map = Map::new();
+        let mut insert = |name: &str, value: Option<String>| {
+            if let Some(value) = value {
+                map.insert(name.to_string(), Value::from(vec![value]));
+            }
+        };
+
+        insert("content", self.content.clone());
+        insert("name", self.name.clone());
+        insert("summary", self.summary.clone());
+        insert("post-status", self.post_status.clone());
+        insert(
+            "published",
+            self.published.and_then(|p| p.format(&Rfc3339).ok()),
+        );
+        if !self.category.is_empty() {
+            map.insert("category".to_string(), Value::from(self.category.clone()));
+        }
+
+        map
+    }
+
+    /// Replace the values of a property
+    fn replace(&mut self, name: &str, values: &Value) -> Result<()> {
+        let values = values_of(name, values)?;
+
+        match name {
+            "content" => self.content = single(name, values)?,
+            "name" => self.name = single(name, values)?.filter(|n| !n.trim().is_empty()),
+            "summary" => self.summary = single(name, values)?.filter(|s| !s.trim().is_empty()),
+            "category" => self.category = texts(name, values)?,
+            "post-status" => {
+                let status = single(name, values)?;
+                if let Some(status) = &status
+                    && !POST_STATUSES.contains(&status.as_str())
+                {
+                    return Err(invalid(format!(
+                        "`post-status` must be one of {}",
+                        POST_STATUSES.join(", ")
+                    )));
+                }
+                self.post_status = status;
+            }
+            "published" => {
+                self.published = single(name, values)?
+                    .map(|p| {
+                        OffsetDateTime::parse(&p, &Rfc3339).map_err(|_| {
+                            invalid(format!("`published` is not an RFC 3339 date: `{}`", p))
+                        })
+                    })
+                    .transpose()?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Add values to a property
+    ///
+    /// Single-valued properties can only be added while they have no value.
+    fn add(&mut self, name: &str, values: &Value) -> Result<()> {
+        if name == "category" {
+            for tag in texts(name, values_of(name, values)?)? {
+                if !self.category.contains(&tag) {
+                    self.category.push(tag);
+                }
+            }
+            return Ok(());
+        }
+
+        let has_value = match name {
+            "content" => self.content.is_some(),
+            "name" => self.name.is_some(),
+            "summary" => self.summary.is_some(),
+            "post-status" => self.post_status.is_some(),
+            "published" => self.published.is_some(),
+            _ => false,
+        };
+        if has_value {
+            return Err(invalid(format!(
+                "`{}` already has a value, use `replace`",
+                name
+            )));
+        }
+
+        self.replace(name, values)
+    }
+
+    /// Remove a property entirely
+    fn remove(&mut self, name: &str) -> Result<()> {
+        match name {
+            "content" => return Err(invalid("`content` can't be deleted")),
+            "name" => self.name = None,
+            "summary" => self.summary = None,
+            "category" => self.category.clear(),
+            "post-status" => self.post_status = None,
+            "published" => self.published = None,
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Remove some values of a multi-valued property
+    fn remove_values(&mut self, name: &str, values: &Value) -> Result<()> {
+        if name != "category" {
+            return Err(invalid(format!(
+                "values can only be deleted from `category`, not `{}`",
+                name
+            )));
+        }
+
+        let removed = texts(name, values_of(name, values)?)?;
+        self.category.retain(|tag| !removed.contains(tag));
+
+        Ok(())
+    }
+}
+
+/// The properties a Micropub client can edit on a stored post
+///
+/// Titles and summaries generated by the server are left out, so they are
+/// not written back as if the author had set them.
+impl From<&Article> for MicropubProperties {
+    fn from(article: &Article) -> Self {
+        Self {
+            content: Some(article.content.clone()),
+            name: Some(article.title.clone()).filter(|t| !t.is_empty()),
+            category: article.tags.clone(),
+            summary: article
+                .summary
+                .clone()
+                .filter(|_| !article.summary_generated),
+            post_status: Some(article.status.clone()),
+            published: Some(article.created_at),
+        }
+    }
+}
+
+/// Changes requested by a Micropub `update` action
+///
+/// Applied in the order of the specification: `replace`, then `add`, then
+/// `delete`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MicropubUpdate {
+    /// Properties whose values are replaced
+    pub replace: Map<String, Value>,
+
+    /// Values added to properties
+    pub add: Map<String, Value>,
+
+    /// Properties removed entirely
+    pub delete: Vec<String>,
+
+    /// Values removed from properties
+    pub delete_values: Map<String, Value>,
+}
+
+impl MicropubUpdate {
+    /// Apply the changes to the properties of a post
+    ///
+    /// # Errors
+    ///
+    /// Returns `MicropubError::InvalidRequest` if a change is malformed or
+    /// touches `published`, which is fixed once a post is created.
+    pub fn apply(&self, properties: &mut MicropubProperties) -> Result<()> {
+        let touched = self
+            .replace
+            .keys()
+            .chain(self.add.keys())
+            .chain(&self.delete)
+            .chain(self.delete_values.keys());
+        for name in touched {
+            if name == "published" {
+                return Err(invalid("`published` can't be changed"));
+            }
+        }
+
+        for (name, values) in &self.replace {
+            properties.replace(name, values)?;
+        }
+        for (name, values) in &self.add {
+            properties.add(name, values)?;
+        }
+        for name in &self.delete {
+            properties.remove(name)?;
+        }
+        for (name, values) in &self.delete_values {
+            properties.remove_values(name, values)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read the changes of a JSON `update` request
+    fn from_json(body: &Map<String, Value>) -> Result<Self> {
+        let object = |field: &str| match body.get(field) {
+            None => Ok(Map::new()),
+            Some(Value::Object(map)) => Ok(map.clone()),
+            Some(_) => Err(invalid(format!("`{}` must be an object", field))),
+        };
+
+        let mut update = Self {
+            replace: object("replace")?,
+            add: object("add")?,
+            ..Default::default()
+        };
+
+        match body.get("delete") {
+            None => {}
+            Some(Value::Array(names)) => {
+                update.delete = texts("delete", names)?;
+            }
+            Some(Value::Object(values)) => update.delete_values = values.clone(),
+            Some(_) => {
+                return Err(invalid(
+                    "`delete` must be an array of property names or an object",
+                ));
+            }
+        }
+
+        Ok(update)
+    }
+}
+
+/// A Micropub request, read from a form-encoded or JSON body
+#[derive(Debug, Clone, PartialEq)]
+pub enum MicropubRequest {
+    /// Create a post
+    Create(MicropubProperties),
+
+    /// Change the properties of a post
+    Update { url: String, update: MicropubUpdate },
+
+    /// Delete a post
+    Delete { url: String },
+}
+
+impl MicropubRequest {
+    /// Read a form-encoded request
+    ///
+    /// Repeated keys and `key[]` both add values to a property. Updates
+    /// can only be sent as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MicropubError::InvalidRequest` for unsupported actions and
+    /// post types and for malformed properties.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::micropub::MicropubRequest;
+    ///
+    /// let pairs = [("h", "entry"), ("content", "Hello"), ("category[]", "life")]
+    ///     .map(|(k, v)| (k.to_string(), v.to_string()));
+    ///
+    /// let MicropubRequest::Create(properties) = MicropubRequest::from_form(&pairs).unwrap()
+    /// else {
+    ///     panic!("expected a create request");
+    /// };
+    /// assert_eq!(properties.content.as_deref(), Some("Hello"));
+    /// assert_eq!(properties.category, ["life"]);
+    /// ```
+    pub fn from_form(pairs: &[(String, String)]) -> Result<Self> {
+        let field = |key: &str| {
+            pairs
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, v)| v.as_str())
+        };
+
+        match field("action") {
+            Some("delete") => {
+                let url = field("url").ok_or_else(|| invalid("`url` is required"))?;
+                return Ok(Self::Delete {
+                    url: url.to_string(),
+                });
+            }
+            Some("update") => return Err(invalid("updates must be sent as JSON")),
+            Some(action) => return Err(invalid(format!("unsupported action `{}`", action))),
+            None => {}
+        }
+
+        if let Some(h) = field("h")
+            && h != "entry"
+        {
+            return Err(invalid(format!(
+                "unsupported type `h-{}`, only `h-entry` posts are supported",
+                h
+            )));
+        }
+
+        let mut properties = Map::new();
+        for (key, value) in pairs {
+            if key == "h" || key == "access_token" {
+                continue;
+            }
+
+            let name = key.strip_suffix("[]").unwrap_or(key);
+            let values = properties
+                .entry(name)
+                .or_insert_with(|| Value::Array(Vec::new()));
+            if let Value::Array(values) = values {
+                values.push(Value::String(value.clone()));
+            }
+        }
+
+        Ok(Self::Create(MicropubProperties::from_map(&properties)?))
+    }
+
+    /// Read a JSON request
+    ///
+    /// # Errors
+    ///
+    /// Returns `MicropubError::InvalidRequest` for unsupported actions and
+    /// post types and for malformed properties.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::micropub::MicropubRequest;
+    /// use serde_json::json;
+    ///
+    /// let body = json!({
+    ///     "action": "update",
+    ///     "url": "https://example.com/talk/67e55044-10b1-426f-9247-bb680e5fe0c8",
+    ///     "add": { "category": ["coffee"] }
+    /// });
+    ///
+    /// let MicropubRequest::Update { url, update } = MicropubRequest::from_json(&body).unwrap()
+    /// else {
+    ///     panic!("expected an update request");
+    /// };
+    /// assert!(url.ends_with("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+    /// assert!(update.add.contains_key("category"));
+    /// ```
+    pub fn from_json(body: &Value) -> Result<Self> {
+        let body = body
+            .as_object()
+            .ok_or_else(|| invalid("the request body must be a JSON object"))?;
+
+        if let Some(action) = body.get("action") {
+            let url = || match body.get("url") {
+                Some(Value::String(url)) => Ok(url.clone()),
+                _ => Err(invalid("`url` is required")),
+            };
+
+            return match action.as_str() {
+                Some("delete") => Ok(Self::Delete { url: url()? }),
+                Some("update") => Ok(Self::Update {
+                    url: url()?,
+                    update: MicropubUpdate::from_json(body)?,
+                }),
+                Some(action) => Err(invalid(format!("unsupported action `{}`", action))),
+                None => Err(invalid("`action` must be a string")),
+            };
+        }
+
+        let is_entry = body
+            .get("type")
+            .and_then(Value::as_array)
+            .is_some_and(|types| types.iter().any(|t| t == "h-entry"));
+        if !is_entry {
+            return Err(invalid("only `h-entry` posts are supported"));
+        }
+
+        match body.get("properties") {
+            Some(Value::Object(properties)) => {
+                Ok(Self::Create(MicropubProperties::from_map(properties)?))
+            }
+            _ => Err(invalid("`properties` must be an object")),
+        }
+    }
+}
+
+/// Outcome of a Micropub request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MicropubOutcome {
+    /// A post was created at the URL
+    Created { url: String },
+
+    /// A post was updated
+    Updated,
+
+    /// A post was deleted
+    Deleted,
+}
+
+fn invalid(message: impl Into<String>) -> SomeError {
+    MicropubError::InvalidRequest(message.into()).into()
+}
+
+/// The values of a property, which are always sent as an array
+fn values_of<'a>(name: &str, values: &'a Value) -> Result<&'a [Value]> {
+    values
+        .as_array()
+        .map(Vec::as_slice)
+        .ok_or_else(|| invalid(format!("`{}` must be an array", name)))
+}
+
+/// The value of a single-valued property; an empty array clears it
+fn single(name: &str, values: &[Value]) -> Result<Option<String>> {
+    match values {
+        [] => Ok(None),
+        [value] => text(name, value).map(Some),
+        _ => Err(invalid(format!("`{}` takes a single value", name))),
+    }
+}
+
+fn texts(name: &str, values: &[Value]) -> Result<Vec<String>> {
+    values.iter().map(|value| text(name, value)).collect()
+}
+
+/// Plain text, or the `html` of a `{"html": ...}` object (allowed for
+/// `content`, since markdown accepts inline HTML)
+fn text(name: &str, value: &Value) -> Result<String> {
+    match value {
+        Value::String(text) => Ok(text.clone()),
+        Value::Object(object) if name == "content" => match object.get("html") {
+            Some(Value::String(html)) => Ok(html.clone()),
+            _ => Err(invalid("`content` must be text or `{\"html\": ...}`")),
+        },
+        _ => Err(invalid(format!("`{}` must be text", name))),
+    }
+}
@@ -0,0 +1,444 @@
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::{
+    domain::{front_matter::FrontMatterFormat, ingestion::IngestionIssue, source_formats::fenced},
+    errors::Result,
+};
+
+/// Key of the notebook metadata holding the front matter
+pub const FRONT_MATTER_METADATA_KEY: &str = "front_matter";
+
+/// Image output types, in order of preference
+///
+/// SVG is left out: the media pipeline only decodes raster images.
+const IMAGE_MIME_TYPES: &[&str] = &["image/png", "image/jpeg", "image/gif", "image/webp"];
+
+/// Check whether a repository file is a Jupyter notebook
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::notebooks::is_notebook;
+///
+/// assert!(is_notebook("posts/analysis.ipynb"));
+/// assert!(!is_notebook("posts/analysis.md"));
+/// ```
+pub fn is_notebook(path: &str) -> bool {
+    path.ends_with(".ipynb")
+}
+
+/// Limits on the cell outputs of notebooks, declared as `[notebooks]` in config.toml
+///
+/// Outputs are kept so readers see results without running the notebook,
+/// but a long training log or a large plot would swamp the post.
+///
+/// # Example
+///
+/// ```toml
+/// [notebooks]
+/// max_output_lines = 50
+/// max_output_chars = 5000
+/// max_image_bytes = 2097152
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default)]
+pub struct NotebookOptions {
+    /// Lines kept of each text output; the rest is cut
+    pub max_output_lines: usize,
+
+    /// Characters kept of each text output; the rest is cut
+    pub max_output_chars: usize,
+
+    /// Decoded size above which image outputs are dropped
+    pub max_image_bytes: usize,
+}
+
+impl Default for NotebookOptions {
+    fn default() -> Self {
+        Self {
+            max_output_lines: 50,
+            max_output_chars: 5000,
+            max_image_bytes: 2 * 1024 * 1024,
+        }
+    }
+}
+
+impl NotebookOptions {
+    /// Reject limits that would drop every output
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a limit is 0.
+    pub fn validate(self) -> Result<Self> {
+        if self.max_output_lines == 0 || self.max_output_chars == 0 || self.max_image_bytes == 0 {
+            return Err(anyhow::anyhow!(
+                "Invalid notebook configuration: output limits must be greater than 0"
+            )
+            .into());
+        }
+
+        Ok(self)
+    }
+}
+
+/// Where the front matter of a notebook was found
+#[derive(Debug, Clone, PartialEq)]
+pub enum NotebookFrontMatter {
+    /// A leading raw cell holding a delimited front matter block, as in a
+    /// markdown file
+    Cell(String),
+
+    /// The `front_matter` entry of the notebook metadata
+    Metadata(Value),
+}
+
+/// A notebook converted to a markdown article body
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvertedNotebook {
+    /// Front matter of the notebook, `None` if it has none
+    pub front_matter: Option<NotebookFrontMatter>,
+
+    /// Markdown body with code cells fenced and outputs inlined
+    pub markdown: String,
+
+    /// Warnings about truncated or dropped outputs
+    pub issues: Vec<IngestionIssue>,
+}
+
+/// Jupyter notebook file, as far as ingestion needs it (nbformat 4)
+#[derive(Debug, Deserialize)]
+struct Notebook {
+    nbformat: u32,
+
+    #[serde(default)]
+    metadata: Map<String, Value>,
+
+    #[serde(default)]
+    cells: Vec<Cell>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "cell_type", rename_all = "lowercase")]
+enum Cell {
+    Markdown {
+        #[serde(deserialize_with = "multiline")]
+        source: String,
+
+        #[serde(default)]
+        attachments: Map<String, Value>,
+    },
+    Code {
+        #[serde(deserialize_with = "multiline")]
+        source: String,
+
+        #[serde(default)]
+        outputs: Vec<Output>,
+    },
+    Raw {
+        #[serde(deserialize_with = "multiline")]
+        source: String,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "output_type", rename_all = "snake_case")]
+enum Output {
+    Stream {
+        #[serde(deserialize_with = "multiline")]
+        text: String,
+    },
+    ExecuteResult {
+        #[serde(default)]
+        data: Map<String, Value>,
+    },
+    DisplayData {
+        #[serde(default)]
+        data: Map<String, Value>,
+    },
+    Error {
+        ename: String,
+        evalue: String,
+    },
+}
+
+/// Read a notebook string, stored either whole or as a list of lines
+fn multiline<'de, D: Deserializer<'de>>(deserializer: D) -> std::result::Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Multiline {
+        Text(String),
+        Lines(Vec<String>),
+    }
+
+    Ok(match Multiline::deserialize(deserializer)? {
+        Multiline::Text(text) => text,
+        Multiline::Lines(lines) => lines.concat(),
+    })
+}
+
+/// Convert a Jupyter notebook to a markdown article body
+///
+/// Markdown cells are kept as written, with their image attachments
+/// inlined. Code cells become fenced blocks in the kernel's language,
+/// followed by their outputs: images as `data:` URIs, which ingestion moves
+/// to the media store, markdown as is, and plain text, streams and errors
+/// as `text` blocks cut to the limits of `options`. Raw cells are dropped,
+/// except a leading raw cell with a front matter block.
+///
+/// # Arguments
+///
+/// * `source` - Content of the `.ipynb` file
+/// * `options` - Limits on the cell outputs
+///
+/// # Returns
+///
+/// * `Ok(ConvertedNotebook)` - The markdown, front matter and warnings
+/// * `Err(SomeError)` - The file is not a notebook of nbformat 4 or later
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::notebooks::{NotebookOptions, convert_notebook};
+///
+/// let source = r##"{
+///     "nbformat": 4,
+///     "metadata": { "kernelspec": { "language": "python" } },
+///     "cells": [
+///         { "cell_type": "markdown", "source": ["# Squares\n"] },
+///         {
+///             "cell_type": "code",
+///             "source": "print(3 ** 2)",
+///             "outputs": [{ "output_type": "stream", "name": "stdout", "text": ["9\n"] }]
+///         }
+///     ]
+/// }"##;
+///
+/// let converted = convert_notebook(source, &NotebookOptions::default()).unwrap();
+/// assert_eq!(
+///     converted.markdown,
+///     "# Squares\n\n```python\nprint(3 ** 2)\n```\n\n```text\n9\n```\n"
+/// );
+/// ```
+pub fn convert_notebook(source: &str, options: &NotebookOptions) -> Result<ConvertedNotebook> {
+    let notebook: Notebook =
+        serde_json::from_str(source).map_err(|e| anyhow::anyhow!("Invalid notebook: {}", e))?;
+    if notebook.nbformat < 4 {
+        return Err(anyhow::anyhow!(
+            "Unsupported notebook format {}; save the notebook with Jupyter 4 or later",
+            notebook.nbformat
+        )
+        .into());
+    }
+
+    let mut issues = Vec::new();
+
+    let leading_cell = match notebook.cells.first() {
+        Some(Cell::Raw { source }) if FrontMatterFormat::detect(source).is_some() => {
+            Some(source.clone())
+        }
+        _ => None,
+    };
+    let metadata_front_matter = notebook.metadata.get(FRONT_MATTER_METADATA_KEY).cloned();
+    if leading_cell.is_some() && metadata_front_matter.is_some() {
+        issues.push(IngestionIssue::warning(
+            Some(FRONT_MATTER_METADATA_KEY),
+            None,
+            "notebook metadata front matter ignored; the leading raw cell is used",
+        ));
+    }
+    let skipped = usize::from(leading_cell.is_some());
+    let front_matter = match leading_cell {
+        Some(source) => Some(NotebookFrontMatter::Cell(source)),
+        None => metadata_front_matter.map(NotebookFrontMatter::Metadata),
+    };
+
+    let language = notebook_language(&notebook.metadata);
+    let mut blocks = Vec::new();
+    for (i, cell) in notebook.cells.iter().enumerate().skip(skipped) {
+        let mut converter = CellConverter {
+            number: i + 1,
+            options,
+            issues: &mut issues,
+        };
+
+        match cell {
+            Cell::Markdown {
+                source,
+                attachments,
+            } => {
+                let source = converter.inline_attachments(source, attachments);
+                if !source.trim().is_empty() {
+                    blocks.push(source.trim_end().to_string());
+                }
+            }
+            Cell::Code { source, outputs } => {
+                if !source.trim().is_empty() {
+                    blocks.push(fenced(source, language));
+                }
+                for output in outputs {
+                    blocks.extend(converter.output(output));
+                }
+            }
+            Cell::Raw { .. } => {}
+        }
+    }
+
+    let mut markdown = blocks.join("\n\n");
+    if !markdown.is_empty() {
+        markdown.push('\n');
+    }
+
+    Ok(ConvertedNotebook {
+        front_matter,
+        markdown,
+        issues,
+    })
+}
+
+/// Language of the code cells, from the kernel metadata
+fn notebook_language(metadata: &Map<String, Value>) -> &str {
+    let kernel = metadata
+        .get("kernelspec")
+        .and_then(|k| k.get("language"))
+        .and_then(Value::as_str);
+    let info = metadata
+        .get("language_info")
+        .and_then(|l| l.get("name"))
+        .and_then(Value::as_str);
+
+    kernel.or(info).unwrap_or_default()
+}
+
+/// Converts the outputs and attachments of one cell, recording warnings
+struct CellConverter<'a> {
+    /// 1-based position of the cell, used in warnings
+    number: usize,
+    options: &'a NotebookOptions,
+    issues: &'a mut Vec<IngestionIssue>,
+}
+
+impl CellConverter<'_> {
+    /// Markdown block of one output, `None` if nothing is shown
+    fn output(&mut self, output: &Output) -> Option<String> {
+        match output {
+            Output::Stream { text } => self.text(text),
+            Output::ExecuteResult { data } | Output::DisplayData { data } => {
+                if let Some(uri) = self.data_uri(data) {
+                    return Some(format!("![output]({})", uri));
+                }
+                if let Some(markdown) = mime_text(data, "text/markdown") {
+                    return Some(markdown.trim_end().to_string());
+                }
+                self.text(&mime_text(data, "text/plain")?)
+            }
+            Output::Error { ename, evalue } => self.text(&format!("{}: {}", ename, evalue)),
+        }
+    }
+
+    /// Text output cut to the limits, as a `text` block
+    fn text(&mut self, text: &str) -> Option<String> {
+        let text = strip_ansi(text);
+        if text.trim().is_empty() {
+            return None;
+        }
+
+        let lines: Vec<&str> = text.trim_end().lines().collect();
+        let full = lines.join("\n");
+        let mut kept = lines[..lines.len().min(self.options.max_output_lines)].join("\n");
+        if let Some((cut, _)) = kept.char_indices().nth(self.options.max_output_chars) {
+            kept.truncate(cut);
+        }
+
+        if kept.len() < full.len() {
+            self.issues.push(IngestionIssue::warning(
+                None,
+                None,
+                format!(
+                    "cell {}: output cut to {} lines or {} characters",
+                    self.number, self.options.max_output_lines, self.options.max_output_chars
+                ),
+            ));
+            kept.push_str("\n…");
+        }
+
+        Some(fenced(&kept, "text"))
+    }
+
+    /// Preferred image of a MIME bundle as a `data:` URI
+    ///
+    /// Images above `max_image_bytes` are dropped with a warning.
+    fn data_uri(&mut self, data: &Map<String, Value>) -> Option<String> {
+        let (mime, payload) = IMAGE_MIME_TYPES
+            .iter()
+            .find_map(|mime| Some((*mime, mime_text(data, mime)?)))?;
+
+        let payload: String = payload.split_whitespace().collect();
+        let size = payload.trim_end_matches('=').len() * 3 / 4;
+        if size > self.options.max_image_bytes {
+            self.issues.push(IngestionIssue::warning(
+                None,
+                None,
+                format!(
+                    "cell {}: {} image of {} bytes dropped, the limit is {} bytes",
+                    self.number, mime, size, self.options.max_image_bytes
+                ),
+            ));
+            return None;
+        }
+
+        Some(format!("data:{};base64,{}", mime, payload))
+    }
+
+    /// Replace `attachment:` image references with `data:` URIs
+    fn inline_attachments(&mut self, source: &str, attachments: &Map<String, Value>) -> String {
+        let mut source = source.to_string();
+
+        for (name, bundle) in attachments {
+            let Some(data) = bundle.as_object() else {
+                continue;
+            };
+            let reference = format!("attachment:{}", name);
+            if !source.contains(&reference) {
+                continue;
+            }
+            if let Some(uri) = self.data_uri(data) {
+                source = source.replace(&reference, &uri);
+            }
+        }
+
+        source
+    }
+}
+
+/// Text of a MIME bundle entry, stored either whole or as a list of lines
+fn mime_text(data: &Map<String, Value>, mime: &str) -> Option<String> {
+    match data.get(mime)? {
+        Value::String(text) => Some(text.clone()),
+        Value::Array(lines) => Some(lines.iter().filter_map(Value::as_str).collect()),
+        _ => None,
+    }
+}
+
+/// Remove terminal color and cursor escapes from output text
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            stripped.push(c);
+            continue;
+        }
+        // CSI sequences end at the first letter
+        if chars.next() == Some('[') {
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+        }
+    }
+
+    stripped
+}
@@ -0,0 +1,425 @@
+use crate::domain::source_formats::{
+    ConvertedDocument, HeaderFields, blockquote, code_span, fenced, is_image_path, join_markdown,
+    link_destination,
+};
+
+/// Deepest markdown heading level
+const MAX_HEADING_LEVEL: usize = 6;
+
+/// Characters that open or close inline markup: bold, italic, verbatim,
+/// code and strike-through
+const EMPHASIS_MARKERS: &[char] = &['*', '/', '=', '~', '+'];
+
+/// Convert an Org-mode document to a markdown article
+///
+/// The keywords at the top of the file, before the first line of content,
+/// form the front matter:
+///
+/// | Keyword          | Front matter field |
+/// |------------------|--------------------|
+/// | `#+TITLE`        | `title`            |
+/// | `#+DESCRIPTION`  | `description`      |
+/// | `#+AUTHOR`       | `authors`, by name |
+/// | `#+LANGUAGE`     | `lang`             |
+/// | `#+FILETAGS`     | `tags`             |
+/// | `#+PROPERTY: key value` | `key`       |
+///
+/// Other keywords, such as `#+OPTIONS`, are export settings and are
+/// ignored. Property values are typed by field as described for
+/// [`header_value`](crate::domain::source_formats::header_value). Several
+/// authors are separated by commas; their names are turned into profile ids
+/// by [`ConvertedDocument::resolve_authors`].
+///
+/// The body is converted to markdown: headlines become headings one level
+/// below the title, source and example blocks become fenced code, quote
+/// blocks become block quotes, and links, emphasis and tables are
+/// rewritten. Comments, drawers and keywords in the body are dropped.
+/// Links without a description to something other than a file or URL are
+/// kept as `[[wiki-style]]` references.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::org::convert_org;
+/// use serde_json::json;
+///
+/// let source = "#+TITLE: Hello Org\n#+PROPERTY: status published\n\n* Intro\nSome /light/ *reading*.\n";
+/// let converted = convert_org(source);
+///
+/// assert_eq!(
+///     converted.front_matter,
+///     Some(json!({ "title": "Hello Org", "status": "published" }))
+/// );
+/// assert_eq!(converted.markdown, "## Intro\nSome *light* **reading**.\n");
+/// ```
+pub fn convert_org(source: &str) -> ConvertedDocument {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut header = HeaderFields::default();
+    let mut start = 0;
+
+    while let Some(line) = lines.get(start) {
+        let trimmed = line.trim();
+        if !trimmed.is_empty() && !is_comment(trimmed) {
+            let Some((keyword, value)) = parse_keyword(trimmed) else {
+                break;
+            };
+            header_keyword(&mut header, keyword, value, start + 1);
+        }
+        start += 1;
+    }
+
+    header.into_document(join_markdown(&convert_lines(&lines[start..])))
+}
+
+/// Map a header keyword to its front matter field
+fn header_keyword(header: &mut HeaderFields, keyword: &str, value: &str, line: usize) {
+    let entry = format!("#+{}", keyword);
+
+    match keyword.to_ascii_uppercase().as_str() {
+        "TITLE" => header.insert("title", value, line, &entry),
+        "DESCRIPTION" => header.insert("description", value, line, &entry),
+        "AUTHOR" => header.insert("authors", value, line, &entry),
+        "LANGUAGE" => header.insert("lang", value, line, &entry),
+        "FILETAGS" => {
+            // `:rust:web:`, the spelling Org uses for tags
+            let tags = value
+                .split([':', ' '])
+                .filter(|tag| !tag.is_empty())
+                .map(|tag| serde_json::Value::String(tag.to_string()))
+                .collect();
+            header.insert_value("tags", tags, line, &entry);
+        }
+        "PROPERTY" => {
+            let (name, value) = value.split_once(char::is_whitespace).unwrap_or((value, ""));
+            if !name.is_empty() {
+                header.insert(name, value, line, &format!("{}: {}", entry, name));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Split a `#+KEYWORD: value` line
+fn parse_keyword(line: &str) -> Option<(&str, &str)> {
+    let (keyword, value) = line.strip_prefix("#+")?.split_once(':')?;
+
+    (!keyword.is_empty() && !keyword.contains(char::is_whitespace)).then(|| (keyword, value.trim()))
+}
+
+/// Check whether a trimmed line is a `# comment`
+fn is_comment(line: &str) -> bool {
+    line == "#" || line.starts_with("# ")
+}
+
+/// Check whether a trimmed line opens a drawer, such as `:PROPERTIES:`
+fn is_drawer_start(line: &str) -> bool {
+    line.len() > 2
+        && !line.eq_ignore_ascii_case(":END:")
+        && line.starts_with(':')
+        && line.ends_with(':')
+        && line[1..line.len() - 1]
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+}
+
+/// Check whether a trimmed line is a `: fixed width` line
+fn is_fixed_width(line: &str) -> bool {
+    line == ":" || line.starts_with(": ")
+}
+
+/// Convert body lines to markdown lines
+fn convert_lines(lines: &[&str]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut i = 0;
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+        i += 1;
+
+        if let Some(rest) = strip_prefix_ignore_case(trimmed, "#+BEGIN_") {
+            let (name, args) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            let end = format!("#+END_{}", name);
+            let body: Vec<&str> = lines[i..]
+                .iter()
+                .take_while(|l| !l.trim().eq_ignore_ascii_case(&end))
+                .copied()
+                .collect();
+            i += body.len() + 1;
+
+            match name.to_ascii_uppercase().as_str() {
+                "SRC" => {
+                    let language = args.split_whitespace().next().unwrap_or_default();
+                    out.push(fenced(&unescape_block(&body), language));
+                }
+                "EXAMPLE" => out.push(fenced(&unescape_block(&body), "text")),
+                "QUOTE" => out.extend(blockquote(&convert_lines(&body))),
+                // CENTER, VERSE and custom blocks keep their content
+                _ => out.extend(convert_lines(&body)),
+            }
+            continue;
+        }
+
+        if is_drawer_start(trimmed) {
+            i += lines[i..]
+                .iter()
+                .position(|l| l.trim().eq_ignore_ascii_case(":END:"))
+                .map_or(lines.len() - i, |end| end + 1);
+            continue;
+        }
+
+        if trimmed.starts_with("#+") || is_comment(trimmed) {
+            continue;
+        }
+
+        if is_fixed_width(trimmed) {
+            let mut text = vec![fixed_width_text(trimmed)];
+            while let Some(next) = lines.get(i).map(|l| l.trim())
+                && is_fixed_width(next)
+            {
+                text.push(fixed_width_text(next));
+                i += 1;
+            }
+            out.push(fenced(&text.join("\n"), "text"));
+            continue;
+        }
+
+        if trimmed.starts_with('|') {
+            let mut rows = vec![trimmed];
+            while let Some(next) = lines.get(i).map(|l| l.trim())
+                && next.starts_with('|')
+            {
+                rows.push(next);
+                i += 1;
+            }
+            out.extend(table(&rows));
+            continue;
+        }
+
+        if trimmed.len() >= 5 && trimmed.chars().all(|c| c == '-') {
+            out.push("---".to_string());
+            continue;
+        }
+
+        out.push(
+            headline(line)
+                .or_else(|| list_item(line))
+                .unwrap_or_else(|| inline(line)),
+        );
+    }
+
+    out
+}
+
+fn strip_prefix_ignore_case<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    line.get(..prefix.len())
+        .filter(|start| start.eq_ignore_ascii_case(prefix))
+        .map(|_| &line[prefix.len()..])
+}
+
+/// Remove the commas Org puts before `*` and `#+` lines inside blocks
+fn unescape_block(lines: &[&str]) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            let content = line.trim_start();
+            if content.starts_with(",*") || content.starts_with(",#+") {
+                let indent = line.len() - content.len();
+                format!("{}{}", &line[..indent], &content[1..])
+            } else {
+                line.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn fixed_width_text(line: &str) -> &str {
+    line.strip_prefix(": ").unwrap_or_default()
+}
+
+/// Convert a `** Headline :tags:` line to a markdown heading
+///
+/// Top-level headlines become `##` headings, since the title is the `#`
+/// heading of the page. Trailing headline tags are dropped.
+fn headline(line: &str) -> Option<String> {
+    let stars = line.chars().take_while(|c| *c == '*').count();
+    if stars == 0 {
+        return None;
+    }
+    let title = line[stars..].strip_prefix(' ')?.trim();
+
+    let title = match title.rsplit_once(char::is_whitespace) {
+        Some((text, tags)) if is_headline_tags(tags) => text.trim_end(),
+        None if is_headline_tags(title) => "",
+        _ => title,
+    };
+    let level = (stars + 1).min(MAX_HEADING_LEVEL);
+
+    Some(
+        format!("{} {}", "#".repeat(level), inline(title))
+            .trim_end()
+            .to_string(),
+    )
+}
+
+/// Check whether a word is a `:tag1:tag2:` list
+fn is_headline_tags(word: &str) -> bool {
+    word.len() > 2
+        && word.starts_with(':')
+        && word.ends_with(':')
+        && word
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_@#%:".contains(c))
+}
+
+/// Convert `- item`, `+ item`, indented `* item` and `1) item` list items
+fn list_item(line: &str) -> Option<String> {
+    let content = line.trim_start();
+    let indent = &line[..line.len() - content.len()];
+
+    let (marker, item) = if let Some(item) = content
+        .strip_prefix("- ")
+        .or_else(|| content.strip_prefix("+ "))
+        .or_else(|| content.strip_prefix("* ").filter(|_| !indent.is_empty()))
+    {
+        ("-".to_string(), item)
+    } else {
+        let digits = content.chars().take_while(char::is_ascii_digit).count();
+        let item = content[digits..]
+            .strip_prefix(". ")
+            .or_else(|| content[digits..].strip_prefix(") "))
+            .filter(|_| digits > 0)?;
+        (format!("{}.", &content[..digits]), item)
+    };
+
+    // `- term :: description`
+    let item = match item.split_once(" :: ") {
+        Some((term, description)) => format!("**{}**: {}", inline(term), inline(description)),
+        None => inline(item),
+    };
+
+    Some(format!("{}{} {}", indent, marker, item))
+}
+
+/// Convert a table, using its first row as the header
+///
+/// Rule lines (`|---+---|`) are dropped; markdown tables only have the one
+/// below the header.
+fn table(rows: &[&str]) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut columns = 0;
+
+    for row in rows.iter().filter(|row| !row.starts_with("|-")) {
+        let cells: Vec<String> = row
+            .trim_matches('|')
+            .split('|')
+            .map(|cell| inline(cell.trim()))
+            .collect();
+        out.push(format!("| {} |", cells.join(" | ")));
+
+        if columns == 0 {
+            columns = cells.len();
+            out.push(format!("|{}", " --- |".repeat(columns)));
+        }
+    }
+
+    out
+}
+
+/// Convert inline markup: links, emphasis and code
+fn inline(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let mut out = String::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+
+        if c == '['
+            && chars.get(i + 1) == Some(&'[')
+            && let Some((link, next)) = link(&chars, i)
+        {
+            out.push_str(&link);
+            i = next;
+            continue;
+        }
+
+        if EMPHASIS_MARKERS.contains(&c)
+            && opens_emphasis(&chars, i)
+            && let Some(close) = closing_marker(&chars, i)
+        {
+            let inner: String = chars[i + 1..close].iter().collect();
+            out.push_str(&match c {
+                '=' | '~' => code_span(&inner),
+                '*' => format!("**{}**", inline(&inner)),
+                '/' => format!("*{}*", inline(&inner)),
+                _ => format!("~~{}~~", inline(&inner)),
+            });
+            i = close + 1;
+            continue;
+        }
+
+        out.push(c);
+        i += 1;
+    }
+
+    out
+}
+
+/// Check whether the marker at `i` can open emphasis
+fn opens_emphasis(chars: &[char], i: usize) -> bool {
+    let before = i.checked_sub(1).map(|j| chars[j]);
+    let after = chars.get(i + 1);
+
+    before.is_none_or(|b| b.is_whitespace() || "-('\"{".contains(b))
+        && after.is_some_and(|a| !a.is_whitespace())
+}
+
+/// Find the marker closing the emphasis opened at `open`
+fn closing_marker(chars: &[char], open: usize) -> Option<usize> {
+    let marker = chars[open];
+
+    (open + 2..chars.len()).find(|&j| {
+        chars[j] == marker
+            && !chars[j - 1].is_whitespace()
+            && chars
+                .get(j + 1)
+                .is_none_or(|a| a.is_whitespace() || "-.,;:!?')}\"[\\".contains(*a))
+    })
+}
+
+/// Convert the `[[target][description]]` link starting at `start`
+///
+/// # Returns
+///
+/// The markdown link and the index after the link, or `None` if the
+/// brackets are not closed.
+fn link(chars: &[char], start: usize) -> Option<(String, usize)> {
+    let rest: String = chars[start + 2..].iter().collect();
+    let inner = &rest[..rest.find("]]")?];
+    let next = start + 2 + inner.chars().count() + 2;
+
+    let (target, description) = match inner.split_once("][") {
+        Some((target, description)) => (target, Some(description)),
+        None => (inner, None),
+    };
+    let is_file = target.starts_with("file:");
+    let dest = target.strip_prefix("file:").unwrap_or(target);
+
+    let markdown = if let Some(heading) = dest.strip_prefix('*') {
+        // Links to a headline of the same file have no stable anchor
+        inline(description.unwrap_or(heading))
+    } else {
+        match description {
+            Some(description) => format!("[{}]({})", inline(description), link_destination(dest)),
+            None if is_image_path(dest) => format!("![]({})", link_destination(dest)),
+            None if is_file || dest.contains([':', '/']) || dest.starts_with('#') => {
+                format!("[{}]({})", dest, link_destination(dest))
+            }
+            None => format!("[[{}]]", dest),
+        }
+    };
+
+    Some((markdown, next))
+}
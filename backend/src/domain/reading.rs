@@ -0,0 +1,171 @@
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+/// Reading speeds used to estimate reading time
+///
+/// Declared in `config.toml` under `[reading]`; every field is optional and
+/// falls back to its default. Reading times are computed at ingestion, so a
+/// changed speed applies to an article the next time it is pushed.
+///
+/// # Example Configuration
+///
+/// ```toml
+/// [reading]
+/// cjk_chars_per_minute = 300
+/// words_per_minute = 200
+/// code_lines_per_minute = 60
+/// seconds_per_image = 12
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ReadingSpeeds {
+    /// CJK characters read per minute
+    pub cjk_chars_per_minute: u32,
+
+    /// Latin (space separated) words read per minute
+    pub words_per_minute: u32,
+
+    /// Lines of code blocks read per minute
+    pub code_lines_per_minute: u32,
+
+    /// Time spent looking at each image, in seconds
+    pub seconds_per_image: u32,
+}
+
+impl Default for ReadingSpeeds {
+    fn default() -> Self {
+        Self {
+            cjk_chars_per_minute: 300,
+            words_per_minute: 200,
+            code_lines_per_minute: 60,
+            seconds_per_image: 12,
+        }
+    }
+}
+
+impl ReadingSpeeds {
+    /// Check that the configured speeds are usable
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the per-minute rates is zero.
+    pub fn validate(self) -> Result<Self> {
+        let rates = [
+            ("cjk_chars_per_minute", self.cjk_chars_per_minute),
+            ("words_per_minute", self.words_per_minute),
+            ("code_lines_per_minute", self.code_lines_per_minute),
+        ];
+
+        if let Some((name, _)) = rates.iter().find(|(_, rate)| *rate == 0) {
+            return Err(anyhow::anyhow!(
+                "Invalid reading configuration: `{}` must be greater than 0",
+                name
+            )
+            .into());
+        }
+
+        Ok(self)
+    }
+
+    /// Estimate the reading time of an article, in whole minutes
+    ///
+    /// Prose, code and images are timed separately and added up; the total is
+    /// rounded up, so any non-empty article takes at least one minute.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::reading::{ContentStats, ReadingSpeeds};
+    ///
+    /// let stats = ContentStats { cjk_chars: 600, words: 200, code_lines: 0, images: 0 };
+    /// // 2 minutes of Chinese + 1 minute of English
+    /// assert_eq!(ReadingSpeeds::default().reading_minutes(&stats), 3);
+    /// assert_eq!(ReadingSpeeds::default().reading_minutes(&ContentStats::default()), 0);
+    /// ```
+    pub fn reading_minutes(&self, stats: &ContentStats) -> u32 {
+        let per_minute = |count: usize, rate: u32| count as f64 * 60.0 / f64::from(rate.max(1));
+
+        let seconds = per_minute(stats.cjk_chars, self.cjk_chars_per_minute)
+            + per_minute(stats.words, self.words_per_minute)
+            + per_minute(stats.code_lines, self.code_lines_per_minute)
+            + stats.images as f64 * f64::from(self.seconds_per_image);
+
+        (seconds / 60.0).ceil() as u32
+    }
+}
+
+/// Counts of the different kinds of content in an article
+///
+/// Filled while walking the parsed markdown: prose goes through
+/// [`ContentStats::add_text`], code block lines and images are counted
+/// separately because they are read at a different pace.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContentStats {
+    /// CJK characters in prose, each counted as one word
+    pub cjk_chars: usize,
+
+    /// Latin words in prose
+    pub words: usize,
+
+    /// Non-empty lines in code blocks
+    pub code_lines: usize,
+
+    /// Images
+    pub images: usize,
+}
+
+impl ContentStats {
+    /// Count the words of a piece of prose
+    ///
+    /// CJK characters count individually; any other run of letters and digits
+    /// counts as one word. Apostrophes and hyphens inside a run (`don't`,
+    /// `well-known`) don't split it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::reading::ContentStats;
+    ///
+    /// let mut stats = ContentStats::default();
+    /// stats.add_text("用 Rust 写一个 well-known 博客");
+    /// assert_eq!((stats.cjk_chars, stats.words), (6, 2));
+    /// assert_eq!(stats.word_count(), 8);
+    /// ```
+    pub fn add_text(&mut self, text: &str) {
+        let mut in_word = false;
+
+        for c in text.chars() {
+            if is_cjk(c) {
+                self.cjk_chars += 1;
+                in_word = false;
+            } else if c.is_alphanumeric() {
+                if !in_word {
+                    self.words += 1;
+                    in_word = true;
+                }
+            } else if !(in_word && matches!(c, '\'' | '’' | '-')) {
+                in_word = false;
+            }
+        }
+    }
+
+    /// Number of words shown to readers: CJK characters plus Latin words
+    ///
+    /// Code and images are not counted.
+    pub fn word_count(&self) -> usize {
+        self.cjk_chars + self.words
+    }
+}
+
+/// Check whether a character is a CJK ideograph, kana or hangul syllable
+pub(crate) fn is_cjk(c: char) -> bool {
+    matches!(c,
+        '\u{3040}'..='\u{30FF}'     // Hiragana, Katakana
+        | '\u{3400}'..='\u{4DBF}'   // CJK Unified Ideographs Extension A
+        | '\u{4E00}'..='\u{9FFF}'   // CJK Unified Ideographs
+        | '\u{AC00}'..='\u{D7AF}'   // Hangul Syllables
+        | '\u{F900}'..='\u{FAFF}'   // CJK Compatibility Ideographs
+        | '\u{20000}'..='\u{3134F}' // CJK Unified Ideographs Extensions B-G
+    )
+}
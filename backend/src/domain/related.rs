@@ -0,0 +1,196 @@
+use std::collections::HashSet;
+
+use time::OffsetDateTime;
+
+use crate::domain::{
+    articles::{ArticleId, PostCategory},
+    reading::is_cjk,
+};
+
+/// Maximum number of related posts returned for an article
+pub const MAX_RELATED_POSTS: usize = 20;
+
+/// Score added for every tag shared with the article
+pub const SHARED_TAG_WEIGHT: f64 = 3.0;
+
+/// Score added when the candidate belongs to the same series
+pub const SAME_SERIES_WEIGHT: f64 = 2.0;
+
+/// Score added when the candidate is in the same category
+pub const SAME_CATEGORY_WEIGHT: f64 = 1.0;
+
+/// Score of identical title and summary terms; partial overlap scales down
+pub const TEXT_SIMILARITY_WEIGHT: f64 = 4.0;
+
+/// The fields of a published article used to find related posts
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedCandidate {
+    /// Unique identifier of the article
+    pub id: ArticleId,
+
+    /// Article title
+    pub title: String,
+
+    /// Category slug
+    pub category: PostCategory,
+
+    /// Normalized tags
+    pub tags: Vec<String>,
+
+    /// Series name, if the article is part of one
+    pub series: Option<String>,
+
+    /// Summary from front matter or generated at ingestion
+    pub summary: Option<String>,
+
+    /// Creation time, newer posts win ties
+    pub created_at: OffsetDateTime,
+}
+
+/// Fingerprint of the published articles
+///
+/// Any ingestion that adds, updates or removes a published article changes
+/// it, so cached recommendations can be compared against the current value
+/// to know whether they are stale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContentVersion {
+    /// Number of published articles
+    pub count: i64,
+
+    /// Most recent `updated_at` among published articles
+    pub last_updated: Option<OffsetDateTime>,
+}
+
+/// Score how related `candidate` is to `article`
+///
+/// The score adds up [`SHARED_TAG_WEIGHT`] per shared tag,
+/// [`SAME_SERIES_WEIGHT`] for the same series, [`SAME_CATEGORY_WEIGHT`] for
+/// the same category and up to [`TEXT_SIMILARITY_WEIGHT`] for the overlap of
+/// title and summary terms. A score of 0 means unrelated.
+pub fn related_score(article: &RelatedCandidate, candidate: &RelatedCandidate) -> f64 {
+    let shared_tags = candidate
+        .tags
+        .iter()
+        .filter(|tag| article.tags.contains(tag))
+        .count();
+    let same_series = article.series.is_some() && article.series == candidate.series;
+    let same_category = article.category == candidate.category;
+
+    shared_tags as f64 * SHARED_TAG_WEIGHT
+        + f64::from(u8::from(same_series)) * SAME_SERIES_WEIGHT
+        + f64::from(u8::from(same_category)) * SAME_CATEGORY_WEIGHT
+        + text_similarity(&terms(article), &terms(candidate)) * TEXT_SIMILARITY_WEIGHT
+}
+
+/// Rank candidates by how related they are to `article`
+///
+/// The article itself and unrelated candidates are left out. Ties are broken
+/// by creation time (newest first), then id, so the order is stable.
+///
+/// # Arguments
+///
+/// * `article` - The article recommendations are made for
+/// * `candidates` - Published articles to choose from
+/// * `limit` - Maximum number of results
+///
+/// # Returns
+///
+/// Up to `limit` candidates with their scores, most related first.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::related::{RelatedCandidate, rank_related};
+/// use time::OffsetDateTime;
+///
+/// let post = |n: u128, title: &str, tags: &[&str]| RelatedCandidate {
+///     id: backend::domain::articles::ArticleId::from_uuid(sqlx::types::Uuid::from_u128(n)),
+///     title: title.to_string(),
+///     category: "article".parse().unwrap(),
+///     tags: tags.iter().map(|t| t.to_string()).collect(),
+///     series: None,
+///     summary: None,
+///     created_at: OffsetDateTime::UNIX_EPOCH,
+/// };
+///
+/// let article = post(1, "Async Rust", &["rust", "async"]);
+/// let related = rank_related(
+///     &article,
+///     &[article.clone(), post(2, "Tokio tips", &["rust", "async"]), post(3, "Cooking", &["rust"])],
+///     5,
+/// );
+/// assert_eq!(related[0].0.title, "Tokio tips");
+/// assert_eq!(related.len(), 2);
+/// ```
+pub fn rank_related(
+    article: &RelatedCandidate,
+    candidates: &[RelatedCandidate],
+    limit: usize,
+) -> Vec<(RelatedCandidate, f64)> {
+    let mut scored: Vec<(RelatedCandidate, f64)> = candidates
+        .iter()
+        .filter(|candidate| candidate.id != article.id)
+        .map(|candidate| (candidate.clone(), related_score(article, candidate)))
+        .filter(|(_, score)| *score > 0.0)
+        .collect();
+
+    scored.sort_by(|(a, a_score), (b, b_score)| {
+        b_score
+            .total_cmp(a_score)
+            .then(b.created_at.cmp(&a.created_at))
+            .then(a.id.cmp(&b.id))
+    });
+    scored.truncate(limit);
+
+    scored
+}
+
+/// Terms of the title and summary used for textual similarity
+///
+/// Latin words of two or more characters are lowercased; CJK text, which has
+/// no spaces, contributes overlapping character pairs.
+fn terms(candidate: &RelatedCandidate) -> HashSet<String> {
+    let text = match &candidate.summary {
+        Some(summary) => format!("{} {}", candidate.title, summary),
+        None => candidate.title.clone(),
+    };
+
+    let mut terms = HashSet::new();
+    let mut word = String::new();
+    let mut previous_cjk: Option<char> = None;
+
+    for c in text.chars() {
+        if is_cjk(c) {
+            if let Some(previous) = previous_cjk {
+                terms.insert([previous, c].iter().collect());
+            }
+            previous_cjk = Some(c);
+        } else {
+            previous_cjk = None;
+        }
+
+        if c.is_alphanumeric() && !is_cjk(c) {
+            word.extend(c.to_lowercase());
+        } else {
+            if word.chars().count() >= 2 {
+                terms.insert(std::mem::take(&mut word));
+            }
+            word.clear();
+        }
+    }
+    if word.chars().count() >= 2 {
+        terms.insert(word);
+    }
+
+    terms
+}
+
+/// Jaccard similarity of two term sets, between 0 and 1
+fn text_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 0.0;
+    }
+
+    a.intersection(b).count() as f64 / union as f64
+}
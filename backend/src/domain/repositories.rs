@@ -1,8 +1,25 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
+use time::OffsetDateTime;
 
-use crate::{domain::articles::Article, errors::Result, interfaces::http::dtos::PostResponse};
+use crate::{
+    domain::{
+        archives::ArchiveMonthCount,
+        articles::{Article, ArticleId, PostCategory},
+        authors::Author,
+        collections::Collection,
+        ingestion::{IngestionReport, IngestionStatus},
+        links::{LinkEdge, LinkTarget},
+        media::{MediaAsset, MediaHash, MediaReference},
+        related::{ContentVersion, RelatedCandidate},
+        toc::TocEntry,
+    },
+    errors::Result,
+    interfaces::http::dtos::{
+        CategoryNavigation, FeaturedPost, PostResponse, SeriesPart, TagResponse, Translation,
+    },
+};
 
 /// Repository trait for article persistence operations
 ///
@@ -28,7 +45,7 @@ use crate::{domain::articles::Article, errors::Result, interfaces::http::dtos::P
 ///
 /// # Example Implementation
 ///
-/// ```rust
+/// ```rust,ignore
 /// use async_trait::async_trait;
 /// use backend::domain::repositories::ArticleRepository;
 ///
@@ -38,7 +55,7 @@ use crate::{domain::articles::Article, errors::Result, interfaces::http::dtos::P
 ///
 /// #[async_trait]
 /// impl ArticleRepository for MyArticleRepository {
-///     async fn find_optional_by_id(&self, id: &str) -> Result<Option<Article>> {
+///     async fn get_post_by_id(&self, id: &ArticleId) -> Result<Article> {
 ///         // implementation
 ///     }
 ///     // ... other methods
@@ -49,12 +66,15 @@ pub trait ArticleRepository: Send + Sync {
     /// Retrieve a paginated list of articles filtered by category
     ///
     /// This method fetches articles belonging to a specific category with
-    /// pagination support. Results are typically ordered by creation date
-    /// in descending order (newest first).
+    /// pagination support. Pinned articles come first, ordered by their
+    /// weight, followed by the rest by creation date in descending order
+    /// (newest first).
     ///
     /// # Arguments
     ///
     /// * `category` - The category to filter by (e.g., "article", "note", "think")
+    /// * `language` - Primary language to filter by; articles without a
+    ///   language are listed for every language
     /// * `page_size` - Maximum number of articles to return
     /// * `offset` - Number of articles to skip (for pagination)
     ///
@@ -65,16 +85,200 @@ pub trait ArticleRepository: Send + Sync {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// // Get first page (10 articles)
-    /// let page1 = repo.get_posts_by_category("article", 10, 0).await?;
+    /// let page1 = repo.get_posts_by_category("article", None, 10, 0).await?;
     ///
-    /// // Get second page
-    /// let page2 = repo.get_posts_by_category("article", 10, 10).await?;
+    /// // Get second page, English articles only
+    /// let page2 = repo.get_posts_by_category("article", Some("en"), 10, 10).await?;
     /// ```
     async fn get_posts_by_category(
         &self,
         category: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>>;
+
+    /// Retrieve a paginated list of articles carrying a tag
+    ///
+    /// Results are ordered by creation date in descending order (newest first).
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - The normalized tag to filter by (e.g., "rust")
+    /// * `language` - Primary language to filter by, as for
+    ///   [`get_posts_by_category`](Self::get_posts_by_category)
+    /// * `page_size` - Maximum number of articles to return
+    /// * `offset` - Number of articles to skip (for pagination)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PostResponse>)` - List of articles (may be empty)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_posts_by_tag(
+        &self,
+        tag: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>>;
+
+    /// Retrieve a paginated list of articles by an author
+    ///
+    /// Results are ordered by creation date in descending order (newest first).
+    ///
+    /// # Arguments
+    ///
+    /// * `author` - The author id to filter by (e.g., "jane")
+    /// * `language` - Primary language to filter by, as for
+    ///   [`get_posts_by_category`](Self::get_posts_by_category)
+    /// * `page_size` - Maximum number of articles to return
+    /// * `offset` - Number of articles to skip (for pagination)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PostResponse>)` - List of articles (may be empty)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_posts_by_author(
+        &self,
+        author: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>>;
+
+    /// Retrieve every stored author profile
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Author>)` - Profiles ordered by id
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_authors(&self) -> Result<Vec<Author>>;
+
+    /// Retrieve the stored profiles of some authors
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Author ids to look up
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Author>)` - Profiles of the ids that have one, in any order
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_authors_by_ids(&self, ids: &[String]) -> Result<Vec<Author>>;
+
+    /// Retrieve a stored data collection by name
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Name of the declared collection (e.g., "links")
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Collection))` - The collection has been pushed
+    /// * `Ok(None)` - No collection is stored under the name
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_collection(&self, name: &str) -> Result<Option<Collection>>;
+
+    /// Retrieve the primary languages the published articles are written in
+    ///
+    /// Used to match `Accept-Language` against the languages that have
+    /// content.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - Lowercased primary subtags (e.g., "en", "zh")
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_languages(&self) -> Result<Vec<String>>;
+
+    /// Retrieve the published translations of an article
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Translation key of the article
+    /// * `id` - Id of the article itself, which is left out
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Translation>)` - Other articles with the key, ordered by
+    ///   language
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_translations(&self, key: &str, id: &ArticleId) -> Result<Vec<Translation>>;
+
+    /// Retrieve the published featured articles for the home page
+    ///
+    /// Ordered by `featured_weight` (lower first, unweighted last), then by
+    /// creation date in descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `limit` - Maximum number of articles to return
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<FeaturedPost>)` - Featured articles (may be empty)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_featured(&self, limit: i64) -> Result<Vec<FeaturedPost>>;
+
+    /// Retrieve every tag together with the number of articles using it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<TagResponse>)` - Tags ordered by count (descending), then name
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_tags(&self) -> Result<Vec<TagResponse>>;
+
+    /// Retrieve the published parts of a series in reading order
+    ///
+    /// Drafts are left out, like in every other listing. Parts are ordered by `series_order` (parts without one go last), then
+    /// by creation date and id. `created_at` is preserved when an article is
+    /// updated, so the order stays stable across pushes.
+    ///
+    /// # Arguments
+    ///
+    /// * `series` - Series name as written in front matter
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<SeriesPart>)` - Published parts of the series (empty if it
+    ///   doesn't exist or has no published part)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_series_parts(&self, series: &str) -> Result<Vec<SeriesPart>>;
+
+    /// Count published posts per month, newest month first
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Only count posts of this category
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ArchiveMonthCount>)` - Months with at least one post (may be empty)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_archive_counts(&self, category: Option<&str>) -> Result<Vec<ArchiveMonthCount>>;
+
+    /// Retrieve a paginated list of published posts created in a time range
+    ///
+    /// Results are ordered by creation date in descending order (newest first).
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start of the range (inclusive)
+    /// * `end` - End of the range (exclusive)
+    /// * `category` - Only list posts of this category
+    /// * `page_size` - Maximum number of posts to return
+    /// * `offset` - Number of posts to skip (for pagination)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<PostResponse>)` - Posts created in the range (may be empty)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_posts_by_period(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        category: Option<&str>,
         page_size: i64,
         offset: i64,
     ) -> Result<Vec<PostResponse>>;
@@ -88,7 +292,7 @@ pub trait ArticleRepository: Send + Sync {
     ///
     /// # Arguments
     ///
-    /// * `id` - The unique identifier of the article
+    /// * `id` - The validated unique identifier of the article
     ///
     /// # Returns
     ///
@@ -98,11 +302,36 @@ pub trait ArticleRepository: Send + Sync {
     ///
     /// # Example
     ///
-    /// ```rust
-    /// let article = repo.get_post_by_id("article-123").await?;
+    /// ```rust,ignore
+    /// let id: ArticleId = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse()?;
+    /// let article = repo.get_post_by_id(&id).await?;
     /// println!("Title: {}", article.title);
     /// ```
-    async fn get_post_by_id(&self, id: &str) -> Result<Article>;
+    async fn get_post_by_id(&self, id: &ArticleId) -> Result<Article>;
+
+    /// Retrieve the published posts just before and after a position in a
+    /// category
+    ///
+    /// Uses keyset lookups on `(created_at, id)`, so only the two neighbours
+    /// are read regardless of the category size. The article at the given
+    /// position doesn't need to be published itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - Category to navigate within
+    /// * `created_at` - Creation time of the current article
+    /// * `id` - Id of the current article, breaking ties on `created_at`
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(CategoryNavigation)` - The closest older and newer posts, if any
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_category_neighbours(
+        &self,
+        category: &PostCategory,
+        created_at: OffsetDateTime,
+        id: &ArticleId,
+    ) -> Result<CategoryNavigation>;
 
     /// Retrieve all articles from the database
     ///
@@ -122,13 +351,145 @@ pub trait ArticleRepository: Send + Sync {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let all_articles = repo.get_all().await?;
     /// println!("Total articles: {}", all_articles.len());
     /// ```
     async fn get_all(&self) -> Result<Vec<Article>>;
 
-    async fn get_by_paths(&self, paths: &[String]) -> Result<HashSet<String>>;
+    /// Retrieve only the stored table of contents of an article
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The validated unique identifier of the article
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Vec<TocEntry>))` - The stored heading tree
+    /// * `Ok(None)` - The article predates TOC extraction
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article doesn't exist
+    /// * `Err(SomeError)` - Other database error occurred
+    async fn get_toc(&self, id: &ArticleId) -> Result<Option<Vec<TocEntry>>>;
+
+    /// Retrieve the metadata of a stored media blob
+    ///
+    /// # Arguments
+    ///
+    /// * `hash` - Content hash of the blob
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(MediaAsset))` - The blob is known
+    /// * `Ok(None)` - No blob is recorded under the hash
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_media_asset(&self, hash: &MediaHash) -> Result<Option<MediaAsset>>;
+
+    /// Retrieve the resized variants of an original image
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<MediaAsset>)` - Variants ordered by width, then content type
+    ///   (empty for images smaller than every configured width)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_media_variants(&self, original: &MediaHash) -> Result<Vec<MediaAsset>>;
+
+    /// Retrieve every article as a link target (id, path, title, category)
+    ///
+    /// Used to resolve internal links during ingestion, so drafts are
+    /// included: a link to a draft starts working once it is published.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<LinkTarget>)` - All articles ordered by title
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_link_targets(&self) -> Result<Vec<LinkTarget>>;
+
+    /// Retrieve every published article as a link target, the node list
+    /// of the link graph
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<LinkTarget>)` - Published articles ordered by title
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_published_link_targets(&self) -> Result<Vec<LinkTarget>>;
+
+    /// Retrieve the articles linking to an article
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The validated unique identifier of the linked article
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<LinkTarget>)` - Published linking articles, newest first (may be empty)
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article doesn't exist
+    /// * `Err(SomeError)` - Other database error occurred
+    async fn get_backlinks(&self, id: &ArticleId) -> Result<Vec<LinkTarget>>;
+
+    /// Retrieve every link between published articles
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<LinkEdge>)` - All edges of the link graph
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_link_edges(&self) -> Result<Vec<LinkEdge>>;
+
+    /// Retrieve the fields used to recommend related posts, for every
+    /// published article
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<RelatedCandidate>)` - All published articles (may be empty)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_related_candidates(&self) -> Result<Vec<RelatedCandidate>>;
+
+    /// Retrieve the fingerprint of the published articles
+    ///
+    /// A cheap aggregate query used to tell whether cached recommendations
+    /// are stale.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(ContentVersion)` - Count and latest update of published articles
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_content_version(&self) -> Result<ContentVersion>;
+
+    /// Retrieve a page of ingestion reports, most recently ingested first
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - Only return reports with this outcome
+    /// * `limit` - Maximum number of reports to return
+    /// * `offset` - Number of reports to skip
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<IngestionReport>)` - The latest report of each path (may be empty)
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_reports(
+        &self,
+        status: Option<IngestionStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<IngestionReport>>;
+
+    /// Retrieve the stored bylines of some articles
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - Article ids to look up, drafts included
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HashMap<ArticleId, Vec<String>>)` - Author ids of the articles
+    ///   that are stored; ids that aren't have no entry
+    /// * `Err(SomeError)` - An error occurred during the query
+    async fn get_article_authors(
+        &self,
+        ids: &[ArticleId],
+    ) -> Result<HashMap<ArticleId, Vec<String>>>;
+
+    async fn get_by_paths(&self, paths: &[String]) -> Result<HashSet<ArticleId>>;
 
     async fn begin_transaction(&self) -> Result<TransactionGuard>;
 }
@@ -142,8 +503,92 @@ impl TransactionGuard {
         self.inner.upsert_batch(articles).await
     }
 
-    pub async fn delete_batch(&mut self, paths: &HashSet<String>) -> Result<()> {
-        self.inner.delete_batch(paths).await
+    pub async fn delete_batch(&mut self, ids: &HashSet<ArticleId>) -> Result<()> {
+        self.inner.delete_batch(ids).await
+    }
+
+    /// Store ingestion reports, replacing earlier reports for the same paths
+    pub async fn upsert_reports(&mut self, reports: &[IngestionReport]) -> Result<()> {
+        self.inner.upsert_reports(reports).await
+    }
+
+    /// Remove the ingestion reports of deleted files
+    pub async fn delete_reports(&mut self, paths: &[String]) -> Result<()> {
+        self.inner.delete_reports(paths).await
+    }
+
+    /// Replace the outgoing links of the given articles
+    ///
+    /// Existing edges starting at any of `sources` are removed before
+    /// `edges` are inserted, so an article that no longer links anywhere
+    /// loses its old edges.
+    pub async fn replace_links(&mut self, sources: &[ArticleId], edges: &[LinkEdge]) -> Result<()> {
+        self.inner.replace_links(sources, edges).await
+    }
+
+    /// Replace the media references of the given articles
+    ///
+    /// Works like [`TransactionGuard::replace_links`]: references of any of
+    /// `sources` are removed before `references` are inserted. Referenced
+    /// media must already be recorded.
+    pub async fn replace_media_references(
+        &mut self,
+        sources: &[ArticleId],
+        references: &[MediaReference],
+    ) -> Result<()> {
+        self.inner
+            .replace_media_references(sources, references)
+            .await
+    }
+
+    /// Record stored media blobs; blobs already recorded are left unchanged
+    ///
+    /// Originals must come before their variants.
+    pub async fn insert_media(&mut self, assets: &[MediaAsset]) -> Result<()> {
+        self.inner.insert_media(assets).await
+    }
+
+    /// Wait for exclusive access to the stored media until the transaction
+    /// ends
+    ///
+    /// Taken by ingestion before it records a push, which checks the known
+    /// blobs it reuses are still there, and by the media garbage collection,
+    /// so a blob is never collected between being checked and being
+    /// referenced.
+    pub async fn lock_media(&mut self) -> Result<()> {
+        self.inner.lock_media().await
+    }
+
+    /// Delete the media no article references any more
+    ///
+    /// Originals without a row in `article_media` are deleted together with
+    /// their variants. Only the rows are deleted; the caller removes the
+    /// blobs from the media store before committing.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<MediaHash>)` - Hashes of the deleted originals and variants
+    /// * `Err(SomeError)` - An error occurred during the query
+    pub async fn delete_unreferenced_media(&mut self) -> Result<Vec<MediaHash>> {
+        self.inner.delete_unreferenced_media().await
+    }
+
+    /// Replace every author profile with the ones of the authors data file
+    pub async fn replace_authors(&mut self, authors: &[Author]) -> Result<()> {
+        self.inner.replace_authors(authors).await
+    }
+
+    /// Store data collections, replacing the stored ones with the same name
+    pub async fn upsert_collections(&mut self, collections: &[Collection]) -> Result<()> {
+        self.inner.upsert_collections(collections).await
+    }
+
+    /// Delete the collections read from removed files
+    ///
+    /// A collection is only deleted if it was last read from one of `paths`,
+    /// so removing `links.json` keeps a collection pushed as `links.yaml`.
+    pub async fn delete_collections(&mut self, paths: &[String]) -> Result<()> {
+        self.inner.delete_collections(paths).await
     }
 
     pub async fn commit(self) -> Result<()> {
@@ -154,6 +599,20 @@ impl TransactionGuard {
 #[async_trait]
 pub trait TransactionOps: Send {
     async fn upsert_batch(&mut self, articles: &[Article]) -> Result<()>;
-    async fn delete_batch(&mut self, id: &HashSet<String>) -> Result<()>;
+    async fn delete_batch(&mut self, ids: &HashSet<ArticleId>) -> Result<()>;
+    async fn upsert_reports(&mut self, reports: &[IngestionReport]) -> Result<()>;
+    async fn delete_reports(&mut self, paths: &[String]) -> Result<()>;
+    async fn replace_links(&mut self, sources: &[ArticleId], edges: &[LinkEdge]) -> Result<()>;
+    async fn insert_media(&mut self, assets: &[MediaAsset]) -> Result<()>;
+    async fn lock_media(&mut self) -> Result<()>;
+    async fn delete_unreferenced_media(&mut self) -> Result<Vec<MediaHash>>;
+    async fn replace_media_references(
+        &mut self,
+        sources: &[ArticleId],
+        references: &[MediaReference],
+    ) -> Result<()>;
+    async fn replace_authors(&mut self, authors: &[Author]) -> Result<()>;
+    async fn upsert_collections(&mut self, collections: &[Collection]) -> Result<()>;
+    async fn delete_collections(&mut self, paths: &[String]) -> Result<()>;
     async fn commit(self: Box<Self>) -> Result<()>;
 }
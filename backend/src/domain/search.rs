@@ -2,13 +2,74 @@ use async_trait::async_trait;
 use meilisearch_sdk::client::Client;
 use serde::{Deserialize, Serialize};
 
-use crate::{domain::articles::PostCategory, errors::Result};
+use crate::{
+    domain::{
+        articles::{Article, ArticleId, PostCategory},
+        gallery::GalleryImage,
+        languages::primary_language,
+    },
+    errors::Result,
+};
 
 /// Default name of the search index in Meilisearch
 ///
 /// This is the index name used for article full-text search operations.
 pub const DEFAULT_SEARCH_INDEX: &str = "articles";
 
+/// Attributes of an [`ArticleDocument`] matched by search queries, by rank
+///
+/// Gallery images contribute their camera metadata, so photos can be found
+/// by camera, lens or exposure (e.g. "X100V" or "f/1.4").
+pub const SEARCHABLE_ATTRIBUTES: &[&str] = &[
+    "title",
+    "content",
+    "summary",
+    "images.camera",
+    "images.lens",
+    "images.exposure",
+];
+
+/// Attributes of an [`ArticleDocument`] searches can be filtered by
+///
+/// `lang` restricts a search to one language, see [`SearchService::search`].
+pub const FILTERABLE_ATTRIBUTES: &[&str] = &["lang"];
+
+/// Document stored in the search index for an article
+///
+/// The article as the API serializes it, plus the gallery images the API
+/// serves separately through `/gallery` and the primary language searches
+/// are filtered by.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let documents: Vec<ArticleDocument> = articles.iter().map(ArticleDocument::from).collect();
+/// client.index("articles").add_documents(&documents, Some("id")).await?;
+/// ```
+#[derive(Debug, Serialize)]
+pub struct ArticleDocument<'a> {
+    /// The article fields
+    #[serde(flatten)]
+    pub article: &'a Article,
+
+    /// Gallery images with their camera metadata
+    pub images: &'a [GalleryImage],
+
+    /// Primary language of the article (e.g. "zh" for "zh-CN"), `null` for
+    /// articles without a language
+    pub lang: Option<String>,
+}
+
+impl<'a> From<&'a Article> for ArticleDocument<'a> {
+    fn from(article: &'a Article) -> Self {
+        Self {
+            article,
+            images: &article.images,
+            lang: article.language.as_deref().and_then(primary_language),
+        }
+    }
+}
+
 /// Search result item containing an article match with optional highlighting
 ///
 /// This struct represents a single search result returned by the search service.
@@ -27,12 +88,11 @@ pub const DEFAULT_SEARCH_INDEX: &str = "articles";
 ///
 /// ```rust
 /// use backend::domain::search::SearchHit;
-/// use backend::domain::articles::PostCategory;
 ///
 /// let hit = SearchHit {
-///     id: "article-123".to_string(),
+///     id: "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap(),
 ///     title: "Introduction to <span class=\"highlight\">Rust</span>".to_string(),
-///     category: PostCategory::Article,
+///     category: "article".parse().unwrap(),
 ///     summary: "A guide to <span class=\"highlight\">Rust</span> programming...".to_string(),
 ///     content: "Learn <span class=\"highlight\">Rust</span> basics...".to_string(),
 /// };
@@ -40,7 +100,7 @@ pub const DEFAULT_SEARCH_INDEX: &str = "articles";
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchHit {
     /// Unique identifier of the article
-    pub id: String,
+    pub id: ArticleId,
 
     /// Article title (may include HTML highlighting tags)
     pub title: String,
@@ -75,7 +135,7 @@ pub struct SearchHit {
 ///
 /// # Example Implementation
 ///
-/// ```rust
+/// ```rust,ignore
 /// use async_trait::async_trait;
 /// use backend::domain::search::{SearchService, SearchHit};
 ///
@@ -89,6 +149,7 @@ pub struct SearchHit {
 ///         &self,
 ///         query: &str,
 ///         index: &str,
+///         language: Option<&str>,
 ///         offset: usize,
 ///         limit: usize,
 ///     ) -> Result<(Vec<SearchHit>, usize, usize, usize)> {
@@ -99,6 +160,7 @@ pub struct SearchHit {
 ///         &self,
 ///         index: &str,
 ///         searchable_attributes: &[&str],
+///         filterable_attributes: &[&str],
 ///     ) -> Result<&Client> {
 ///         // implementation
 ///     }
@@ -116,6 +178,8 @@ pub trait SearchService: Send + Sync {
     ///
     /// * `query` - The search query string (supports full-text search syntax)
     /// * `index` - Name of the search index to query (e.g., "articles")
+    /// * `language` - Primary language to search; articles without a
+    ///   language match every language
     /// * `offset` - Current page number (1-based indexing)
     /// * `limit` - Maximum number of results to return per page
     ///
@@ -137,9 +201,9 @@ pub trait SearchService: Send + Sync {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let (results, total, pages, current) =
-    ///     search_service.search("rust programming", "articles", 1, 10).await?;
+    ///     search_service.search("rust programming", "articles", Some("en"), 1, 10).await?;
     ///
     /// println!("Found {} results across {} pages", total, pages);
     /// for hit in results {
@@ -150,6 +214,7 @@ pub trait SearchService: Send + Sync {
         &self,
         query: &str,
         index: &str,
+        language: Option<&str>,
         offset: usize,
         limit: usize,
     ) -> Result<(Vec<SearchHit>, usize, usize, usize)>;
@@ -157,14 +222,17 @@ pub trait SearchService: Send + Sync {
     /// Create and configure a search index client
     ///
     /// This method creates a new search index with the specified name and
-    /// configures which fields should be searchable. It returns a client
-    /// that can be used for bulk operations like importing documents.
+    /// configures which fields should be searchable and which can be
+    /// filtered by. It returns a client that can be used for bulk operations
+    /// like importing documents.
     ///
     /// # Arguments
     ///
     /// * `index` - Name for the new search index
     /// * `searchable_attributes` - Array of field names that should be searchable
     ///                             (e.g., ["title", "content", "summary"])
+    /// * `filterable_attributes` - Array of field names searches can be
+    ///                             filtered by (e.g., ["lang"])
     ///
     /// # Returns
     ///
@@ -181,9 +249,9 @@ pub trait SearchService: Send + Sync {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let client = search_service
-    ///     .create_index_client("articles", &["title", "summary", "content"])
+    ///     .create_index_client("articles", &["title", "summary", "content"], &["lang"])
     ///     .await?;
     ///
     /// // Use the client for bulk operations
@@ -201,5 +269,35 @@ pub trait SearchService: Send + Sync {
         &self,
         index: &str,
         searchable_attributes: &[&str],
+        filterable_attributes: &[&str],
     ) -> Result<&Client>;
+
+    /// Update or add an article to the search index
+    ///
+    /// A document with the same ID is replaced, so this is how a single
+    /// changed article is indexed without rebuilding the whole index.
+    ///
+    /// # Arguments
+    ///
+    /// * `article` - The article to index, with its gallery metadata
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The document is searchable
+    /// * `Err(SomeError)` - Indexing operation failed
+    async fn update_or_add_index_item(&self, article: &Article) -> Result<()>;
+
+    /// Delete an article from the search index
+    ///
+    /// Deleting a document that isn't indexed succeeds.
+    ///
+    /// # Arguments
+    ///
+    /// * `article` - The article to remove (only its ID is used)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - The document no longer appears in search results
+    /// * `Err(SomeError)` - Deletion operation failed
+    async fn delete_index_item(&self, article: &Article) -> Result<()>;
 }
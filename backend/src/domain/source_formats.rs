@@ -0,0 +1,351 @@
+use std::{collections::HashMap, fmt};
+
+use serde_json::{Map, Value};
+
+use crate::domain::{
+    authors::{Author, author_id_by_name, is_author_id},
+    ingestion::IngestionIssue,
+};
+
+/// Front matter fields holding a list of strings, written comma separated
+const LIST_FIELDS: &[&str] = &["tags", "authors"];
+
+/// Front matter fields holding a whole number
+const INTEGER_FIELDS: &[&str] = &["series_order", "pinned_weight", "featured_weight"];
+
+/// Front matter fields holding a flag
+const BOOLEAN_FIELDS: &[&str] = &["pinned", "featured", "keep_location"];
+
+/// Front matter fields holding a list or mapping, written as JSON
+const JSON_FIELDS: &[&str] = &["images", "extra"];
+
+/// Markup language an article is written in, detected from the file extension
+///
+/// | Extension       | Format   |
+/// |-----------------|----------|
+/// | `.md`, `.mdx`   | Markdown |
+/// | `.ipynb`        | Jupyter notebook |
+/// | `.org`          | Org-mode |
+/// | `.adoc`, `.asciidoc` | AsciiDoc |
+///
+/// Every format is normalized to a markdown body and front matter, so the
+/// rest of the ingestion pipeline and the stored articles don't depend on
+/// the format a post was written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SourceFormat {
+    Markdown,
+    Notebook,
+    Org,
+    AsciiDoc,
+}
+
+impl SourceFormat {
+    /// Detect the format of a repository file from its extension
+    ///
+    /// Extensions are matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(format)` - The file is an article source
+    /// * `None` - The file is not an article (e.g., an image or a data file)
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::source_formats::SourceFormat;
+    ///
+    /// assert_eq!(SourceFormat::from_path("posts/intro.org"), Some(SourceFormat::Org));
+    /// assert_eq!(SourceFormat::from_path("posts/intro.MD"), Some(SourceFormat::Markdown));
+    /// assert_eq!(SourceFormat::from_path("images/crab.png"), None);
+    /// ```
+    pub fn from_path(path: &str) -> Option<Self> {
+        let (_, extension) = path.rsplit_once('.')?;
+        if extension.contains('/') {
+            return None;
+        }
+
+        match extension.to_ascii_lowercase().as_str() {
+            "md" | "mdx" => Some(Self::Markdown),
+            "ipynb" => Some(Self::Notebook),
+            "org" => Some(Self::Org),
+            "adoc" | "asciidoc" => Some(Self::AsciiDoc),
+            _ => None,
+        }
+    }
+
+    /// Human readable format name, used in error messages
+    pub fn name(&self) -> &'static str {
+        match self {
+            SourceFormat::Markdown => "Markdown",
+            SourceFormat::Notebook => "Jupyter notebook",
+            SourceFormat::Org => "Org-mode",
+            SourceFormat::AsciiDoc => "AsciiDoc",
+        }
+    }
+}
+
+impl fmt::Display for SourceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// An Org-mode or AsciiDoc document converted to a markdown article
+///
+/// The document header is mapped to front matter fields; see
+/// [`convert_org`](crate::domain::org::convert_org) and
+/// [`convert_asciidoc`](crate::domain::asciidoc::convert_asciidoc) for the
+/// headers each format understands.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConvertedDocument {
+    /// Front matter built from the header, `None` if the header sets no field
+    pub front_matter: Option<Value>,
+
+    /// 1-based line of the header entry that set each front matter field
+    pub key_lines: HashMap<String, usize>,
+
+    /// Markdown body
+    pub markdown: String,
+
+    /// Warnings about header entries that were ignored
+    pub issues: Vec<IngestionIssue>,
+}
+
+impl ConvertedDocument {
+    /// Replace the author names of the header by the ids of their profiles
+    ///
+    /// Headers name the authors, while front matter lists profile ids; see
+    /// [`author_id_by_name`]. A name matching no profile is left out of the
+    /// byline with a warning, unless it is written as an id: that one is kept
+    /// and reported like an unknown id in a markdown file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use backend::domain::authors::Author;
+    /// use backend::domain::org::convert_org;
+    /// use serde_json::json;
+    ///
+    /// let authors = vec![Author {
+    ///     name: "Jane Doe".to_string(),
+    ///     ..Author::placeholder("jane")
+    /// }];
+    /// let mut converted = convert_org("#+AUTHOR: Jane Doe, Kim Lee\n\nText\n");
+    /// converted.resolve_authors(&authors);
+    ///
+    /// assert_eq!(converted.front_matter, Some(json!({ "authors": ["jane"] })));
+    /// assert_eq!(converted.issues.len(), 1);
+    /// ```
+    pub fn resolve_authors(&mut self, authors: &[Author]) {
+        let Some(Value::Array(names)) = self
+            .front_matter
+            .as_mut()
+            .and_then(|front_matter| front_matter.get_mut("authors"))
+        else {
+            return;
+        };
+        let line = self.key_lines.get("authors").copied();
+
+        let mut ids = Vec::new();
+        for value in names.drain(..) {
+            // Anything else is reported by front matter validation
+            let Some(name) = value.as_str() else {
+                ids.push(value);
+                continue;
+            };
+
+            match author_id_by_name(name, authors) {
+                Some(id) => ids.push(Value::String(id)),
+                None if is_author_id(name) => ids.push(value),
+                None => self.issues.push(IngestionIssue::warning(
+                    Some("authors"),
+                    line,
+                    format!(
+                        "author `{}` matches no profile in the authors data file and is left out",
+                        name
+                    ),
+                )),
+            }
+        }
+        *names = ids;
+    }
+}
+
+/// Front matter fields collected from the header of a document
+#[derive(Debug, Default)]
+pub(crate) struct HeaderFields {
+    fields: Map<String, Value>,
+    key_lines: HashMap<String, usize>,
+    issues: Vec<IngestionIssue>,
+}
+
+impl HeaderFields {
+    /// Set a field from its header text, typed by [`header_value`]
+    ///
+    /// If the field was already set, the first value is kept and a warning
+    /// names the repeated header entry.
+    pub(crate) fn insert(&mut self, field: &str, raw: &str, line: usize, entry: &str) {
+        self.insert_value(field, header_value(field, raw), line, entry);
+    }
+
+    /// Set a field to an already typed value
+    pub(crate) fn insert_value(&mut self, field: &str, value: Value, line: usize, entry: &str) {
+        if self.fields.contains_key(field) {
+            self.issues.push(IngestionIssue::warning(
+                Some(field),
+                Some(line),
+                format!(
+                    "`{}` sets `{}` again; the first value is used",
+                    entry, field
+                ),
+            ));
+            return;
+        }
+
+        self.fields.insert(field.to_string(), value);
+        self.key_lines.insert(field.to_string(), line);
+    }
+
+    /// Finish the conversion with the markdown body
+    pub(crate) fn into_document(self, markdown: String) -> ConvertedDocument {
+        ConvertedDocument {
+            front_matter: (!self.fields.is_empty()).then_some(Value::Object(self.fields)),
+            key_lines: self.key_lines,
+            markdown,
+            issues: self.issues,
+        }
+    }
+}
+
+/// Type the text of a header entry for a front matter field
+///
+/// Headers are plain text, so values are typed by field:
+///
+/// * `tags` and `authors` - Comma separated list
+/// * `series_order`, `pinned_weight` and `featured_weight` - Integer
+/// * `pinned`, `featured` and `keep_location` - `true` or `false`; an empty
+///   value means `true`, as for AsciiDoc attributes set without a value
+/// * `images` and `extra` - JSON list or mapping
+/// * Any other field - String
+///
+/// Text that doesn't fit the field's type is kept as a string, so front
+/// matter validation reports it like a mistyped value in a markdown file.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::source_formats::header_value;
+/// use serde_json::json;
+///
+/// assert_eq!(header_value("tags", "rust, web"), json!(["rust", "web"]));
+/// assert_eq!(header_value("series_order", "2"), json!(2));
+/// assert_eq!(header_value("title", "2024"), json!("2024"));
+/// ```
+pub fn header_value(field: &str, raw: &str) -> Value {
+    let raw = raw.trim();
+
+    if LIST_FIELDS.contains(&field) {
+        return raw
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| Value::String(item.to_string()))
+            .collect();
+    }
+
+    if INTEGER_FIELDS.contains(&field)
+        && let Ok(number) = raw.parse::<i64>()
+    {
+        return number.into();
+    }
+
+    if BOOLEAN_FIELDS.contains(&field) {
+        match raw {
+            "" | "true" => return Value::Bool(true),
+            "false" => return Value::Bool(false),
+            _ => {}
+        }
+    }
+
+    if JSON_FIELDS.contains(&field)
+        && let Ok(value) = serde_json::from_str(raw)
+    {
+        return value;
+    }
+
+    Value::String(raw.to_string())
+}
+
+/// Fence code in a block longer than any backtick run it contains
+pub(crate) fn fenced(code: &str, info: &str) -> String {
+    let longest = code
+        .lines()
+        .map(|line| line.trim_start().chars().take_while(|c| *c == '`').count())
+        .max()
+        .unwrap_or_default();
+    let fence = "`".repeat(longest.max(2) + 1);
+
+    format!("{}{}\n{}\n{}", fence, info, code.trim_end(), fence)
+}
+
+/// Wrap text in a code span delimited by more backticks than it contains
+pub(crate) fn code_span(text: &str) -> String {
+    let mut longest = 0;
+    let mut run = 0;
+    for c in text.chars() {
+        run = if c == '`' { run + 1 } else { 0 };
+        longest = longest.max(run);
+    }
+    let ticks = "`".repeat(longest + 1);
+
+    if longest > 0 {
+        format!("{} {} {}", ticks, text, ticks)
+    } else {
+        format!("{}{}{}", ticks, text, ticks)
+    }
+}
+
+/// Check whether a link destination names an image, from its extension
+pub(crate) fn is_image_path(path: &str) -> bool {
+    path.rsplit_once('.').is_some_and(|(_, ext)| {
+        ["png", "jpg", "jpeg", "gif", "webp", "svg", "avif"]
+            .iter()
+            .any(|known| ext.eq_ignore_ascii_case(known))
+    })
+}
+
+/// Prefix converted lines with `>` to quote them
+pub(crate) fn blockquote(lines: &[String]) -> Vec<String> {
+    lines
+        .join("\n")
+        .lines()
+        .map(|line| {
+            if line.is_empty() {
+                ">".to_string()
+            } else {
+                format!("> {}", line)
+            }
+        })
+        .collect()
+}
+
+/// Wrap a link destination in `<>` if it contains whitespace
+pub(crate) fn link_destination(dest: &str) -> String {
+    if dest.contains(char::is_whitespace) {
+        format!("<{}>", dest)
+    } else {
+        dest.to_string()
+    }
+}
+
+/// Join converted lines into a markdown body with a trailing newline
+pub(crate) fn join_markdown(lines: &[String]) -> String {
+    let markdown = lines.join("\n");
+    let markdown = markdown.trim_matches('\n');
+
+    if markdown.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", markdown)
+    }
+}
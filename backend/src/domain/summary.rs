@@ -0,0 +1,103 @@
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+/// Options for summaries generated when front matter has none
+///
+/// Declared in `config.toml` under `[summary]`.
+///
+/// # Example Configuration
+///
+/// ```toml
+/// [summary]
+/// max_chars = 160
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SummaryOptions {
+    /// Maximum length of a generated summary in characters, excluding the
+    /// trailing ellipsis
+    pub max_chars: usize,
+}
+
+impl Default for SummaryOptions {
+    fn default() -> Self {
+        Self { max_chars: 160 }
+    }
+}
+
+impl SummaryOptions {
+    /// Check that the configured options are usable
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `max_chars` is zero.
+    pub fn validate(self) -> Result<Self> {
+        if self.max_chars == 0 {
+            return Err(anyhow::anyhow!(
+                "Invalid summary configuration: `max_chars` must be greater than 0"
+            )
+            .into());
+        }
+
+        Ok(self)
+    }
+}
+
+/// Characters that end a sentence, in Latin and CJK text
+const SENTENCE_ENDS: [char; 6] = ['.', '!', '?', '。', '！', '？'];
+
+/// Shorten plain text to at most `max_chars` characters
+///
+/// Text that fits is returned unchanged. Longer text is cut at the last
+/// sentence end within the limit; if there is none in the second half of the
+/// limit, at the last space, and otherwise at the character limit (which is
+/// the common case for CJK text without spaces). A cut that doesn't end a
+/// sentence gets a trailing `…`.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::summary::truncate_summary;
+///
+/// assert_eq!(truncate_summary("Short.", 20), "Short.");
+/// assert_eq!(
+///     truncate_summary("First sentence. Second sentence is long.", 30),
+///     "First sentence."
+/// );
+/// assert_eq!(truncate_summary("one two three four", 12), "one two…");
+/// assert_eq!(truncate_summary("一二三四五六七八九十", 4), "一二三四…");
+/// ```
+pub fn truncate_summary(text: &str, max_chars: usize) -> String {
+    let text = text.trim();
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let all: Vec<char> = text.chars().collect();
+    let chars = &all[..max_chars];
+    let half = max_chars / 2;
+
+    // A CJK full stop, or a Latin one followed by whitespace (not `3.14`)
+    let sentence_end = (0..chars.len()).rev().find(|&i| {
+        SENTENCE_ENDS.contains(&chars[i])
+            && (!chars[i].is_ascii() || all.get(i + 1).is_some_and(|next| next.is_whitespace()))
+    });
+    if let Some(end) = sentence_end.filter(|&end| end + 1 >= half) {
+        return chars[..=end].iter().collect();
+    }
+
+    let cut = chars
+        .iter()
+        .rposition(|c| c.is_whitespace())
+        .filter(|&space| space >= half)
+        .unwrap_or(chars.len());
+    let mut summary: String = chars[..cut]
+        .iter()
+        .collect::<String>()
+        .trim_end()
+        .to_string();
+    summary.push('…');
+
+    summary
+}
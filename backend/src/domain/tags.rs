@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::Result;
+
+/// Listing page size used by `/tags/{tag}/posts` when none is requested
+pub const DEFAULT_TAG_PAGE_SIZE: i64 = 20;
+
+/// Normalize a raw tag into its canonical spelling
+///
+/// The following rules are applied, in order:
+///
+/// 1. Full-width ASCII forms (`Ｒｕｓｔ`) and the ideographic space are folded
+///    to their half-width equivalents
+/// 2. Leading and trailing whitespace is removed and inner whitespace runs
+///    are collapsed into a single space
+/// 3. The tag is lowercased
+///
+/// # Returns
+///
+/// * `Some(String)` - The normalized tag
+/// * `None` - The tag is empty after normalization
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::tags::normalize_tag;
+///
+/// assert_eq!(normalize_tag("  Ｒｕｓｔ ").as_deref(), Some("rust"));
+/// assert_eq!(normalize_tag("Web   Dev").as_deref(), Some("web dev"));
+/// assert_eq!(normalize_tag("　"), None);
+/// ```
+pub fn normalize_tag(raw: &str) -> Option<String> {
+    let folded: String = raw.chars().map(fold_full_width).collect();
+    let normalized = folded
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+    (!normalized.is_empty()).then_some(normalized)
+}
+
+/// Map a full-width character (U+FF01..=U+FF5E, U+3000) to its ASCII form
+fn fold_full_width(c: char) -> char {
+    match c {
+        '\u{3000}' => ' ',
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// A group of tag spellings that should be merged into one tag
+///
+/// Declared in `config.toml` under `[[tag_synonyms]]`. Both the canonical
+/// name and the aliases go through [`normalize_tag`] first, so aliases only
+/// need to cover spellings that differ by more than case or width.
+///
+/// # Example Configuration
+///
+/// ```toml
+/// [[tag_synonyms]]
+/// canonical = "rust"
+/// aliases = ["rust-lang", "rustlang"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TagSynonym {
+    /// Tag that all aliases are rewritten to
+    pub canonical: String,
+
+    /// Alternative spellings of the canonical tag
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+/// Normalizes tags and resolves configured synonyms
+///
+/// Built from configuration at startup (and rebuilt when the config file
+/// changes). Tags are normalized during ingestion so the database only ever
+/// stores canonical tags; request paths go through the same normalizer so
+/// `/tags/Rust-Lang/posts` finds posts tagged `rust`.
+#[derive(Debug, Clone, Default)]
+pub struct TagNormalizer {
+    /// Normalized alias -> normalized canonical tag
+    synonyms: HashMap<String, String>,
+}
+
+impl TagNormalizer {
+    /// Build a normalizer from configured synonym groups
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a canonical tag is empty or an alias is claimed by
+    /// two different canonical tags.
+    pub fn new(groups: Vec<TagSynonym>) -> Result<Self> {
+        let mut synonyms = HashMap::new();
+
+        for group in groups {
+            let canonical = normalize_tag(&group.canonical).ok_or_else(|| {
+                anyhow::anyhow!("Invalid tag synonym configuration: empty canonical tag")
+            })?;
+
+            for alias in group.aliases.iter().filter_map(|a| normalize_tag(a)) {
+                if alias == canonical {
+                    continue;
+                }
+
+                match synonyms.get(&alias) {
+                    Some(existing) if existing != &canonical => {
+                        return Err(anyhow::anyhow!(
+                            "Invalid tag synonym configuration: `{}` is an alias of both `{}` and `{}`",
+                            alias,
+                            existing,
+                            canonical
+                        )
+                        .into());
+                    }
+                    _ => {
+                        synonyms.insert(alias, canonical.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(Self { synonyms })
+    }
+
+    /// Normalize a single tag and resolve it to its canonical name
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The canonical tag
+    /// * `None` - The tag is empty after normalization
+    pub fn normalize(&self, raw: &str) -> Option<String> {
+        let tag = normalize_tag(raw)?;
+
+        Some(self.synonyms.get(&tag).cloned().unwrap_or(tag))
+    }
+
+    /// Normalize a list of tags, dropping empty and duplicate entries
+    ///
+    /// The order of first occurrence is preserved.
+    pub fn normalize_all(&self, tags: &[String]) -> Vec<String> {
+        let mut normalized: Vec<String> = Vec::with_capacity(tags.len());
+
+        for tag in tags.iter().filter_map(|t| self.normalize(t)) {
+            if !normalized.contains(&tag) {
+                normalized.push(tag);
+            }
+        }
+
+        normalized
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+/// One heading in an article's table of contents
+///
+/// The `anchor` is the `id` given to the heading in the rendered HTML, so the
+/// frontend can link to `#anchor` directly. Entries nest by heading level:
+/// an `h3` following an `h2` becomes one of its `children`.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "level": 2,
+///   "text": "安装 Rust",
+///   "anchor": "安装-rust",
+///   "children": [
+///     { "level": 3, "text": "Linux", "anchor": "linux", "children": [] }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    /// Heading level, 1 to 6
+    pub level: u8,
+
+    /// Plain text of the heading
+    pub text: String,
+
+    /// Anchor slug of the heading, unique within the article
+    pub anchor: String,
+
+    /// Headings nested below this one
+    #[serde(default)]
+    pub children: Vec<TocEntry>,
+}
+
+/// Nest a flat list of headings into a tree
+///
+/// Each heading becomes a child of the closest preceding heading with a
+/// lower level. Skipped levels are tolerated (an `h4` directly after an `h2`
+/// is nested under the `h2`), and a document starting at `h2` simply has
+/// `h2` entries at the top.
+///
+/// # Arguments
+///
+/// * `headings` - Headings in document order, without children
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::toc::{TocEntry, build_toc};
+///
+/// let entry = |level, text: &str| TocEntry {
+///     level,
+///     text: text.to_string(),
+///     anchor: text.to_lowercase(),
+///     children: Vec::new(),
+/// };
+///
+/// let toc = build_toc(vec![entry(2, "Intro"), entry(3, "Setup"), entry(2, "Usage")]);
+/// assert_eq!(toc.len(), 2);
+/// assert_eq!(toc[0].children[0].text, "Setup");
+/// ```
+pub fn build_toc(headings: Vec<TocEntry>) -> Vec<TocEntry> {
+    let mut roots = Vec::new();
+
+    for heading in headings {
+        insert_heading(&mut roots, heading);
+    }
+
+    roots
+}
+
+/// Append `heading` below the last entry of `siblings` if it is deeper, or
+/// next to it otherwise
+fn insert_heading(siblings: &mut Vec<TocEntry>, heading: TocEntry) {
+    match siblings.last_mut() {
+        Some(last) if last.level < heading.level => insert_heading(&mut last.children, heading),
+        _ => siblings.push(heading),
+    }
+}
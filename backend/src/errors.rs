@@ -53,9 +53,118 @@ pub enum GetPostsError {
     #[error("Invalid Category type.")]
     CategoryError,
 
+    /// Category slug is well formed but not configured (or not listed)
+    #[error("Unknown category `{0}`")]
+    UnknownCategory(String),
+
     /// Requested article was not found in the database
     #[error("Article not found")]
     ArticleNotFound,
+
+    /// Article id is not a valid UUID
+    #[error("Invalid article id `{id}`: {reason}")]
+    InvalidArticleId { id: String, reason: String },
+
+    /// No article belongs to the requested series
+    #[error("Series `{0}` not found")]
+    SeriesNotFound(String),
+
+    /// No author profile has the requested id
+    #[error("Author `{0}` not found")]
+    AuthorNotFound(String),
+
+    /// The requested collection is not declared or has not been pushed yet
+    #[error("Collection `{0}` not found")]
+    CollectionNotFound(String),
+
+    /// Tag is empty after normalization
+    #[error("Invalid tag `{0}`")]
+    InvalidTag(String),
+
+    /// Archive year or month is out of range
+    #[error("Invalid archive period `{year}/{month}`")]
+    InvalidArchivePeriod { year: i32, month: u8 },
+
+    /// Requested language is not a valid language tag
+    #[error("Invalid language `{0}`")]
+    InvalidLanguage(String),
+}
+
+/// Errors related to stored media (images and their variants)
+#[derive(Debug, Error)]
+pub enum MediaError {
+    /// Media hash is not a lowercase hex SHA-256 digest
+    #[error("Invalid media hash `{0}`")]
+    InvalidHash(String),
+
+    /// No media blob is stored under the hash
+    #[error("Media `{0}` not found")]
+    NotFound(String),
+
+    /// Reading or writing the media store failed
+    #[error("Media storage failed: {0}")]
+    Storage(#[from] std::io::Error),
+
+    /// A request to the S3-compatible object storage failed
+    #[error("Object storage request failed: {0}")]
+    ObjectStorage(#[from] s3::error::S3Error),
+
+    /// An image could not be decoded, resized or encoded (webhook feature only)
+    #[cfg(feature = "webhook")]
+    #[error("Image processing failed: {0}")]
+    Processing(#[from] image::ImageError),
+
+    /// Metadata could not be removed from an image (webhook feature only)
+    #[cfg(feature = "webhook")]
+    #[error("Image metadata could not be removed: {0}")]
+    Metadata(#[from] img_parts::Error),
+}
+
+/// Errors reported to Micropub clients
+///
+/// Rendered in the format of the Micropub specification,
+/// `{"error": "invalid_request", "error_description": "..."}`, instead of the
+/// format of the other endpoints.
+#[derive(Debug, Error)]
+pub enum MicropubError {
+    /// No access token was sent
+    #[error("Missing access token")]
+    Unauthorized,
+
+    /// The access token is wrong, Micropub is not configured, or the post
+    /// can't be changed through Micropub
+    #[error("{0}")]
+    Forbidden(String),
+
+    /// The request is malformed or names a post that doesn't exist
+    #[error("{0}")]
+    InvalidRequest(String),
+}
+
+impl MicropubError {
+    /// Render the error with the status and error code defined by the
+    /// Micropub specification
+    fn response(&self) -> axum::response::Response {
+        let (status, error) = match self {
+            MicropubError::Unauthorized => (StatusCode::UNAUTHORIZED, "unauthorized"),
+            MicropubError::Forbidden(_) => (StatusCode::FORBIDDEN, "forbidden"),
+            MicropubError::InvalidRequest(_) => (StatusCode::BAD_REQUEST, "invalid_request"),
+        };
+
+        tracing::warn!(
+            status = status.as_u16(),
+            error_code = error,
+            error = %self,
+            "Micropub client error"
+        );
+
+        let body = Json(json!({
+            "error": error,
+            "error_description": self.to_string(),
+        }));
+
+        (status, body).into_response()
+    }
 }
 
 /// Errors related to GitHub webhook operations
@@ -147,10 +256,18 @@ pub enum SomeError {
     #[error(transparent)]
     GetPosts(#[from] GetPostsError),
 
+    /// Media storage and processing errors
+    #[error(transparent)]
+    Media(#[from] MediaError),
+
     /// GitHub webhook processing errors
     #[error(transparent)]
     WebHooks(#[from] WebHooksError),
 
+    /// Micropub request errors
+    #[error(transparent)]
+    Micropub(#[from] MicropubError),
+
     /// Parsing errors (JSON, YAML, etc.)
     #[error(transparent)]
     Parse(#[from] ParseError),
@@ -259,6 +376,26 @@ impl IntoResponse for SomeError {
                 "Internal server error",
             ),
 
+            SomeError::Media(MediaError::Storage(_)) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INTERNAL_ERROR",
+                "Internal server error",
+            ),
+
+            SomeError::Media(MediaError::ObjectStorage(_)) => (
+                StatusCode::BAD_GATEWAY,
+                "SERVICE_UNAVAILABLE",
+                "Media storage temporarily unavailable",
+            ),
+
+            #[cfg(feature = "webhook")]
+            SomeError::Media(MediaError::Processing(_))
+            | SomeError::Media(MediaError::Metadata(_)) => (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "INTERNAL_ERROR",
+                "Internal server error",
+            ),
+
             SomeError::Database(DBError::DatabaseUrlMissing) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "INTERNAL_ERROR",
@@ -286,12 +423,41 @@ impl IntoResponse for SomeError {
             ),
 
             // 来自客户端的无效请求 (4xx) - 可以提供适度详细的信息
-            SomeError::GetPosts(GetPostsError::CategoryError) => (
+            SomeError::GetPosts(GetPostsError::CategoryError)
+            | SomeError::GetPosts(GetPostsError::UnknownCategory(_)) => (
                 StatusCode::BAD_REQUEST,
                 "INVALID_CATEGORY",
                 "Invalid category parameter",
             ),
 
+            SomeError::GetPosts(GetPostsError::InvalidArticleId { .. }) => (
+                StatusCode::BAD_REQUEST,
+                "INVALID_ARTICLE_ID",
+                "Invalid article id",
+            ),
+
+            SomeError::GetPosts(GetPostsError::InvalidTag(_)) => {
+                (StatusCode::BAD_REQUEST, "INVALID_TAG", "Invalid tag")
+            }
+
+            SomeError::GetPosts(GetPostsError::InvalidArchivePeriod { .. }) => (
+                StatusCode::BAD_REQUEST,
+                "INVALID_ARCHIVE_PERIOD",
+                "Invalid archive period",
+            ),
+
+            SomeError::GetPosts(GetPostsError::InvalidLanguage(_)) => (
+                StatusCode::BAD_REQUEST,
+                "INVALID_LANGUAGE",
+                "Invalid language",
+            ),
+
+            SomeError::Media(MediaError::InvalidHash(_)) => (
+                StatusCode::BAD_REQUEST,
+                "INVALID_MEDIA_HASH",
+                "Invalid media hash",
+            ),
+
             SomeError::Parse(ParseError::JsonParseError(_)) => (
                 StatusCode::BAD_REQUEST,
                 "INVALID_REQUEST",
@@ -323,7 +489,11 @@ impl IntoResponse for SomeError {
                 "Unsupported event type",
             ),
 
-            SomeError::GetPosts(GetPostsError::ArticleNotFound) => {
+            SomeError::GetPosts(GetPostsError::ArticleNotFound)
+            | SomeError::GetPosts(GetPostsError::SeriesNotFound(_))
+            | SomeError::GetPosts(GetPostsError::AuthorNotFound(_))
+            | SomeError::GetPosts(GetPostsError::CollectionNotFound(_))
+            | SomeError::Media(MediaError::NotFound(_)) => {
                 (StatusCode::NOT_FOUND, "NOT_FOUND", "Resource not found")
             }
 
@@ -345,6 +515,9 @@ impl IntoResponse for SomeError {
                 "Invalid encoding",
             ),
 
+            // Micropub clients expect the error format of the specification
+            SomeError::Micropub(error) => return error.response(),
+
             // 所有其他未预料到的内部错误
             SomeError::Other(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -1,6 +1,8 @@
 pub mod db;
 #[cfg(feature = "webhook")]
 pub mod github;
+pub mod markdown;
+pub mod media;
 pub mod search;
 #[cfg(feature = "webhook")]
 pub mod time_utils;
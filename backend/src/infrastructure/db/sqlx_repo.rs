@@ -1,17 +1,52 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use async_trait::async_trait;
-use sqlx::{Acquire, Postgres, Transaction, types::Uuid};
+use sqlx::{
+    Acquire, Postgres, Transaction,
+    types::{Json, Uuid},
+};
+use time::OffsetDateTime;
 
 use crate::{
     domain::{
-        articles::Article,
+        archives::ArchiveMonthCount,
+        articles::{Article, ArticleId, PostCategory},
+        authors::Author,
+        collections::Collection,
+        exif::ExifMetadata,
+        ingestion::{IngestionIssue, IngestionReport, IngestionStatus, dedupe_reports},
+        links::{LinkEdge, LinkTarget},
+        media::{MediaAsset, MediaHash, MediaReference},
+        related::{ContentVersion, RelatedCandidate},
         repositories::{ArticleRepository, TransactionGuard, TransactionOps},
+        toc::TocEntry,
     },
     errors::{GetPostsError, Result},
-    interfaces::http::dtos::PostResponse,
+    interfaces::http::dtos::{
+        AdjacentPost, AuthorSummary, CategoryNavigation, FeaturedPost, PostResponse, SeriesPart,
+        TagResponse, Translation,
+    },
 };
 
+/// Advisory lock key serializing media ingestion with garbage collection
+///
+/// A push holds it from checking its reused blobs until it commits, and
+/// garbage collection or the cleanup of a failed push while deleting blobs,
+/// so no blob is deleted while a push is about to reference it.
+const MEDIA_LOCK_KEY: i64 = 0x6d69_7a75_6d65_6469;
+
+/// Byline of a listed article, decoded from the JSON built by the
+/// `article_authors` SQL function
+#[derive(sqlx::Type)]
+#[sqlx(transparent, no_pg_array)]
+struct AuthorSummaries(Json<Vec<AuthorSummary>>);
+
+impl From<AuthorSummaries> for Vec<AuthorSummary> {
+    fn from(AuthorSummaries(Json(authors)): AuthorSummaries) -> Self {
+        authors
+    }
+}
+
 /// SQLx-based implementation of the ArticleRepository trait
 ///
 /// This struct provides PostgreSQL database access for article operations using
@@ -32,13 +67,31 @@ use crate::{
 ///
 /// ```sql
 /// CREATE TABLE articles (
-///     id TEXT PRIMARY KEY,
+///     id UUID PRIMARY KEY,
 ///     title TEXT NOT NULL,
 ///     tags TEXT[] NOT NULL DEFAULT '{}',
 ///     category TEXT NOT NULL,
 ///     summary TEXT NOT NULL DEFAULT '',
 ///     content TEXT NOT NULL,
 ///     status TEXT NOT NULL DEFAULT 'draft',
+///     series TEXT,
+///     series_order INTEGER,
+///     description TEXT,
+///     cover_image TEXT,
+///     canonical_url TEXT,
+///     language TEXT,
+///     translation_key TEXT,
+///     license TEXT,
+///     author TEXT,
+///     authors TEXT[] NOT NULL DEFAULT '{}',
+///     pinned BOOLEAN NOT NULL DEFAULT FALSE,
+///     pinned_weight INTEGER,
+///     featured BOOLEAN NOT NULL DEFAULT FALSE,
+///     featured_weight INTEGER,
+///     images JSONB NOT NULL DEFAULT '[]',
+///     keep_location BOOLEAN NOT NULL DEFAULT FALSE,
+///     source TEXT NOT NULL DEFAULT 'git' CHECK (source IN ('git', 'micropub')),
+///     extra JSONB NOT NULL DEFAULT '{}',
 ///     created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
 ///     updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
 /// );
@@ -46,7 +99,7 @@ use crate::{
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// use sqlx::PgPool;
 /// use backend::infrastructure::db::sqlx_repo::SqlxArticleRepository;
 ///
@@ -74,7 +127,7 @@ impl SqlxArticleRepository {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// use sqlx::PgPool;
     /// use backend::infrastructure::db::sqlx_repo::SqlxArticleRepository;
     ///
@@ -107,33 +160,41 @@ impl ArticleRepository for SqlxArticleRepository {
     /// # SQL Query
     ///
     /// ```sql
-    /// SELECT id, title, tags, summary
+    /// SELECT id, title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+    ///        article_authors(authors) AS authors
     /// FROM articles
     /// WHERE category = $1
-    /// ORDER BY created_at DESC
-    /// LIMIT $2 OFFSET $3
+    ///   AND ($2 IS NULL OR language IS NULL OR lower(split_part(language, '-', 1)) = $2)
+    /// ORDER BY pinned DESC, pinned_weight ASC NULLS LAST, created_at DESC
+    /// LIMIT $3 OFFSET $4
     /// ```
     ///
     /// # Note
     ///
     /// Returns PostResponse DTOs rather than full Article entities for efficiency,
-    /// as list views typically don't need the complete article content.
+    /// as list views typically don't need the complete article content. The
+    /// `article_authors` SQL function embeds the byline as JSON.
     async fn get_posts_by_category(
         &self,
         category: &str,
+        language: Option<&str>,
         page_size: i64,
         offset: i64,
     ) -> Result<Vec<PostResponse>> {
         let query_results = sqlx::query_as!(
             PostResponse,
             // SQL query: Select required columns from articles table with pagination
-            // Filters by category and orders by creation date (newest first)
-            "SELECT id, title, tags, summary
+            // Filters by category and language; pinned posts first (by weight),
+            // then by creation date (newest first)
+            r#"SELECT id AS "id: ArticleId", title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+                    article_authors(authors) AS "authors!: AuthorSummaries"
              FROM articles
              WHERE category = $1
-             ORDER BY created_at DESC
-             LIMIT $2 OFFSET $3",
+               AND ($2::TEXT IS NULL OR language IS NULL OR lower(split_part(language, '-', 1)) = $2)
+             ORDER BY pinned DESC, pinned_weight ASC NULLS LAST, created_at DESC
+             LIMIT $3 OFFSET $4"#,
             category,
+            language,
             page_size,
             offset
         )
@@ -143,6 +204,355 @@ impl ArticleRepository for SqlxArticleRepository {
         Ok(query_results)
     }
 
+    /// Retrieve a paginated list of articles carrying a tag
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+    ///        article_authors(authors) AS authors
+    /// FROM articles
+    /// WHERE tags @> ARRAY[$1] AND status = 'published'
+    ///   AND ($2 IS NULL OR language IS NULL OR lower(split_part(language, '-', 1)) = $2)
+    /// ORDER BY created_at DESC
+    /// LIMIT $3 OFFSET $4
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// The containment operator `@>` (rather than `= ANY(tags)`) lets
+    /// PostgreSQL use the GIN index on `tags`.
+    async fn get_posts_by_tag(
+        &self,
+        tag: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let query_results = sqlx::query_as!(
+            PostResponse,
+            r#"SELECT id AS "id: ArticleId", title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+                    article_authors(authors) AS "authors!: AuthorSummaries"
+             FROM articles
+             WHERE tags @> ARRAY[$1::TEXT] AND status = 'published'
+               AND ($2::TEXT IS NULL OR language IS NULL OR lower(split_part(language, '-', 1)) = $2)
+             ORDER BY created_at DESC
+             LIMIT $3 OFFSET $4"#,
+            tag,
+            language,
+            page_size,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(query_results)
+    }
+
+    /// Retrieve a paginated list of articles by an author
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+    ///        article_authors(authors) AS authors
+    /// FROM articles
+    /// WHERE authors @> ARRAY[$1] AND status = 'published'
+    ///   AND ($2 IS NULL OR language IS NULL OR lower(split_part(language, '-', 1)) = $2)
+    /// ORDER BY created_at DESC
+    /// LIMIT $3 OFFSET $4
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// Like tag listings, `@>` lets PostgreSQL use the GIN index on `authors`.
+    async fn get_posts_by_author(
+        &self,
+        author: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let query_results = sqlx::query_as!(
+            PostResponse,
+            r#"SELECT id AS "id: ArticleId", title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+                    article_authors(authors) AS "authors!: AuthorSummaries"
+             FROM articles
+             WHERE authors @> ARRAY[$1::TEXT] AND status = 'published'
+               AND ($2::TEXT IS NULL OR language IS NULL OR lower(split_part(language, '-', 1)) = $2)
+             ORDER BY created_at DESC, id DESC
+             LIMIT $3 OFFSET $4"#,
+            author,
+            language,
+            page_size,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(query_results)
+    }
+
+    /// Retrieve every stored author profile
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT * FROM authors ORDER BY id
+    /// ```
+    async fn get_authors(&self) -> Result<Vec<Author>> {
+        let authors = sqlx::query_as::<_, Author>("SELECT * FROM authors ORDER BY id")
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(authors)
+    }
+
+    /// Retrieve the stored profiles of some authors
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT * FROM authors WHERE id = ANY($1)
+    /// ```
+    async fn get_authors_by_ids(&self, ids: &[String]) -> Result<Vec<Author>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let authors = sqlx::query_as::<_, Author>("SELECT * FROM authors WHERE id = ANY($1)")
+            .bind(ids)
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(authors)
+    }
+
+    /// Retrieve a stored data collection by name
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT name, path, data, updated_at
+    /// FROM collections
+    /// WHERE name = $1
+    /// ```
+    async fn get_collection(&self, name: &str) -> Result<Option<Collection>> {
+        let collection = sqlx::query_as!(
+            Collection,
+            "SELECT name, path, data, updated_at
+             FROM collections
+             WHERE name = $1",
+            name
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(collection)
+    }
+
+    /// Retrieve the primary languages of the published articles
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT DISTINCT lower(split_part(language, '-', 1))
+    /// FROM articles
+    /// WHERE language IS NOT NULL AND status = 'published'
+    /// ```
+    async fn get_languages(&self) -> Result<Vec<String>> {
+        let languages = sqlx::query_scalar!(
+            r#"SELECT DISTINCT lower(split_part(language, '-', 1)) AS "language!"
+             FROM articles
+             WHERE language IS NOT NULL AND status = 'published'
+             ORDER BY 1"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(languages)
+    }
+
+    /// Retrieve the published translations of an article
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, title, category, language
+    /// FROM articles
+    /// WHERE translation_key = $1 AND id <> $2 AND status = 'published'
+    /// ORDER BY language ASC NULLS LAST, id ASC
+    /// ```
+    async fn get_translations(&self, key: &str, id: &ArticleId) -> Result<Vec<Translation>> {
+        let translations = sqlx::query_as!(
+            Translation,
+            r#"SELECT id AS "id: ArticleId", title, category AS "category: PostCategory", language
+             FROM articles
+             WHERE translation_key = $1 AND id <> $2 AND status = 'published'
+             ORDER BY language ASC NULLS LAST, id ASC"#,
+            key,
+            id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(translations)
+    }
+
+    /// Retrieve the published featured articles
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, title, category, summary, description, cover_image, tags, featured_weight, created_at
+    /// FROM articles
+    /// WHERE featured AND status = 'published'
+    /// ORDER BY featured_weight ASC NULLS LAST, created_at DESC
+    /// LIMIT $1
+    /// ```
+    async fn get_featured(&self, limit: i64) -> Result<Vec<FeaturedPost>> {
+        let featured = sqlx::query_as!(
+            FeaturedPost,
+            r#"SELECT id AS "id: ArticleId", title, category AS "category: PostCategory", summary,
+                    description, cover_image, tags, featured_weight, created_at
+             FROM articles
+             WHERE featured AND status = 'published'
+             ORDER BY featured_weight ASC NULLS LAST, created_at DESC, id ASC
+             LIMIT $1"#,
+            limit
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(featured)
+    }
+
+    /// Retrieve every tag together with its article count
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT tag AS name, COUNT(*) AS count
+    /// FROM articles, unnest(tags) AS tag
+    /// WHERE status = 'published'
+    /// GROUP BY tag
+    /// ORDER BY count DESC, name
+    /// ```
+    async fn get_tags(&self) -> Result<Vec<TagResponse>> {
+        let tags = sqlx::query_as!(
+            TagResponse,
+            r#"SELECT tag AS "name!", COUNT(*) AS "count!"
+             FROM articles, unnest(tags) AS tag
+             WHERE status = 'published'
+             GROUP BY tag
+             ORDER BY 2 DESC, 1"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tags)
+    }
+
+    /// Retrieve the published parts of a series in reading order
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, title, category, series_order
+    /// FROM articles
+    /// WHERE series = $1 AND status = 'published'
+    /// ORDER BY series_order ASC NULLS LAST, created_at ASC, id ASC
+    /// ```
+    async fn get_series_parts(&self, series: &str) -> Result<Vec<SeriesPart>> {
+        let parts = sqlx::query_as!(
+            SeriesPart,
+            r#"SELECT id AS "id: ArticleId", title, category AS "category: PostCategory", series_order
+             FROM articles
+             WHERE series = $1 AND status = 'published'
+             ORDER BY series_order ASC NULLS LAST, created_at ASC, id ASC"#,
+            series
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(parts)
+    }
+
+    /// Count published posts per month
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT EXTRACT(YEAR FROM created_at AT TIME ZONE 'UTC') AS year,
+    ///        EXTRACT(MONTH FROM created_at AT TIME ZONE 'UTC') AS month,
+    ///        COUNT(*) AS count
+    /// FROM articles
+    /// WHERE status = 'published' AND ($1 IS NULL OR category = $1)
+    /// GROUP BY year, month
+    /// ORDER BY year DESC, month DESC
+    /// ```
+    async fn get_archive_counts(&self, category: Option<&str>) -> Result<Vec<ArchiveMonthCount>> {
+        let counts = sqlx::query_as!(
+            ArchiveMonthCount,
+            r#"SELECT EXTRACT(YEAR FROM created_at AT TIME ZONE 'UTC')::INTEGER AS "year!",
+                    EXTRACT(MONTH FROM created_at AT TIME ZONE 'UTC')::INTEGER AS "month!",
+                    COUNT(*) AS "count!"
+             FROM articles
+             WHERE status = 'published' AND ($1::TEXT IS NULL OR category = $1)
+             GROUP BY 1, 2
+             ORDER BY 1 DESC, 2 DESC"#,
+            category
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(counts)
+    }
+
+    /// Retrieve a paginated list of published posts created in a time range
+    ///
+    /// The half-open range lets the lookup use `idx_articles_created_at`.
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+    ///        article_authors(authors) AS authors
+    /// FROM articles
+    /// WHERE created_at >= $1 AND created_at < $2
+    ///   AND status = 'published' AND ($3 IS NULL OR category = $3)
+    /// ORDER BY created_at DESC
+    /// LIMIT $4 OFFSET $5
+    /// ```
+    async fn get_posts_by_period(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        category: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let posts = sqlx::query_as!(
+            PostResponse,
+            r#"SELECT id AS "id: ArticleId", title, tags, summary, summary_generated, word_count, reading_minutes, pinned,
+                    article_authors(authors) AS "authors!: AuthorSummaries"
+             FROM articles
+             WHERE created_at >= $1 AND created_at < $2
+               AND status = 'published' AND ($3::TEXT IS NULL OR category = $3)
+             ORDER BY created_at DESC, id DESC
+             LIMIT $4 OFFSET $5"#,
+            start,
+            end,
+            category,
+            page_size,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(posts)
+    }
+
     /// Retrieve a single article by its unique identifier
     ///
     /// Fetches the complete article entity from the database. Unlike
@@ -152,7 +562,7 @@ impl ArticleRepository for SqlxArticleRepository {
     ///
     /// # Arguments
     ///
-    /// * `id` - The unique identifier of the article
+    /// * `id` - The validated unique identifier of the article
     ///
     /// # Returns
     ///
@@ -165,12 +575,12 @@ impl ArticleRepository for SqlxArticleRepository {
     /// ```sql
     /// SELECT * FROM articles WHERE id = $1
     /// ```
-    async fn get_post_by_id(&self, id: &str) -> Result<Article> {
+    async fn get_post_by_id(&self, id: &ArticleId) -> Result<Article> {
         let result = sqlx::query_as::<_, Article>("SELECT * FROM articles WHERE id = $1")
-            .bind(&id)
+            .bind(id)
             .fetch_optional(&self.pool)
             .await?
-            .ok_or_else(|| GetPostsError::ArticleNotFound)?;
+            .ok_or(GetPostsError::ArticleNotFound)?;
 
         Ok(result)
     }
@@ -200,7 +610,7 @@ impl ArticleRepository for SqlxArticleRepository {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// // Rebuild search index with all articles
     /// let all_articles = repo.get_all().await?;
     /// search_service.index_documents(&all_articles).await?;
@@ -213,12 +623,371 @@ impl ArticleRepository for SqlxArticleRepository {
         Ok(db_items)
     }
 
-    async fn get_by_paths(&self, paths: &[String]) -> Result<HashSet<String>> {
-        let results = sqlx::query!("SELECT id FROM articles WHERE path = ANY($1)", paths)
-            .fetch_all(&self.pool)
-            .await?;
+    /// Retrieve only the stored table of contents of an article
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(Vec<TocEntry>))` - The stored heading tree
+    /// * `Ok(None)` - The `toc` column is NULL (article predates TOC extraction)
+    /// * `Err(GetPostsError::ArticleNotFound)` - Article doesn't exist
+    /// * `Err(SomeError)` - Database query failed
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT toc FROM articles WHERE id = $1
+    /// ```
+    async fn get_toc(&self, id: &ArticleId) -> Result<Option<Vec<TocEntry>>> {
+        let row = sqlx::query!(
+            r#"SELECT toc AS "toc: Json<Vec<TocEntry>>" FROM articles WHERE id = $1"#,
+            id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?
+        .ok_or(GetPostsError::ArticleNotFound)?;
+
+        Ok(row.toc.map(|toc| toc.0))
+    }
+
+    /// Retrieve the metadata of a stored media blob
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT hash, content_type, byte_size, width, height, blurhash, original_hash, exif
+    /// FROM media_assets
+    /// WHERE hash = $1
+    /// ```
+    async fn get_media_asset(&self, hash: &MediaHash) -> Result<Option<MediaAsset>> {
+        let asset = sqlx::query_as!(
+            MediaAsset,
+            r#"SELECT hash AS "hash: MediaHash", content_type, byte_size, width, height, blurhash,
+                    original_hash AS "original: MediaHash", exif AS "exif: ExifMetadata"
+             FROM media_assets
+             WHERE hash = $1"#,
+            hash.as_str()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(asset)
+    }
+
+    /// Retrieve the resized variants of an original image
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT hash, content_type, byte_size, width, height, blurhash, original_hash, exif
+    /// FROM media_assets
+    /// WHERE original_hash = $1
+    /// ORDER BY width ASC, content_type ASC
+    /// ```
+    async fn get_media_variants(&self, original: &MediaHash) -> Result<Vec<MediaAsset>> {
+        let variants = sqlx::query_as!(
+            MediaAsset,
+            r#"SELECT hash AS "hash: MediaHash", content_type, byte_size, width, height, blurhash,
+                    original_hash AS "original: MediaHash", exif AS "exif: ExifMetadata"
+             FROM media_assets
+             WHERE original_hash = $1
+             ORDER BY width ASC, content_type ASC"#,
+            original.as_str()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(variants)
+    }
+
+    /// Retrieve the published posts just before and after a position in a
+    /// category
+    ///
+    /// Both lookups walk `idx_articles_category_created_at` from the current
+    /// position and stop at the first row.
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// -- previous (older)
+    /// SELECT id, title, category, created_at
+    /// FROM articles
+    /// WHERE category = $1 AND status = 'published' AND (created_at, id) < ($2, $3)
+    /// ORDER BY created_at DESC, id DESC
+    /// LIMIT 1
+    ///
+    /// -- next (newer): `>` and ascending order
+    /// ```
+    async fn get_category_neighbours(
+        &self,
+        category: &PostCategory,
+        created_at: OffsetDateTime,
+        id: &ArticleId,
+    ) -> Result<CategoryNavigation> {
+        let previous = sqlx::query_as!(
+            AdjacentPost,
+            r#"SELECT id AS "id: ArticleId", title, category AS "category: PostCategory", created_at
+             FROM articles
+             WHERE category = $1 AND status = 'published' AND (created_at, id) < ($2, $3)
+             ORDER BY created_at DESC, id DESC
+             LIMIT 1"#,
+            category.as_str(),
+            created_at,
+            id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let next = sqlx::query_as!(
+            AdjacentPost,
+            r#"SELECT id AS "id: ArticleId", title, category AS "category: PostCategory", created_at
+             FROM articles
+             WHERE category = $1 AND status = 'published' AND (created_at, id) > ($2, $3)
+             ORDER BY created_at ASC, id ASC
+             LIMIT 1"#,
+            category.as_str(),
+            created_at,
+            id.as_uuid()
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(CategoryNavigation { previous, next })
+    }
 
-        let id_set: HashSet<String> = results.into_iter().map(|row| row.id.to_string()).collect();
+    /// Retrieve every article as a link target
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, path, title, category FROM articles ORDER BY title, id
+    /// ```
+    async fn get_link_targets(&self) -> Result<Vec<LinkTarget>> {
+        let targets = sqlx::query_as!(
+            LinkTarget,
+            r#"SELECT id AS "id: ArticleId", path, title, category AS "category: PostCategory"
+             FROM articles
+             ORDER BY title ASC, id ASC"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(targets)
+    }
+
+    /// Retrieve every published article as a link target
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, path, title, category FROM articles
+    /// WHERE status = 'published'
+    /// ORDER BY title, id
+    /// ```
+    async fn get_published_link_targets(&self) -> Result<Vec<LinkTarget>> {
+        let targets = sqlx::query_as!(
+            LinkTarget,
+            r#"SELECT id AS "id: ArticleId", path, title, category AS "category: PostCategory"
+             FROM articles
+             WHERE status = 'published'
+             ORDER BY title ASC, id ASC"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(targets)
+    }
+
+    /// Retrieve the articles linking to an article
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT a.id, a.path, a.title, a.category
+    /// FROM article_links l
+    /// JOIN articles a ON a.id = l.source_id
+    /// WHERE l.target_id = $1 AND a.status = 'published'
+    /// ORDER BY a.created_at DESC
+    /// ```
+    async fn get_backlinks(&self, id: &ArticleId) -> Result<Vec<LinkTarget>> {
+        let exists = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM articles WHERE id = $1) AS "exists!""#,
+            id.as_uuid()
+        )
+        .fetch_one(&self.pool)
+        .await?;
+        if !exists {
+            return Err(GetPostsError::ArticleNotFound.into());
+        }
+
+        let backlinks = sqlx::query_as!(
+            LinkTarget,
+            r#"SELECT a.id AS "id: ArticleId", a.path, a.title, a.category AS "category: PostCategory"
+             FROM article_links l
+             JOIN articles a ON a.id = l.source_id
+             WHERE l.target_id = $1 AND a.status = 'published'
+             ORDER BY a.created_at DESC, a.id ASC"#,
+            id.as_uuid()
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(backlinks)
+    }
+
+    /// Retrieve every link between published articles
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT l.source_id, l.target_id
+    /// FROM article_links l
+    /// JOIN articles s ON s.id = l.source_id AND s.status = 'published'
+    /// JOIN articles t ON t.id = l.target_id AND t.status = 'published'
+    /// ```
+    async fn get_link_edges(&self) -> Result<Vec<LinkEdge>> {
+        let edges = sqlx::query_as!(
+            LinkEdge,
+            r#"SELECT l.source_id AS "source: ArticleId", l.target_id AS "target: ArticleId"
+             FROM article_links l
+             JOIN articles s ON s.id = l.source_id AND s.status = 'published'
+             JOIN articles t ON t.id = l.target_id AND t.status = 'published'
+             ORDER BY l.source_id, l.target_id"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(edges)
+    }
+
+    /// Retrieve the fields used to recommend related posts
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, title, category, tags, series, summary, created_at
+    /// FROM articles
+    /// WHERE status = 'published'
+    /// ```
+    async fn get_related_candidates(&self) -> Result<Vec<RelatedCandidate>> {
+        let candidates = sqlx::query_as!(
+            RelatedCandidate,
+            r#"SELECT id AS "id: ArticleId", title, category AS "category: PostCategory", tags, series, summary, created_at
+             FROM articles
+             WHERE status = 'published'"#
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(candidates)
+    }
+
+    /// Retrieve the fingerprint of the published articles
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT COUNT(*), MAX(updated_at) FROM articles WHERE status = 'published'
+    /// ```
+    async fn get_content_version(&self) -> Result<ContentVersion> {
+        let version = sqlx::query_as!(
+            ContentVersion,
+            r#"SELECT COUNT(*) AS "count!", MAX(updated_at) AS last_updated
+             FROM articles
+             WHERE status = 'published'"#
+        )
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(version)
+    }
+
+    /// Retrieve a page of ingestion reports
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT path, article_id, status, issues, ingested_at
+    /// FROM ingestion_reports
+    /// WHERE ($1 IS NULL OR status = $1)
+    /// ORDER BY ingested_at DESC, path ASC
+    /// LIMIT $2 OFFSET $3
+    /// ```
+    async fn get_reports(
+        &self,
+        status: Option<IngestionStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<IngestionReport>> {
+        let rows = sqlx::query!(
+            r#"SELECT path, article_id AS "article_id: ArticleId", status,
+                    issues AS "issues: Json<Vec<IngestionIssue>>", ingested_at
+             FROM ingestion_reports
+             WHERE ($1::TEXT IS NULL OR status = $1)
+             ORDER BY ingested_at DESC, path ASC
+             LIMIT $2 OFFSET $3"#,
+            status.as_ref().map(IngestionStatus::as_str),
+            limit,
+            offset
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let reports = rows
+            .into_iter()
+            .map(|row| {
+                let status = row
+                    .status
+                    .parse()
+                    .map_err(|e: String| sqlx::Error::Decode(e.into()))?;
+
+                Ok(IngestionReport {
+                    path: row.path,
+                    article_id: row.article_id,
+                    status,
+                    issues: row.issues.0,
+                    ingested_at: row.ingested_at,
+                })
+            })
+            .collect::<std::result::Result<_, sqlx::Error>>()?;
+
+        Ok(reports)
+    }
+
+    /// Retrieve the stored bylines of some articles
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT id, authors FROM articles WHERE id = ANY($1)
+    /// ```
+    async fn get_article_authors(
+        &self,
+        ids: &[ArticleId],
+    ) -> Result<HashMap<ArticleId, Vec<String>>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let ids: Vec<Uuid> = ids.iter().map(ArticleId::as_uuid).collect();
+        let rows = sqlx::query!(
+            r#"SELECT id AS "id: ArticleId", authors FROM articles WHERE id = ANY($1)"#,
+            &ids
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|row| (row.id, row.authors)).collect())
+    }
+
+    async fn get_by_paths(&self, paths: &[String]) -> Result<HashSet<ArticleId>> {
+        let results = sqlx::query!(
+            r#"SELECT id AS "id: ArticleId" FROM articles WHERE path = ANY($1)"#,
+            paths
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let id_set: HashSet<ArticleId> = results.into_iter().map(|row| row.id).collect();
 
         Ok(id_set)
     }
@@ -244,17 +1013,41 @@ impl TransactionOps for SqlxTransaction {
         }
 
         let mut query = sqlx::QueryBuilder::new(
-            "INSERT INTO articles (id, path, title, tags, category, summary, content, status, created_at, updated_at) ",
+            "INSERT INTO articles (id, path, title, tags, category, summary, summary_generated, content, content_html, toc, word_count, reading_minutes, status, series, series_order, \
+             description, cover_image, canonical_url, language, translation_key, license, author, authors, pinned, pinned_weight, featured, featured_weight, images, keep_location, source, extra, created_at, updated_at) ",
         );
         query.push_values(articles, |mut b, article| {
-            b.push_bind(&article.id);
+            b.push_bind(article.id);
             b.push_bind(&article.path);
             b.push_bind(&article.title);
             b.push_bind(&article.tags);
             b.push_bind(&article.category);
             b.push_bind(&article.summary);
+            b.push_bind(article.summary_generated);
             b.push_bind(&article.content);
+            b.push_bind(&article.content_html);
+            b.push_bind(article.toc.as_ref().map(Json));
+            b.push_bind(article.word_count);
+            b.push_bind(article.reading_minutes);
             b.push_bind(&article.status);
+            b.push_bind(&article.series);
+            b.push_bind(article.series_order);
+            b.push_bind(&article.description);
+            b.push_bind(&article.cover_image);
+            b.push_bind(&article.canonical_url);
+            b.push_bind(&article.language);
+            b.push_bind(&article.translation_key);
+            b.push_bind(&article.license);
+            b.push_bind(&article.author);
+            b.push_bind(&article.authors);
+            b.push_bind(article.pinned);
+            b.push_bind(article.pinned_weight);
+            b.push_bind(article.featured);
+            b.push_bind(article.featured_weight);
+            b.push_bind(Json(&article.images));
+            b.push_bind(article.keep_location);
+            b.push_bind(article.source);
+            b.push_bind(Json(&article.extra));
             b.push_bind(article.created_at);
             b.push_bind(article.updated_at);
         });
@@ -265,8 +1058,31 @@ impl TransactionOps for SqlxTransaction {
                     tags = EXCLUDED.tags, \
                     category = EXCLUDED.category, \
                     summary = EXCLUDED.summary, \
+                    summary_generated = EXCLUDED.summary_generated, \
                     content = EXCLUDED.content, \
+                    content_html = EXCLUDED.content_html, \
+                    toc = EXCLUDED.toc, \
+                    word_count = EXCLUDED.word_count, \
+                    reading_minutes = EXCLUDED.reading_minutes, \
                     status = EXCLUDED.status, \
+                    series = EXCLUDED.series, \
+                    series_order = EXCLUDED.series_order, \
+                    description = EXCLUDED.description, \
+                    cover_image = EXCLUDED.cover_image, \
+                    canonical_url = EXCLUDED.canonical_url, \
+                    language = EXCLUDED.language, \
+                    translation_key = EXCLUDED.translation_key, \
+                    license = EXCLUDED.license, \
+                    author = EXCLUDED.author, \
+                    authors = EXCLUDED.authors, \
+                    pinned = EXCLUDED.pinned, \
+                    pinned_weight = EXCLUDED.pinned_weight, \
+                    featured = EXCLUDED.featured, \
+                    featured_weight = EXCLUDED.featured_weight, \
+                    images = EXCLUDED.images, \
+                    keep_location = EXCLUDED.keep_location, \
+                    source = EXCLUDED.source, \
+                    extra = EXCLUDED.extra, \
                     updated_at = EXCLUDED.updated_at",
         );
         query.build().execute(self.tx.acquire().await?).await?;
@@ -274,15 +1090,14 @@ impl TransactionOps for SqlxTransaction {
         Ok(())
     }
 
-    async fn delete_batch(&mut self, id: &HashSet<String>) -> Result<()> {
-        if id.is_empty() {
+    async fn delete_batch(&mut self, ids: &HashSet<ArticleId>) -> Result<()> {
+        if ids.is_empty() {
             return Ok(());
         }
 
-        let del_id: Vec<Uuid> = id
-            .into_iter()
-            .filter_map(|p| Uuid::parse_str(&p).ok())
-            .collect();
+        // Ids are validated when they enter the system, so every one of them
+        // maps to a UUID and nothing is silently dropped here
+        let del_id: Vec<Uuid> = ids.iter().map(ArticleId::as_uuid).collect();
 
         sqlx::query!("DELETE FROM articles WHERE id = ANY($1)", &del_id)
             .execute(self.tx.acquire().await?)
@@ -291,6 +1106,248 @@ impl TransactionOps for SqlxTransaction {
         Ok(())
     }
 
+    async fn upsert_reports(&mut self, reports: &[IngestionReport]) -> Result<()> {
+        if reports.is_empty() {
+            return Ok(());
+        }
+
+        // A single INSERT ... ON CONFLICT can't touch the same path twice
+        let reports = dedupe_reports(reports);
+
+        let mut query = sqlx::QueryBuilder::new(
+            "INSERT INTO ingestion_reports (path, article_id, status, issues, ingested_at) ",
+        );
+        query.push_values(&reports, |mut b, report| {
+            b.push_bind(&report.path);
+            b.push_bind(report.article_id);
+            b.push_bind(report.status.as_str());
+            b.push_bind(Json(&report.issues));
+            b.push_bind(report.ingested_at);
+        });
+        query.push(
+            " ON CONFLICT (path) DO UPDATE SET \
+                    article_id = EXCLUDED.article_id, \
+                    status = EXCLUDED.status, \
+                    issues = EXCLUDED.issues, \
+                    ingested_at = EXCLUDED.ingested_at",
+        );
+        query.build().execute(self.tx.acquire().await?).await?;
+
+        Ok(())
+    }
+
+    async fn delete_reports(&mut self, paths: &[String]) -> Result<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query!("DELETE FROM ingestion_reports WHERE path = ANY($1)", paths)
+            .execute(self.tx.acquire().await?)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn replace_links(&mut self, sources: &[ArticleId], edges: &[LinkEdge]) -> Result<()> {
+        if sources.is_empty() {
+            return Ok(());
+        }
+
+        let source_ids: Vec<Uuid> = sources.iter().map(ArticleId::as_uuid).collect();
+        sqlx::query!(
+            "DELETE FROM article_links WHERE source_id = ANY($1)",
+            &source_ids
+        )
+        .execute(self.tx.acquire().await?)
+        .await?;
+
+        if edges.is_empty() {
+            return Ok(());
+        }
+
+        let mut query =
+            sqlx::QueryBuilder::new("INSERT INTO article_links (source_id, target_id) ");
+        query.push_values(edges, |mut b, edge| {
+            b.push_bind(edge.source);
+            b.push_bind(edge.target);
+        });
+        query.push(" ON CONFLICT DO NOTHING");
+        query.build().execute(self.tx.acquire().await?).await?;
+
+        Ok(())
+    }
+
+    async fn insert_media(&mut self, assets: &[MediaAsset]) -> Result<()> {
+        if assets.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = sqlx::QueryBuilder::new(
+            "INSERT INTO media_assets (hash, content_type, byte_size, width, height, blurhash, original_hash, exif) ",
+        );
+        query.push_values(assets, |mut b, asset| {
+            b.push_bind(&asset.hash);
+            b.push_bind(&asset.content_type);
+            b.push_bind(asset.byte_size);
+            b.push_bind(asset.width);
+            b.push_bind(asset.height);
+            b.push_bind(&asset.blurhash);
+            b.push_bind(&asset.original);
+            b.push_bind(asset.exif.as_ref().map(Json));
+        });
+        // Identical content always hashes the same, so a known blob is unchanged
+        query.push(" ON CONFLICT (hash) DO NOTHING");
+        query.build().execute(self.tx.acquire().await?).await?;
+
+        Ok(())
+    }
+
+    /// Take the media advisory lock, released when the transaction ends
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// SELECT pg_advisory_xact_lock($1)
+    /// ```
+    async fn lock_media(&mut self) -> Result<()> {
+        sqlx::query("SELECT pg_advisory_xact_lock($1)")
+            .bind(MEDIA_LOCK_KEY)
+            .execute(self.tx.acquire().await?)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete the media no article references any more
+    ///
+    /// Variants are listed explicitly so their hashes are returned too; the
+    /// `ON DELETE CASCADE` on `original_hash` would remove them silently.
+    ///
+    /// # SQL Query
+    ///
+    /// ```sql
+    /// WITH orphans AS (
+    ///     SELECT hash FROM media_assets m
+    ///     WHERE original_hash IS NULL
+    ///       AND NOT EXISTS (SELECT 1 FROM article_media r WHERE r.hash = m.hash)
+    /// )
+    /// DELETE FROM media_assets
+    /// WHERE hash IN (SELECT hash FROM orphans)
+    ///    OR original_hash IN (SELECT hash FROM orphans)
+    /// RETURNING hash
+    /// ```
+    async fn delete_unreferenced_media(&mut self) -> Result<Vec<MediaHash>> {
+        let hashes = sqlx::query_scalar!(
+            r#"WITH orphans AS (
+                 SELECT hash FROM media_assets m
+                 WHERE original_hash IS NULL
+                   AND NOT EXISTS (SELECT 1 FROM article_media r WHERE r.hash = m.hash)
+             )
+             DELETE FROM media_assets
+             WHERE hash IN (SELECT hash FROM orphans)
+                OR original_hash IN (SELECT hash FROM orphans)
+             RETURNING hash AS "hash: MediaHash""#
+        )
+        .fetch_all(self.tx.acquire().await?)
+        .await?;
+
+        Ok(hashes)
+    }
+
+    async fn replace_media_references(
+        &mut self,
+        sources: &[ArticleId],
+        references: &[MediaReference],
+    ) -> Result<()> {
+        if sources.is_empty() {
+            return Ok(());
+        }
+
+        let source_ids: Vec<Uuid> = sources.iter().map(ArticleId::as_uuid).collect();
+        sqlx::query!(
+            "DELETE FROM article_media WHERE article_id = ANY($1)",
+            &source_ids
+        )
+        .execute(self.tx.acquire().await?)
+        .await?;
+
+        if references.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = sqlx::QueryBuilder::new("INSERT INTO article_media (article_id, hash) ");
+        query.push_values(references, |mut b, reference| {
+            b.push_bind(reference.article);
+            b.push_bind(&reference.hash);
+        });
+        query.push(" ON CONFLICT DO NOTHING");
+        query.build().execute(self.tx.acquire().await?).await?;
+
+        Ok(())
+    }
+
+    async fn replace_authors(&mut self, authors: &[Author]) -> Result<()> {
+        sqlx::query!("DELETE FROM authors")
+            .execute(self.tx.acquire().await?)
+            .await?;
+
+        if authors.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = sqlx::QueryBuilder::new(
+            "INSERT INTO authors (id, name, avatar, bio, links, github, emails) ",
+        );
+        query.push_values(authors, |mut b, author| {
+            b.push_bind(&author.id);
+            b.push_bind(&author.name);
+            b.push_bind(&author.avatar);
+            b.push_bind(&author.bio);
+            b.push_bind(Json(&author.links));
+            b.push_bind(&author.github);
+            b.push_bind(&author.emails);
+        });
+        query.build().execute(self.tx.acquire().await?).await?;
+
+        Ok(())
+    }
+
+    async fn upsert_collections(&mut self, collections: &[Collection]) -> Result<()> {
+        if collections.is_empty() {
+            return Ok(());
+        }
+
+        let mut query =
+            sqlx::QueryBuilder::new("INSERT INTO collections (name, path, data, updated_at) ");
+        query.push_values(collections, |mut b, collection| {
+            b.push_bind(&collection.name);
+            b.push_bind(&collection.path);
+            b.push_bind(&collection.data);
+            b.push_bind(collection.updated_at);
+        });
+        query.push(
+            " ON CONFLICT (name) DO UPDATE SET \
+                    path = EXCLUDED.path, \
+                    data = EXCLUDED.data, \
+                    updated_at = EXCLUDED.updated_at",
+        );
+        query.build().execute(self.tx.acquire().await?).await?;
+
+        Ok(())
+    }
+
+    async fn delete_collections(&mut self, paths: &[String]) -> Result<()> {
+        if paths.is_empty() {
+            return Ok(());
+        }
+
+        sqlx::query!("DELETE FROM collections WHERE path = ANY($1)", paths)
+            .execute(self.tx.acquire().await?)
+            .await?;
+
+        Ok(())
+    }
+
     async fn commit(self: Box<Self>) -> Result<()> {
         self.tx.commit().await?;
 
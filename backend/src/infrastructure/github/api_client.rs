@@ -2,6 +2,7 @@ use async_trait::async_trait;
 use base64::{Engine, prelude::BASE64_STANDARD};
 use chrono::{DateTime, Utc};
 use futures::{StreamExt, stream};
+use http_body_util::BodyExt;
 
 use crate::{
     errors::Result,
@@ -30,11 +31,13 @@ use crate::{
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// use backend::infrastructure::github::api_client::GithubApiClient;
 ///
 /// let client = GithubApiClient::new("ghp_your_token_here")?;
-/// let content = client.get_file_content("owner", "repo", "path/to/file.md").await?;
+/// let content = client
+///     .get_file_content("owner", "repo", "main", "path/to/file.md")
+///     .await?;
 /// ```
 pub struct GithubApiClient {
     /// Octocrab client instance configured with authentication
@@ -74,7 +77,7 @@ impl GithubApiClient {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// use backend::infrastructure::github::api_client::GithubApiClient;
     ///
     /// // Load token from environment
@@ -108,6 +111,7 @@ impl GithubClient for GithubApiClient {
     ///
     /// * `owner` - Repository owner (username or organization)
     /// * `repo` - Repository name
+    /// * `commit` - SHA of the commit to read the file at
     /// * `path` - Path to the file within the repository
     ///
     /// # Returns
@@ -127,14 +131,15 @@ impl GithubClient for GithubApiClient {
     ///
     /// # API Endpoint
     ///
-    /// Uses: `GET /repos/{owner}/{repo}/contents/{path}`
+    /// Uses: `GET /repos/{owner}/{repo}/contents/{path}?ref={commit}`
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let content = client.get_file_content(
     ///     "octocat",
     ///     "Hello-World",
+    ///     "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d",
     ///     "README.md"
     /// ).await?;
     /// ```
@@ -147,7 +152,13 @@ impl GithubClient for GithubApiClient {
     /// 4. Decodes base64 to bytes
     /// 5. Converts bytes to UTF-8 string
     /// 6. Returns the decoded content or an error
-    async fn get_file_content(&self, owner: &str, repo: &str, path: &str) -> Result<String> {
+    async fn get_file_content(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        path: &str,
+    ) -> Result<String> {
         // Fetch file content from GitHub API
         // This returns a response that may contain multiple items if path is a directory
         let response = self
@@ -155,6 +166,7 @@ impl GithubClient for GithubApiClient {
             .repos(owner, repo)
             .get_content()
             .path(path)
+            .r#ref(commit)
             .send()
             .await?;
 
@@ -178,10 +190,77 @@ impl GithubClient for GithubApiClient {
         Err(anyhow::anyhow!("No content found for file: {}", path).into())
     }
 
+    /// Check for a file with the GitHub Contents API
+    ///
+    /// Uses: `GET /repos/{owner}/{repo}/contents/{path}?ref={commit}`. A file
+    /// is listed as a single item of type `file`, while a directory lists its
+    /// entries. A 404 response means the path doesn't exist; any other error
+    /// is returned as is.
+    async fn file_exists(&self, owner: &str, repo: &str, commit: &str, path: &str) -> Result<bool> {
+        let response = self
+            .client
+            .repos(owner, repo)
+            .get_content()
+            .path(path)
+            .r#ref(commit)
+            .send()
+            .await;
+
+        match response {
+            Ok(content) => Ok(matches!(content.items.as_slice(), [item] if item.r#type == "file")),
+            Err(octocrab::Error::GitHub { source, .. }) if source.status_code.as_u16() == 404 => {
+                Ok(false)
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Download a file in raw format as of a commit
+    ///
+    /// Uses: `GET /repos/{owner}/{repo}/contents/{path}?ref={commit}` with the
+    /// `application/vnd.github.v3.raw` media type, which returns the bytes
+    /// directly instead of base64 and works for files up to 100 MB. The body
+    /// is read frame by frame and the download is abandoned as soon as it
+    /// exceeds `max_bytes`.
+    async fn get_file_bytes(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        path: &str,
+        max_bytes: usize,
+    ) -> Result<Vec<u8>> {
+        let response = self
+            .client
+            .repos(owner, repo)
+            .raw_file(commit.to_string(), path)
+            .await?;
+        let response = octocrab::map_github_error(response).await?;
+
+        let mut body = response.into_body();
+        let mut bytes = Vec::new();
+        while let Some(frame) = body.frame().await {
+            if let Some(data) = frame?.data_ref() {
+                if bytes.len() + data.len() > max_bytes {
+                    return Err(anyhow::anyhow!(
+                        "{} exceeds the limit of {} bytes",
+                        path,
+                        max_bytes
+                    )
+                    .into());
+                }
+                bytes.extend_from_slice(data);
+            }
+        }
+
+        Ok(bytes)
+    }
+
     async fn fetch_files(
         &self,
         owner: &str,
         repo: &str,
+        commit: &str,
         changes: &[FileChange],
     ) -> Vec<(DateTime<Utc>, Result<String>, String)> {
         const MAX_CONCURRENT: usize = 5;
@@ -193,7 +272,7 @@ impl GithubClient for GithubApiClient {
 
         let contents: Vec<(DateTime<Utc>, Result<String>, String)> = stream::iter(tasks)
             .map(|(file_path, ts)| async move {
-                let content = self.get_file_content(owner, repo, &file_path).await;
+                let content = self.get_file_content(owner, repo, commit, &file_path).await;
                 (ts, content, file_path)
             })
             .buffer_unordered(MAX_CONCURRENT)
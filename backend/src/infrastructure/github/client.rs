@@ -40,7 +40,7 @@ use crate::{errors::Result, infrastructure::github::webhook::FileChange};
 ///
 /// # Example Implementation
 ///
-/// ```rust
+/// ```rust,ignore
 /// use async_trait::async_trait;
 /// use backend::infrastructure::github::client::GithubClient;
 /// use backend::errors::Result;
@@ -55,6 +55,7 @@ use crate::{errors::Result, infrastructure::github::webhook::FileChange};
 ///         &self,
 ///         owner: &str,
 ///         repo: &str,
+///         commit: &str,
 ///         path: &str
 ///     ) -> Result<String> {
 ///         // Implementation using octocrab, reqwest, etc.
@@ -68,17 +69,19 @@ pub trait GithubClient: Send + Sync {
     ///
     /// This method retrieves the raw content of a file from a specified GitHub
     /// repository. It's primarily used when processing webhook events to fetch
-    /// the latest version of markdown files containing article content.
+    /// the pushed version of markdown files containing article content.
     ///
     /// # Arguments
     ///
     /// * `owner` - The username or organization that owns the repository
     /// * `repo` - The repository name (without the owner prefix)
+    /// * `commit` - SHA of the commit to read the file at, so every file of a
+    ///   push is read as of the same commit
     /// * `path` - The file path within the repository (e.g., "posts/my-article.md")
     ///
     /// # Returns
     ///
-    /// * `Ok(String)` - The decoded file content as a UTF-8 string
+    /// * `Ok(String)` - The decoded file content as a UTF-8 string, as of `commit`
     /// * `Err(SomeError)` - Error if:
     ///   - File doesn't exist in the repository
     ///   - Repository is not accessible (private without proper auth)
@@ -90,7 +93,7 @@ pub trait GithubClient: Send + Sync {
     ///
     /// This method typically uses the GitHub Contents API:
     /// ```text
-    /// GET /repos/{owner}/{repo}/contents/{path}
+    /// GET /repos/{owner}/{repo}/contents/{path}?ref={commit}
     /// ```
     ///
     /// The API returns base64-encoded content which implementations should
@@ -98,13 +101,14 @@ pub trait GithubClient: Send + Sync {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let client = GithubApiClient::new("ghp_token")?;
     ///
     /// // Fetch a markdown file from the repository
     /// let content = client.get_file_content(
     ///     "octocat",
     ///     "Hello-World",
+    ///     "7fd1a60b01f91b314f59955a4e4d4e80d8edf11d",
     ///     "posts/introduction.md"
     /// ).await?;
     ///
@@ -123,12 +127,75 @@ pub trait GithubClient: Send + Sync {
     /// Implementations should convert GitHub-specific errors into the
     /// application's error types, providing clear error messages for
     /// common scenarios like missing files or permission issues.
-    async fn get_file_content(&self, owner: &str, repo: &str, path: &str) -> Result<String>;
+    async fn get_file_content(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        path: &str,
+    ) -> Result<String>;
+
+    /// Check whether a file exists in a GitHub repository
+    ///
+    /// Used to validate assets referenced by articles (e.g. gallery images)
+    /// without downloading them.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The username or organization that owns the repository
+    /// * `repo` - The repository name (without the owner prefix)
+    /// * `commit` - SHA of the commit to look the file up at
+    /// * `path` - The file path within the repository
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(true)` - The path is a file as of `commit`
+    /// * `Ok(false)` - The repository has no such path, or it is a directory
+    /// * `Err(SomeError)` - The repository could not be queried
+    async fn file_exists(&self, owner: &str, repo: &str, commit: &str, path: &str) -> Result<bool>;
+
+    /// Download a binary file from a GitHub repository
+    ///
+    /// Used for assets referenced by articles (e.g. images), which can't go
+    /// through [`GithubClient::get_file_content`] since they aren't UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The username or organization that owns the repository
+    /// * `repo` - The repository name (without the owner prefix)
+    /// * `commit` - SHA of the commit to read the file at, so assets match
+    ///   the pushed articles rather than the current default branch
+    /// * `path` - The file path within the repository
+    /// * `max_bytes` - Size limit; a larger file is not downloaded in full
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<u8>)` - The raw bytes of the file as of `commit`
+    /// * `Err(SomeError)` - The file doesn't exist, is larger than
+    ///   `max_bytes`, or could not be fetched
+    async fn get_file_bytes(
+        &self,
+        owner: &str,
+        repo: &str,
+        commit: &str,
+        path: &str,
+        max_bytes: usize,
+    ) -> Result<Vec<u8>>;
 
+    /// Fetch the content of the changed files of a push
+    ///
+    /// Files are fetched concurrently with
+    /// [`GithubClient::get_file_content`], all as of the same `commit`.
+    ///
+    /// # Returns
+    ///
+    /// One `(timestamp, content, path)` entry per change, in no particular
+    /// order; a file that could not be fetched has an `Err` content.
     async fn fetch_files(
         &self,
         owner: &str,
         repo: &str,
+        commit: &str,
         changes: &[FileChange],
     ) -> Vec<(DateTime<Utc>, Result<String>, String)>;
 }
@@ -49,7 +49,7 @@ use crate::errors::{Result, WebHooksError};
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// use axum::body::Bytes;
 /// use axum::http::HeaderMap;
 /// use backend::infrastructure::github::signature::verify_signature;
@@ -113,7 +113,7 @@ pub fn verify_signature(
     };
 
     // Compute HMAC of the payload using our secret
-    mac.update(&payload_bytes);
+    mac.update(payload_bytes);
 
     // Verify that our computed signature matches the one from GitHub
     // This uses constant-time comparison to prevent timing attacks
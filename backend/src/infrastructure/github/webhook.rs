@@ -4,7 +4,10 @@ use octocrab::models::webhook_events::{
 };
 use serde::{Deserialize, Serialize};
 
-use crate::errors::{Result, WebHooksError};
+use crate::{
+    domain::authors::CommitAuthor,
+    errors::{Result, WebHooksError},
+};
 
 /// Represents a file change detected in a webhook event
 ///
@@ -16,16 +19,23 @@ use crate::errors::{Result, WebHooksError};
 ///
 /// * `file_path` - Relative path to the file within the repository
 /// * `timestamp` - Timestamp of the file change
+/// * `author` - Author of the commit that changed the file
 /// * `row_url` - Optional URL to view the file (currently unused)
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
+/// use backend::domain::authors::CommitAuthor;
 /// use backend::infrastructure::github::webhook::FileChange;
 ///
 /// let change = FileChange {
 ///     file_path: "posts/my-article.md".to_string(),
 ///     timestamp: Utc::now(),
+///     author: CommitAuthor {
+///         name: "Jane Doe".to_string(),
+///         email: Some("jane@example.com".to_string()),
+///         username: Some("janedoe".to_string()),
+///     },
 ///     row_url: None,
 /// };
 /// ```
@@ -36,6 +46,9 @@ pub struct FileChange {
 
     pub timestamp: DateTime<Utc>,
 
+    /// Author of the commit, used to attribute posts without `authors`
+    pub author: CommitAuthor,
+
     /// Optional URL to view the file in the repository
     /// Note: Currently not populated, reserved for future use
     pub row_url: Option<String>,
@@ -54,7 +67,7 @@ pub struct FileChange {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// use backend::infrastructure::github::webhook::WebhookHandler;
 /// use octocrab::models::webhook_events::WebhookEvent;
 ///
@@ -84,7 +97,7 @@ pub trait WebhookHandler {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let changes = event.get_push_file_changes();
     /// for change in changes {
     ///     match change.status.as_str() {
@@ -115,7 +128,7 @@ pub trait WebhookHandler {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let repo = event.get_repository_name()?;
     /// if allowed_repos.contains(&repo) {
     ///     // Process the webhook
@@ -135,11 +148,22 @@ pub trait WebhookHandler {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let owner = event.get_repository_owner()?;
     /// let file_content = github_client.get_file_content(&owner, "repo", "path").await?;
     /// ```
     fn get_repository_owner(&self) -> Result<String>;
+
+    /// Extract the commit a push event moved the branch to
+    ///
+    /// Files are read at this commit rather than at the branch head, which
+    /// may already have moved on by the time the push is processed.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - SHA of the head commit after the push
+    /// * `Err(WebHooksError::UnsupportedWebhookEvent)` - Not a push event
+    fn get_push_head_commit(&self) -> Result<String>;
 }
 
 /// Implementation of WebhookHandler for octocrab's WebhookEvent
@@ -158,7 +182,7 @@ impl WebhookHandler for WebhookEvent {
     /// 2. Extracts the push payload from the event
     /// 3. Iterates through all commits in the push
     /// 4. For each commit, collects added, removed, and modified files
-    /// 5. Creates a FileChange object for each file with its status
+    /// 5. Creates a FileChange object for each file, carrying the commit author
     ///
     /// # Returns
     ///
@@ -175,11 +199,18 @@ impl WebhookHandler for WebhookEvent {
             if let WebhookEventPayload::Push(push_payload) = &self.specific {
                 // Iterate through all commits in the push
                 for commit in &push_payload.commits {
+                    let author = CommitAuthor {
+                        name: commit.author.user.name.clone(),
+                        email: commit.author.user.email.clone(),
+                        username: commit.author.username.clone(),
+                    };
+
                     // Collect added files
                     for file in &commit.added {
                         added_files.push(FileChange {
                             file_path: file.clone(),
                             timestamp: commit.timestamp,
+                            author: author.clone(),
                             row_url: None,
                         });
                     }
@@ -189,6 +220,7 @@ impl WebhookHandler for WebhookEvent {
                         removed_files.push(FileChange {
                             file_path: file.clone(),
                             timestamp: commit.timestamp,
+                            author: author.clone(),
                             row_url: None,
                         });
                     }
@@ -198,6 +230,7 @@ impl WebhookHandler for WebhookEvent {
                         modified_files.push(FileChange {
                             file_path: file.clone(),
                             timestamp: commit.timestamp,
+                            author: author.clone(),
                             row_url: None,
                         });
                     }
@@ -259,17 +292,34 @@ impl WebhookHandler for WebhookEvent {
             _ => Err(WebHooksError::UnsupportedWebhookEvent.into()),
         }
     }
+
+    /// Get the `after` SHA of a push event
+    fn get_push_head_commit(&self) -> Result<String> {
+        match &self.specific {
+            WebhookEventPayload::Push(push_payload) => Ok(push_payload.after.clone()),
+            _ => Err(WebHooksError::UnsupportedWebhookEvent.into()),
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn test_commit_author() -> CommitAuthor {
+        CommitAuthor {
+            name: "Test".to_string(),
+            email: None,
+            username: None,
+        }
+    }
+
     #[test]
     fn test_file_change_creation() {
         let change = FileChange {
             file_path: "test.md".to_string(),
             timestamp: Utc::now(),
+            author: test_commit_author(),
             row_url: None,
         };
 
@@ -282,11 +332,13 @@ mod tests {
         let change = FileChange {
             file_path: "test.md".to_string(),
             timestamp: Utc::now(),
+            author: test_commit_author(),
             row_url: Some("https://github.com/...".to_string()),
         };
 
         let json = serde_json::to_string(&change).unwrap();
         assert!(json.contains("test.md"));
-        assert!(json.contains("modified"));
+        assert!(json.contains("\"author\":{\"name\":\"Test\""));
+        assert!(json.contains("https://github.com/..."));
     }
 }
@@ -0,0 +1,766 @@
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::LazyLock;
+
+use ammonia::Builder;
+use pulldown_cmark::{CowStr, Event, LinkType, Options, Parser, Tag, TagEnd, html};
+
+use crate::domain::{
+    links::{
+        BrokenLink, LinkIndex, LinkTarget, ResolvedLinks, is_external_url,
+        is_internal_markdown_link, split_fragment,
+    },
+    reading::ContentStats,
+    summary::{SummaryOptions, truncate_summary},
+    toc::{TocEntry, build_toc},
+};
+
+/// Markdown extensions enabled for article content
+///
+/// GFM tables, footnotes, task lists and strikethrough, plus `{#custom-id}`
+/// heading attributes so authors can pin an anchor that survives edits to the
+/// heading text.
+fn markdown_options() -> Options {
+    Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_TASKLISTS
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_HEADING_ATTRIBUTES
+}
+
+/// Allowlist sanitizer applied to rendered HTML
+///
+/// Starts from ammonia's defaults (which already strip `<script>`, `<style>`,
+/// event handler attributes and `javascript:` URLs) and only adds what the
+/// renderer itself emits:
+///
+/// * `id` on headings, for anchors
+/// * `id` and the footnote classes on footnote markup
+/// * disabled checkboxes for task lists
+/// * `text-align` on table cells for column alignment
+/// * `language-*` classes on code blocks, for client-side highlighting
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::default();
+
+    builder
+        .add_tags(["input"])
+        .add_tag_attributes("h1", ["id"])
+        .add_tag_attributes("h2", ["id"])
+        .add_tag_attributes("h3", ["id"])
+        .add_tag_attributes("h4", ["id"])
+        .add_tag_attributes("h5", ["id"])
+        .add_tag_attributes("h6", ["id"])
+        .add_tag_attributes("div", ["id"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .add_tag_attributes("th", ["style"])
+        .add_tag_attributes("td", ["style"])
+        .filter_style_properties(HashSet::from(["text-align"]))
+        .allowed_classes(HashMap::from([
+            (
+                "sup",
+                HashSet::from(["footnote-reference", "footnote-definition-label"]),
+            ),
+            ("div", HashSet::from(["footnote-definition"])),
+        ]))
+        .attribute_filter(|element, attribute, value| match (element, attribute) {
+            // Only read-only checkboxes, never text inputs or buttons
+            ("input", "type") => (value == "checkbox").then_some(value.into()),
+            ("input", "disabled") => Some("".into()),
+            ("code", "class") => {
+                let classes: Vec<&str> = value
+                    .split_whitespace()
+                    .filter(|class| is_language_class(class))
+                    .collect();
+                (!classes.is_empty()).then(|| classes.join(" ").into())
+            }
+            _ => Some(value.into()),
+        });
+
+    // `class` on code is filtered by hand above, since `allowed_classes`
+    // can't express a `language-*` prefix
+    builder.add_tag_attributes("code", ["class"]);
+
+    builder
+});
+
+/// Check whether a class is a fenced code block language (e.g. `language-rust`)
+fn is_language_class(class: &str) -> bool {
+    class.strip_prefix("language-").is_some_and(|lang| {
+        !lang.is_empty()
+            && lang
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '+' | '#' | '.'))
+    })
+}
+
+/// Markdown rendered at ingestion
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderedMarkdown {
+    /// Sanitized HTML fragment
+    pub html: String,
+
+    /// Heading tree, with anchors matching the heading ids in `html`
+    pub toc: Vec<TocEntry>,
+
+    /// Prose, code and image counts for word count and reading time
+    pub stats: ContentStats,
+}
+
+/// Render article markdown to sanitized HTML and extract its table of contents
+/// and content statistics
+///
+/// Headings get an `id` derived from their text with [`slugify`] (or the
+/// explicit `{#id}` attribute if given), deduplicated with `-1`, `-2`, …
+/// suffixes so every anchor on the page is unique. The same anchors are used
+/// for the table of contents. The HTML is passed through an allowlist
+/// sanitizer, so raw HTML embedded in the markdown can't inject scripts or
+/// event handlers.
+///
+/// # Arguments
+///
+/// * `source` - Markdown body, without front matter
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::markdown::render;
+///
+/// let rendered = render("# Hello World\n\n<script>alert(1)</script>");
+/// assert!(rendered.html.contains(r#"<h1 id="hello-world">Hello World</h1>"#));
+/// assert!(!rendered.html.contains("<script>"));
+/// assert_eq!(rendered.toc[0].anchor, "hello-world");
+/// ```
+pub fn render(source: &str) -> RenderedMarkdown {
+    let mut events: Vec<Event> = Parser::new_ext(source, markdown_options()).collect();
+    let headings = assign_heading_ids(&mut events);
+    let stats = collect_stats(&events);
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    RenderedMarkdown {
+        html: SANITIZER.clean(&unsafe_html).to_string(),
+        toc: build_toc(headings),
+        stats,
+    }
+}
+
+/// Render article markdown to sanitized HTML
+///
+/// Shorthand for [`render`] when the table of contents isn't needed.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::markdown::render_markdown;
+///
+/// let html = render_markdown("# Hello World\n\n<script>alert(1)</script>");
+/// assert!(html.contains(r#"<h1 id="hello-world">Hello World</h1>"#));
+/// assert!(!html.contains("<script>"));
+/// ```
+pub fn render_markdown(source: &str) -> String {
+    render(source).html
+}
+
+/// Extract the table of contents of article markdown without rendering it
+///
+/// Anchors are identical to the heading ids produced by [`render`].
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::markdown::extract_toc;
+///
+/// let toc = extract_toc("## 安装\n\n### Linux\n\n## 使用");
+/// assert_eq!(toc.len(), 2);
+/// assert_eq!(toc[0].anchor, "安装");
+/// assert_eq!(toc[0].children[0].anchor, "linux");
+/// ```
+pub fn extract_toc(source: &str) -> Vec<TocEntry> {
+    let mut events: Vec<Event> = Parser::new_ext(source, markdown_options()).collect();
+
+    build_toc(assign_heading_ids(&mut events))
+}
+
+/// Count the prose, code and images of article markdown without rendering it
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::markdown::content_stats;
+///
+/// let stats = content_stats("你好 world\n\n![图](a.png)\n\n```\nfn main() {}\n```");
+/// assert_eq!((stats.cjk_chars, stats.words), (2, 1));
+/// assert_eq!((stats.code_lines, stats.images), (1, 1));
+/// ```
+pub fn content_stats(source: &str) -> ContentStats {
+    let events: Vec<Event> = Parser::new_ext(source, markdown_options()).collect();
+
+    collect_stats(&events)
+}
+
+/// Walk parsed markdown and count prose, code block lines and images
+///
+/// Image alt text and raw HTML are not counted as prose.
+fn collect_stats(events: &[Event]) -> ContentStats {
+    let mut stats = ContentStats::default();
+    let mut in_code_block = false;
+    let mut image_depth = 0usize;
+
+    for event in events {
+        match event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            Event::Start(Tag::Image { .. }) => {
+                stats.images += 1;
+                image_depth += 1;
+            }
+            Event::End(TagEnd::Image) => image_depth = image_depth.saturating_sub(1),
+            Event::Text(text) if in_code_block => {
+                stats.code_lines += text.lines().filter(|l| !l.trim().is_empty()).count();
+            }
+            Event::Text(text) | Event::Code(text) if image_depth == 0 => stats.add_text(text),
+            _ => {}
+        }
+    }
+
+    stats
+}
+
+/// Generate a plain text summary from article markdown
+///
+/// Uses the first paragraph with any text in it. Headings, code blocks,
+/// tables, block quotes and footnote definitions are skipped; inside the
+/// paragraph, markdown syntax, images, footnote references and raw HTML tags
+/// are dropped. Inline code keeps its text, without the backticks. The text is then shortened with
+/// [`truncate_summary`].
+///
+/// # Returns
+///
+/// * `Some(String)` - The generated summary
+/// * `None` - The article has no prose paragraph
+///
+/// # Example
+///
+/// ```rust
+/// use backend::domain::summary::SummaryOptions;
+/// use backend::infrastructure::markdown::generate_summary;
+///
+/// let source = "# Title\n\n![cover](a.png)\n\nThis is **the** [first](b.md) paragraph.\n";
+/// assert_eq!(
+///     generate_summary(source, &SummaryOptions::default()).as_deref(),
+///     Some("This is the first paragraph.")
+/// );
+/// ```
+pub fn generate_summary(source: &str, options: &SummaryOptions) -> Option<String> {
+    let text = first_paragraph(Parser::new_ext(source, markdown_options()))?;
+
+    Some(truncate_summary(&text, options.max_chars))
+}
+
+/// Collect the plain text of the first top-level paragraph that has any
+fn first_paragraph<'a>(events: impl Iterator<Item = Event<'a>>) -> Option<String> {
+    let mut skip_depth = 0usize;
+    let mut image_depth = 0usize;
+    let mut in_paragraph = false;
+    let mut text = String::new();
+
+    for event in events {
+        match event {
+            Event::Start(
+                Tag::BlockQuote(_) | Tag::FootnoteDefinition(_) | Tag::Table(_) | Tag::CodeBlock(_),
+            ) => skip_depth += 1,
+            Event::End(
+                TagEnd::BlockQuote(_)
+                | TagEnd::FootnoteDefinition
+                | TagEnd::Table
+                | TagEnd::CodeBlock,
+            ) => skip_depth = skip_depth.saturating_sub(1),
+            Event::Start(Tag::Paragraph) if skip_depth == 0 => {
+                in_paragraph = true;
+                text.clear();
+            }
+            Event::End(TagEnd::Paragraph) if in_paragraph => {
+                in_paragraph = false;
+                let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+                if !collapsed.is_empty() {
+                    return Some(collapsed);
+                }
+            }
+            Event::Start(Tag::Image { .. }) => image_depth += 1,
+            Event::End(TagEnd::Image) => image_depth = image_depth.saturating_sub(1),
+            Event::Text(t) | Event::Code(t) if in_paragraph && image_depth == 0 => {
+                text.push_str(&t)
+            }
+            Event::SoftBreak | Event::HardBreak if in_paragraph => text.push(' '),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+/// Rewrite internal links of an article to canonical article URLs
+///
+/// Two kinds of internal links are recognized (links in code are left alone):
+///
+/// * Markdown links to repository files, inline or reference-style, e.g.
+///   `[Ownership](../notes/ownership.md#borrowing)`. The destination is
+///   replaced with the article URL; the `#fragment` is kept.
+/// * `[[wiki-style]]` references, `[[Target]]`, `[[Target|label]]` or
+///   `[[Target#Heading]]`, matched by title, file name or id. They are
+///   replaced with a markdown link; a heading becomes the anchor produced by
+///   [`slugify`].
+///
+/// Links that can't be resolved are kept as written and reported in
+/// [`ResolvedLinks::broken`].
+///
+/// # Arguments
+///
+/// * `source_path` - Repository path of the article, for relative links
+/// * `content` - Markdown body of the article
+/// * `index` - Articles that can be linked to
+pub fn resolve_internal_links(
+    source_path: &str,
+    content: &str,
+    index: &LinkIndex,
+) -> ResolvedLinks {
+    let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+    let mut resolved = ResolvedLinks::default();
+
+    let parser = Parser::new_ext(content, markdown_options() | Options::ENABLE_WIKILINKS);
+
+    // Reference definitions are resolved up front and rewritten in place
+    for (_, def) in parser.reference_definitions().iter() {
+        if let Some(span) = find_destination(content, def.span.clone(), &def.dest) {
+            match resolve_markdown_link(source_path, &def.dest, index) {
+                Some(Ok(target)) => {
+                    let fragment = split_fragment(&def.dest).1;
+                    let url = with_fragment(link_to(target, &mut resolved), fragment);
+                    replacements.push((span, url));
+                }
+                Some(Err(broken)) => resolved.broken.push(broken),
+                None => {}
+            }
+        }
+    }
+
+    for (event, range) in parser.into_offset_iter() {
+        let Event::Start(Tag::Link {
+            link_type,
+            dest_url,
+            ..
+        }) = event
+        else {
+            continue;
+        };
+
+        match link_type {
+            LinkType::WikiLink { .. } => {
+                let raw = &content[range.clone()];
+                let inner = raw.trim_start_matches('[').trim_end_matches(']');
+                let (reference, label) = match inner.split_once('|') {
+                    Some((reference, label)) => (reference, Some(label)),
+                    None => (inner, None),
+                };
+                let (name, heading) = match reference.split_once('#') {
+                    Some((name, heading)) => (name, Some(heading)),
+                    None => (reference, None),
+                };
+
+                match index.resolve_wiki(name) {
+                    Ok(target) => {
+                        let anchor = heading.map(slugify);
+                        let url = with_fragment(link_to(target, &mut resolved), anchor.as_deref());
+                        let label = label.unwrap_or(reference).trim();
+                        replacements.push((range, format!("[{}]({})", escape_label(label), url)));
+                    }
+                    Err(reason) => resolved.broken.push(BrokenLink {
+                        link: raw.to_string(),
+                        reason,
+                    }),
+                }
+            }
+            LinkType::Inline => {
+                let Some(span) = find_destination(content, range, &dest_url) else {
+                    continue;
+                };
+                match resolve_markdown_link(source_path, &dest_url, index) {
+                    Some(Ok(target)) => {
+                        let fragment = split_fragment(&dest_url).1;
+                        let url = with_fragment(link_to(target, &mut resolved), fragment);
+                        replacements.push((span, url));
+                    }
+                    Some(Err(broken)) => resolved.broken.push(broken),
+                    None => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Apply from the end so earlier ranges stay valid
+    replacements.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+    let mut rewritten = content.to_string();
+    for (range, replacement) in replacements {
+        rewritten.replace_range(range, &replacement);
+    }
+    resolved.content = rewritten;
+
+    resolved
+}
+
+/// List the repository images an article embeds
+///
+/// Collects the destinations of inline and reference-style images, in
+/// document order and without duplicates. URLs (`https:`, `data:`, …) and
+/// page-local `#anchors` are skipped; everything else is a path into the
+/// source repository, relative to the article unless it starts with `/`.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::markdown::image_sources;
+///
+/// let content = "![Map](./map.png)\n\n![Logo][logo]\n\n![Remote](https://example.com/a.png)\n\n[logo]: /assets/logo.svg";
+/// assert_eq!(image_sources(content), vec!["./map.png", "/assets/logo.svg"]);
+/// ```
+pub fn image_sources(content: &str) -> Vec<String> {
+    let mut sources: Vec<String> = Vec::new();
+
+    for event in Parser::new_ext(content, markdown_options()) {
+        let Event::Start(Tag::Image { dest_url, .. }) = event else {
+            continue;
+        };
+
+        let dest = dest_url.trim();
+        if dest.is_empty() || dest.starts_with('#') || is_external_url(dest) {
+            continue;
+        }
+        if !sources.iter().any(|s| s == dest) {
+            sources.push(dest.to_string());
+        }
+    }
+
+    sources
+}
+
+/// List the images an article embeds inline as `data:` URIs
+///
+/// Converted notebooks carry their image outputs this way until ingestion
+/// moves them to the media store. Collected in document order and without
+/// duplicates, like [`image_sources`].
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::markdown::inline_image_sources;
+///
+/// let content = "![Plot](data:image/png;base64,iVBORw==)\n\n![Map](./map.png)";
+/// assert_eq!(inline_image_sources(content), vec!["data:image/png;base64,iVBORw=="]);
+/// ```
+pub fn inline_image_sources(content: &str) -> Vec<String> {
+    let mut sources: Vec<String> = Vec::new();
+
+    for event in Parser::new_ext(content, markdown_options()) {
+        let Event::Start(Tag::Image { dest_url, .. }) = event else {
+            continue;
+        };
+
+        let dest = dest_url.trim();
+        if dest.starts_with("data:image/") && !sources.iter().any(|s| s == dest) {
+            sources.push(dest.to_string());
+        }
+    }
+
+    sources
+}
+
+/// Replace image destinations with new URLs
+///
+/// Every inline image or reference definition whose destination is a key of
+/// `urls` is rewritten in place; the rest of the content is left untouched,
+/// byte for byte.
+///
+/// # Arguments
+///
+/// * `content` - Markdown body of the article
+/// * `urls` - New URL for each destination, as returned by [`image_sources`]
+///
+/// # Returns
+///
+/// The rewritten markdown
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use backend::infrastructure::markdown::rewrite_image_sources;
+///
+/// let urls = HashMap::from([("./map.png".to_string(), "/api/media/abc".to_string())]);
+/// assert_eq!(
+///     rewrite_image_sources("See ![Map](./map.png \"Route\")", &urls),
+///     "See ![Map](/api/media/abc \"Route\")"
+/// );
+/// ```
+pub fn rewrite_image_sources(content: &str, urls: &HashMap<String, String>) -> String {
+    let mut replacements: Vec<(Range<usize>, String)> = Vec::new();
+
+    let parser = Parser::new_ext(content, markdown_options());
+
+    for (_, def) in parser.reference_definitions().iter() {
+        let Some(url) = urls.get(def.dest.trim()) else {
+            continue;
+        };
+        if let Some(span) = find_destination(content, def.span.clone(), &def.dest) {
+            replacements.push((span, url.clone()));
+        }
+    }
+
+    for (event, range) in parser.into_offset_iter() {
+        let Event::Start(Tag::Image {
+            link_type: LinkType::Inline,
+            dest_url,
+            ..
+        }) = event
+        else {
+            continue;
+        };
+
+        let Some(url) = urls.get(dest_url.trim()) else {
+            continue;
+        };
+        if let Some(span) = find_destination(content, range, &dest_url) {
+            replacements.push((span, url.clone()));
+        }
+    }
+
+    // Apply from the end so earlier ranges stay valid
+    replacements.sort_by_key(|(range, _)| std::cmp::Reverse(range.start));
+    let mut rewritten = content.to_string();
+    for (range, replacement) in replacements {
+        rewritten.replace_range(range, &replacement);
+    }
+
+    rewritten
+}
+
+/// Remove the inline images whose destination is one of `sources`
+///
+/// The whole image, alt text included, is cut out; the rest of the content
+/// is left untouched, byte for byte. Used for inline images that could not
+/// be ingested, so their `data:` payload isn't stored with the article.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashSet;
+/// use backend::infrastructure::markdown::remove_images;
+///
+/// let sources = HashSet::from(["data:image/png;base64,AAAA".to_string()]);
+/// assert_eq!(
+///     remove_images("Plot: ![Plot](data:image/png;base64,AAAA)", &sources),
+///     "Plot: "
+/// );
+/// ```
+pub fn remove_images(content: &str, sources: &HashSet<String>) -> String {
+    let mut removed: Vec<Range<usize>> = Vec::new();
+
+    for (event, range) in Parser::new_ext(content, markdown_options()).into_offset_iter() {
+        let Event::Start(Tag::Image {
+            link_type: LinkType::Inline,
+            dest_url,
+            ..
+        }) = event
+        else {
+            continue;
+        };
+
+        if sources.contains(dest_url.trim()) {
+            removed.push(range);
+        }
+    }
+
+    // Apply from the end so earlier ranges stay valid
+    let mut rewritten = content.to_string();
+    for range in removed.into_iter().rev() {
+        rewritten.replace_range(range, "");
+    }
+
+    rewritten
+}
+
+/// Record a link to `target` and return its URL
+fn link_to(target: &LinkTarget, resolved: &mut ResolvedLinks) -> String {
+    if !resolved.targets.contains(&target.id) {
+        resolved.targets.push(target.id);
+    }
+
+    target.url()
+}
+
+/// Resolve a markdown link destination if it is internal
+///
+/// Returns `None` for external and page-local links.
+fn resolve_markdown_link<'a>(
+    source_path: &str,
+    dest: &str,
+    index: &'a LinkIndex,
+) -> Option<Result<&'a LinkTarget, BrokenLink>> {
+    if !is_internal_markdown_link(dest) {
+        return None;
+    }
+
+    let path = split_fragment(dest).0;
+    Some(
+        index
+            .resolve_path(source_path, path)
+            .map_err(|reason| BrokenLink {
+                link: dest.to_string(),
+                reason,
+            }),
+    )
+}
+
+/// Locate a link destination inside the source span of a link or definition
+///
+/// The destination follows `](` for inline links or `]:` for reference
+/// definitions, optionally wrapped in `<…>`.
+fn find_destination(content: &str, span: Range<usize>, dest: &str) -> Option<Range<usize>> {
+    let text = content.get(span.clone())?;
+
+    text.match_indices("](")
+        .chain(text.match_indices("]:"))
+        .filter_map(|(index, _)| {
+            let after = index + 2;
+            let offset = text[after..].len() - text[after..].trim_start().len();
+            let start = after + offset;
+            let start = start + usize::from(text[start..].starts_with('<'));
+            text[start..]
+                .starts_with(dest)
+                .then(|| span.start + start..span.start + start + dest.len())
+        })
+        .last()
+}
+
+/// Append an optional `#fragment` to a URL
+fn with_fragment(url: String, fragment: Option<&str>) -> String {
+    match fragment.filter(|f| !f.is_empty()) {
+        Some(fragment) => format!("{}#{}", url, fragment),
+        None => url,
+    }
+}
+
+/// Escape brackets so a wiki label can't break out of the link text
+fn escape_label(label: &str) -> String {
+    label.replace('[', "\\[").replace(']', "\\]")
+}
+
+/// Fill in missing heading ids and make all heading ids unique
+///
+/// Returns the headings in document order, without nesting.
+fn assign_heading_ids(events: &mut [Event]) -> Vec<TocEntry> {
+    let mut seen = HashMap::new();
+    let mut headings = Vec::new();
+
+    for index in 0..events.len() {
+        let Event::Start(Tag::Heading { level, id, .. }) = &events[index] else {
+            continue;
+        };
+
+        let level = *level as u8;
+        let text = heading_text(&events[index + 1..]);
+        let base = match id {
+            Some(id) => id.to_string(),
+            None => slugify(&text),
+        };
+        let anchor = dedupe_slug(base, &mut seen);
+
+        if let Event::Start(Tag::Heading { id, .. }) = &mut events[index] {
+            *id = Some(CowStr::from(anchor.clone()));
+        }
+
+        headings.push(TocEntry {
+            level,
+            text: text.trim().to_string(),
+            anchor,
+            children: Vec::new(),
+        });
+    }
+
+    headings
+}
+
+/// Collect the plain text of a heading, starting right after its start tag
+fn heading_text(events: &[Event]) -> String {
+    let mut text = String::new();
+
+    for event in events {
+        match event {
+            Event::End(TagEnd::Heading(_)) => break,
+            Event::Text(t) | Event::Code(t) => text.push_str(t),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Return `slug`, or `slug-N` if the slug has already been used on the page
+///
+/// Empty slugs (e.g. a heading made only of punctuation) fall back to
+/// `section`.
+pub fn dedupe_slug(slug: String, seen: &mut HashMap<String, usize>) -> String {
+    let slug = if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug
+    };
+
+    match seen.get_mut(&slug) {
+        Some(count) => {
+            *count += 1;
+            let unique = format!("{}-{}", slug, count);
+            seen.insert(unique.clone(), 0);
+            unique
+        }
+        None => {
+            seen.insert(slug.clone(), 0);
+            slug
+        }
+    }
+}
+
+/// Turn heading text into a URL fragment
+///
+/// Letters and digits from any script are kept (lowercased), so CJK headings
+/// produce readable anchors instead of empty ones. Whitespace, `-` and `_`
+/// become a single `-`; all other punctuation is dropped.
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::markdown::slugify;
+///
+/// assert_eq!(slugify("Hello, World!"), "hello-world");
+/// assert_eq!(slugify("Rust 所有权 简介"), "rust-所有权-简介");
+/// assert_eq!(slugify("  --Trim--  "), "trim");
+/// ```
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    let mut pending_dash = false;
+
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            if pending_dash && !slug.is_empty() {
+                slug.push('-');
+            }
+            pending_dash = false;
+            slug.extend(c.to_lowercase());
+        } else if c.is_whitespace() || c == '-' || c == '_' {
+            pending_dash = true;
+        }
+    }
+
+    slug
+}
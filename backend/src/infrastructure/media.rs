@@ -0,0 +1,4 @@
+pub mod local;
+#[cfg(feature = "webhook")]
+pub mod processing;
+pub mod s3;
@@ -0,0 +1,85 @@
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use tokio::fs;
+
+use crate::{
+    domain::media::{MediaContent, MediaHash, MediaStore},
+    errors::{MediaError, Result},
+};
+
+/// Content-addressed media store on the local filesystem
+///
+/// Every blob is written once under its hash, sharded by the first two hex
+/// characters so no directory grows too large:
+///
+/// ```text
+/// {root}/9f/9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08
+/// ```
+///
+/// Since a hash always names the same bytes, writes of a blob that already
+/// exists are skipped and readers never see a blob change. Reads are always
+/// proxied through the API.
+pub struct LocalMediaStore {
+    /// Directory holding the blobs
+    root: PathBuf,
+}
+
+impl LocalMediaStore {
+    /// Create a store rooted at `root`
+    ///
+    /// The directory is created lazily on the first write.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Location of a blob on disk
+    fn blob_path(&self, hash: &MediaHash) -> PathBuf {
+        self.root.join(&hash.as_str()[..2]).join(hash.as_str())
+    }
+}
+
+#[async_trait]
+impl MediaStore for LocalMediaStore {
+    /// Write a blob to `{root}/{hash[..2]}/{hash}`
+    ///
+    /// The bytes are written to a temporary file and renamed into place, so a
+    /// crash never leaves a truncated blob behind a valid hash. The content
+    /// type is not stored; it is recorded in `media_assets`.
+    async fn put(&self, hash: &MediaHash, bytes: &[u8], _content_type: &str) -> Result<()> {
+        let path = self.blob_path(hash);
+        if fs::try_exists(&path).await.map_err(MediaError::from)? {
+            return Ok(());
+        }
+
+        let dir = path.parent().unwrap_or(Path::new(&self.root));
+        fs::create_dir_all(dir).await.map_err(MediaError::from)?;
+
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, bytes).await.map_err(MediaError::from)?;
+        fs::rename(&tmp, &path).await.map_err(MediaError::from)?;
+
+        Ok(())
+    }
+
+    async fn get(&self, hash: &MediaHash) -> Result<Option<Vec<u8>>> {
+        match fs::read(self.blob_path(hash)).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(MediaError::from(e).into()),
+        }
+    }
+
+    async fn delete(&self, hash: &MediaHash) -> Result<()> {
+        match fs::remove_file(self.blob_path(hash)).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(MediaError::from(e).into()),
+        }
+    }
+
+    /// Local files have no URL of their own, reads are always proxied
+    async fn presigned_read(&self, _hash: &MediaHash) -> Result<Option<MediaContent>> {
+        Ok(None)
+    }
+}
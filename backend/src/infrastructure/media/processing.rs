@@ -0,0 +1,546 @@
+use std::io::Cursor;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use exif::{Exif, Field, In, Rational, Reader, Tag, Value, experimental::Writer};
+use image::{
+    DynamicImage, GenericImageView, ImageDecoder, ImageFormat, ImageReader,
+    codecs::avif::AvifEncoder,
+    error::{EncodingError, ImageFormatHint},
+    imageops::FilterType,
+};
+use img_parts::{
+    Bytes, DynImage, ImageEXIF,
+    jpeg::{JpegSegment, markers},
+    webp::CHUNK_XMP,
+};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+use crate::{
+    domain::{
+        exif::{ExifMetadata, Exposure, GeoLocation},
+        media::{MediaAsset, MediaHash, MediaOptions, VariantFormat},
+    },
+    errors::{MediaError, Result},
+};
+
+/// Encoder speed for AVIF variants, 1 (slowest) to 10 (fastest)
+const AVIF_SPEED: u8 = 8;
+
+/// Encoder quality for AVIF variants, 1 to 100
+const AVIF_QUALITY: u8 = 75;
+
+/// Encoder quality for WebP variants, 0 to 100
+const WEBP_QUALITY: f32 = 80.0;
+
+/// Blurhash components along each axis
+const BLURHASH_COMPONENTS: (u32, u32) = (4, 3);
+
+/// Longest side of the thumbnail a blurhash is computed from
+const BLURHASH_THUMBNAIL_SIZE: u32 = 32;
+
+/// EXIF tags kept in served files: what browsers need to display the image
+/// and the camera settings shown in galleries
+///
+/// Everything else is removed, notably serial numbers, owner names, maker
+/// notes and the embedded thumbnail, which may show the uncropped photo.
+const KEPT_TAGS: &[Tag] = &[
+    Tag::Orientation,
+    Tag::XResolution,
+    Tag::YResolution,
+    Tag::ResolutionUnit,
+    Tag::ColorSpace,
+    Tag::PixelXDimension,
+    Tag::PixelYDimension,
+    Tag::ExifVersion,
+    Tag::Make,
+    Tag::Model,
+    Tag::LensMake,
+    Tag::LensModel,
+    Tag::DateTimeOriginal,
+    Tag::OffsetTimeOriginal,
+    Tag::ExposureTime,
+    Tag::FNumber,
+    Tag::ExposureProgram,
+    Tag::ExposureBiasValue,
+    Tag::PhotographicSensitivity,
+    Tag::Flash,
+    Tag::FocalLength,
+    Tag::FocalLengthIn35mmFilm,
+];
+
+/// GPS tags kept when the post opts in with `keep_location`
+const LOCATION_TAGS: &[Tag] = &[
+    Tag::GPSVersionID,
+    Tag::GPSLatitudeRef,
+    Tag::GPSLatitude,
+    Tag::GPSLongitudeRef,
+    Tag::GPSLongitude,
+    Tag::GPSAltitudeRef,
+    Tag::GPSAltitude,
+];
+
+/// PNG chunks holding free text, which may embed XMP with GPS data
+const PNG_TEXT_CHUNKS: &[[u8; 4]] = &[*b"tEXt", *b"zTXt", *b"iTXt"];
+
+/// An encoded blob ready to be written to the media store
+#[derive(Debug, Clone)]
+pub struct EncodedBlob {
+    /// Metadata recorded in `media_assets`
+    pub asset: MediaAsset,
+
+    /// Content of the blob
+    pub bytes: Vec<u8>,
+}
+
+/// An original image together with its responsive variants
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    /// The source image as served, see [`strip_metadata`]
+    pub original: EncodedBlob,
+
+    /// Resized variants, by width then format
+    pub variants: Vec<EncodedBlob>,
+}
+
+/// Run a source image through the pipeline
+///
+/// Decodes the image and turns it upright according to its EXIF
+/// orientation, then reads its dimensions, computes a blurhash placeholder
+/// and encodes one variant per configured width and format. Widths that are
+/// not smaller than the upright original are skipped. Variants carry no EXIF
+/// data, so their pixels are stored upright.
+///
+/// The camera metadata of the original is read from the EXIF tags left in
+/// `bytes`, which should already have gone through [`strip_metadata`].
+///
+/// This is CPU bound (AVIF encoding especially) and should run on a
+/// blocking thread.
+///
+/// # Arguments
+///
+/// * `bytes` - Content of the source file
+/// * `options` - Variant widths and formats
+///
+/// # Returns
+///
+/// * `Ok(ProcessedImage)` - The original and its variants
+/// * `Err(MediaError::Processing)` - The image format is unsupported or the
+///   image could not be decoded or encoded
+pub fn process_image(bytes: Vec<u8>, options: &MediaOptions) -> Result<ProcessedImage> {
+    let format = image::guess_format(&bytes).map_err(MediaError::from)?;
+    let image = decode_upright(&bytes, format).map_err(MediaError::from)?;
+    let (width, height) = image.dimensions();
+    let exif = read_exif(&bytes);
+
+    let original = EncodedBlob {
+        asset: MediaAsset {
+            hash: MediaHash::of(&bytes),
+            content_type: format.to_mime_type().to_string(),
+            byte_size: byte_size(&bytes),
+            width: dimension(width),
+            height: dimension(height),
+            blurhash: blurhash(&image),
+            original: None,
+            exif,
+        },
+        bytes,
+    };
+
+    let mut variants = Vec::new();
+    for &target_width in options.widths.iter().filter(|&&w| w < width) {
+        let resized = image.resize(target_width, u32::MAX, FilterType::Lanczos3);
+        let pixels = if resized.color().has_alpha() {
+            DynamicImage::ImageRgba8(resized.to_rgba8())
+        } else {
+            DynamicImage::ImageRgb8(resized.to_rgb8())
+        };
+
+        for &variant_format in &options.formats {
+            let bytes = encode(&pixels, variant_format)?;
+            variants.push(EncodedBlob {
+                asset: MediaAsset {
+                    hash: MediaHash::of(&bytes),
+                    content_type: variant_format.content_type().to_string(),
+                    byte_size: byte_size(&bytes),
+                    width: dimension(pixels.width()),
+                    height: dimension(pixels.height()),
+                    blurhash: None,
+                    original: Some(original.asset.hash.clone()),
+                    exif: None,
+                },
+                bytes,
+            });
+        }
+    }
+
+    Ok(ProcessedImage { original, variants })
+}
+
+/// Decode an image embedded as a `data:` URI
+///
+/// Only base64 encoded images are accepted, as written by Jupyter for cell
+/// outputs (`data:image/png;base64,...`).
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The image bytes
+/// * `Err(SomeError)` - The URI is not a base64 image or does not decode
+///
+/// # Example
+///
+/// ```rust
+/// use backend::infrastructure::media::processing::decode_data_uri;
+///
+/// assert_eq!(decode_data_uri("data:image/png;base64,iVBORw==").unwrap(), b"\x89PNG");
+/// assert!(decode_data_uri("data:text/plain,hello").is_err());
+/// ```
+pub fn decode_data_uri(uri: &str) -> Result<Vec<u8>> {
+    let (header, payload) = uri
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(','))
+        .ok_or_else(|| anyhow::anyhow!("not a data URI"))?;
+
+    let mime = header
+        .strip_suffix(";base64")
+        .filter(|mime| mime.starts_with("image/"))
+        .ok_or_else(|| anyhow::anyhow!("`{}` is not a base64 encoded image", header))?;
+
+    BASE64_STANDARD
+        .decode(payload.trim())
+        .map_err(|e| anyhow::anyhow!("invalid base64 in {} data URI: {}", mime, e).into())
+}
+
+/// Remove sensitive metadata from a source image
+///
+/// The EXIF block is rewritten with the tags in [`KEPT_TAGS`] only, plus the
+/// GPS position when `keep_location` is set. XMP, IPTC and comment blocks,
+/// which repeat much of the EXIF data, are dropped, as is anything after the
+/// end of a JPEG image (motion photo videos, secondary MPF images). Pixel
+/// data is left untouched, so nothing is re-encoded.
+///
+/// GIF files carry no EXIF block and are returned as is, like any format
+/// the pipeline would reject anyway.
+///
+/// # Arguments
+///
+/// * `bytes` - Content of the source file
+/// * `keep_location` - Whether to keep the GPS position
+///
+/// # Returns
+///
+/// * `Ok(Vec<u8>)` - The image to store and serve
+/// * `Err(MediaError::Metadata)` - The JPEG, PNG or WebP container is
+///   corrupted, so its metadata can't be removed safely
+pub fn strip_metadata(bytes: Vec<u8>, keep_location: bool) -> Result<Vec<u8>> {
+    let source = Bytes::from(bytes);
+    let Some(mut image) = DynImage::from_bytes(source.clone()).map_err(MediaError::from)? else {
+        return Ok(source.into());
+    };
+
+    let exif = image
+        .exif()
+        .and_then(|raw| rewrite_exif(raw, keep_location));
+
+    match &mut image {
+        DynImage::Jpeg(jpeg) => {
+            let segments = jpeg.segments_mut();
+            segments.retain(|segment| !is_metadata_segment(segment));
+            // The EXIF segment is inserted after the first three segments
+            if segments.len() < 3 {
+                return Err(MediaError::from(img_parts::Error::Truncated).into());
+            }
+        }
+        DynImage::Png(png) => png
+            .chunks_mut()
+            .retain(|chunk| !PNG_TEXT_CHUNKS.contains(&chunk.kind())),
+        DynImage::WebP(webp) => webp.remove_chunks_by_id(CHUNK_XMP),
+    }
+    image.set_exif(exif);
+
+    // SOI marker, then the segments; entropy-coded data follows
+    let header_len = match &image {
+        DynImage::Jpeg(jpeg) => {
+            Some(2 + jpeg.segments().iter().map(JpegSegment::len).sum::<usize>())
+        }
+        _ => None,
+    };
+
+    let mut stripped: Vec<u8> = image.encoder().bytes().into();
+    if let Some(header_len) = header_len {
+        truncate_after_eoi(&mut stripped, header_len);
+    }
+
+    Ok(stripped)
+}
+
+/// Decode an image and apply its EXIF orientation
+///
+/// Browsers rotate the original according to its `Orientation` tag, so the
+/// dimensions, blurhash and variants are taken from the rotated image.
+fn decode_upright(bytes: &[u8], format: ImageFormat) -> image::ImageResult<DynamicImage> {
+    let mut decoder = ImageReader::with_format(Cursor::new(bytes), format).into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+
+    Ok(image)
+}
+
+/// Encode an 8-bit RGB(A) image in a variant format
+///
+/// WebP goes through libwebp, as the `image` encoder only writes lossless
+/// WebP, which is often larger than the original.
+fn encode(image: &DynamicImage, format: VariantFormat) -> Result<Vec<u8>> {
+    let mut bytes = Vec::new();
+    match format {
+        VariantFormat::Webp => return encode_webp(image),
+        VariantFormat::Avif => image.write_with_encoder(AvifEncoder::new_with_speed_quality(
+            &mut bytes,
+            AVIF_SPEED,
+            AVIF_QUALITY,
+        )),
+    }
+    .map_err(MediaError::from)?;
+
+    Ok(bytes)
+}
+
+/// Encode an 8-bit RGB(A) image as lossy WebP
+fn encode_webp(image: &DynamicImage) -> Result<Vec<u8>> {
+    let (width, height) = image.dimensions();
+    let encoder = match image {
+        DynamicImage::ImageRgb8(pixels) => webp::Encoder::from_rgb(pixels, width, height),
+        DynamicImage::ImageRgba8(pixels) => webp::Encoder::from_rgba(pixels, width, height),
+        other => return encode_webp(&DynamicImage::ImageRgba8(other.to_rgba8())),
+    };
+
+    let encoded = encoder.encode_simple(false, WEBP_QUALITY).map_err(|e| {
+        MediaError::from(image::ImageError::Encoding(EncodingError::new(
+            ImageFormatHint::Exact(ImageFormat::WebP),
+            format!("{:?}", e),
+        )))
+    })?;
+
+    Ok(encoded.to_vec())
+}
+
+/// Compute a blurhash placeholder from a small thumbnail
+///
+/// Returns `None` for images blurhash can't encode (e.g. zero-sized).
+fn blurhash(image: &DynamicImage) -> Option<String> {
+    let thumbnail = image
+        .thumbnail(BLURHASH_THUMBNAIL_SIZE, BLURHASH_THUMBNAIL_SIZE)
+        .to_rgba8();
+    let (x, y) = BLURHASH_COMPONENTS;
+
+    blurhash::encode(
+        x,
+        y,
+        thumbnail.width(),
+        thumbnail.height(),
+        thumbnail.as_raw(),
+    )
+    .ok()
+}
+
+/// Keep only the allowed tags of a raw EXIF block
+///
+/// Returns `None` when no tag is left or the block can't be parsed, in which
+/// case it is dropped entirely.
+fn rewrite_exif(raw: Bytes, keep_location: bool) -> Option<Bytes> {
+    let exif = Reader::new().read_raw(raw.to_vec()).ok()?;
+    let fields: Vec<&Field> = exif
+        .fields()
+        .filter(|field| field.ifd_num == In::PRIMARY)
+        .filter(|field| {
+            KEPT_TAGS.contains(&field.tag) || (keep_location && LOCATION_TAGS.contains(&field.tag))
+        })
+        .collect();
+    if fields.is_empty() {
+        return None;
+    }
+
+    let mut writer = Writer::new();
+    for field in fields {
+        writer.push_field(field);
+    }
+    let mut buf = Cursor::new(Vec::new());
+    writer.write(&mut buf, exif.little_endian()).ok()?;
+
+    Some(Bytes::from(buf.into_inner()))
+}
+
+/// Whether a JPEG segment holds metadata rather than image data
+///
+/// APP1 holds EXIF (set again afterwards) and XMP, APP13 IPTC; APP0 (JFIF),
+/// APP2 ICC profiles and APP14 (Adobe colour transform) are needed to
+/// display the image. MPF indexes in APP2 point at the images cut off after
+/// the end of the image.
+fn is_metadata_segment(segment: &JpegSegment) -> bool {
+    match segment.marker() {
+        markers::APP2 => segment.contents().starts_with(b"MPF\0"),
+        markers::APP1 | markers::APP3..=markers::APP13 | markers::APP15 | markers::COM => true,
+        _ => false,
+    }
+}
+
+/// Cut a JPEG file after its end of image marker
+///
+/// Entropy-coded data never contains an unescaped `FF D9`, so the first one
+/// after the header ends the image.
+fn truncate_after_eoi(bytes: &mut Vec<u8>, header_len: usize) {
+    let end = bytes.get(header_len..).and_then(|data| {
+        data.windows(2)
+            .position(|w| w == [markers::P, markers::EOI])
+    });
+
+    if let Some(end) = end {
+        bytes.truncate(header_len + end + 2);
+    }
+}
+
+/// Read the camera metadata of an image
+///
+/// Returns `None` when the image has no EXIF block or none of the fields
+/// could be read.
+fn read_exif(bytes: &[u8]) -> Option<ExifMetadata> {
+    let exif = Reader::new()
+        .read_from_container(&mut Cursor::new(bytes))
+        .ok()?;
+
+    let exposure = Exposure {
+        shutter_speed: rational(&exif, Tag::ExposureTime).map(shutter_speed),
+        aperture: rational(&exif, Tag::FNumber).map(round_to_tenth),
+        iso: field(&exif, Tag::PhotographicSensitivity).and_then(|f| f.value.get_uint(0)),
+        focal_length: rational(&exif, Tag::FocalLength).map(round_to_tenth),
+    };
+
+    let metadata = ExifMetadata {
+        camera: make_and_model(text(&exif, Tag::Make), text(&exif, Tag::Model)),
+        lens: make_and_model(text(&exif, Tag::LensMake), text(&exif, Tag::LensModel)),
+        exposure: (exposure != Exposure::default()).then_some(exposure),
+        taken_at: taken_at(&exif),
+        location: location(&exif),
+    };
+
+    (!metadata.is_empty()).then_some(metadata)
+}
+
+fn field(exif: &Exif, tag: Tag) -> Option<&Field> {
+    exif.get_field(tag, In::PRIMARY)
+}
+
+/// First string of an ASCII field, without padding
+fn text(exif: &Exif, tag: Tag) -> Option<String> {
+    let Value::Ascii(values) = &field(exif, tag)?.value else {
+        return None;
+    };
+    let value = String::from_utf8_lossy(values.first()?);
+    let value = value.trim_matches(|c: char| c == '\0' || c.is_whitespace());
+
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// First value of a rational field, as a float
+fn rational(exif: &Exif, tag: Tag) -> Option<f64> {
+    let Value::Rational(values) = &field(exif, tag)?.value else {
+        return None;
+    };
+
+    values.first().and_then(finite)
+}
+
+fn finite(value: &Rational) -> Option<f64> {
+    Some(value.to_f64()).filter(|v| v.is_finite())
+}
+
+fn round_to_tenth(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+/// Write an exposure time as cameras show it: `1/250` or `2.5`
+fn shutter_speed(seconds: f64) -> String {
+    if seconds > 0.0 && seconds < 1.0 {
+        format!("1/{}", (1.0 / seconds).round())
+    } else {
+        round_to_tenth(seconds).to_string()
+    }
+}
+
+/// Join a make and a model, unless the model already names the make
+fn make_and_model(make: Option<String>, model: Option<String>) -> Option<String> {
+    match (make, model) {
+        (Some(make), Some(model)) => {
+            let has_make = model.to_lowercase().starts_with(&make.to_lowercase());
+            Some(if has_make {
+                model
+            } else {
+                format!("{} {}", make, model)
+            })
+        }
+        (make, model) => model.or(make),
+    }
+}
+
+/// When the photo was taken, assuming UTC when no offset is recorded
+fn taken_at(exif: &Exif) -> Option<OffsetDateTime> {
+    let Value::Ascii(values) = &field(exif, Tag::DateTimeOriginal)?.value else {
+        return None;
+    };
+    let mut datetime = exif::DateTime::from_ascii(values.first()?).ok()?;
+    if let Some(Field {
+        value: Value::Ascii(offsets),
+        ..
+    }) = field(exif, Tag::OffsetTimeOriginal)
+        && let Some(offset) = offsets.first()
+    {
+        // A blank or malformed offset leaves it unset
+        let _ = datetime.parse_offset(offset);
+    }
+
+    let date = Date::from_calendar_date(
+        i32::from(datetime.year),
+        Month::try_from(datetime.month).ok()?,
+        datetime.day,
+    )
+    .ok()?;
+    let time = Time::from_hms(datetime.hour, datetime.minute, datetime.second).ok()?;
+    let offset = match datetime.offset {
+        Some(minutes) => UtcOffset::from_whole_seconds(i32::from(minutes) * 60).ok()?,
+        None => UtcOffset::UTC,
+    };
+
+    Some(PrimitiveDateTime::new(date, time).assume_offset(offset))
+}
+
+/// GPS position of the photo, if both coordinates are recorded
+fn location(exif: &Exif) -> Option<GeoLocation> {
+    let location = GeoLocation {
+        latitude: coordinate(exif, Tag::GPSLatitude, Tag::GPSLatitudeRef, "S")?,
+        longitude: coordinate(exif, Tag::GPSLongitude, Tag::GPSLongitudeRef, "W")?,
+    };
+
+    location.is_valid().then_some(location)
+}
+
+/// Convert a degrees, minutes, seconds coordinate to decimal degrees
+fn coordinate(exif: &Exif, tag: Tag, reference: Tag, negative: &str) -> Option<f64> {
+    let Value::Rational(values) = &field(exif, tag)?.value else {
+        return None;
+    };
+    let [degrees, minutes, seconds] = values.as_slice() else {
+        return None;
+    };
+    let value = finite(degrees)? + finite(minutes)? / 60.0 + finite(seconds)? / 3600.0;
+
+    let is_negative = text(exif, reference)?.eq_ignore_ascii_case(negative);
+    Some(if is_negative { -value } else { value })
+}
+
+fn byte_size(bytes: &[u8]) -> i64 {
+    i64::try_from(bytes.len()).unwrap_or(i64::MAX)
+}
+
+fn dimension(pixels: u32) -> i32 {
+    i32::try_from(pixels).unwrap_or(i32::MAX)
+}
@@ -0,0 +1,162 @@
+use async_trait::async_trait;
+use axum::http::{HeaderMap, HeaderValue, header};
+use s3::{Bucket, Region, creds::Credentials, error::S3Error};
+
+use crate::{
+    domain::media::{MediaContent, MediaHash, MediaStore, S3Options},
+    errors::{MediaError, Result},
+};
+
+/// Cache policy stored with every object, so the bucket (or a CDN in front
+/// of it) serves blobs with the same headers as `/media/{hash}`
+const OBJECT_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Content-addressed media store in an S3-compatible bucket
+///
+/// Blobs use the same sharded layout as the local store, under an optional
+/// key prefix:
+///
+/// ```text
+/// {prefix}/9f/9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08
+/// ```
+///
+/// Objects are uploaded with their content type and an immutable
+/// `Cache-Control`. With `presign_seconds` set, reads are redirected to
+/// presigned URLs so image traffic bypasses the API; otherwise they are
+/// proxied, which keeps the bucket private.
+pub struct S3MediaStore {
+    /// Bucket handle with region, endpoint and credentials
+    bucket: Box<Bucket>,
+
+    /// Key prefix, without leading or trailing slashes
+    prefix: String,
+
+    /// Lifetime of presigned read URLs, 0 to proxy reads
+    presign_seconds: u32,
+}
+
+impl S3MediaStore {
+    /// Create a store for the configured bucket
+    ///
+    /// No request is made: a missing bucket or wrong credentials surface on
+    /// the first read or write.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Validated bucket settings
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(S3MediaStore)` - The store
+    /// * `Err(MediaError::ObjectStorage)` - No credentials were given or found
+    ///   in the environment
+    pub fn new(options: &S3Options) -> Result<Self> {
+        let region = match &options.endpoint {
+            Some(endpoint) => Region::Custom {
+                region: options.region.clone(),
+                endpoint: endpoint.trim_end_matches('/').to_string(),
+            },
+            None => options
+                .region
+                .parse()
+                .map_err(S3Error::from)
+                .map_err(MediaError::from)?,
+        };
+
+        let credentials = Credentials::new(
+            options.access_key.as_deref(),
+            options.secret_key.as_deref(),
+            None,
+            None,
+            None,
+        )
+        .map_err(S3Error::from)
+        .map_err(MediaError::from)?;
+
+        let mut bucket =
+            Bucket::new(&options.bucket, region, credentials).map_err(MediaError::from)?;
+        if options.path_style {
+            bucket = bucket.with_path_style();
+        }
+
+        Ok(Self {
+            bucket,
+            prefix: options.prefix.trim_matches('/').to_string(),
+            presign_seconds: options.presign_seconds,
+        })
+    }
+
+    /// Object key of a blob
+    fn key(&self, hash: &MediaHash) -> String {
+        let shard = &hash.as_str()[..2];
+
+        if self.prefix.is_empty() {
+            format!("{}/{}", shard, hash)
+        } else {
+            format!("{}/{}/{}", self.prefix, shard, hash)
+        }
+    }
+}
+
+#[async_trait]
+impl MediaStore for S3MediaStore {
+    /// Upload a blob with its content type and an immutable `Cache-Control`
+    ///
+    /// A single PUT replaces the object atomically; uploading a hash twice
+    /// rewrites the same bytes.
+    async fn put(&self, hash: &MediaHash, bytes: &[u8], content_type: &str) -> Result<()> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static(OBJECT_CACHE_CONTROL),
+        );
+
+        self.bucket
+            .put_object_with_content_type_and_headers(
+                self.key(hash),
+                bytes,
+                content_type,
+                Some(headers),
+            )
+            .await
+            .map_err(MediaError::from)?;
+
+        Ok(())
+    }
+
+    async fn get(&self, hash: &MediaHash) -> Result<Option<Vec<u8>>> {
+        match self.bucket.get_object(self.key(hash)).await {
+            Ok(response) => Ok(Some(response.to_vec())),
+            Err(S3Error::HttpFailWithBody(404, _)) => Ok(None),
+            Err(e) => Err(MediaError::from(e).into()),
+        }
+    }
+
+    /// Delete an object; S3 reports success for missing keys as well
+    async fn delete(&self, hash: &MediaHash) -> Result<()> {
+        match self.bucket.delete_object(self.key(hash)).await {
+            Ok(_) | Err(S3Error::HttpFailWithBody(404, _)) => Ok(()),
+            Err(e) => Err(MediaError::from(e).into()),
+        }
+    }
+
+    /// Presign a GET for the object, unless reads are proxied
+    ///
+    /// Signing is local, no request is made.
+    async fn presigned_read(&self, hash: &MediaHash) -> Result<Option<MediaContent>> {
+        if self.presign_seconds == 0 {
+            return Ok(None);
+        }
+
+        let url = self
+            .bucket
+            .presign_get(self.key(hash), self.presign_seconds, None)
+            .await
+            .map_err(MediaError::from)?;
+
+        Ok(Some(MediaContent::Redirect {
+            url,
+            expires_in: self.presign_seconds,
+        }))
+    }
+}
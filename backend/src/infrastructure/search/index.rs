@@ -5,7 +5,7 @@ use crate::{
     config::Config,
     domain::{
         articles::Article,
-        search::{SearchHit, SearchService},
+        search::{ArticleDocument, SearchHit, SearchService},
     },
     errors::{Result, SearchError},
 };
@@ -48,7 +48,7 @@ pub enum ClientType {
 ///
 /// # Example
 ///
-/// ```rust
+/// ```rust,ignore
 /// use backend::infrastructure::search::index::MeiliSearchService;
 /// use backend::config::Config;
 ///
@@ -102,7 +102,7 @@ impl MeiliSearchService {
     ///
     /// # Example
     ///
-    /// ```rust
+    /// ```rust,ignore
     /// let config = Config::new()?;
     /// let service = MeiliSearchService::new(&config, "articles").await?;
     /// ```
@@ -135,99 +135,6 @@ impl MeiliSearchService {
         })
     }
 
-    /// Update or add an article to the search index
-    ///
-    /// This method performs an upsert operation: if a document with the same
-    /// ID already exists in the index, it will be updated; otherwise, a new
-    /// document will be added.
-    ///
-    /// # Arguments
-    ///
-    /// * `article` - The article entity to index
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - Article successfully indexed
-    /// * `Err(SomeError)` - Indexing operation failed
-    ///
-    /// # Behavior
-    ///
-    /// - Uses the admin client (requires write permissions)
-    /// - Waits for the indexing operation to complete before returning
-    /// - Uses "id" field as the primary key for documents
-    /// - Replaces existing documents with the same ID
-    ///
-    /// # Performance
-    ///
-    /// This method is synchronous - it waits for Meilisearch to finish
-    /// processing the document. For bulk operations, consider batching
-    /// multiple documents together.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// let article = Article { /* ... */ };
-    /// service.update_or_add_index_item(&article).await?;
-    /// ```
-    pub async fn update_or_add_index_item(&self, article: &Article) -> Result<()> {
-        let client = &self.admin_client;
-        let index = client.index(&self.index_name);
-
-        // Add or replace the document in the index, using "id" as primary key
-        // If a document with this ID exists, it will be replaced
-        index
-            .add_or_replace(&[article], Some("id"))
-            .await?
-            // Wait for Meilisearch to finish processing this task
-            // This ensures the document is immediately searchable
-            .wait_for_completion(&client, None, None)
-            .await?;
-
-        Ok(())
-    }
-
-    /// Delete an article from the search index
-    ///
-    /// Removes a document from the Meilisearch index by its ID. The article
-    /// will no longer appear in search results after this operation completes.
-    ///
-    /// # Arguments
-    ///
-    /// * `article` - The article entity to remove (only ID is used)
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - Article successfully deleted
-    /// * `Err(SomeError)` - Deletion operation failed
-    ///
-    /// # Behavior
-    ///
-    /// - Uses the admin client (requires delete permissions)
-    /// - Waits for the deletion to complete before returning
-    /// - If the document doesn't exist, the operation still succeeds (idempotent)
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// let article = Article { id: "article-123".to_string(), /* ... */ };
-    /// service.delete_index_item(&article).await?;
-    /// ```
-    pub async fn delete_index_item(&self, article: &Article) -> Result<()> {
-        let client = &self.admin_client;
-        let index = client.index(&self.index_name);
-
-        // Delete the document from the index by its ID
-        index
-            .delete_document(&article.id)
-            .await?
-            // Wait for Meilisearch to finish processing the deletion
-            // This ensures the document is immediately removed from search results
-            .wait_for_completion(&client, None, None)
-            .await?;
-
-        Ok(())
-    }
-
     /// Create a Meilisearch client with master key authentication
     ///
     /// This private method creates a client with full administrative privileges
@@ -286,6 +193,7 @@ enum StandardApiKey {
 ///
 /// * `Ok(Vec<Key>)` - List of all API keys
 /// * `Err(SomeError)` - Failed to retrieve keys
+#[allow(dead_code)]
 async fn get_api_keys(config: &Config) -> Result<Vec<Key>> {
     let client = MeiliSearchService::create_master_client(config)?;
     Ok(client.get_keys().await?.results)
@@ -353,6 +261,7 @@ async fn get_standard_key(client: &Client, key_type: StandardApiKey) -> Result<S
 ///
 /// This function is currently unused but kept for potential future use
 /// with custom API keys.
+#[allow(dead_code)]
 async fn get_custom_key(client: &Client, key_name: &str) -> Result<String> {
     // Fetch all API keys from Meilisearch
     let keys = client.get_keys().await?.results;
@@ -382,6 +291,7 @@ impl SearchService for MeiliSearchService {
     ///
     /// * `query` - Search query string
     /// * `index` - Name of the search index
+    /// * `language` - Primary language to filter by, if any
     /// * `current_page` - Page number (1-based indexing)
     /// * `limit` - Number of results per page
     ///
@@ -398,12 +308,14 @@ impl SearchService for MeiliSearchService {
     /// - Uses the search client (read-only permissions)
     /// - Ensures page number is at least 1
     /// - Calculates offset for pagination
+    /// - Filters on the `lang` attribute, keeping documents without one
     /// - Processes results with highlighting and cropping
     /// - Extracts formatted content with HTML highlighting
     async fn search(
         &self,
         query: &str,
         index: &str,
+        language: Option<&str>,
         current_page: usize,
         limit: usize,
     ) -> Result<(Vec<SearchHit>, usize, usize, usize)> {
@@ -415,8 +327,15 @@ impl SearchService for MeiliSearchService {
         // Calculate offset for pagination
         let offset = (current_page - 1) * limit;
 
-        let search_result = index
-            .search()
+        // The language is a validated primary subtag, so it can be quoted as is
+        let filter = language.map(|lang| format!("lang = \"{}\" OR lang IS NULL", lang));
+
+        let mut search = index.search();
+        if let Some(filter) = &filter {
+            search.with_filter(filter);
+        }
+
+        let search_result = search
             .with_query(query)
             .with_offset(offset)
             .with_limit(limit)
@@ -429,7 +348,7 @@ impl SearchService for MeiliSearchService {
 
         // 计算总命中数和总页数
         let total_hits = search_result.total_hits.unwrap_or(0);
-        let total_pages = (total_hits + limit - 1) / limit;
+        let total_pages = total_hits.div_ceil(limit);
 
         let results: Vec<SearchHit> = search_result
             .hits
@@ -437,7 +356,7 @@ impl SearchService for MeiliSearchService {
             .map(|r| {
                 // 创建一个默认的 SearchHit
                 let mut hit_result = SearchHit {
-                    id: r.result.id.clone(),
+                    id: r.result.id,
                     category: r.result.category.clone(),
                     title: r.result.title.clone(),
                     summary: String::new(),
@@ -470,20 +389,120 @@ impl SearchService for MeiliSearchService {
         &self,
         index: &str,
         searchable_attributes: &[&str],
+        filterable_attributes: &[&str],
     ) -> Result<&Client> {
         let client = &self.admin_client;
 
         client
             .create_index(index, Some("id"))
             .await?
-            .wait_for_completion(&client, None, None)
+            .wait_for_completion(client, None, None)
             .await?;
 
-        client
-            .index(index)
-            .set_filterable_attributes(searchable_attributes)
+        let index = client.index(index);
+        index
+            .set_searchable_attributes(searchable_attributes)
+            .await?;
+        index
+            .set_filterable_attributes(filterable_attributes)
             .await?;
 
         Ok(client)
     }
+
+    /// Update or add an article to the search index
+    ///
+    /// This method performs an upsert operation: if a document with the same
+    /// ID already exists in the index, it will be updated; otherwise, a new
+    /// document will be added.
+    ///
+    /// # Arguments
+    ///
+    /// * `article` - The article entity to index
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Article successfully indexed
+    /// * `Err(SomeError)` - Indexing operation failed
+    ///
+    /// # Behavior
+    ///
+    /// - Uses the admin client (requires write permissions)
+    /// - Waits for the indexing operation to complete before returning
+    /// - Uses "id" field as the primary key for documents
+    /// - Replaces existing documents with the same ID
+    ///
+    /// # Performance
+    ///
+    /// This method is synchronous - it waits for Meilisearch to finish
+    /// processing the document. For bulk operations, consider batching
+    /// multiple documents together.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let article = Article { /* ... */ };
+    /// service.update_or_add_index_item(&article).await?;
+    /// ```
+    async fn update_or_add_index_item(&self, article: &Article) -> Result<()> {
+        let client = &self.admin_client;
+        let index = client.index(&self.index_name);
+
+        // Add or replace the document in the index, using "id" as primary key
+        // If a document with this ID exists, it will be replaced
+        index
+            .add_or_replace(&[ArticleDocument::from(article)], Some("id"))
+            .await?
+            // Wait for Meilisearch to finish processing this task
+            // This ensures the document is immediately searchable
+            .wait_for_completion(client, None, None)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Delete an article from the search index
+    ///
+    /// Removes a document from the Meilisearch index by its ID. The article
+    /// will no longer appear in search results after this operation completes.
+    ///
+    /// # Arguments
+    ///
+    /// * `article` - The article entity to remove (only ID is used)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Article successfully deleted
+    /// * `Err(SomeError)` - Deletion operation failed
+    ///
+    /// # Behavior
+    ///
+    /// - Uses the admin client (requires delete permissions)
+    /// - Waits for the deletion to complete before returning
+    /// - If the document doesn't exist, the operation still succeeds (idempotent)
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let article = Article {
+    ///     id: "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap(),
+    ///     /* ... */
+    /// };
+    /// service.delete_index_item(&article).await?;
+    /// ```
+    async fn delete_index_item(&self, article: &Article) -> Result<()> {
+        let client = &self.admin_client;
+        let index = client.index(&self.index_name);
+
+        // Delete the document from the index by its ID
+        index
+            .delete_document(&article.id)
+            .await?
+            // Wait for Meilisearch to finish processing the deletion
+            // This ensures the document is immediately removed from search results
+            .wait_for_completion(client, None, None)
+            .await?;
+
+        Ok(())
+    }
 }
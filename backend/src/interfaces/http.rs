@@ -61,7 +61,7 @@
 //!
 //! # Example Usage
 //!
-//! ```rust
+//! ```rust,ignore
 //! use backend::interfaces::http::route::router;
 //! use backend::app_state::AppState;
 //! use std::sync::Arc;
@@ -1,6 +1,22 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use time::OffsetDateTime;
 
-use crate::domain::search::SearchHit;
+use crate::domain::{
+    articles::{Article, ArticleId, PostCategory, default_page},
+    authors::Author,
+    categories::Category,
+    collections::Collection,
+    exif::ExifMetadata,
+    gallery::GalleryImage,
+    ingestion::IngestionStatus,
+    links::{LinkEdge, LinkTarget},
+    media::{MediaAsset, MediaHash, MediaOptions},
+    micropub::MicropubProperties,
+    related::RelatedCandidate,
+    search::SearchHit,
+    toc::TocEntry,
+};
 
 /// Data Transfer Object for article list responses
 ///
@@ -14,6 +30,11 @@ use crate::domain::search::SearchHit;
 /// * `title` - Article title
 /// * `tags` - List of tags associated with the article
 /// * `summary` - Brief summary or excerpt of the article
+/// * `summary_generated` - Whether the summary was generated from the content
+/// * `word_count` - Number of words (CJK characters count individually)
+/// * `reading_minutes` - Estimated reading time in minutes
+/// * `pinned` - Whether the article is pinned
+/// * `authors` - Byline of the article
 ///
 /// # Serialization
 ///
@@ -24,10 +45,17 @@ use crate::domain::search::SearchHit;
 ///
 /// ```json
 /// {
-///   "id": "my-first-post",
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
 ///   "title": "My First Post",
 ///   "tags": ["rust", "programming"],
-///   "summary": "This is a brief introduction to Rust programming..."
+///   "summary": "This is a brief introduction to Rust programming...",
+///   "summary_generated": false,
+///   "word_count": 1250,
+///   "reading_minutes": 6,
+///   "pinned": false,
+///   "authors": [
+///     { "id": "jane", "name": "Jane Doe", "avatar": "https://example.com/jane.png" }
+///   ]
 /// }
 /// ```
 ///
@@ -37,16 +65,21 @@ use crate::domain::search::SearchHit;
 /// use backend::interfaces::http::dtos::PostResponse;
 ///
 /// let post = PostResponse {
-///     id: "article-123".to_string(),
+///     id: "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap(),
 ///     title: "Introduction to Rust".to_string(),
 ///     tags: vec!["rust".to_string(), "tutorial".to_string()],
 ///     summary: Some("Learn Rust basics...".to_string()),
+///     summary_generated: false,
+///     word_count: Some(1250),
+///     reading_minutes: Some(6),
+///     pinned: false,
+///     authors: vec![],
 /// };
 /// ```
 #[derive(Serialize, Deserialize)]
 pub struct PostResponse {
     /// Unique identifier of the article
-    pub id: String,
+    pub id: ArticleId,
 
     /// Article title displayed in lists and detail views
     pub title: String,
@@ -57,6 +90,118 @@ pub struct PostResponse {
     /// Brief summary or excerpt of the article content
     /// Used in list views instead of full content
     pub summary: Option<String>,
+
+    /// Whether `summary` was generated from the content, so the frontend can
+    /// style it differently from an author-written summary
+    pub summary_generated: bool,
+
+    /// Number of words, `null` until the article is next ingested
+    pub word_count: Option<i32>,
+
+    /// Estimated reading time in minutes, `null` until the article is next ingested
+    pub reading_minutes: Option<i32>,
+
+    /// Whether the article is pinned; pinned articles lead category listings
+    pub pinned: bool,
+
+    /// Authors of the article, in byline order (empty if unattributed)
+    pub authors: Vec<AuthorSummary>,
+}
+
+/// Author of a post as embedded in listings
+///
+/// A compact form of `domain::authors::Author`; the full profile is
+/// embedded in the detail response and served by `/authors/{id}`.
+///
+/// # Example JSON
+///
+/// ```json
+/// { "id": "jane", "name": "Jane Doe", "avatar": "https://example.com/jane.png" }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AuthorSummary {
+    /// Author id, used in `/authors/{id}`
+    pub id: String,
+
+    /// Display name, the id itself if the author has no profile
+    pub name: String,
+
+    /// Avatar image path or URL
+    pub avatar: Option<String>,
+}
+
+impl From<Author> for AuthorSummary {
+    fn from(author: Author) -> Self {
+        Self {
+            id: author.id,
+            name: author.name,
+            avatar: author.avatar,
+        }
+    }
+}
+
+/// Response of the `/authors/{id}` endpoint
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "jane",
+///   "name": "Jane Doe",
+///   "avatar": "https://example.com/jane.png",
+///   "bio": "Writes about Rust and distributed systems.",
+///   "links": [{ "name": "Website", "url": "https://jane.example.com" }],
+///   "posts": [
+///     { "id": "...", "title": "Ownership", "...": "..." }
+///   ]
+/// }
+/// ```
+#[derive(Serialize)]
+pub struct AuthorResponse {
+    /// The author profile
+    #[serde(flatten)]
+    pub author: Author,
+
+    /// One page of the author's posts, newest first
+    pub posts: Vec<PostResponse>,
+}
+
+/// Response of the `/collections/{name}` endpoint
+///
+/// `data` is the content of the collection file as pushed, either a list of
+/// entries or a single mapping, depending on the file.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "name": "links",
+///   "data": [
+///     { "name": "Jane's blog", "url": "https://jane.example.com" }
+///   ],
+///   "updated_at": "2025-12-14T09:00:00Z"
+/// }
+/// ```
+#[derive(Serialize, Debug)]
+pub struct CollectionResponse {
+    /// Name of the collection
+    pub name: String,
+
+    /// Entries of the collection
+    pub data: Value,
+
+    /// Timestamp when the collection was last pushed
+    pub updated_at: OffsetDateTime,
+}
+
+impl From<Collection> for CollectionResponse {
+    fn from(collection: Collection) -> Self {
+        Self {
+            name: collection.name,
+            data: collection.data,
+            updated_at: collection.updated_at,
+        }
+    }
 }
 
 /// Data Transfer Object for search results
@@ -177,4 +322,883 @@ pub struct SearchParams {
     /// Requested page number for pagination (1-based indexing)
     /// Defaults to 1 if not specified (handled by handler logic)
     pub page: usize,
+
+    /// Language to search, `all` for every language
+    /// Negotiated from `Accept-Language` when omitted
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+/// Data Transfer Object for category listings
+///
+/// Returned by the `/categories` endpoint so the frontend can build its
+/// navigation from configuration instead of a hardcoded list. Hidden
+/// categories are never exposed through this DTO.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "slug": "article",
+///   "display_name": "Articles",
+///   "description": "Long-form articles and tutorials",
+///   "sort_order": 0,
+///   "page_size": 20
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug)]
+pub struct CategoryResponse {
+    /// Slug used in URLs and the `category` query parameter
+    pub slug: PostCategory,
+
+    /// Human readable name for navigation
+    pub display_name: String,
+
+    /// Optional longer description of the category
+    pub description: Option<String>,
+
+    /// Position of the category in navigation (ascending)
+    pub sort_order: i32,
+
+    /// Default number of posts per listing page
+    pub page_size: i64,
+}
+
+impl From<Category> for CategoryResponse {
+    fn from(category: Category) -> Self {
+        Self {
+            slug: category.slug,
+            display_name: category.display_name,
+            description: category.description,
+            sort_order: category.sort_order,
+            page_size: category.page_size,
+        }
+    }
+}
+
+/// Data Transfer Object for tag listings
+///
+/// Returned by the `/tags` endpoint. Tags are stored normalized, so `name`
+/// is the canonical spelling.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "name": "rust",
+///   "count": 12
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TagResponse {
+    /// Canonical tag name
+    pub name: String,
+
+    /// Number of articles carrying the tag
+    pub count: i64,
+}
+
+/// Pagination query parameters shared by listing endpoints
+///
+/// # Query String Format
+///
+/// ```text
+/// /tags/rust/posts?page=2&page_size=10&lang=en
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct PageParams {
+    /// Page number for pagination (1-based, defaults to 1)
+    #[serde(default = "default_page")]
+    pub page: i64,
+
+    /// Number of items per page (endpoint specific default when omitted)
+    #[serde(default)]
+    pub page_size: Option<i64>,
+
+    /// Language to list, `all` for every language (negotiated from
+    /// `Accept-Language` when omitted)
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+/// Query parameters of the `/reports` endpoint
+///
+/// # Query String Format
+///
+/// ```text
+/// /reports?status=failed&page=1&page_size=50
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ReportParams {
+    /// Only list reports with this outcome
+    #[serde(default)]
+    pub status: Option<IngestionStatus>,
+
+    /// Page number for pagination (1-based, defaults to 1)
+    #[serde(default = "default_page")]
+    pub page: i64,
+
+    /// Number of reports per page (defaults to 50)
+    #[serde(default)]
+    pub page_size: Option<i64>,
+}
+
+/// Query parameters of the `/featured` endpoint
+///
+/// # Query String Format
+///
+/// ```text
+/// /featured?limit=3
+/// ```
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct FeaturedParams {
+    /// Number of posts to return (defaults to 5, at most 20)
+    #[serde(default)]
+    pub limit: Option<i64>,
+}
+
+/// Data Transfer Object for the home page hero section
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Building Mizu",
+///   "category": "article",
+///   "summary": "How this blog is built...",
+///   "description": "A tour of the Rust backend",
+///   "cover_image": "/images/mizu.png",
+///   "tags": ["rust", "blog"],
+///   "featured_weight": 1,
+///   "created_at": "2025-11-01T08:00:00Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FeaturedPost {
+    /// Unique identifier of the article
+    pub id: ArticleId,
+
+    /// Article title
+    pub title: String,
+
+    /// Category slug, needed to build the article URL
+    pub category: PostCategory,
+
+    /// Brief summary of the article
+    pub summary: Option<String>,
+
+    /// Meta description from front matter
+    pub description: Option<String>,
+
+    /// Cover image path or URL
+    pub cover_image: Option<String>,
+
+    /// Normalized tags
+    pub tags: Vec<String>,
+
+    /// Order among featured articles, lower first
+    pub featured_weight: Option<i32>,
+
+    /// Timestamp when the article was created
+    pub created_at: OffsetDateTime,
+}
+
+/// Query parameters of the `/archives` endpoint
+///
+/// # Query String Format
+///
+/// ```text
+/// /archives?category=note
+/// ```
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ArchiveParams {
+    /// Only count posts of this category
+    #[serde(default)]
+    pub category: Option<PostCategory>,
+}
+
+/// Query parameters of the `/archives/{year}/{month}` endpoint
+///
+/// # Query String Format
+///
+/// ```text
+/// /archives/2025/11?category=note&page=2&page_size=10
+/// ```
+#[derive(Deserialize, Debug, Clone)]
+pub struct ArchivePostsParams {
+    /// Only list posts of this category
+    #[serde(default)]
+    pub category: Option<PostCategory>,
+
+    /// Page number for pagination (1-based, defaults to 1)
+    #[serde(default = "default_page")]
+    pub page: i64,
+
+    /// Number of posts per page (defaults to 20)
+    #[serde(default)]
+    pub page_size: Option<i64>,
+}
+
+/// Post count of one month in the archive
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ArchiveMonth {
+    /// Month of the year, 1 to 12
+    pub month: i32,
+
+    /// Number of published posts created in the month
+    pub count: i64,
+}
+
+/// Data Transfer Object for one year of the `/archives` endpoint
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "year": 2025,
+///   "count": 7,
+///   "months": [
+///     { "month": 11, "count": 4 },
+///     { "month": 3, "count": 3 }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ArchiveYear {
+    /// Calendar year
+    pub year: i32,
+
+    /// Number of published posts created in the year
+    pub count: i64,
+
+    /// Months with at least one post, newest first
+    pub months: Vec<ArchiveMonth>,
+}
+
+/// Data Transfer Object for one part of a series
+///
+/// Used both in `/series/{name}` listings and as the previous/next links of
+/// a [`SeriesNavigation`].
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Building a Blog, Part 2",
+///   "category": "article",
+///   "series_order": 2
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SeriesPart {
+    /// Unique identifier of the article
+    pub id: ArticleId,
+
+    /// Article title
+    pub title: String,
+
+    /// Category slug, needed to build the article URL
+    pub category: PostCategory,
+
+    /// Explicit position from front matter, if any
+    pub series_order: Option<i32>,
+}
+
+/// Data Transfer Object for the `/series/{name}` endpoint
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "name": "Building a Blog",
+///   "total": 3,
+///   "parts": [ { "id": "...", "title": "Part 1", "category": "article", "series_order": 1 } ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SeriesResponse {
+    /// Series name as written in front matter
+    pub name: String,
+
+    /// Number of parts in the series
+    pub total: usize,
+
+    /// Parts in reading order
+    pub parts: Vec<SeriesPart>,
+}
+
+/// Position of an article within its series
+///
+/// Embedded in [`PostDetailResponse`] so the frontend can render
+/// "Part 2 of 5" together with previous/next links.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SeriesNavigation {
+    /// Series name as written in front matter
+    pub name: String,
+
+    /// 1-based position of the current article
+    pub position: usize,
+
+    /// Number of parts in the series
+    pub total: usize,
+
+    /// Part before the current one, if any
+    pub previous: Option<SeriesPart>,
+
+    /// Part after the current one, if any
+    pub next: Option<SeriesPart>,
+}
+
+/// A neighbouring post within the same category
+///
+/// Used as the previous/next links of a [`CategoryNavigation`].
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Hello World",
+///   "category": "article",
+///   "created_at": "2025-11-01T08:00:00Z"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AdjacentPost {
+    /// Unique identifier of the article
+    pub id: ArticleId,
+
+    /// Article title
+    pub title: String,
+
+    /// Category slug, needed to build the article URL
+    pub category: PostCategory,
+
+    /// Timestamp when the article was created
+    pub created_at: OffsetDateTime,
+}
+
+/// Another language version of an article
+///
+/// Listed in the `translations` of a [`PostDetailResponse`], ordered by
+/// language.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "你好，世界",
+///   "category": "article",
+///   "language": "zh-CN"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Translation {
+    /// Unique identifier of the article
+    pub id: ArticleId,
+
+    /// Article title
+    pub title: String,
+
+    /// Category slug, needed to build the article URL
+    pub category: PostCategory,
+
+    /// Language tag of the translation, `null` if it has none
+    pub language: Option<String>,
+}
+
+/// Chronological neighbours of an article within its category
+///
+/// Embedded in [`PostDetailResponse`]. Only published posts are considered,
+/// ordered by `created_at` with the id breaking ties.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct CategoryNavigation {
+    /// The closest older post, if any
+    pub previous: Option<AdjacentPost>,
+
+    /// The closest newer post, if any
+    pub next: Option<AdjacentPost>,
+}
+
+/// Body representation requested from the article detail endpoint
+///
+/// The legacy `content` field (raw markdown) is always present; this selects
+/// which additional representations are added next to it.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentFormat {
+    /// No additional representation (default)
+    #[default]
+    None,
+
+    /// Add `content_markdown`
+    Markdown,
+
+    /// Add `content_html`
+    Html,
+
+    /// Add both `content_markdown` and `content_html`
+    Both,
+}
+
+impl ContentFormat {
+    /// Whether `content_markdown` should be included
+    pub fn includes_markdown(&self) -> bool {
+        matches!(self, ContentFormat::Markdown | ContentFormat::Both)
+    }
+
+    /// Whether `content_html` should be included
+    pub fn includes_html(&self) -> bool {
+        matches!(self, ContentFormat::Html | ContentFormat::Both)
+    }
+}
+
+/// Query parameters of the article detail endpoint
+///
+/// # Query String Format
+///
+/// ```text
+/// /posts/article/67e55044-10b1-426f-9247-bb680e5fe0c8?format=html
+/// ```
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct DetailParams {
+    /// Additional body representations to include
+    #[serde(default)]
+    pub format: ContentFormat,
+}
+
+/// Query parameters of the related posts endpoint
+///
+/// # Query String Format
+///
+/// ```text
+/// /posts/article/67e55044-10b1-426f-9247-bb680e5fe0c8/related?limit=3
+/// ```
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct RelatedParams {
+    /// Number of posts to return (defaults to 5, at most 20)
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+/// Data Transfer Object for related post recommendations
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Async Rust in Practice",
+///   "category": "article",
+///   "tags": ["rust", "async"],
+///   "summary": "Futures, executors and pinning...",
+///   "score": 7.5
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RelatedPost {
+    /// Unique identifier of the article
+    pub id: ArticleId,
+
+    /// Article title
+    pub title: String,
+
+    /// Category slug, needed to build the article URL
+    pub category: PostCategory,
+
+    /// Normalized tags
+    pub tags: Vec<String>,
+
+    /// Brief summary of the article
+    pub summary: Option<String>,
+
+    /// Relatedness score, higher is more related
+    pub score: f64,
+}
+
+impl From<(RelatedCandidate, f64)> for RelatedPost {
+    fn from((candidate, score): (RelatedCandidate, f64)) -> Self {
+        Self {
+            id: candidate.id,
+            title: candidate.title,
+            category: candidate.category,
+            tags: candidate.tags,
+            summary: candidate.summary,
+            score,
+        }
+    }
+}
+
+/// Data Transfer Object for the article detail endpoint
+///
+/// Serializes as the full article with additional navigation blocks next to
+/// the article fields. `content_markdown` and `content_html` are only present
+/// when requested through [`DetailParams`].
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Building a Blog, Part 2",
+///   "...": "...",
+///   "authors": [
+///     { "id": "jane", "name": "Jane Doe", "avatar": null, "bio": "...", "links": [] }
+///   ],
+///   "toc": [
+///     { "level": 2, "text": "Setup", "anchor": "setup", "children": [] }
+///   ],
+///   "series_navigation": {
+///     "name": "Building a Blog",
+///     "position": 2,
+///     "total": 3,
+///     "previous": { "id": "...", "title": "Part 1", "category": "article", "series_order": 1 },
+///     "next": { "id": "...", "title": "Part 3", "category": "article", "series_order": 3 }
+///   },
+///   "category_navigation": {
+///     "previous": { "id": "...", "title": "Hello World", "category": "article", "created_at": "..." },
+///     "next": null
+///   },
+///   "translations": [
+///     { "id": "...", "title": "搭建博客（二）", "category": "article", "language": "zh-CN" }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Debug)]
+pub struct PostDetailResponse {
+    /// The complete article
+    #[serde(flatten)]
+    pub article: Article,
+
+    /// Profiles of the authors, in byline order (empty if unattributed)
+    pub authors: Vec<Author>,
+
+    /// Heading tree of the article (empty if it has no headings)
+    pub toc: Vec<TocEntry>,
+
+    /// Series navigation, present only for articles that belong to a series
+    pub series_navigation: Option<SeriesNavigation>,
+
+    /// Previous and next published posts in the same category
+    pub category_navigation: CategoryNavigation,
+
+    /// Published versions of the article in other languages (empty if it
+    /// has no `translation_key` or no translations)
+    pub translations: Vec<Translation>,
+
+    /// Markdown source, present for `format=markdown` and `format=both`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_markdown: Option<String>,
+
+    /// Sanitized HTML, present for `format=html` and `format=both`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_html: Option<String>,
+}
+
+/// An article taking part in internal links
+///
+/// Returned by the `/backlinks` endpoint and as a node of the link graph.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Ownership",
+///   "category": "note",
+///   "url": "/note/67e55044-10b1-426f-9247-bb680e5fe0c8"
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LinkedArticle {
+    /// Unique identifier of the article
+    pub id: ArticleId,
+
+    /// Article title
+    pub title: String,
+
+    /// Category slug
+    pub category: PostCategory,
+
+    /// Canonical site URL of the article
+    pub url: String,
+}
+
+impl From<LinkTarget> for LinkedArticle {
+    fn from(target: LinkTarget) -> Self {
+        Self {
+            url: target.url(),
+            id: target.id,
+            title: target.title,
+            category: target.category,
+        }
+    }
+}
+
+/// Data Transfer Object for the `/graph` endpoint
+///
+/// The whole internal link graph, for a "digital garden" view. Every article
+/// is a node, including articles without links.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "nodes": [
+///     { "id": "67e5...", "title": "Ownership", "category": "note", "url": "/note/67e5..." },
+///     { "id": "9b1d...", "title": "Borrowing", "category": "note", "url": "/note/9b1d..." }
+///   ],
+///   "edges": [ { "source": "9b1d...", "target": "67e5..." } ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug)]
+pub struct GraphResponse {
+    /// Every article
+    pub nodes: Vec<LinkedArticle>,
+
+    /// Links between articles, from the linking to the linked article
+    pub edges: Vec<LinkEdge>,
+}
+
+/// Data Transfer Object for the `/gallery` endpoint
+///
+/// The images of a post, in the order declared in its front matter. Posts
+/// without an `images` field have an empty gallery. `location` is only
+/// filled from the photo for posts that set `keep_location`.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Kyoto in spring",
+///   "category": "pictures",
+///   "images": [
+///     {
+///       "src": "/api/media/3f0c8e4e1a9b2d7c6f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0918",
+///       "alt": "Vermilion torii gates climbing the hill",
+///       "caption": "Fushimi Inari at dawn",
+///       "width": 4000,
+///       "height": 3000,
+///       "taken_at": "2025-04-02T05:48:00+09:00",
+///       "camera": "FUJIFILM X100V",
+///       "lens": null,
+///       "exposure": { "shutter_speed": "1/250", "aperture": 2.8, "iso": 400, "focal_length": 23.0 },
+///       "location": null
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GalleryResponse {
+    /// Unique identifier of the post
+    pub id: ArticleId,
+
+    /// Post title
+    pub title: String,
+
+    /// Category slug
+    pub category: PostCategory,
+
+    /// Images in display order
+    pub images: Vec<GalleryImage>,
+}
+
+impl From<Article> for GalleryResponse {
+    fn from(article: Article) -> Self {
+        Self {
+            id: article.id,
+            title: article.title,
+            category: article.category,
+            images: article.images,
+        }
+    }
+}
+
+/// Data Transfer Object for the `/media/{hash}/info` endpoint
+///
+/// Describes an ingested image so the frontend can build a responsive
+/// `<picture>` with a `srcset` per format and paint the blurhash placeholder
+/// while the image loads.
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "hash": "3f0c8e4e1a9b2d7c6f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0918",
+///   "url": "/api/media/3f0c8e4e1a9b2d7c6f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0918",
+///   "content_type": "image/jpeg",
+///   "width": 4000,
+///   "height": 3000,
+///   "blurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj",
+///   "exif": {
+///     "camera": "FUJIFILM X100V",
+///     "lens": null,
+///     "exposure": { "shutter_speed": "1/250", "aperture": 2.8, "iso": 400, "focal_length": 23.0 },
+///     "taken_at": "2025-04-02T05:48:00+09:00",
+///     "location": null
+///   },
+///   "variants": [
+///     {
+///       "url": "/api/media/9b74c9897bac770ffc029102a200c5de5c5f6b0b4a1d0f2e3c4b5a6978695a4b",
+///       "content_type": "image/avif",
+///       "width": 480,
+///       "height": 360
+///     },
+///     {
+///       "url": "/api/media/0a1b2c3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8f9",
+///       "content_type": "image/webp",
+///       "width": 480,
+///       "height": 360
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MediaInfoResponse {
+    /// Content hash of the original image
+    pub hash: MediaHash,
+
+    /// URL of the original image
+    pub url: String,
+
+    /// MIME type of the original image
+    pub content_type: String,
+
+    /// Width of the original in pixels
+    pub width: i32,
+
+    /// Height of the original in pixels
+    pub height: i32,
+
+    /// Blurhash placeholder
+    pub blurhash: Option<String>,
+
+    /// Camera metadata left in the served file
+    pub exif: Option<ExifMetadata>,
+
+    /// Resized variants, by width then format
+    pub variants: Vec<MediaVariant>,
+}
+
+/// One resized variant of an image
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MediaVariant {
+    /// URL of the variant
+    pub url: String,
+
+    /// MIME type of the variant
+    pub content_type: String,
+
+    /// Width in pixels
+    pub width: i32,
+
+    /// Height in pixels
+    pub height: i32,
+}
+
+impl MediaInfoResponse {
+    /// Build the response for an original and its variants
+    pub fn new(original: MediaAsset, variants: Vec<MediaAsset>, options: &MediaOptions) -> Self {
+        Self {
+            url: options.url(&original.hash),
+            hash: original.hash,
+            content_type: original.content_type,
+            width: original.width,
+            height: original.height,
+            blurhash: original.blurhash,
+            exif: original.exif,
+            variants: variants
+                .into_iter()
+                .map(|v| MediaVariant {
+                    url: options.url(&v.hash),
+                    content_type: v.content_type,
+                    width: v.width,
+                    height: v.height,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Data Transfer Object for `GET /micropub?q=config`
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "syndicate-to": [],
+///   "post-types": [{ "type": "note", "name": "Talks" }]
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MicropubConfigResponse {
+    /// Syndication targets, always empty
+    #[serde(rename = "syndicate-to")]
+    pub syndicate_to: Vec<Value>,
+
+    /// Post types the endpoint can create
+    #[serde(rename = "post-types")]
+    pub post_types: Vec<MicropubPostType>,
+}
+
+/// A post type supported by the Micropub endpoint
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MicropubPostType {
+    /// Post type, as named by Post Type Discovery
+    #[serde(rename = "type")]
+    pub kind: String,
+
+    /// Name shown by clients
+    pub name: String,
+}
+
+impl MicropubConfigResponse {
+    /// Describe an endpoint creating notes under the given name
+    pub fn new(name: String) -> Self {
+        Self {
+            syndicate_to: Vec::new(),
+            post_types: vec![MicropubPostType {
+                kind: "note".to_string(),
+                name,
+            }],
+        }
+    }
+}
+
+/// Data Transfer Object for `GET /micropub?q=source&url={url}`
+///
+/// # Example JSON
+///
+/// ```json
+/// {
+///   "type": ["h-entry"],
+///   "properties": {
+///     "content": ["Finally tried the new coffee place"],
+///     "category": ["coffee"],
+///     "post-status": ["published"],
+///     "published": ["2025-12-09T08:30:00Z"]
+///   }
+/// }
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MicropubSourceResponse {
+    /// Microformats type, always `h-entry`
+    #[serde(rename = "type")]
+    pub kind: Vec<String>,
+
+    /// Properties as arrays of values
+    pub properties: Map<String, Value>,
+}
+
+impl From<&MicropubProperties> for MicropubSourceResponse {
+    fn from(properties: &MicropubProperties) -> Self {
+        Self {
+            kind: vec!["h-entry".to_string()],
+            properties: properties.to_map(),
+        }
+    }
 }
@@ -1,5 +1,16 @@
+pub mod archives;
 pub mod articles;
+pub mod authors;
+pub mod categories;
+pub mod collections;
+pub mod featured;
+pub mod graph;
+pub mod media;
+pub mod micropub;
 pub mod not_found;
+pub mod reports;
 pub mod search;
+pub mod series;
+pub mod tags;
 #[cfg(feature = "webhook")]
 pub mod webhook;
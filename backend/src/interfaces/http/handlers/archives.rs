@@ -0,0 +1,130 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use std::sync::Arc;
+
+use crate::{
+    app_state::AppState,
+    domain::archives::{ArchivePeriod, DEFAULT_ARCHIVE_PAGE_SIZE},
+    errors::Result,
+    interfaces::http::{
+        dtos::{ArchiveParams, ArchivePostsParams, ArchiveYear, PostResponse},
+        handlers::articles::MAX_PAGE_SIZE,
+    },
+};
+
+/// HTTP handler to count published posts per year and month
+///
+/// Powers the archive page and the writing calendar. Months are in UTC and
+/// only months with at least one post are listed.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /archives?category={category}
+/// ```
+///
+/// # Query Parameters
+///
+/// * `category` - Only count posts of this category (optional)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<ArchiveYear>>)` - Years with their months, both newest first
+/// * `Err(GetPostsError::CategoryError)` - Malformed category slug provided
+/// * `Err(GetPostsError::UnknownCategory)` - Category is not configured or hidden
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   {
+///     "year": 2025,
+///     "count": 7,
+///     "months": [
+///       { "month": 11, "count": 4 },
+///       { "month": 3, "count": 3 }
+///     ]
+///   },
+///   { "year": 2024, "count": 1, "months": [ { "month": 12, "count": 1 } ] }
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/archives?category=note"
+/// ```
+pub async fn get_archives(
+    Query(params): Query<ArchiveParams>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<ArchiveYear>>> {
+    if let Some(category) = &params.category {
+        state.article_service.get_listed_category(category).await?;
+    }
+
+    let archives = state
+        .article_service
+        .get_archives(params.category.as_ref())
+        .await?;
+
+    Ok(Json(archives))
+}
+
+/// HTTP handler to list the published posts created in a month
+///
+/// # Request Format
+///
+/// ```text
+/// GET /archives/{year}/{month}?category={category}&page={page}&page_size={size}
+/// ```
+///
+/// # Path Parameters
+///
+/// * `year` - Calendar year, e.g. `2025`
+/// * `month` - Month of the year, `1` to `12` (UTC)
+///
+/// # Query Parameters
+///
+/// * `category` - Only list posts of this category (optional)
+/// * `page` - Page number, 1-based indexing (optional, default: 1)
+/// * `page_size` - Number of items per page (optional, default: 20, max: 100)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<PostResponse>>)` - Posts of the month, newest first
+/// * `Err(GetPostsError::InvalidArchivePeriod)` - Year or month out of range (400)
+/// * `Err(GetPostsError::UnknownCategory)` - Category is not configured or hidden
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/archives/2025/11?page=1&page_size=20"
+/// ```
+pub async fn get_archive_posts(
+    Path((year, month)): Path<(i32, u8)>,
+    Query(params): Query<ArchivePostsParams>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<PostResponse>>> {
+    let period = ArchivePeriod::new(year, month)?;
+    if let Some(category) = &params.category {
+        state.article_service.get_listed_category(category).await?;
+    }
+
+    let page = params.page.max(1);
+    let page_size = params
+        .page_size
+        .unwrap_or(DEFAULT_ARCHIVE_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = (page - 1) * page_size;
+
+    let posts = state
+        .article_service
+        .get_archive_posts(period, params.category.as_ref(), page_size, offset)
+        .await?;
+
+    Ok(Json(posts))
+}
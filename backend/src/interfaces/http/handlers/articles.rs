@@ -1,49 +1,126 @@
 use axum::{
     Json,
-    extract::{Path, Query, State},
+    extract::{FromRequestParts, Query, RawPathParams, State},
+    http::{HeaderMap, HeaderName, header, request::Parts},
 };
 use std::sync::Arc;
 
 use crate::{
     app_state::AppState,
-    domain::articles::{Article, PostParams},
-    errors::Result,
-    interfaces::http::dtos::PostResponse,
+    domain::{
+        articles::{ArticleId, PostCategory, PostParams},
+        toc::TocEntry,
+    },
+    errors::{GetPostsError, Result, SomeError},
+    interfaces::http::dtos::{
+        DetailParams, GalleryResponse, LinkedArticle, PostDetailResponse, PostResponse,
+        RelatedParams, RelatedPost,
+    },
 };
 
 /// Maximum number of articles allowed per page
 ///
 /// This limit prevents excessive memory usage and database load from
 /// requests asking for too many results at once.
-const MAX_PAGE_SIZE: i64 = 100;
+pub(crate) const MAX_PAGE_SIZE: i64 = 100;
+
+/// Number of related posts returned when `limit` is omitted
+const DEFAULT_RELATED_LIMIT: usize = 5;
+
+/// `Vary` header of responses that depend on `Accept-Language`
+pub(crate) type VaryAcceptLanguage = [(HeaderName, &'static str); 1];
+
+/// Sent with every response negotiated with [`accept_language`], so caches
+/// keep one copy per language instead of serving the first one to everybody
+pub(crate) const VARY_ACCEPT_LANGUAGE: VaryAcceptLanguage = [(header::VARY, "Accept-Language")];
+
+/// Read the `Accept-Language` header of a request, if present and valid
+pub(crate) fn accept_language(headers: &HeaderMap) -> Option<&str> {
+    headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|value| value.to_str().ok())
+}
+
+/// Article addressed by the `{category}` and `{id}` segments of a post route
+///
+/// Both are validated while extracting, so a malformed id is answered with
+/// `INVALID_ARTICLE_ID` before the handler runs. `category` is `None` on
+/// routes that address the article by id alone.
+pub struct ArticlePath {
+    pub category: Option<PostCategory>,
+    pub id: ArticleId,
+}
+
+impl<S: Send + Sync> FromRequestParts<S> for ArticlePath {
+    type Rejection = SomeError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self> {
+        let params = RawPathParams::from_request_parts(parts, state)
+            .await
+            .map_err(anyhow::Error::new)?;
+
+        let mut category = None;
+        let mut id = None;
+        for (name, value) in &params {
+            match name {
+                "category" => {
+                    category = Some(
+                        value
+                            .parse::<PostCategory>()
+                            .map_err(|_| GetPostsError::CategoryError)?,
+                    )
+                }
+                "id" => id = Some(value.parse::<ArticleId>()?),
+                _ => {}
+            }
+        }
+
+        let id = id.ok_or_else(|| anyhow::anyhow!("Route has no `{{id}}` segment"))?;
+        Ok(Self { category, id })
+    }
+}
 
 /// HTTP handler to retrieve a paginated list of articles by category
 ///
 /// This endpoint returns articles filtered by category with pagination support.
-/// Results are ordered by creation date in descending order (newest first).
+/// Pinned articles come first, ordered by their `pinned_weight` (lower first),
+/// followed by the rest by creation date in descending order (newest first).
+///
+/// Listings are restricted to one language: the `lang` parameter, or else
+/// the best match for the `Accept-Language` header among the languages
+/// articles are written in. Articles without a language are always listed.
 ///
 /// # Request Format
 ///
 /// ```text
-/// GET /posts?category=article&page=1&page_size=20
+/// GET /posts?category=article&page=1&page_size=20&lang=en
+/// Accept-Language: zh-CN,zh;q=0.9,en;q=0.8
 /// ```
 ///
 /// # Query Parameters
 ///
 /// * `category` - Filter articles by category (required)
-///   - Valid values: "article", "note", "think", "pictures", "talk"
+///   - Must be a visible category configured in `config.toml`
+///     (defaults: "article", "note", "think", "pictures", "talk")
 /// * `page` - Page number, 1-based indexing (optional, default: 1)
-/// * `page_size` - Number of items per page (optional, default: 20, max: 100)
+/// * `page_size` - Number of items per page (optional, defaults to the
+///   category's configured `page_size`, max: 100)
+/// * `lang` - Language tag to list, matched by its primary language so `en`
+///   also lists `en-US` articles; `all` lists every language (optional)
 ///
 /// # Arguments
 ///
 /// * `Query(params)` - Query parameters extracted from the URL query string
+/// * `headers` - Request headers, for `Accept-Language`
 /// * `State(state)` - Shared application state containing services and configuration
 ///
 /// # Returns
 ///
-/// * `Ok(Json<Vec<PostResponse>>)` - JSON array of article summaries
-/// * `Err(GetPostsError::CategoryError)` - Invalid category provided
+/// * `Ok(Json<Vec<PostResponse>>)` - JSON array of article summaries, sent with
+///   `Vary: Accept-Language`
+/// * `Err(GetPostsError::CategoryError)` - Malformed category slug provided
+/// * `Err(GetPostsError::UnknownCategory)` - Category is not configured or hidden
+/// * `Err(GetPostsError::InvalidLanguage)` - `lang` is not a language tag (400)
 /// * `Err(SomeError)` - Database or other error occurred
 ///
 /// # Response Format
@@ -64,100 +141,443 @@ const MAX_PAGE_SIZE: i64 = 100;
 ///
 /// ```bash
 /// curl "http://localhost:8124/api/posts?category=article&page=2&page_size=10"
+/// curl "http://localhost:8124/api/posts?category=article" -H "Accept-Language: en"
 /// ```
 pub async fn get_posts(
     Query(params): Query<PostParams>,
+    headers: HeaderMap,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Vec<PostResponse>>> {
+) -> Result<(VaryAcceptLanguage, Json<Vec<PostResponse>>)> {
+    // Reject categories that are not configured (or hidden)
+    let category = state
+        .article_service
+        .get_listed_category(&params.category)
+        .await?;
+
     // Ensure page number is at least 1 (1-based indexing)
     let page = params.page.max(1);
 
-    // Clamp page size between 1 and MAX_PAGE_SIZE to prevent abuse
-    let page_size = params.page_size.min(MAX_PAGE_SIZE).max(1);
+    // Fall back to the category's page size, then clamp between 1 and
+    // MAX_PAGE_SIZE to prevent abuse
+    let page_size = params
+        .page_size
+        .unwrap_or(category.page_size)
+        .clamp(1, MAX_PAGE_SIZE);
 
     // Calculate database offset for pagination
     // Example: page 1 with size 20 → offset 0
     //          page 2 with size 20 → offset 20
     let offset = (page - 1) * page_size;
 
-    // Convert category enum to string for database query
-    let category = params.category.as_str();
+    // Explicit `lang` first, then `Accept-Language`
+    let language = state
+        .article_service
+        .resolve_language(params.lang.as_deref(), accept_language(&headers))
+        .await?;
 
     // Fetch articles from the service layer
     let query_results = state
         .article_service
-        .get_posts_by_category(category, page_size, offset)
+        .get_posts_by_category(
+            category.slug.as_str(),
+            language.as_deref(),
+            page_size,
+            offset,
+        )
         .await?;
 
     // Wrap the results in JSON response and return
-    Ok(Json(query_results))
+    Ok((VARY_ACCEPT_LANGUAGE, Json(query_results)))
 }
 
 /// HTTP handler to retrieve a single article by its ID
 ///
-/// This endpoint returns the complete article entity including full content.
-/// The category parameter in the URL is currently not used but kept for
+/// This endpoint returns the complete article entity including full content,
+/// its table of contents (`toc`), plus a `series_navigation` block for articles that belong to a series
+/// (`null` otherwise), a `category_navigation` block with the previous (older) and next (newer)
+/// published posts in the same category, and the published versions in other languages sharing
+/// its `translation_key` (`translations`). The category parameter in the URL is currently not used but kept for
 /// potential future category-based routing or validation.
 ///
 /// # Request Format
 ///
 /// ```text
-/// GET /posts/{category}/{id}
+/// GET /posts/{category}/{id}?format=html
 /// ```
 ///
 /// # Path Parameters
 ///
-/// * `category` - Article category (currently unused, reserved for future use)
-/// * `id` - Unique identifier of the article (required)
+/// * `category` - Article category, must be a well-formed slug (otherwise unused)
+/// * `id` - Unique identifier of the article, must be a valid UUID (required)
+///
+/// # Query Parameters
+///
+/// * `format` - Additional body representations (optional)
+///   - `markdown`: adds `content_markdown`
+///   - `html`: adds `content_html`, rendered and sanitized at ingestion
+///   - `both`: adds both fields
 ///
 /// # Arguments
 ///
-/// * `Path((category, id))` - Path parameters extracted from the URL
+/// * `ArticlePath { id, .. }` - Validated path parameters of the URL
+/// * `Query(params)` - Detail query parameters
 /// * `State(state)` - Shared application state containing services
 ///
 /// # Returns
 ///
-/// * `Ok(Json<Article>)` - Complete article entity with full content
+/// * `Ok(Json<PostDetailResponse>)` - Complete article with navigation blocks
+/// * `Err(GetPostsError::CategoryError)` - The category is not a well-formed slug (400)
+/// * `Err(GetPostsError::InvalidArticleId)` - The id is not a valid UUID (400)
 /// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
 /// * `Err(SomeError)` - Database or other error occurred
 ///
 /// # Response Format
 ///
+/// With `?format=html`:
+///
 /// ```json
 /// {
-///   "id": "article-1",
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
 ///   "title": "Introduction to Rust",
 ///   "tags": ["rust", "programming"],
 ///   "category": "article",
 ///   "summary": "A beginner's guide...",
 ///   "content": "# Introduction\n\nRust is...",
 ///   "status": "published",
+///   "series": "Learning Rust",
+///   "series_order": 1,
 ///   "created_at": "2024-01-15T10:30:00Z",
-///   "updated_at": "2024-01-15T10:30:00Z"
+///   "updated_at": "2024-01-15T10:30:00Z",
+///   "toc": [
+///     { "level": 1, "text": "Introduction", "anchor": "introduction", "children": [] }
+///   ],
+///   "series_navigation": {
+///     "name": "Learning Rust",
+///     "position": 1,
+///     "total": 3,
+///     "previous": null,
+///     "next": {
+///       "id": "a3bb189e-8bf9-3888-9912-ace4e6543002",
+///       "title": "Ownership and Borrowing",
+///       "category": "article",
+///       "series_order": 2
+///     }
+///   },
+///   "category_navigation": {
+///     "previous": {
+///       "id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+///       "title": "Why I Write",
+///       "category": "article",
+///       "created_at": "2024-01-10T09:00:00Z"
+///     },
+///     "next": null
+///   },
+///   "translations": [
+///     {
+///       "id": "1b4e28ba-2fa1-41d2-883f-0016d3cca427",
+///       "title": "Rust 入门",
+///       "category": "article",
+///       "language": "zh-CN"
+///     }
+///   ],
+///   "content_html": "<h1 id=\"introduction\">Introduction</h1>\n<p>Rust is...</p>"
 /// }
 /// ```
 ///
 /// # Example Request
 ///
 /// ```bash
-/// curl "http://localhost:8124/api/posts/article/my-first-post"
+/// curl "http://localhost:8124/api/posts/article/67e55044-10b1-426f-9247-bb680e5fe0c8"
 /// ```
 ///
 /// # Note
 ///
-/// The category path parameter is currently unused. It's kept in the signature
-/// for potential future use in category-based validation or routing logic.
+/// The category path parameter is only checked to be a well-formed slug. It's
+/// kept in the route for potential future category-based routing logic.
 pub async fn get_post_digital(
-    Path((_category, id)): Path<(String, String)>,
+    ArticlePath { id, .. }: ArticlePath,
+    Query(params): Query<DetailParams>,
     State(state): State<Arc<AppState>>,
-) -> Result<Json<Article>> {
-    // Fetch the complete article from the service layer
-    let result = state.article_service.get_article_by_id(&id).await?;
+) -> Result<Json<PostDetailResponse>> {
+    // Fetch the complete article and its navigation from the service layer
+    let result = state
+        .article_service
+        .get_article_detail(&id, params.format)
+        .await?;
 
     // Wrap the article in JSON response and return
     Ok(Json(result))
 }
 
+/// HTTP handler to retrieve the table of contents of an article
+///
+/// A lightweight alternative to the detail endpoint for rendering a sidebar
+/// TOC: only the heading tree extracted at ingestion is returned. Anchors
+/// match the heading ids in the rendered `content_html`.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /posts/{category}/{id}/toc
+/// ```
+///
+/// # Path Parameters
+///
+/// * `category` - Article category, must be a well-formed slug (otherwise unused)
+/// * `id` - Unique identifier of the article, must be a valid UUID (required)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<TocEntry>>)` - Top-level headings with nested children
+/// * `Err(GetPostsError::CategoryError)` - The category is not a well-formed slug (400)
+/// * `Err(GetPostsError::InvalidArticleId)` - The id is not a valid UUID (400)
+/// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   {
+///     "level": 2,
+///     "text": "安装",
+///     "anchor": "安装",
+///     "children": [
+///       { "level": 3, "text": "Linux", "anchor": "linux", "children": [] }
+///     ]
+///   },
+///   { "level": 2, "text": "Usage", "anchor": "usage", "children": [] }
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/posts/article/67e55044-10b1-426f-9247-bb680e5fe0c8/toc"
+/// ```
+pub async fn get_post_toc(
+    ArticlePath { id, .. }: ArticlePath,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<TocEntry>>> {
+    let toc = state.article_service.get_toc(&id).await?;
+
+    Ok(Json(toc))
+}
+
+/// HTTP handler to retrieve the gallery of a post
+///
+/// Returns the images a post (typically in the `pictures` category) declares
+/// in the `images` front matter field, so the gallery page doesn't have to
+/// scrape the markdown body.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /posts/{category}/{id}/gallery
+/// ```
+///
+/// # Path Parameters
+///
+/// * `category` - Article category, must be a well-formed slug (otherwise unused)
+/// * `id` - Unique identifier of the article, must be a valid UUID (required)
+///
+/// # Returns
+///
+/// * `Ok(Json<GalleryResponse>)` - The images in display order
+/// * `Err(GetPostsError::CategoryError)` - The category is not a well-formed slug (400)
+/// * `Err(GetPostsError::InvalidArticleId)` - The id is not a valid UUID (400)
+/// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// {
+///   "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///   "title": "Kyoto in spring",
+///   "category": "pictures",
+///   "images": [
+///     {
+///       "src": "/api/media/3f0c8e4e1a9b2d7c6f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0918",
+///       "alt": "Vermilion torii gates climbing the hill",
+///       "caption": "Fushimi Inari at dawn",
+///       "width": 4000,
+///       "height": 3000,
+///       "taken_at": "2025-04-02T05:48:00+09:00",
+///       "camera": "FUJIFILM X100V",
+///       "lens": null,
+///       "exposure": { "shutter_speed": "1/250", "aperture": 2.8, "iso": 400, "focal_length": 23.0 },
+///       "location": null
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/posts/pictures/67e55044-10b1-426f-9247-bb680e5fe0c8/gallery"
+/// ```
+pub async fn get_post_gallery(
+    ArticlePath { id, .. }: ArticlePath,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<GalleryResponse>> {
+    let gallery = state.article_service.get_gallery(&id).await?;
+
+    Ok(Json(gallery))
+}
+
+/// HTTP handler to list the articles linking to an article
+///
+/// # Request Format
+///
+/// ```text
+/// GET /posts/{category}/{id}/backlinks
+/// ```
+///
+/// # Path Parameters
+///
+/// * `category` - Article category, must be a well-formed slug (otherwise unused)
+/// * `id` - Unique identifier of the article, must be a valid UUID (required)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<LinkedArticle>>)` - Linking articles, newest first
+/// * `Err(GetPostsError::CategoryError)` - The category is not a well-formed slug (400)
+/// * `Err(GetPostsError::InvalidArticleId)` - The id is not a valid UUID (400)
+/// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   {
+///     "id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+///     "title": "Borrowing",
+///     "category": "note",
+///     "url": "/note/9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d"
+///   }
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/posts/note/67e55044-10b1-426f-9247-bb680e5fe0c8/backlinks"
+/// ```
+pub async fn get_post_backlinks(
+    ArticlePath { id, .. }: ArticlePath,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<LinkedArticle>>> {
+    let backlinks = state.article_service.get_backlinks(&id).await?;
+
+    Ok(Json(backlinks))
+}
+
+/// HTTP handler to recommend posts related to an article
+///
+/// Published posts are ranked by shared tags, same series, same category and
+/// similar title and summary. Results are cached per article until the
+/// published articles change.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /posts/{category}/{id}/related?limit={limit}
+/// ```
+///
+/// # Path Parameters
+///
+/// * `category` - Article category, must be a well-formed slug (otherwise unused)
+/// * `id` - Unique identifier of the article, must be a valid UUID (required)
+///
+/// # Query Parameters
+///
+/// * `limit` - Number of posts to return (optional, default: 5, max: 20)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<RelatedPost>>)` - Related posts, most related first
+/// * `Err(GetPostsError::CategoryError)` - The category is not a well-formed slug (400)
+/// * `Err(GetPostsError::InvalidArticleId)` - The id is not a valid UUID (400)
+/// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   {
+///     "id": "9b1deb4d-3b7d-4bad-9bdd-2b0d7b3dcb6d",
+///     "title": "Async Rust in Practice",
+///     "category": "article",
+///     "tags": ["rust", "async"],
+///     "summary": "Futures, executors and pinning...",
+///     "score": 7.5
+///   }
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/posts/article/67e55044-10b1-426f-9247-bb680e5fe0c8/related?limit=3"
+/// ```
+pub async fn get_related_posts(
+    ArticlePath { id, .. }: ArticlePath,
+    Query(params): Query<RelatedParams>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<RelatedPost>>> {
+    let limit = params.limit.unwrap_or(DEFAULT_RELATED_LIMIT);
+
+    let related = state.article_service.get_related_posts(&id, limit).await?;
+
+    Ok(Json(related))
+}
+
+/// HTTP handler to recommend posts related to an article given by id alone
+///
+/// Same as [`get_related_posts`] for clients that only know the article id.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /posts/{id}/related?limit={limit}
+/// ```
+///
+/// # Path Parameters
+///
+/// * `id` - Unique identifier of the article, must be a valid UUID (required)
+///
+/// # Query Parameters
+///
+/// * `limit` - Number of posts to return (optional, default: 5, max: 20)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<RelatedPost>>)` - Related posts, most related first
+/// * `Err(GetPostsError::InvalidArticleId)` - The id is not a valid UUID (400)
+/// * `Err(GetPostsError::ArticleNotFound)` - Article with given ID doesn't exist
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/posts/67e55044-10b1-426f-9247-bb680e5fe0c8/related?limit=3"
+/// ```
+pub async fn get_related_posts_by_id(
+    ArticlePath { id, .. }: ArticlePath,
+    Query(params): Query<RelatedParams>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<RelatedPost>>> {
+    let limit = params.limit.unwrap_or(DEFAULT_RELATED_LIMIT);
+
+    let related = state.article_service.get_related_posts(&id, limit).await?;
+
+    Ok(Json(related))
+}
+
 #[cfg(test)]
 mod tests {
 
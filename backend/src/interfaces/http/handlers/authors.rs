@@ -0,0 +1,94 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::HeaderMap,
+};
+use std::sync::Arc;
+
+use crate::{
+    app_state::AppState,
+    domain::authors::DEFAULT_AUTHOR_PAGE_SIZE,
+    errors::Result,
+    interfaces::http::{
+        dtos::{AuthorResponse, PageParams},
+        handlers::articles::{
+            MAX_PAGE_SIZE, VARY_ACCEPT_LANGUAGE, VaryAcceptLanguage, accept_language,
+        },
+    },
+};
+
+/// HTTP handler to retrieve an author profile with their posts
+///
+/// Profiles come from the authors data file of the content repository.
+/// Posts are listed newest first and, like `/posts`, restricted to the
+/// `lang` parameter or the best `Accept-Language` match.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /authors/{id}?page=1&page_size=20
+/// ```
+///
+/// # Path Parameters
+///
+/// * `id` - Author id as referenced from front matter
+///
+/// # Query Parameters
+///
+/// * `page` - Page number of the posts, 1-based indexing (optional, default: 1)
+/// * `page_size` - Number of posts per page (optional, default: 20, max: 100)
+/// * `lang` - Language to list, `all` for every language (optional)
+///
+/// # Returns
+///
+/// * `Ok(Json<AuthorResponse>)` - The profile with one page of posts, sent with
+///   `Vary: Accept-Language`
+/// * `Err(GetPostsError::AuthorNotFound)` - No profile has the id (404)
+/// * `Err(GetPostsError::InvalidLanguage)` - `lang` is not a language tag (400)
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// {
+///   "id": "jane",
+///   "name": "Jane Doe",
+///   "avatar": "https://example.com/jane.png",
+///   "bio": "Writes about Rust and distributed systems.",
+///   "links": [{ "name": "Website", "url": "https://jane.example.com" }],
+///   "posts": [
+///     { "id": "...", "title": "Ownership", "...": "...", "authors": [...] }
+///   ]
+/// }
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/authors/jane?page=2&page_size=10"
+/// ```
+pub async fn get_author(
+    Path(id): Path<String>,
+    Query(params): Query<PageParams>,
+    headers: HeaderMap,
+    State(state): State<Arc<AppState>>,
+) -> Result<(VaryAcceptLanguage, Json<AuthorResponse>)> {
+    let page = params.page.max(1);
+    let page_size = params
+        .page_size
+        .unwrap_or(DEFAULT_AUTHOR_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = (page - 1) * page_size;
+
+    let language = state
+        .article_service
+        .resolve_language(params.lang.as_deref(), accept_language(&headers))
+        .await?;
+
+    let author = state
+        .article_service
+        .get_author(&id, language.as_deref(), page_size, offset)
+        .await?;
+
+    Ok((VARY_ACCEPT_LANGUAGE, Json(author)))
+}
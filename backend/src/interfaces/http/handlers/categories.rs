@@ -0,0 +1,42 @@
+use axum::{Json, extract::State};
+use std::sync::Arc;
+
+use crate::{app_state::AppState, interfaces::http::dtos::CategoryResponse};
+
+/// HTTP handler to list the configured content categories
+///
+/// Returns every visible category in navigation order (by `sort_order`,
+/// then slug). Categories come from the `[[categories]]` section of
+/// `config.toml` and are reloaded when the config file changes.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /categories
+/// ```
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   {
+///     "slug": "article",
+///     "display_name": "Articles",
+///     "description": "Long-form articles and tutorials",
+///     "sort_order": 0,
+///     "page_size": 20
+///   },
+///   ...
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/categories"
+/// ```
+pub async fn get_categories(State(state): State<Arc<AppState>>) -> Json<Vec<CategoryResponse>> {
+    let categories = state.article_service.get_categories().await;
+
+    Json(categories.into_iter().map(CategoryResponse::from).collect())
+}
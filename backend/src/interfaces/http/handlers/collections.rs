@@ -0,0 +1,55 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use std::sync::Arc;
+
+use crate::{app_state::AppState, errors::Result, interfaces::http::dtos::CollectionResponse};
+
+/// HTTP handler to retrieve a data collection
+///
+/// Collections are declared with a schema under `[collections]` in
+/// `config.toml` and filled from the matching file of the content
+/// repository (e.g., `data/links.yaml`) on push.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /collections/{name}
+/// ```
+///
+/// # Path Parameters
+///
+/// * `name` - Name of the declared collection
+///
+/// # Returns
+///
+/// * `Ok(Json<CollectionResponse>)` - The stored entries of the collection
+/// * `Err(GetPostsError::CollectionNotFound)` - The collection is not declared or not pushed yet (404)
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// {
+///   "name": "links",
+///   "data": [
+///     { "name": "Jane's blog", "url": "https://jane.example.com", "avatar": null }
+///   ],
+///   "updated_at": "2025-12-14T09:00:00Z"
+/// }
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/collections/links"
+/// ```
+pub async fn get_collection(
+    Path(name): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<CollectionResponse>> {
+    let collection = state.article_service.get_collection(&name).await?;
+
+    Ok(Json(collection))
+}
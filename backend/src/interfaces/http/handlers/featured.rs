@@ -0,0 +1,75 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use std::sync::Arc;
+
+use crate::{
+    app_state::AppState,
+    errors::Result,
+    interfaces::http::dtos::{FeaturedParams, FeaturedPost},
+};
+
+/// Number of featured posts returned when `limit` is omitted
+const DEFAULT_FEATURED_LIMIT: i64 = 5;
+
+/// Maximum number of featured posts per request
+const MAX_FEATURED_LIMIT: i64 = 20;
+
+/// HTTP handler to list the featured posts for the home page hero section
+///
+/// Posts are featured with `featured: true` in their front matter and
+/// ordered by `featured_weight` (lower first, unweighted last), then newest
+/// first. Only published posts are listed.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /featured?limit={limit}
+/// ```
+///
+/// # Query Parameters
+///
+/// * `limit` - Number of posts to return (optional, default: 5, max: 20)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<FeaturedPost>>)` - Featured posts (may be empty)
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   {
+///     "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///     "title": "Building Mizu",
+///     "category": "article",
+///     "summary": "How this blog is built...",
+///     "description": "A tour of the Rust backend",
+///     "cover_image": "/images/mizu.png",
+///     "tags": ["rust", "blog"],
+///     "featured_weight": 1,
+///     "created_at": "2025-11-01T08:00:00Z"
+///   }
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/featured?limit=3"
+/// ```
+pub async fn get_featured(
+    Query(params): Query<FeaturedParams>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<FeaturedPost>>> {
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_FEATURED_LIMIT)
+        .clamp(1, MAX_FEATURED_LIMIT);
+
+    let featured = state.article_service.get_featured(limit).await?;
+
+    Ok(Json(featured))
+}
@@ -0,0 +1,38 @@
+use axum::{Json, extract::State};
+use std::sync::Arc;
+
+use crate::{app_state::AppState, errors::Result, interfaces::http::dtos::GraphResponse};
+
+/// HTTP handler to retrieve the internal link graph
+///
+/// Returns every article as a node and every resolved internal link as an
+/// edge, for rendering a "digital garden" view.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /graph
+/// ```
+///
+/// # Response Format
+///
+/// ```json
+/// {
+///   "nodes": [
+///     { "id": "67e5...", "title": "Ownership", "category": "note", "url": "/note/67e5..." },
+///     { "id": "9b1d...", "title": "Borrowing", "category": "note", "url": "/note/9b1d..." }
+///   ],
+///   "edges": [ { "source": "9b1d...", "target": "67e5..." } ]
+/// }
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/graph"
+/// ```
+pub async fn get_graph(State(state): State<Arc<AppState>>) -> Result<Json<GraphResponse>> {
+    let graph = state.article_service.get_link_graph().await?;
+
+    Ok(Json(graph))
+}
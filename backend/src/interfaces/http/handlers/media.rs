@@ -0,0 +1,188 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use std::sync::Arc;
+
+use crate::{
+    app_state::AppState,
+    domain::media::{MediaContent, MediaHash},
+    errors::Result,
+    interfaces::http::dtos::MediaInfoResponse,
+};
+
+/// Cache policy for media blobs
+///
+/// A hash always names the same bytes, so browsers and CDNs may keep a blob
+/// for a year without revalidating.
+const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// HTTP handler to serve an ingested image or one of its variants
+///
+/// Images referenced by posts are collected at ingestion and stored under
+/// the SHA-256 hash of their content; image URLs in the stored markdown and
+/// gallery point here.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /media/{hash}
+/// ```
+///
+/// # Path Parameters
+///
+/// * `hash` - Lowercase hex SHA-256 of the blob (required)
+///
+/// # Returns
+///
+/// * `Ok(Response)` - The blob with its content type and immutable caching
+///   headers; `304 Not Modified` if `If-None-Match` carries the blob's ETag;
+///   `307 Temporary Redirect` to a presigned URL if the media store is an S3
+///   bucket with presigned reads enabled
+/// * `Err(MediaError::InvalidHash)` - The hash is malformed (400)
+/// * `Err(MediaError::NotFound)` - No blob is stored under the hash (404)
+/// * `Err(SomeError)` - Database or storage error occurred
+///
+/// # Response Headers
+///
+/// ```text
+/// Content-Type: image/webp
+/// Cache-Control: public, max-age=31536000, immutable
+/// ETag: "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+/// X-Content-Type-Options: nosniff
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl -O "http://localhost:8124/api/media/9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+/// ```
+pub async fn get_media(
+    Path(hash): Path<String>,
+    State(state): State<Arc<AppState>>,
+    request_headers: HeaderMap,
+) -> Result<Response> {
+    let hash: MediaHash = hash.parse()?;
+    let etag = format!("\"{}\"", hash);
+
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::CACHE_CONTROL,
+        HeaderValue::from_static(IMMUTABLE_CACHE_CONTROL),
+    );
+    // A valid hash is plain hex, always a valid header value
+    if let Ok(value) = HeaderValue::from_str(&etag) {
+        headers.insert(header::ETAG, value);
+    }
+
+    // The ETag is the content hash, so a match needs no lookup
+    let is_cached = request_headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.split(',').any(|tag| tag.trim() == etag));
+    if is_cached {
+        return Ok((StatusCode::NOT_MODIFIED, headers).into_response());
+    }
+
+    let (asset, content) = state.article_service.get_media(&hash).await?;
+
+    let bytes = match content {
+        MediaContent::Bytes(bytes) => bytes,
+        MediaContent::Redirect { url, expires_in } => return Ok(redirect(&url, expires_in)),
+    };
+
+    if let Ok(value) = HeaderValue::from_str(&asset.content_type) {
+        headers.insert(header::CONTENT_TYPE, value);
+    }
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+
+    Ok((headers, bytes).into_response())
+}
+
+/// Redirect to a presigned URL
+///
+/// The redirect may be cached for half the URL's lifetime, so a cached
+/// redirect never points at an expired signature.
+fn redirect(url: &str, expires_in: u32) -> Response {
+    let mut headers = HeaderMap::new();
+    if let Ok(location) = HeaderValue::from_str(url) {
+        headers.insert(header::LOCATION, location);
+    }
+    if let Ok(value) = HeaderValue::from_str(&format!("private, max-age={}", expires_in / 2)) {
+        headers.insert(header::CACHE_CONTROL, value);
+    }
+
+    (StatusCode::TEMPORARY_REDIRECT, headers).into_response()
+}
+
+/// HTTP handler to describe an ingested image
+///
+/// Returns the dimensions, blurhash placeholder, camera metadata and
+/// responsive variants of an original image, for building a `<picture>`
+/// element with a `srcset`.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /media/{hash}/info
+/// ```
+///
+/// # Path Parameters
+///
+/// * `hash` - Lowercase hex SHA-256 of the original image (required)
+///
+/// # Returns
+///
+/// * `Ok(Json<MediaInfoResponse>)` - The image and its variants
+/// * `Err(MediaError::InvalidHash)` - The hash is malformed (400)
+/// * `Err(MediaError::NotFound)` - No original image is stored under the hash (404)
+/// * `Err(SomeError)` - Database error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// {
+///   "hash": "3f0c8e4e1a9b2d7c6f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0918",
+///   "url": "/api/media/3f0c8e4e1a9b2d7c6f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0918",
+///   "content_type": "image/jpeg",
+///   "width": 4000,
+///   "height": 3000,
+///   "blurhash": "LEHV6nWB2yk8pyo0adR*.7kCMdnj",
+///   "exif": {
+///     "camera": "FUJIFILM X100V",
+///     "lens": null,
+///     "exposure": { "shutter_speed": "1/250", "aperture": 2.8, "iso": 400, "focal_length": 23.0 },
+///     "taken_at": "2025-04-02T05:48:00+09:00",
+///     "location": null
+///   },
+///   "variants": [
+///     {
+///       "url": "/api/media/9b74c9897bac770ffc029102a200c5de5c5f6b0b4a1d0f2e3c4b5a6978695a4b",
+///       "content_type": "image/webp",
+///       "width": 480,
+///       "height": 360
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/media/3f0c8e4e1a9b2d7c6f5e4d3c2b1a09f8e7d6c5b4a39281706f5e4d3c2b1a0918/info"
+/// ```
+pub async fn get_media_info(
+    Path(hash): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<MediaInfoResponse>> {
+    let hash: MediaHash = hash.parse()?;
+
+    let info = state.article_service.get_media_info(&hash).await?;
+
+    Ok(Json(info))
+}
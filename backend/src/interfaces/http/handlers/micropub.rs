@@ -0,0 +1,190 @@
+use axum::{
+    Form, Json,
+    extract::{FromRequest, Query, Request, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+use crate::{
+    app_state::AppState,
+    domain::micropub::{MicropubOutcome, MicropubRequest},
+    errors::{MicropubError, Result},
+};
+
+/// Query parameters of `GET /micropub`
+#[derive(Debug, Deserialize)]
+pub struct MicropubQuery {
+    /// Query kind: `config`, `source` or `syndicate-to`
+    pub q: Option<String>,
+
+    /// Post URL, required for `q=source`
+    pub url: Option<String>,
+
+    /// Access token, for clients that can't send the `Authorization` header
+    pub access_token: Option<String>,
+}
+
+/// HTTP handler for Micropub queries
+///
+/// Lets clients discover what the endpoint supports and load a post for
+/// editing. Requires the configured access token.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /micropub?q=config
+/// GET /micropub?q=source&url={post_url}
+/// GET /micropub?q=syndicate-to
+/// Authorization: Bearer {token}
+/// ```
+///
+/// # Returns
+///
+/// * `Ok(Response)` - The endpoint configuration, the post as an `h-entry`
+///   (see `MicropubSourceResponse`), or the (empty) syndication targets
+/// * `Err(MicropubError::Unauthorized)` - No access token (401)
+/// * `Err(MicropubError::Forbidden)` - Wrong token or Micropub disabled (403)
+/// * `Err(MicropubError::InvalidRequest)` - Unknown query or post (400)
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/micropub?q=config" \
+///   -H "Authorization: Bearer $MICROPUB_TOKEN"
+/// ```
+pub async fn get_micropub(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(query): Query<MicropubQuery>,
+) -> Result<Response> {
+    let token = bearer_token(&headers).or(query.access_token.as_deref());
+    state.article_service.authorize_micropub(token)?;
+
+    match query.q.as_deref() {
+        Some("config") => {
+            Ok(Json(state.article_service.get_micropub_config().await).into_response())
+        }
+        Some("syndicate-to") => Ok(Json(json!({ "syndicate-to": [] })).into_response()),
+        Some("source") => {
+            let url = query
+                .url
+                .ok_or_else(|| MicropubError::InvalidRequest("`url` is required".to_string()))?;
+            let source = state.article_service.get_micropub_source(&url).await?;
+
+            Ok(Json(source).into_response())
+        }
+        Some(q) => Err(MicropubError::InvalidRequest(format!("unsupported query `{}`", q)).into()),
+        None => Err(MicropubError::InvalidRequest("`q` is required".to_string()).into()),
+    }
+}
+
+/// HTTP handler for Micropub create, update and delete requests
+///
+/// Short posts are published from Micropub clients (e.g. phone apps)
+/// instead of markdown files in the repository. They are created in the
+/// configured Micropub category and marked as API-authored; see
+/// `ArticleService::handle_micropub`.
+///
+/// # Request Format
+///
+/// Creating a post, form-encoded or JSON:
+///
+/// ```text
+/// POST /micropub
+/// Authorization: Bearer {token}
+/// Content-Type: application/x-www-form-urlencoded
+///
+/// h=entry&content=Finally+tried+the+new+coffee+place&category[]=coffee
+/// ```
+///
+/// ```text
+/// POST /micropub
+/// Authorization: Bearer {token}
+/// Content-Type: application/json
+///
+/// {"type": ["h-entry"], "properties": {"content": ["Finally tried the new coffee place"]}}
+/// ```
+///
+/// Updating (JSON only) and deleting a post:
+///
+/// ```text
+/// {"action": "update", "url": "{post_url}", "replace": {"content": ["Edited"]}}
+/// {"action": "delete", "url": "{post_url}"}
+/// ```
+///
+/// The token may also be sent as an `access_token` form field.
+///
+/// # Response Codes
+///
+/// - `201 Created` - Post created, its URL is in the `Location` header
+/// - `204 No Content` - Post updated or deleted
+/// - `400 Bad Request` - Malformed request or unknown post (`invalid_request`)
+/// - `401 Unauthorized` - No access token (`unauthorized`)
+/// - `403 Forbidden` - Wrong token, Micropub disabled, or a post from the
+///   repository (`forbidden`)
+///
+/// # Example Request
+///
+/// ```bash
+/// curl -i -X POST http://localhost:8124/api/micropub \
+///   -H "Authorization: Bearer $MICROPUB_TOKEN" \
+///   -d h=entry \
+///   -d "content=Finally tried the new coffee place" \
+///   -d "category[]=coffee"
+/// ```
+pub async fn post_micropub(
+    State(state): State<Arc<AppState>>,
+    request: Request,
+) -> Result<Response> {
+    let header_token = bearer_token(request.headers()).map(str::to_string);
+    let is_json = request
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+
+    let (micropub_request, form_token) = if is_json {
+        let Json(body) = Json::<Value>::from_request(request, &())
+            .await
+            .map_err(|e| MicropubError::InvalidRequest(e.body_text()))?;
+        (MicropubRequest::from_json(&body), None)
+    } else {
+        let Form(pairs) = Form::<Vec<(String, String)>>::from_request(request, &())
+            .await
+            .map_err(|e| MicropubError::InvalidRequest(e.body_text()))?;
+        let token = pairs
+            .iter()
+            .find(|(key, _)| key == "access_token")
+            .map(|(_, value)| value.clone());
+        (MicropubRequest::from_form(&pairs), token)
+    };
+
+    // Check the token before reporting problems with the properties
+    state
+        .article_service
+        .authorize_micropub(header_token.as_deref().or(form_token.as_deref()))?;
+
+    match state
+        .article_service
+        .handle_micropub(micropub_request?)
+        .await?
+    {
+        MicropubOutcome::Created { url } => {
+            Ok((StatusCode::CREATED, [(header::LOCATION, url)]).into_response())
+        }
+        MicropubOutcome::Updated | MicropubOutcome::Deleted => {
+            Ok(StatusCode::NO_CONTENT.into_response())
+        }
+    }
+}
+
+/// Token of an `Authorization: Bearer {token}` header
+pub(crate) fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let (scheme, token) = value.split_once(' ')?;
+
+    scheme.eq_ignore_ascii_case("bearer").then(|| token.trim())
+}
@@ -15,7 +15,7 @@ use axum::{http::StatusCode, response::IntoResponse};
 ///
 /// This handler is typically registered as a fallback route in the Axum router:
 ///
-/// ```rust
+/// ```rust,ignore
 /// use axum::Router;
 /// use backend::interfaces::http::handlers::not_found::handle_404;
 ///
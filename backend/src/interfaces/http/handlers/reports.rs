@@ -0,0 +1,98 @@
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::HeaderMap,
+};
+use std::sync::Arc;
+
+use crate::{
+    app_state::AppState,
+    domain::ingestion::IngestionReport,
+    errors::Result,
+    interfaces::http::{
+        dtos::ReportParams,
+        handlers::{articles::MAX_PAGE_SIZE, micropub::bearer_token},
+    },
+};
+
+/// Number of reports per page when `page_size` is omitted
+const DEFAULT_REPORT_PAGE_SIZE: i64 = 50;
+
+/// HTTP handler to list the ingestion reports of the content repository
+///
+/// One report is kept per source path and replaced on every push that
+/// touches the file, so this lists why a file is missing or incomplete.
+///
+/// Reports name draft files and quote parser errors, so they are only
+/// listed for the holder of the Micropub access token.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /reports?status=failed&page=1&page_size=50
+/// Authorization: Bearer {token}
+/// ```
+///
+/// # Query Parameters
+///
+/// * `status` - `ingested`, `ingested_with_warnings` or `failed` (optional)
+/// * `page` - Page number, 1-based indexing (optional, default: 1)
+/// * `page_size` - Number of reports per page (optional, default: 50, max: 100)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<IngestionReport>>)` - Reports, most recently ingested first
+/// * `Err(MicropubError::Unauthorized)` - No access token (401)
+/// * `Err(MicropubError::Forbidden)` - Wrong token, or none is configured (403)
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   {
+///     "path": "posts/ownership.md",
+///     "article_id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+///     "status": "failed",
+///     "issues": [
+///       {
+///         "severity": "error",
+///         "field": "title",
+///         "line": 3,
+///         "message": "invalid type: integer `42`, expected a string"
+///       }
+///     ],
+///     "ingested_at": "2025-11-16T08:00:00Z"
+///   }
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/reports?status=failed" \
+///   -H "Authorization: Bearer $MICROPUB_TOKEN"
+/// ```
+pub async fn get_reports(
+    Query(params): Query<ReportParams>,
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<IngestionReport>>> {
+    state
+        .article_service
+        .authorize_micropub(bearer_token(&headers))?;
+
+    let page = params.page.max(1);
+    let page_size = params
+        .page_size
+        .unwrap_or(DEFAULT_REPORT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = (page - 1) * page_size;
+
+    let reports = state
+        .article_service
+        .get_reports(params.status, page_size, offset)
+        .await?;
+
+    Ok(Json(reports))
+}
@@ -1,6 +1,7 @@
 use axum::{
     Json,
     extract::{Query, State},
+    http::HeaderMap,
 };
 use std::sync::Arc;
 
@@ -8,7 +9,10 @@ use crate::{
     app_state::AppState,
     domain::search::DEFAULT_SEARCH_INDEX,
     errors::Result,
-    interfaces::http::dtos::{SearchParams, SearchResponse},
+    interfaces::http::{
+        dtos::{SearchParams, SearchResponse},
+        handlers::articles::{VARY_ACCEPT_LANGUAGE, VaryAcceptLanguage, accept_language},
+    },
 };
 
 /// Number of search results to display per page
@@ -23,10 +27,13 @@ const PAGE_ITEMS: usize = 6;
 /// This endpoint performs full-text search across articles using Meilisearch.
 /// Results include highlighting and are paginated for optimal performance.
 ///
+/// Like the listings, searches are restricted to the `lang` parameter or the
+/// best `Accept-Language` match; articles without a language always match.
+///
 /// # Request Format
 ///
 /// ```text
-/// GET /search?q=rust+programming&page=1
+/// GET /search?q=rust+programming&page=1&lang=en
 /// ```
 ///
 /// # Query Parameters
@@ -35,15 +42,19 @@ const PAGE_ITEMS: usize = 6;
 ///   - Supports full-text search with relevance ranking
 ///   - Empty or whitespace-only queries return no results
 /// * `page` - Page number, 1-based indexing (optional, default: 1)
+/// * `lang` - Language to search, `all` for every language (optional)
 ///
 /// # Arguments
 ///
 /// * `State(state)` - Shared application state containing services
+/// * `headers` - Request headers, for `Accept-Language`
 /// * `Query(params)` - Query parameters extracted from the URL
 ///
 /// # Returns
 ///
-/// * `Ok(Json<SearchResponse>)` - Search results with metadata
+/// * `Ok(Json<SearchResponse>)` - Search results with metadata, sent with
+///   `Vary: Accept-Language`
+/// * `Err(GetPostsError::InvalidLanguage)` - `lang` is not a language tag (400)
 /// * `Err(SomeError)` - Search service or other error occurred
 ///
 /// # Response Format
@@ -72,31 +83,49 @@ const PAGE_ITEMS: usize = 6;
 /// ```
 pub async fn get_search_results(
     State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
     Query(params): Query<SearchParams>,
-) -> Result<Json<SearchResponse>> {
+) -> Result<(VaryAcceptLanguage, Json<SearchResponse>)> {
     // Return empty results for empty or whitespace-only queries
     // This prevents unnecessary search operations and maintains clean UX
     if params.q.trim().is_empty() {
-        return Ok(Json(SearchResponse {
-            total_hits: 0,
-            total_pages: 0,
-            current_page: params.page,
-            results: vec![],
-        }));
+        return Ok((
+            VARY_ACCEPT_LANGUAGE,
+            Json(SearchResponse {
+                total_hits: 0,
+                total_pages: 0,
+                current_page: params.page,
+                results: vec![],
+            }),
+        ));
     }
 
+    let language = state
+        .article_service
+        .resolve_language(params.lang.as_deref(), accept_language(&headers))
+        .await?;
+
     // Execute search through the article service
     // Returns results with highlighting, total counts, and pagination info
     let (results, total_hits, total_pages, current_page) = state
         .article_service
-        .search(DEFAULT_SEARCH_INDEX, &params.q, params.page, PAGE_ITEMS)
+        .search(
+            DEFAULT_SEARCH_INDEX,
+            &params.q,
+            language.as_deref(),
+            params.page,
+            PAGE_ITEMS,
+        )
         .await?;
 
     // Construct and return the JSON search response
-    Ok(Json(SearchResponse {
-        total_hits,
-        total_pages,
-        current_page,
-        results,
-    }))
+    Ok((
+        VARY_ACCEPT_LANGUAGE,
+        Json(SearchResponse {
+            total_hits,
+            total_pages,
+            current_page,
+            results,
+        }),
+    ))
 }
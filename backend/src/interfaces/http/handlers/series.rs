@@ -0,0 +1,55 @@
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use std::sync::Arc;
+
+use crate::{app_state::AppState, errors::Result, interfaces::http::dtos::SeriesResponse};
+
+/// HTTP handler to list the parts of a series in reading order
+///
+/// Parts are ordered by their `series_order` front matter field; parts
+/// without one follow in the order they were first published.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /series/{name}
+/// ```
+///
+/// # Path Parameters
+///
+/// * `name` - Series name exactly as written in front matter (URL-encoded)
+///
+/// # Returns
+///
+/// * `Ok(Json<SeriesResponse>)` - The series with its ordered parts
+/// * `Err(GetPostsError::SeriesNotFound)` - No article belongs to the series (404)
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Response Format
+///
+/// ```json
+/// {
+///   "name": "Learning Rust",
+///   "total": 2,
+///   "parts": [
+///     { "id": "...", "title": "Getting Started", "category": "article", "series_order": 1 },
+///     { "id": "...", "title": "Ownership and Borrowing", "category": "article", "series_order": 2 }
+///   ]
+/// }
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/series/Learning%20Rust"
+/// ```
+pub async fn get_series(
+    Path(name): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<SeriesResponse>> {
+    let series = state.article_service.get_series(&name).await?;
+
+    Ok(Json(series))
+}
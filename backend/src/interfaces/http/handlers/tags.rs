@@ -0,0 +1,108 @@
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+    http::HeaderMap,
+};
+use std::sync::Arc;
+
+use crate::{
+    app_state::AppState,
+    domain::tags::DEFAULT_TAG_PAGE_SIZE,
+    errors::Result,
+    interfaces::http::{
+        dtos::{PageParams, PostResponse, TagResponse},
+        handlers::articles::{
+            MAX_PAGE_SIZE, VARY_ACCEPT_LANGUAGE, VaryAcceptLanguage, accept_language,
+        },
+    },
+};
+
+/// HTTP handler to list every tag with its article count
+///
+/// Tags are ordered by article count (most used first), then by name.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /tags
+/// ```
+///
+/// # Response Format
+///
+/// ```json
+/// [
+///   { "name": "rust", "count": 12 },
+///   { "name": "web", "count": 4 }
+/// ]
+/// ```
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/tags"
+/// ```
+pub async fn get_tags(State(state): State<Arc<AppState>>) -> Result<Json<Vec<TagResponse>>> {
+    let tags = state.article_service.get_tags().await?;
+
+    Ok(Json(tags))
+}
+
+/// HTTP handler to retrieve a paginated list of articles carrying a tag
+///
+/// The tag in the path is normalized (case, whitespace, full-width
+/// characters) and resolved through the configured synonyms before
+/// querying, so `/tags/Rust-Lang/posts` lists posts tagged `rust`.
+///
+/// Like `/posts`, the listing is restricted to the `lang` parameter or the
+/// best `Accept-Language` match.
+///
+/// # Request Format
+///
+/// ```text
+/// GET /tags/{tag}/posts?page=1&page_size=20
+/// ```
+///
+/// # Query Parameters
+///
+/// * `page` - Page number, 1-based indexing (optional, default: 1)
+/// * `page_size` - Number of items per page (optional, default: 20, max: 100)
+/// * `lang` - Language to list, `all` for every language (optional)
+///
+/// # Returns
+///
+/// * `Ok(Json<Vec<PostResponse>>)` - JSON array of article summaries, sent with
+///   `Vary: Accept-Language`
+/// * `Err(GetPostsError::InvalidTag)` - Tag is empty after normalization (400)
+/// * `Err(GetPostsError::InvalidLanguage)` - `lang` is not a language tag (400)
+/// * `Err(SomeError)` - Database or other error occurred
+///
+/// # Example Request
+///
+/// ```bash
+/// curl "http://localhost:8124/api/tags/rust/posts?page=2&page_size=10"
+/// ```
+pub async fn get_posts_by_tag(
+    Path(tag): Path<String>,
+    Query(params): Query<PageParams>,
+    headers: HeaderMap,
+    State(state): State<Arc<AppState>>,
+) -> Result<(VaryAcceptLanguage, Json<Vec<PostResponse>>)> {
+    let page = params.page.max(1);
+    let page_size = params
+        .page_size
+        .unwrap_or(DEFAULT_TAG_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let offset = (page - 1) * page_size;
+
+    let language = state
+        .article_service
+        .resolve_language(params.lang.as_deref(), accept_language(&headers))
+        .await?;
+
+    let posts = state
+        .article_service
+        .get_posts_by_tag(&tag, language.as_deref(), page_size, offset)
+        .await?;
+
+    Ok((VARY_ACCEPT_LANGUAGE, Json(posts)))
+}
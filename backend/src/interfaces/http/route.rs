@@ -11,15 +11,49 @@ use crate::{app_state::AppState, interfaces::http::handlers::not_found};
 /// # Available Endpoints
 ///
 /// - `GET /api/search` - Full-text search across articles
+/// - `GET /api/categories` - List configured categories
+/// - `GET /api/tags` - List tags with article counts
+/// - `GET /api/tags/{tag}/posts` - List articles carrying a tag
+/// - `GET /api/series/{name}` - List the parts of a series in order
+/// - `GET /api/authors/{id}` - Get an author profile with their posts
+/// - `GET /api/collections/{name}` - Get a data collection from the content repository
+/// - `GET /api/archives` - Count posts per year and month
+/// - `GET /api/archives/{year}/{month}` - List the posts of a month
+/// - `GET /api/reports` - List the ingestion reports of the content repository (Micropub token)
+/// - `GET /api/featured` - List featured posts for the home page
 /// - `GET /api/posts` - List articles by category with pagination
 /// - `GET /api/posts/{category}/{id}` - Get a specific article by ID
+/// - `GET /api/posts/{category}/{id}/toc` - Get the table of contents of an article
+/// - `GET /api/posts/{category}/{id}/gallery` - Get the gallery images of a post
+/// - `GET /api/posts/{category}/{id}/backlinks` - List the articles linking to an article
+/// - `GET /api/posts/{category}/{id}/related` - Recommend related posts
+/// - `GET /api/posts/{id}/related` - Recommend related posts by article id alone
+/// - `GET /api/graph` - Get the internal link graph
+/// - `GET /api/media/{hash}` - Serve an ingested image or variant
+/// - `GET /api/media/{hash}/info` - Describe an image and its variants
+/// - `GET /api/micropub` - Micropub configuration and post source queries
+/// - `POST /api/micropub` - Create, update or delete talk posts through Micropub
 mod api {
     use super::*;
     use axum::routing::get;
 
     use crate::interfaces::http::handlers::{
-        articles::{get_post_digital, get_posts},
+        archives::{get_archive_posts, get_archives},
+        articles::{
+            get_post_backlinks, get_post_digital, get_post_gallery, get_post_toc, get_posts,
+            get_related_posts, get_related_posts_by_id,
+        },
+        authors::get_author,
+        categories::get_categories,
+        collections::get_collection,
+        featured::get_featured,
+        graph::get_graph,
+        media::{get_media, get_media_info},
+        micropub::{get_micropub, post_micropub},
+        reports::get_reports,
         search::get_search_results,
+        series::get_series,
+        tags::{get_posts_by_tag, get_tags},
     };
 
     /// Create the API router with all public endpoints
@@ -31,11 +65,51 @@ mod api {
     /// # Routes
     ///
     /// - **Search**
-    ///   - `GET /search?q={query}&page={page}` - Search articles
+    ///   - `GET /search?q={query}&page={page}&lang={lang}` - Search articles
+    ///
+    /// - **Categories**
+    ///   - `GET /categories` - List visible categories in navigation order
+    ///
+    /// - **Tags**
+    ///   - `GET /tags` - List tags with article counts
+    ///   - `GET /tags/{tag}/posts?page={page}&page_size={size}&lang={lang}` - List articles by tag
+    ///
+    /// - **Series**
+    ///   - `GET /series/{name}` - List the parts of a series in reading order
+    ///
+    /// - **Authors**
+    ///   - `GET /authors/{id}?page={page}&page_size={size}&lang={lang}` - Get a profile and its posts
+    ///
+    /// - **Collections**
+    ///   - `GET /collections/{name}` - Get a data collection (links, projects, ...)
+    ///
+    /// - **Archives**
+    ///   - `GET /archives?category={category}` - Count posts per year and month
+    ///   - `GET /archives/{year}/{month}?page={page}&page_size={size}` - List posts of a month
+    ///
+    /// - **Ingestion**
+    ///   - `GET /reports?status={status}&page={page}&page_size={size}` - List ingestion reports (requires the Micropub token)
     ///
     /// - **Articles**
-    ///   - `GET /posts?category={category}&page={page}&page_size={size}` - List articles
+    ///   - `GET /featured?limit={limit}` - List featured posts for the home page
+    ///   - `GET /posts?category={category}&page={page}&page_size={size}&lang={lang}` - List articles
     ///   - `GET /posts/{category}/{id}` - Get specific article
+    ///   - `GET /posts/{category}/{id}/toc` - Get the table of contents
+    ///   - `GET /posts/{category}/{id}/gallery` - Get the gallery images
+    ///   - `GET /posts/{category}/{id}/backlinks` - List linking articles
+    ///   - `GET /posts/{category}/{id}/related?limit={limit}` - Recommend related posts
+    ///   - `GET /posts/{id}/related?limit={limit}` - Same, by article id alone
+    ///
+    /// - **Links**
+    ///   - `GET /graph` - Get the internal link graph
+    ///
+    /// - **Media**
+    ///   - `GET /media/{hash}` - Serve an image or variant with immutable caching
+    ///   - `GET /media/{hash}/info` - Get dimensions, blurhash and variants
+    ///
+    /// - **Micropub** (requires the configured access token)
+    ///   - `GET /micropub?q={config|source|syndicate-to}` - Query the endpoint
+    ///   - `POST /micropub` - Create, update or delete a post
     ///
     /// # Returns
     ///
@@ -45,17 +119,73 @@ mod api {
     ///
     /// ```text
     /// GET /api/search?q=rust&page=1
+    /// GET /api/categories
+    /// GET /api/tags
+    /// GET /api/tags/rust/posts?page=1&page_size=20
+    /// GET /api/series/Learning%20Rust
+    /// GET /api/authors/jane?page=1
+    /// GET /api/collections/links
+    /// GET /api/archives?category=note
+    /// GET /api/archives/2025/11?page=1
+    /// GET /api/reports?status=failed
+    /// GET /api/featured?limit=3
     /// GET /api/posts?category=article&page=1&page_size=20
     /// GET /api/posts/article/my-first-post
+    /// GET /api/posts/article/my-first-post/toc
+    /// GET /api/posts/pictures/my-trip/gallery
+    /// GET /api/posts/article/my-first-post/backlinks
+    /// GET /api/posts/article/my-first-post/related?limit=3
+    /// GET /api/posts/67e55044-10b1-426f-9247-bb680e5fe0c8/related
+    /// GET /api/graph
+    /// GET /api/media/9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08
+    /// GET /api/media/9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08/info
+    /// GET /api/micropub?q=config
+    /// POST /api/micropub
     /// ```
     pub fn router() -> Router<Arc<AppState>> {
         axum::Router::new()
             // Search endpoint: Full-text search with highlighting
             .route("/search", get(get_search_results))
-            // List articles by category with pagination
+            // List configured categories for navigation
+            .route("/categories", get(get_categories))
+            // Tag taxonomy: counts and per-tag listings
+            .route("/tags", get(get_tags))
+            .route("/tags/{tag}/posts", get(get_posts_by_tag))
+            // Multi-part series in reading order
+            .route("/series/{name}", get(get_series))
+            // Author profiles from the content repository, with their posts
+            .route("/authors/{id}", get(get_author))
+            // Structured data files of the content repository
+            .route("/collections/{name}", get(get_collection))
+            // Date archive: monthly counts and per-month listings
+            .route("/archives", get(get_archives))
+            .route("/archives/{year}/{month}", get(get_archive_posts))
+            // Per-file outcome of the latest ingestion of each source path
+            .route("/reports", get(get_reports))
+            // Featured posts for the home page hero section
+            .route("/featured", get(get_featured))
+            // List articles by category with pagination (pinned first)
             .route("/posts", get(get_posts))
             // Get a single article by category and ID
             .route("/posts/{category}/{id}", get(get_post_digital))
+            // Table of contents only, for sidebar navigation
+            .route("/posts/{category}/{id}/toc", get(get_post_toc))
+            // Images declared in front matter, for gallery pages
+            .route("/posts/{category}/{id}/gallery", get(get_post_gallery))
+            // Articles linking here, resolved at ingestion
+            .route("/posts/{category}/{id}/backlinks", get(get_post_backlinks))
+            // Recommendations, cached until the published articles change
+            .route("/posts/{category}/{id}/related", get(get_related_posts))
+            // Same by id alone; article ids are UUIDs, so the static segment
+            // never hides a post
+            .route("/posts/{id}/related", get(get_related_posts_by_id))
+            // Whole internal link graph for a "digital garden" view
+            .route("/graph", get(get_graph))
+            // Content-addressed images collected at ingestion
+            .route("/media/{hash}", get(get_media))
+            .route("/media/{hash}/info", get(get_media_info))
+            // Micropub endpoint for short posts from phone apps
+            .route("/micropub", get(get_micropub).post(post_micropub))
     }
 }
 
@@ -134,8 +264,27 @@ mod webhook {
 /// /
 /// ├── /api/
 /// │   ├── /search                    (GET)
+/// │   ├── /categories                (GET)
+/// │   ├── /tags                      (GET)
+/// │   ├── /tags/{tag}/posts          (GET)
+/// │   ├── /series/{name}             (GET)
+/// │   ├── /authors/{id}              (GET)
+/// │   ├── /collections/{name}        (GET)
+/// │   ├── /archives                  (GET)
+/// │   ├── /archives/{year}/{month}   (GET)
+/// │   ├── /reports                   (GET)
+/// │   ├── /featured                  (GET)
 /// │   ├── /posts                     (GET)
 /// │   ├── /posts/{category}/{id}     (GET)
+/// │   ├── /posts/{category}/{id}/toc (GET)
+/// │   ├── /posts/{category}/{id}/gallery (GET)
+/// │   ├── /posts/{category}/{id}/backlinks (GET)
+/// │   ├── /posts/{category}/{id}/related (GET)
+/// │   ├── /posts/{id}/related        (GET)
+/// │   ├── /graph                     (GET)
+/// │   ├── /media/{hash}              (GET)
+/// │   ├── /media/{hash}/info         (GET)
+/// │   ├── /micropub                  (GET, POST)
 /// │   └── /webhook/github            (POST, webhook feature only)
 /// └── /* (fallback)                  (404 handler)
 /// ```
@@ -151,7 +300,7 @@ mod webhook {
 ///
 /// # Example Usage
 ///
-/// ```rust
+/// ```rust,ignore
 /// use backend::interfaces::http::route::router;
 /// use backend::app_state::AppState;
 /// use std::sync::Arc;
@@ -171,14 +320,12 @@ mod webhook {
 /// ```
 pub fn router() -> Router<Arc<AppState>> {
     // Start with the base API router (always available)
-    let mut api_router = api::router();
+    let api_router = api::router();
 
     // Conditionally merge webhook routes if feature is enabled
     // This allows the webhook functionality to be compiled out when not needed
     #[cfg(feature = "webhook")]
-    {
-        api_router = api_router.merge(webhook::router());
-    }
+    let api_router = api_router.merge(webhook::router());
 
     // Build the main router with:
     // 1. All API routes nested under /api prefix
@@ -3,18 +3,11 @@ use std::{net::SocketAddr, sync::Arc};
 use tracing::Level;
 use tracing_subscriber::FmtSubscriber;
 
-use crate::app_state::AppState;
-use crate::interfaces::http::route::router;
-
-// Module declarations
-// These tell the Rust compiler where to find the module definitions
-mod app_state;
-mod application;
-mod config; // Configuration management module
-mod domain;
-mod errors; // Custom error handling module
-mod infrastructure;
-mod interfaces;
+// The binary reuses the library crate's modules instead of re-declaring them,
+// so every module is compiled (and linted) exactly once
+use backend::app_state::AppState;
+use backend::config;
+use backend::interfaces::http::route::router;
 
 // Global memory allocator using MiMalloc
 // MiMalloc is a high-performance memory allocator that can significantly
@@ -149,13 +142,95 @@ async fn watch_config_file(state: Arc<AppState>) {
             Ok(event) => {
                 // Check if the event is a modification or creation
                 if event.kind.is_modify() || event.kind.is_create() {
-                    use crate::config::Config;
+                    use backend::config::Config;
+                    use backend::domain::{categories::CategoryRegistry, tags::TagNormalizer};
 
                     tracing::info!("Config file change detected, attempting to reload...");
 
                     // Attempt to reload the configuration
                     match Config::new() {
                         Ok(new_config) => {
+                            // Keep the current categories if the new ones are invalid
+                            match CategoryRegistry::new(new_config.categories) {
+                                Ok(categories) => {
+                                    *state.app_config.categories.write().await = categories;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid categories: {}", e);
+                                }
+                            }
+
+                            // Same for the tag synonyms
+                            match TagNormalizer::new(new_config.tag_synonyms) {
+                                Ok(tags) => {
+                                    *state.app_config.tags.write().await = tags;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid tag synonyms: {}", e);
+                                }
+                            }
+
+                            // And for the reading speeds
+                            match new_config.reading.validate() {
+                                Ok(reading) => {
+                                    *state.app_config.reading.write().await = reading;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid reading speeds: {}", e);
+                                }
+                            }
+
+                            match new_config.summary.validate() {
+                                Ok(summary) => {
+                                    *state.app_config.summary.write().await = summary;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid summary options: {}", e);
+                                }
+                            }
+
+                            // Checked against the categories in effect after the reload
+                            let categories = state.app_config.categories.read().await.clone();
+                            match new_config.micropub.validate(&categories) {
+                                Ok(micropub) => {
+                                    *state.app_config.micropub.write().await = micropub;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid micropub options: {}", e);
+                                }
+                            }
+
+                            match new_config.collections.validate() {
+                                Ok(collections) => {
+                                    *state.app_config.collections.write().await = collections;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid collection schemas: {}", e);
+                                }
+                            }
+
+                            match new_config.notebooks.validate() {
+                                Ok(notebooks) => {
+                                    *state.app_config.notebooks.write().await = notebooks;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid notebook limits: {}", e);
+                                }
+                            }
+
+                            // The media store was opened at startup, so its location is kept
+                            match new_config.media.validate() {
+                                Ok(mut media) => {
+                                    let mut current = state.app_config.media.write().await;
+                                    media.root = current.root.clone();
+                                    media.s3 = current.s3.clone();
+                                    *current = media;
+                                }
+                                Err(e) => {
+                                    tracing::error!("Ignoring invalid media options: {}", e);
+                                }
+                            }
+
                             // Update the allowed repositories in the application state
                             let mut config_writer =
                                 state.app_config.allowed_repositories.write().await;
@@ -2,16 +2,32 @@
 ///
 /// Provides Mock implementations and helper functions for testing
 use async_trait::async_trait;
-use backend::domain::articles::{Article, PostCategory};
-use backend::domain::repositories::{ArticleRepository, TransactionGuard};
+use backend::application::article_service::ArticleService;
+use backend::config::AppConfig;
+use backend::domain::archives::ArchiveMonthCount;
+use backend::domain::articles::{Article, ArticleId, ArticleSource, PostCategory};
+use backend::domain::authors::Author;
+use backend::domain::collections::Collection;
+use backend::domain::ingestion::{IngestionReport, IngestionStatus};
+use backend::domain::languages::primary_language;
+use backend::domain::links::{LinkEdge, LinkTarget};
+use backend::domain::media::{MediaAsset, MediaHash, MediaReference};
+use backend::domain::related::{ContentVersion, RelatedCandidate};
+use backend::domain::repositories::{ArticleRepository, TransactionGuard, TransactionOps};
 use backend::domain::search::{SearchHit, SearchService};
+use backend::domain::toc::TocEntry;
 use backend::errors::{GetPostsError, Result};
 #[cfg(feature = "webhook")]
 use backend::infrastructure::github::webhook::FileChange;
-use backend::interfaces::http::dtos::PostResponse;
+use backend::infrastructure::media::local::LocalMediaStore;
+use backend::interfaces::http::dtos::{
+    AdjacentPost, AuthorSummary, CategoryNavigation, FeaturedPost, PostResponse, SeriesPart,
+    TagResponse, Translation,
+};
 #[cfg(feature = "webhook")]
 use chrono::{DateTime, Utc};
 use meilisearch_sdk::client::Client;
+use sqlx::types::Uuid;
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, Mutex};
 use time::OffsetDateTime;
@@ -39,21 +55,68 @@ use backend::infrastructure::github::client::GithubClient;
 /// let repo = MockArticleRepository::with_articles(vec![article1, article2]);
 /// ```
 pub struct MockArticleRepository {
-    pub articles: Arc<Mutex<HashMap<String, Article>>>,
+    pub articles: Arc<Mutex<HashMap<ArticleId, Article>>>,
+    pub links: Arc<Mutex<Vec<LinkEdge>>>,
+    pub media: Arc<Mutex<HashMap<MediaHash, MediaAsset>>>,
+    pub media_references: Arc<Mutex<HashSet<MediaReference>>>,
+    pub authors: Arc<Mutex<Vec<Author>>>,
+    pub collections: Arc<Mutex<HashMap<String, Collection>>>,
+    pub reports: Arc<Mutex<Vec<IngestionReport>>>,
 }
 
 impl MockArticleRepository {
     pub fn new() -> Self {
         Self {
             articles: Arc::new(Mutex::new(HashMap::new())),
+            links: Arc::new(Mutex::new(Vec::new())),
+            media: Arc::new(Mutex::new(HashMap::new())),
+            media_references: Arc::new(Mutex::new(HashSet::new())),
+            authors: Arc::new(Mutex::new(Vec::new())),
+            collections: Arc::new(Mutex::new(HashMap::new())),
+            reports: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
+    #[allow(dead_code)]
+    pub fn add_author(&self, author: Author) {
+        self.authors.lock().unwrap().push(author);
+    }
+
+    #[allow(dead_code)]
+    pub fn add_report(&self, report: IngestionReport) {
+        self.reports.lock().unwrap().push(report);
+    }
+
+    #[allow(dead_code)]
+    pub fn add_collection(&self, collection: Collection) {
+        self.collections
+            .lock()
+            .unwrap()
+            .insert(collection.name.clone(), collection);
+    }
+
+    #[allow(dead_code)]
+    pub fn add_media(&self, asset: MediaAsset) {
+        self.media.lock().unwrap().insert(asset.hash.clone(), asset);
+    }
+
+    #[allow(dead_code)]
+    pub fn add_media_reference(&self, article: ArticleId, hash: MediaHash) {
+        self.media_references
+            .lock()
+            .unwrap()
+            .insert(MediaReference { article, hash });
+    }
+
+    pub fn add_link(&self, source: ArticleId, target: ArticleId) {
+        self.links.lock().unwrap().push(LinkEdge { source, target });
+    }
+
     pub fn with_articles(articles: Vec<Article>) -> Self {
         let repo = Self::new();
         let mut map = repo.articles.lock().unwrap();
         for article in articles {
-            map.insert(article.id.clone(), article);
+            map.insert(article.id, article);
         }
         drop(map);
         repo
@@ -65,64 +128,640 @@ impl ArticleRepository for MockArticleRepository {
     async fn get_posts_by_category(
         &self,
         category: &str,
+        language: Option<&str>,
         page_size: i64,
         offset: i64,
     ) -> Result<Vec<PostResponse>> {
         let articles = self.articles.lock().unwrap();
+        let authors = self.authors.lock().unwrap();
         let mut filtered: Vec<_> = articles
             .values()
-            .filter(|a| a.category.as_str() == category)
+            .filter(|a| a.category.as_str() == category && in_language(a, language))
             .collect();
 
-        // 按创建时间降序排序
-        filtered.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        // 置顶文章优先（按权重升序，无权重排最后），其余按创建时间降序排序
+        filtered.sort_by_key(|a| {
+            (
+                !a.pinned,
+                a.pinned_weight.is_none(),
+                a.pinned_weight,
+                std::cmp::Reverse(a.created_at),
+            )
+        });
 
         let results = filtered
             .iter()
             .skip(offset as usize)
             .take(page_size as usize)
-            .map(|a| PostResponse {
-                id: a.id.clone(),
+            .map(|a| post_response(a, &authors))
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn get_posts_by_tag(
+        &self,
+        tag: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let articles = self.articles.lock().unwrap();
+        let authors = self.authors.lock().unwrap();
+        let mut filtered: Vec<_> = articles
+            .values()
+            .filter(|a| {
+                a.tags.iter().any(|t| t == tag)
+                    && a.status == "published"
+                    && in_language(a, language)
+            })
+            .collect();
+
+        filtered.sort_by_key(|a| std::cmp::Reverse(a.created_at));
+
+        let results = filtered
+            .iter()
+            .skip(offset as usize)
+            .take(page_size as usize)
+            .map(|a| post_response(a, &authors))
+            .collect();
+
+        Ok(results)
+    }
+
+    async fn get_posts_by_author(
+        &self,
+        author: &str,
+        language: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let articles = self.articles.lock().unwrap();
+        let authors = self.authors.lock().unwrap();
+        let mut filtered: Vec<_> = articles
+            .values()
+            .filter(|a| {
+                a.authors.iter().any(|id| id == author)
+                    && a.status == "published"
+                    && in_language(a, language)
+            })
+            .collect();
+
+        filtered.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+
+        Ok(filtered
+            .iter()
+            .skip(offset as usize)
+            .take(page_size as usize)
+            .map(|a| post_response(a, &authors))
+            .collect())
+    }
+
+    async fn get_authors(&self) -> Result<Vec<Author>> {
+        let mut authors = self.authors.lock().unwrap().clone();
+        authors.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(authors)
+    }
+
+    async fn get_authors_by_ids(&self, ids: &[String]) -> Result<Vec<Author>> {
+        let authors = self.authors.lock().unwrap();
+        Ok(authors
+            .iter()
+            .filter(|a| ids.contains(&a.id))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_collection(&self, name: &str) -> Result<Option<Collection>> {
+        Ok(self.collections.lock().unwrap().get(name).cloned())
+    }
+
+    async fn get_featured(&self, limit: i64) -> Result<Vec<FeaturedPost>> {
+        let articles = self.articles.lock().unwrap();
+        let mut featured: Vec<_> = articles
+            .values()
+            .filter(|a| a.featured && a.status == "published")
+            .collect();
+
+        featured.sort_by_key(|a| {
+            (
+                a.featured_weight.is_none(),
+                a.featured_weight,
+                std::cmp::Reverse(a.created_at),
+                a.id,
+            )
+        });
+
+        Ok(featured
+            .into_iter()
+            .take(limit as usize)
+            .map(|a| FeaturedPost {
+                id: a.id,
                 title: a.title.clone(),
-                tags: a.tags.clone(),
+                category: a.category.clone(),
                 summary: a.summary.clone(),
+                description: a.description.clone(),
+                cover_image: a.cover_image.clone(),
+                tags: a.tags.clone(),
+                featured_weight: a.featured_weight,
+                created_at: a.created_at,
             })
+            .collect())
+    }
+
+    async fn get_tags(&self) -> Result<Vec<TagResponse>> {
+        let articles = self.articles.lock().unwrap();
+        let mut counts: HashMap<String, i64> = HashMap::new();
+        for tag in articles
+            .values()
+            .filter(|a| a.status == "published")
+            .flat_map(|a| a.tags.iter())
+        {
+            *counts.entry(tag.clone()).or_default() += 1;
+        }
+
+        let mut tags: Vec<_> = counts
+            .into_iter()
+            .map(|(name, count)| TagResponse { name, count })
             .collect();
+        tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
 
-        Ok(results)
+        Ok(tags)
+    }
+
+    async fn get_languages(&self) -> Result<Vec<String>> {
+        let articles = self.articles.lock().unwrap();
+        let mut languages: Vec<String> = articles
+            .values()
+            .filter(|a| a.status == "published")
+            .filter_map(|a| a.language.as_deref().and_then(primary_language))
+            .collect();
+        languages.sort();
+        languages.dedup();
+
+        Ok(languages)
+    }
+
+    async fn get_translations(&self, key: &str, id: &ArticleId) -> Result<Vec<Translation>> {
+        let articles = self.articles.lock().unwrap();
+        let mut translations: Vec<_> = articles
+            .values()
+            .filter(|a| {
+                a.translation_key.as_deref() == Some(key) && a.id != *id && a.status == "published"
+            })
+            .collect();
+
+        // language ASC NULLS LAST, id ASC
+        translations.sort_by_key(|a| (a.language.is_none(), a.language.clone(), a.id));
+
+        Ok(translations
+            .into_iter()
+            .map(|a| Translation {
+                id: a.id,
+                title: a.title.clone(),
+                category: a.category.clone(),
+                language: a.language.clone(),
+            })
+            .collect())
     }
 
-    async fn get_post_by_id(&self, id: &str) -> Result<Article> {
+    async fn get_series_parts(&self, series: &str) -> Result<Vec<SeriesPart>> {
+        let articles = self.articles.lock().unwrap();
+        let mut parts: Vec<_> = articles
+            .values()
+            .filter(|a| a.series.as_deref() == Some(series) && a.status == "published")
+            .collect();
+
+        // series_order ASC NULLS LAST, created_at ASC, id ASC
+        parts.sort_by_key(|a| (a.series_order.is_none(), a.series_order, a.created_at, a.id));
+
+        Ok(parts
+            .into_iter()
+            .map(|a| SeriesPart {
+                id: a.id,
+                title: a.title.clone(),
+                category: a.category.clone(),
+                series_order: a.series_order,
+            })
+            .collect())
+    }
+
+    async fn get_post_by_id(&self, id: &ArticleId) -> Result<Article> {
         let articles = self.articles.lock().unwrap();
         articles
             .get(id)
-            .map(|a| a.clone())
+            .cloned()
             .ok_or_else(|| GetPostsError::ArticleNotFound.into())
     }
 
     async fn get_all(&self) -> Result<Vec<Article>> {
         let articles = self.articles.lock().unwrap();
-        Ok(articles.values().map(|a| a.clone()).collect())
+        Ok(articles.values().cloned().collect())
+    }
+
+    async fn get_archive_counts(&self, category: Option<&str>) -> Result<Vec<ArchiveMonthCount>> {
+        let articles = self.articles.lock().unwrap();
+        let mut counts: HashMap<(i32, i32), i64> = HashMap::new();
+        for a in articles.values().filter(|a| {
+            a.status == "published" && category.is_none_or(|c| a.category.as_str() == c)
+        }) {
+            let date = a.created_at.to_offset(time::UtcOffset::UTC).date();
+            *counts
+                .entry((date.year(), i32::from(u8::from(date.month()))))
+                .or_default() += 1;
+        }
+
+        let mut counts: Vec<ArchiveMonthCount> = counts
+            .into_iter()
+            .map(|((year, month), count)| ArchiveMonthCount { year, month, count })
+            .collect();
+        counts.sort_by_key(|c| std::cmp::Reverse((c.year, c.month)));
+        Ok(counts)
     }
 
-    async fn get_by_paths(&self, paths: &[String]) -> Result<HashSet<String>> {
+    async fn get_posts_by_period(
+        &self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+        category: Option<&str>,
+        page_size: i64,
+        offset: i64,
+    ) -> Result<Vec<PostResponse>> {
+        let articles = self.articles.lock().unwrap();
+        let authors = self.authors.lock().unwrap();
+        let mut posts: Vec<&Article> = articles
+            .values()
+            .filter(|a| {
+                a.status == "published"
+                    && a.created_at >= start
+                    && a.created_at < end
+                    && category.is_none_or(|c| a.category.as_str() == c)
+            })
+            .collect();
+        posts.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(b.id.cmp(&a.id)));
+
+        Ok(posts
+            .into_iter()
+            .skip(offset as usize)
+            .take(page_size as usize)
+            .map(|a| post_response(a, &authors))
+            .collect())
+    }
+
+    async fn get_category_neighbours(
+        &self,
+        category: &PostCategory,
+        created_at: OffsetDateTime,
+        id: &ArticleId,
+    ) -> Result<CategoryNavigation> {
+        let articles = self.articles.lock().unwrap();
+        let mut published: Vec<&Article> = articles
+            .values()
+            .filter(|a| &a.category == category && a.status == "published")
+            .collect();
+        published.sort_by_key(|a| (a.created_at, a.id));
+
+        let key = (created_at, *id);
+        let adjacent = |a: &&Article| AdjacentPost {
+            id: a.id,
+            title: a.title.clone(),
+            category: a.category.clone(),
+            created_at: a.created_at,
+        };
+        Ok(CategoryNavigation {
+            previous: published
+                .iter()
+                .rev()
+                .find(|a| (a.created_at, a.id) < key)
+                .map(adjacent),
+            next: published
+                .iter()
+                .find(|a| (a.created_at, a.id) > key)
+                .map(adjacent),
+        })
+    }
+
+    async fn get_toc(&self, id: &ArticleId) -> Result<Option<Vec<TocEntry>>> {
+        let articles = self.articles.lock().unwrap();
+        articles
+            .get(id)
+            .map(|a| a.toc.clone())
+            .ok_or_else(|| GetPostsError::ArticleNotFound.into())
+    }
+
+    async fn get_media_asset(&self, hash: &MediaHash) -> Result<Option<MediaAsset>> {
+        Ok(self.media.lock().unwrap().get(hash).cloned())
+    }
+
+    async fn get_media_variants(&self, original: &MediaHash) -> Result<Vec<MediaAsset>> {
+        let media = self.media.lock().unwrap();
+        let mut variants: Vec<MediaAsset> = media
+            .values()
+            .filter(|m| m.original.as_ref() == Some(original))
+            .cloned()
+            .collect();
+        variants.sort_by(|a, b| {
+            a.width
+                .cmp(&b.width)
+                .then_with(|| a.content_type.cmp(&b.content_type))
+        });
+        Ok(variants)
+    }
+
+    async fn get_link_targets(&self) -> Result<Vec<LinkTarget>> {
+        let articles = self.articles.lock().unwrap();
+        let mut targets: Vec<LinkTarget> = articles.values().map(link_target).collect();
+        targets.sort_by(|a, b| a.title.cmp(&b.title).then(a.id.cmp(&b.id)));
+        Ok(targets)
+    }
+
+    async fn get_published_link_targets(&self) -> Result<Vec<LinkTarget>> {
+        let articles = self.articles.lock().unwrap();
+        let mut targets: Vec<LinkTarget> = articles
+            .values()
+            .filter(|a| a.status == "published")
+            .map(link_target)
+            .collect();
+        targets.sort_by(|a, b| a.title.cmp(&b.title).then(a.id.cmp(&b.id)));
+        Ok(targets)
+    }
+
+    async fn get_backlinks(&self, id: &ArticleId) -> Result<Vec<LinkTarget>> {
+        let articles = self.articles.lock().unwrap();
+        if !articles.contains_key(id) {
+            return Err(GetPostsError::ArticleNotFound.into());
+        }
+
+        let links = self.links.lock().unwrap();
+        let mut sources: Vec<&Article> = links
+            .iter()
+            .filter(|edge| edge.target == *id)
+            .filter_map(|edge| articles.get(&edge.source))
+            .filter(|a| a.status == "published")
+            .collect();
+        sources.sort_by(|a, b| b.created_at.cmp(&a.created_at).then(a.id.cmp(&b.id)));
+        Ok(sources.into_iter().map(link_target).collect())
+    }
+
+    async fn get_link_edges(&self) -> Result<Vec<LinkEdge>> {
+        let articles = self.articles.lock().unwrap();
+        let published = |id: &ArticleId| articles.get(id).is_some_and(|a| a.status == "published");
+        Ok(self
+            .links
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|edge| published(&edge.source) && published(&edge.target))
+            .cloned()
+            .collect())
+    }
+
+    async fn get_related_candidates(&self) -> Result<Vec<RelatedCandidate>> {
+        let articles = self.articles.lock().unwrap();
+        Ok(articles
+            .values()
+            .filter(|a| a.status == "published")
+            .map(|a| RelatedCandidate {
+                id: a.id,
+                title: a.title.clone(),
+                category: a.category.clone(),
+                tags: a.tags.clone(),
+                series: a.series.clone(),
+                summary: a.summary.clone(),
+                created_at: a.created_at,
+            })
+            .collect())
+    }
+
+    async fn get_content_version(&self) -> Result<ContentVersion> {
+        let articles = self.articles.lock().unwrap();
+        let published = articles.values().filter(|a| a.status == "published");
+        Ok(ContentVersion {
+            count: published.clone().count() as i64,
+            last_updated: published.map(|a| a.updated_at).max(),
+        })
+    }
+
+    async fn get_reports(
+        &self,
+        status: Option<IngestionStatus>,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<IngestionReport>> {
+        let reports = self.reports.lock().unwrap();
+        let mut filtered: Vec<_> = reports
+            .iter()
+            .filter(|r| status.is_none_or(|s| r.status == s))
+            .cloned()
+            .collect();
+
+        filtered.sort_by(|a, b| {
+            b.ingested_at
+                .cmp(&a.ingested_at)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+
+        Ok(filtered
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn get_article_authors(
+        &self,
+        ids: &[ArticleId],
+    ) -> Result<HashMap<ArticleId, Vec<String>>> {
+        let articles = self.articles.lock().unwrap();
+        Ok(ids
+            .iter()
+            .filter_map(|id| articles.get(id).map(|a| (*id, a.authors.clone())))
+            .collect())
+    }
+
+    async fn get_by_paths(&self, _paths: &[String]) -> Result<HashSet<ArticleId>> {
         todo!()
     }
 
     async fn begin_transaction(&self) -> Result<TransactionGuard> {
-        todo!()
+        Ok(TransactionGuard {
+            inner: Box::new(MockTransaction {
+                articles: self.articles.clone(),
+                media: self.media.clone(),
+                media_references: self.media_references.clone(),
+                upserted: Vec::new(),
+                deleted: HashSet::new(),
+            }),
+        })
+    }
+}
+
+/// Mock 事务：文章的写入和删除在提交时才生效
+///
+/// 只记录文章本身；链接、媒体、作者、数据集合和导入报告的写入被忽略。
+/// 未引用媒体的删除立即生效，媒体锁不做任何事。
+struct MockTransaction {
+    articles: Arc<Mutex<HashMap<ArticleId, Article>>>,
+    media: Arc<Mutex<HashMap<MediaHash, MediaAsset>>>,
+    media_references: Arc<Mutex<HashSet<MediaReference>>>,
+    upserted: Vec<Article>,
+    deleted: HashSet<ArticleId>,
+}
+
+#[async_trait]
+impl TransactionOps for MockTransaction {
+    async fn upsert_batch(&mut self, articles: &[Article]) -> Result<()> {
+        self.upserted.extend_from_slice(articles);
+        Ok(())
+    }
+
+    async fn delete_batch(&mut self, ids: &HashSet<ArticleId>) -> Result<()> {
+        self.deleted.extend(ids);
+        Ok(())
+    }
+
+    async fn upsert_reports(&mut self, _reports: &[IngestionReport]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_reports(&mut self, _paths: &[String]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn replace_links(&mut self, _sources: &[ArticleId], _edges: &[LinkEdge]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn insert_media(&mut self, _assets: &[MediaAsset]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn lock_media(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_unreferenced_media(&mut self) -> Result<Vec<MediaHash>> {
+        let references = self.media_references.lock().unwrap();
+        let mut media = self.media.lock().unwrap();
+
+        let orphans: HashSet<MediaHash> = media
+            .values()
+            .filter(|m| m.original.is_none())
+            .filter(|m| !references.iter().any(|r| r.hash == m.hash))
+            .map(|m| m.hash.clone())
+            .collect();
+        let mut deleted: Vec<MediaHash> = media
+            .values()
+            .filter(|m| {
+                orphans.contains(&m.hash)
+                    || m.original.as_ref().is_some_and(|o| orphans.contains(o))
+            })
+            .map(|m| m.hash.clone())
+            .collect();
+        deleted.sort();
+        media.retain(|hash, _| !deleted.contains(hash));
+
+        Ok(deleted)
+    }
+
+    async fn replace_media_references(
+        &mut self,
+        _sources: &[ArticleId],
+        _references: &[MediaReference],
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn replace_authors(&mut self, _authors: &[Author]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn upsert_collections(&mut self, _collections: &[Collection]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn delete_collections(&mut self, _paths: &[String]) -> Result<()> {
+        Ok(())
+    }
+
+    async fn commit(self: Box<Self>) -> Result<()> {
+        let mut articles = self.articles.lock().unwrap();
+        for article in self.upserted {
+            // 与数据库的 upsert 一致：更新时保留原有的创建时间
+            let created_at = articles
+                .get(&article.id)
+                .map_or(article.created_at, |a| a.created_at);
+            articles.insert(
+                article.id,
+                Article {
+                    created_at,
+                    ..article
+                },
+            );
+        }
+        articles.retain(|id, _| !self.deleted.contains(id));
+        Ok(())
+    }
+}
+
+/// 将文章转换为链接目标
+fn link_target(article: &Article) -> LinkTarget {
+    LinkTarget {
+        id: article.id,
+        path: article.path.clone(),
+        title: article.title.clone(),
+        category: article.category.clone(),
+    }
+}
+
+/// 将文章转换为列表项，作者与数据库的 `article_authors` 一致：
+/// 按署名顺序，没有资料的作者以 id 作为名字
+fn post_response(article: &Article, authors: &[Author]) -> PostResponse {
+    PostResponse {
+        id: article.id,
+        title: article.title.clone(),
+        tags: article.tags.clone(),
+        summary: article.summary.clone(),
+        summary_generated: article.summary_generated,
+        word_count: article.word_count,
+        reading_minutes: article.reading_minutes,
+        pinned: article.pinned,
+        authors: article
+            .authors
+            .iter()
+            .map(|id| {
+                authors
+                    .iter()
+                    .find(|a| &a.id == id)
+                    .cloned()
+                    .unwrap_or_else(|| Author::placeholder(id))
+                    .into()
+            })
+            .collect::<Vec<AuthorSummary>>(),
+    }
+}
+
+/// 与数据库的语言过滤一致：按主语言匹配，没有语言的文章总是匹配
+fn in_language(article: &Article, language: Option<&str>) -> bool {
+    match (language, article.language.as_deref()) {
+        (Some(language), Some(tag)) => primary_language(tag).as_deref() == Some(language),
+        _ => true,
     }
 }
 
 /// Mock 搜索服务实现
+///
+/// 单篇文章的索引和删除记录在 `documents` 中，重建整个索引总是失败。
 pub struct MockSearchService {
     pub search_results: Arc<Mutex<HashMap<String, Vec<SearchHit>>>>,
+    pub documents: Arc<Mutex<HashMap<ArticleId, Article>>>,
 }
 
 impl MockSearchService {
     pub fn new() -> Self {
         Self {
             search_results: Arc::new(Mutex::new(HashMap::new())),
+            documents: Arc::new(Mutex::new(HashMap::new())),
         }
     }
 
@@ -138,14 +777,15 @@ impl SearchService for MockSearchService {
         &self,
         query: &str,
         _index: &str,
+        _language: Option<&str>,
         current_page: usize,
         limit: usize,
     ) -> Result<(Vec<SearchHit>, usize, usize, usize)> {
         let results = self.search_results.lock().unwrap();
-        let hits = results.get(query).map(|v| v.clone()).unwrap_or_default();
+        let hits = results.get(query).cloned().unwrap_or_default();
 
         let total_hits = hits.len();
-        let total_pages = (total_hits + limit - 1) / limit;
+        let total_pages = total_hits.div_ceil(limit);
         let offset = (current_page - 1) * limit;
 
         let page_results = hits.into_iter().skip(offset).take(limit).collect();
@@ -157,9 +797,23 @@ impl SearchService for MockSearchService {
         &self,
         _index: &str,
         _searchable_attributes: &[&str],
+        _filterable_attributes: &[&str],
     ) -> Result<&Client> {
-        // Mock 实现不需要真实的 Client
-        unimplemented!("MockSearchService does not provide real Client")
+        // Mock 实现不提供真实的 Client，重建索引总是失败
+        Err(anyhow::anyhow!("MockSearchService does not provide real Client").into())
+    }
+
+    async fn update_or_add_index_item(&self, article: &Article) -> Result<()> {
+        self.documents
+            .lock()
+            .unwrap()
+            .insert(article.id, article.clone());
+        Ok(())
+    }
+
+    async fn delete_index_item(&self, article: &Article) -> Result<()> {
+        self.documents.lock().unwrap().remove(&article.id);
+        Ok(())
     }
 }
 
@@ -167,6 +821,9 @@ impl SearchService for MockSearchService {
 #[cfg(feature = "webhook")]
 pub struct MockGithubClient {
     pub file_contents: Arc<Mutex<HashMap<String, String>>>,
+    pub binary_files: Arc<Mutex<HashMap<String, Vec<u8>>>>,
+    /// 查询时返回错误的路径，模拟 GitHub API 暂时不可用
+    pub unreachable_files: Arc<Mutex<HashSet<String>>>,
 }
 
 #[cfg(feature = "webhook")]
@@ -174,36 +831,120 @@ impl MockGithubClient {
     pub fn new() -> Self {
         Self {
             file_contents: Arc::new(Mutex::new(HashMap::new())),
+            binary_files: Arc::new(Mutex::new(HashMap::new())),
+            unreachable_files: Arc::new(Mutex::new(HashSet::new())),
         }
     }
 
+    #[allow(dead_code)]
     pub fn set_file_content(&self, path: &str, content: String) {
         let mut map = self.file_contents.lock().unwrap();
         map.insert(path.to_string(), content);
     }
+
+    #[allow(dead_code)]
+    pub fn set_file_bytes(&self, path: &str, bytes: Vec<u8>) {
+        let mut map = self.binary_files.lock().unwrap();
+        map.insert(path.to_string(), bytes);
+    }
+
+    #[allow(dead_code)]
+    pub fn set_unreachable(&self, path: &str) {
+        self.unreachable_files
+            .lock()
+            .unwrap()
+            .insert(path.to_string());
+    }
 }
 
 #[cfg(feature = "webhook")]
 #[async_trait]
 impl GithubClient for MockGithubClient {
-    async fn get_file_content(&self, _owner: &str, _repo: &str, path: &str) -> Result<String> {
+    async fn get_file_content(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _commit: &str,
+        path: &str,
+    ) -> Result<String> {
         let contents = self.file_contents.lock().unwrap();
         contents
             .get(path)
-            .map(|s| s.clone())
+            .cloned()
             .ok_or_else(|| anyhow::anyhow!("File not found: {}", path).into())
     }
 
+    async fn file_exists(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _commit: &str,
+        path: &str,
+    ) -> Result<bool> {
+        if self.unreachable_files.lock().unwrap().contains(path) {
+            return Err(anyhow::anyhow!("GitHub API unavailable").into());
+        }
+        Ok(self.file_contents.lock().unwrap().contains_key(path)
+            || self.binary_files.lock().unwrap().contains_key(path))
+    }
+
+    async fn get_file_bytes(
+        &self,
+        _owner: &str,
+        _repo: &str,
+        _commit: &str,
+        path: &str,
+        max_bytes: usize,
+    ) -> Result<Vec<u8>> {
+        let files = self.binary_files.lock().unwrap();
+        let bytes = files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("File not found: {}", path))?;
+        if bytes.len() > max_bytes {
+            return Err(
+                anyhow::anyhow!("{} exceeds the limit of {} bytes", path, max_bytes).into(),
+            );
+        }
+        Ok(bytes)
+    }
+
     async fn fetch_files(
         &self,
-        owner: &str,
-        repo: &str,
+        _owner: &str,
+        _repo: &str,
+        _commit: &str,
         changes: &[FileChange],
     ) -> Vec<(DateTime<Utc>, Result<String>, String)> {
-        todo!()
+        let contents = self.file_contents.lock().unwrap();
+        changes
+            .iter()
+            .map(|change| {
+                let content = contents
+                    .get(&change.file_path)
+                    .cloned()
+                    .ok_or_else(|| anyhow::anyhow!("File not found: {}", change.file_path).into());
+                (change.timestamp, content, change.file_path.clone())
+            })
+            .collect()
     }
 }
 
+/// Build a deterministic article id for tests
+///
+/// Produces a valid UUID from a small integer so tests can refer to
+/// articles by number while still exercising the `ArticleId` type.
+///
+/// # Example
+///
+/// ```rust
+/// let id = test_article_id(1);
+/// assert_eq!(id.to_string(), "00000000-0000-0000-0000-000000000001");
+/// ```
+pub fn test_article_id(n: u128) -> ArticleId {
+    ArticleId::from_uuid(Uuid::from_u128(n))
+}
+
 /// Create a test article with the given parameters
 ///
 /// Helper function to quickly create article entities for testing.
@@ -226,25 +967,164 @@ impl GithubClient for MockGithubClient {
 /// # Example
 ///
 /// ```rust
-/// let article = create_test_article("test-1", "Test Article", PostCategory::Article);
-/// assert_eq!(article.id, "test-1");
+/// let article = create_test_article(test_article_id(1), "Test Article", test_category("article"));
+/// assert_eq!(article.id, test_article_id(1));
 /// assert_eq!(article.status, "published");
 /// ```
-pub fn create_test_article(id: &str, title: &str, category: PostCategory) -> Article {
+pub fn create_test_article(id: ArticleId, title: &str, category: PostCategory) -> Article {
     Article {
-        id: id.to_string(),
+        id,
         path: "example-path".to_string(),
         title: title.to_string(),
         tags: vec!["test".to_string(), "rust".to_string()],
         category,
         summary: Some(format!("Summary for {}", title)),
+        summary_generated: false,
         content: format!("Content for {}", title),
+        content_html: String::new(),
+        toc: None,
+        word_count: None,
+        reading_minutes: None,
         status: "published".to_string(),
+        series: None,
+        series_order: None,
+        description: None,
+        cover_image: None,
+        canonical_url: None,
+        language: None,
+        translation_key: None,
+        license: None,
+        author: None,
+        authors: Vec::new(),
+        pinned: false,
+        pinned_weight: None,
+        featured: false,
+        featured_weight: None,
+        images: Vec::new(),
+        keep_location: false,
+        source: ArticleSource::Git,
+        extra: Default::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
     }
 }
 
+/// Parse a category slug for use in tests
+///
+/// # Panics
+///
+/// Panics if `slug` is not a well-formed category slug.
+pub fn test_category(slug: &str) -> PostCategory {
+    slug.parse().expect("valid category slug")
+}
+
+/// Builder for test articles
+///
+/// Starts from [`create_test_article`] with the id `test_article_id(n)`, the
+/// title "Post {n}" and the "article" category; every setter overrides one
+/// field.
+///
+/// # Example
+///
+/// ```rust
+/// let article = ArticleBuilder::new(1).tags(&["rust"]).day(3).build();
+/// assert_eq!(article.title, "Post 1");
+/// ```
+pub struct ArticleBuilder {
+    article: Article,
+}
+
+#[allow(dead_code)]
+impl ArticleBuilder {
+    pub fn new(n: u128) -> Self {
+        Self {
+            article: create_test_article(
+                test_article_id(n),
+                &format!("Post {}", n),
+                test_category("article"),
+            ),
+        }
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.article.title = title.to_string();
+        self
+    }
+
+    pub fn category(mut self, slug: &str) -> Self {
+        self.article.category = test_category(slug);
+        self
+    }
+
+    pub fn tags(mut self, tags: &[&str]) -> Self {
+        self.article.tags = tags.iter().map(|t| t.to_string()).collect();
+        self
+    }
+
+    pub fn series(mut self, series: &str, order: Option<i32>) -> Self {
+        self.article.series = Some(series.to_string());
+        self.article.series_order = order;
+        self
+    }
+
+    pub fn language(mut self, language: &str) -> Self {
+        self.article.language = Some(language.to_string());
+        self
+    }
+
+    pub fn translation_key(mut self, key: &str) -> Self {
+        self.article.translation_key = Some(key.to_string());
+        self
+    }
+
+    pub fn authors(mut self, authors: &[&str]) -> Self {
+        self.article.authors = authors.iter().map(|a| a.to_string()).collect();
+        self
+    }
+
+    pub fn draft(mut self) -> Self {
+        self.article.status = "draft".to_string();
+        self
+    }
+
+    /// Created `day` days after the Unix epoch
+    pub fn day(self, day: i64) -> Self {
+        self.created_at(OffsetDateTime::UNIX_EPOCH + time::Duration::days(day))
+    }
+
+    pub fn created_at(mut self, created_at: OffsetDateTime) -> Self {
+        self.article.created_at = created_at;
+        self
+    }
+
+    pub fn build(self) -> Article {
+        self.article
+    }
+}
+
+/// Media store under a shared directory of the system's temp dir
+pub fn create_test_media_store() -> Arc<LocalMediaStore> {
+    Arc::new(LocalMediaStore::new(
+        std::env::temp_dir().join("mizu-test-media"),
+    ))
+}
+
+/// Create an article service over `repo` and `config`
+///
+/// The GitHub client and search service are empty mocks and media goes to
+/// [`create_test_media_store`]. Tests that need to prepare those build the
+/// service with `ArticleService::new` instead.
+pub fn create_service(repo: Arc<MockArticleRepository>, config: Arc<AppConfig>) -> ArticleService {
+    ArticleService::new(
+        repo,
+        #[cfg(feature = "webhook")]
+        Arc::new(MockGithubClient::new()),
+        Arc::new(MockSearchService::new()),
+        create_test_media_store(),
+        config,
+    )
+}
+
 /// Create a test search hit with the given parameters
 ///
 /// Helper function to quickly create search result entities for testing
@@ -264,15 +1144,15 @@ pub fn create_test_article(id: &str, title: &str, category: PostCategory) -> Art
 /// # Example
 ///
 /// ```rust
-/// let hit = create_test_search_hit("1", "Test Article");
-/// assert_eq!(hit.id, "1");
-/// assert_eq!(hit.category, PostCategory::Article);
+/// let hit = create_test_search_hit(test_article_id(1), "Test Article");
+/// assert_eq!(hit.id, test_article_id(1));
+/// assert_eq!(hit.category, test_category("article"));
 /// ```
-pub fn create_test_search_hit(id: &str, title: &str) -> SearchHit {
+pub fn create_test_search_hit(id: ArticleId, title: &str) -> SearchHit {
     SearchHit {
-        id: id.to_string(),
+        id,
         title: title.to_string(),
-        category: PostCategory::Article,
+        category: test_category("article"),
         summary: format!("Summary for {}", title),
         content: format!("Content for {}", title),
     }
@@ -285,11 +1165,13 @@ mod tests {
     #[tokio::test]
     async fn test_mock_search_service() {
         let service = MockSearchService::new();
-        let hit = create_test_search_hit("1", "Test Article");
+        let hit = create_test_search_hit(test_article_id(1), "Test Article");
         service.set_search_result("rust", vec![hit]);
 
-        let (results, total_hits, total_pages, current_page) =
-            service.search("rust", "articles", 1, 10).await.unwrap();
+        let (results, total_hits, total_pages, current_page) = service
+            .search("rust", "articles", None, 1, 10)
+            .await
+            .unwrap();
 
         assert_eq!(results.len(), 1);
         assert_eq!(total_hits, 1);
@@ -1,49 +1,85 @@
 //! Domain layer unit tests
 //! Tests domain models, enum conversions, and pure business logic
 
-use backend::domain::articles::{Article, PostCategory};
+use backend::domain::articles::{Article, ArticleId, ArticleSource, PostCategory};
+use backend::domain::asciidoc::convert_asciidoc;
+use backend::domain::authors::{
+    Author, AuthorLink, AuthorsData, CommitAuthor, authors_data_format, byline, is_author_id,
+    validate_authors,
+};
+use backend::domain::categories::{Category, CategoryRegistry, DEFAULT_CATEGORY_PAGE_SIZE};
+use backend::domain::collections::{
+    CollectionField, CollectionOptions, CollectionSchema, FieldType, is_collection_name,
+};
+use backend::domain::exif::{ExifMetadata, Exposure, GeoLocation};
+use backend::domain::front_matter::{FrontMatterFormat, key_lines, validate_front_matter};
+use backend::domain::gallery::GalleryImage;
+use backend::domain::ingestion::{
+    IngestionIssue, IngestionReport, IngestionStatus, IssueSeverity, dedupe_reports,
+};
+use backend::domain::languages::{
+    accept_language, is_language_tag, negotiate_language, primary_language,
+};
+use backend::domain::links::{BrokenLinkReason, LinkIndex, LinkTarget, is_internal_markdown_link};
+use backend::domain::media::{MediaHash, MediaOptions, VariantFormat};
+use backend::domain::micropub::{
+    MicropubOptions, MicropubProperties, MicropubRequest, MicropubUpdate, article_id_from_url,
+    token_matches,
+};
+use backend::domain::notebooks::{NotebookFrontMatter, NotebookOptions, convert_notebook};
+use backend::domain::org::convert_org;
+use backend::domain::reading::{ContentStats, ReadingSpeeds};
+use backend::domain::related::{
+    RelatedCandidate, SAME_CATEGORY_WEIGHT, SAME_SERIES_WEIGHT, SHARED_TAG_WEIGHT, related_score,
+};
+use backend::domain::search::ArticleDocument;
+use backend::domain::source_formats::{SourceFormat, header_value};
+use backend::domain::summary::{SummaryOptions, truncate_summary};
+use backend::domain::tags::{TagNormalizer, TagSynonym, normalize_tag};
+use backend::domain::toc::{TocEntry, build_toc};
+use backend::infrastructure::markdown::{
+    content_stats, extract_toc, generate_summary, image_sources, remove_images, render,
+    render_markdown, resolve_internal_links, rewrite_image_sources, slugify,
+};
+use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
 use time::OffsetDateTime;
 
+fn category(slug: &str) -> PostCategory {
+    PostCategory::from_str(slug).unwrap()
+}
+
 #[test]
 fn test_post_category_as_str() {
-    assert_eq!(PostCategory::Article.as_str(), "article");
-    assert_eq!(PostCategory::Note.as_str(), "note");
-    assert_eq!(PostCategory::Think.as_str(), "think");
-    assert_eq!(PostCategory::Pictures.as_str(), "pictures");
-    assert_eq!(PostCategory::Talk.as_str(), "talk");
+    assert_eq!(category("article").as_str(), "article");
+    assert_eq!(category("note").as_str(), "note");
+    assert_eq!(category("think").as_str(), "think");
+    assert_eq!(category("pictures").as_str(), "pictures");
+    assert_eq!(category("talk").as_str(), "talk");
 }
 
 #[test]
 fn test_post_category_from_str() {
-    assert_eq!(
-        PostCategory::from_str("article").unwrap(),
-        PostCategory::Article
-    );
-    assert_eq!(PostCategory::from_str("note").unwrap(), PostCategory::Note);
-    assert_eq!(
-        PostCategory::from_str("think").unwrap(),
-        PostCategory::Think
-    );
-    assert_eq!(
-        PostCategory::from_str("pictures").unwrap(),
-        PostCategory::Pictures
-    );
-    assert_eq!(PostCategory::from_str("talk").unwrap(), PostCategory::Talk);
+    // Any well-formed slug is accepted; the registry decides if it exists
+    assert_eq!(category("reading").as_str(), "reading");
+    assert_eq!(category("side-projects_2").as_str(), "side-projects_2");
 
     // Test invalid inputs
-    assert!(PostCategory::from_str("invalid").is_err());
+    assert!(PostCategory::from_str("").is_err());
     assert!(PostCategory::from_str("ARTICLE").is_err()); // Case-sensitive
+    assert!(PostCategory::from_str("with space").is_err());
+    assert!(PostCategory::from_str("文章").is_err());
+    assert!(PostCategory::from_str(&"a".repeat(65)).is_err());
 }
 
 #[test]
 fn test_post_category_roundtrip() {
-    let categories = vec![
-        PostCategory::Article,
-        PostCategory::Note,
-        PostCategory::Think,
-        PostCategory::Pictures,
-        PostCategory::Talk,
+    let categories = [
+        category("article"),
+        category("note"),
+        category("think"),
+        category("pictures"),
+        category("talk"),
     ];
 
     for category in categories {
@@ -58,34 +94,63 @@ fn test_post_category_serialization() {
     use serde_json;
 
     // Test serialization
-    let category = PostCategory::Article;
-    let json = serde_json::to_string(&category).unwrap();
+    let article = category("article");
+    let json = serde_json::to_string(&article).unwrap();
     assert_eq!(json, "\"article\"");
 
     // Test deserialization
     let deserialized: PostCategory = serde_json::from_str(&json).unwrap();
-    assert_eq!(deserialized, PostCategory::Article);
+    assert_eq!(deserialized, article);
+
+    // Malformed slugs are rejected during deserialization
+    assert!(serde_json::from_str::<PostCategory>("\"Not Valid\"").is_err());
 }
 
 #[test]
 fn test_article_creation() {
     let article = Article {
-        id: "test-123".to_string(),
+        id: "00000000-0000-0000-0000-000000000123".parse().unwrap(),
         path: "example-path".to_string(),
         title: "Test Article".to_string(),
         tags: vec!["rust".to_string(), "testing".to_string()],
-        category: PostCategory::Article,
+        category: category("article"),
         summary: Some("Test summary".to_string()),
+        summary_generated: false,
         content: "Test content".to_string(),
+        content_html: String::new(),
+        toc: None,
+        word_count: None,
+        reading_minutes: None,
         status: "published".to_string(),
+        series: None,
+        series_order: None,
+        description: None,
+        cover_image: None,
+        canonical_url: None,
+        language: None,
+        translation_key: None,
+        license: None,
+        author: None,
+        authors: Vec::new(),
+        pinned: false,
+        pinned_weight: None,
+        featured: false,
+        featured_weight: None,
+        images: Vec::new(),
+        keep_location: false,
+        source: ArticleSource::Git,
+        extra: Default::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
     };
 
-    assert_eq!(article.id, "test-123");
+    assert_eq!(
+        article.id.to_string(),
+        "00000000-0000-0000-0000-000000000123"
+    );
     assert_eq!(article.title, "Test Article");
     assert_eq!(article.tags.len(), 2);
-    assert_eq!(article.category, PostCategory::Article);
+    assert_eq!(article.category, category("article"));
 }
 
 #[test]
@@ -93,41 +158,87 @@ fn test_article_serialization() {
     use serde_json;
 
     let article = Article {
-        id: "test-456".to_string(),
+        id: "00000000-0000-0000-0000-000000000456".parse().unwrap(),
         path: "example-path".to_string(),
         title: "Serialization Test".to_string(),
         tags: vec!["test".to_string()],
-        category: PostCategory::Note,
+        category: category("note"),
         summary: Some("Summary".to_string()),
+        summary_generated: false,
         content: "Content".to_string(),
+        content_html: String::new(),
+        toc: None,
+        word_count: None,
+        reading_minutes: None,
         status: "draft".to_string(),
+        series: None,
+        series_order: None,
+        description: None,
+        cover_image: None,
+        canonical_url: None,
+        language: None,
+        translation_key: None,
+        license: None,
+        author: None,
+        authors: Vec::new(),
+        pinned: false,
+        pinned_weight: None,
+        featured: false,
+        featured_weight: None,
+        images: Vec::new(),
+        keep_location: false,
+        source: ArticleSource::Git,
+        extra: Default::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
     };
 
     // Test serialization
     let json = serde_json::to_string(&article).unwrap();
-    assert!(json.contains("test-456"));
+    assert!(json.contains("\"id\":\"00000000-0000-0000-0000-000000000456\""));
     assert!(json.contains("Serialization Test"));
     assert!(json.contains("note")); // Category should be serialized as lowercase
 
     // Test deserialization
     let deserialized: Article = serde_json::from_str(&json).unwrap();
     assert_eq!(deserialized.id, article.id);
-    assert_eq!(deserialized.category, PostCategory::Note);
+    assert_eq!(deserialized.category, category("note"));
 }
 
 #[test]
 fn test_article_with_empty_tags() {
     let article = Article {
-        id: "no-tags".to_string(),
+        id: "00000000-0000-0000-0000-000000000001".parse().unwrap(),
         path: "example-path".to_string(),
         title: "Article Without Tags".to_string(),
         tags: vec![],
-        category: PostCategory::Think,
+        category: category("think"),
         summary: Some("Summary".to_string()),
+        summary_generated: false,
         content: "Content".to_string(),
+        content_html: String::new(),
+        toc: None,
+        word_count: None,
+        reading_minutes: None,
         status: "published".to_string(),
+        series: None,
+        series_order: None,
+        description: None,
+        cover_image: None,
+        canonical_url: None,
+        language: None,
+        translation_key: None,
+        license: None,
+        author: None,
+        authors: Vec::new(),
+        pinned: false,
+        pinned_weight: None,
+        featured: false,
+        featured_weight: None,
+        images: Vec::new(),
+        keep_location: false,
+        source: ArticleSource::Git,
+        extra: Default::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
     };
@@ -146,14 +257,37 @@ fn test_article_with_multiple_tags() {
     ];
 
     let article = Article {
-        id: "multi-tags".to_string(),
+        id: "00000000-0000-0000-0000-000000000002".parse().unwrap(),
         path: "example-path".to_string(),
         title: "Article With Multiple Tags".to_string(),
         tags: tags.clone(),
-        category: PostCategory::Article,
+        category: category("article"),
         summary: Some("Summary".to_string()),
+        summary_generated: false,
         content: "Content".to_string(),
+        content_html: String::new(),
+        toc: None,
+        word_count: None,
+        reading_minutes: None,
         status: "published".to_string(),
+        series: None,
+        series_order: None,
+        description: None,
+        cover_image: None,
+        canonical_url: None,
+        language: None,
+        translation_key: None,
+        license: None,
+        author: None,
+        authors: Vec::new(),
+        pinned: false,
+        pinned_weight: None,
+        featured: false,
+        featured_weight: None,
+        images: Vec::new(),
+        keep_location: false,
+        source: ArticleSource::Git,
+        extra: Default::default(),
         created_at: OffsetDateTime::now_utc(),
         updated_at: OffsetDateTime::now_utc(),
     };
@@ -166,16 +300,41 @@ fn test_article_with_multiple_tags() {
 fn test_article_status_values() {
     let statuses = vec!["draft", "published", "archived"];
 
-    for status in statuses {
+    for (i, status) in statuses.into_iter().enumerate() {
         let article = Article {
-            id: format!("article-{}", status),
+            id: format!("00000000-0000-0000-0000-{:012}", i)
+                .parse()
+                .unwrap(),
             path: "example-path".to_string(),
             title: "Test".to_string(),
             tags: vec![],
-            category: PostCategory::Article,
+            category: category("article"),
             summary: Some("Summary".to_string()),
+            summary_generated: false,
             content: "Content".to_string(),
+            content_html: String::new(),
+            toc: None,
+            word_count: None,
+            reading_minutes: None,
             status: status.to_string(),
+            series: None,
+            series_order: None,
+            description: None,
+            cover_image: None,
+            canonical_url: None,
+            language: None,
+            translation_key: None,
+            license: None,
+            author: None,
+            authors: Vec::new(),
+            pinned: false,
+            pinned_weight: None,
+            featured: false,
+            featured_weight: None,
+            images: Vec::new(),
+            keep_location: false,
+            source: ArticleSource::Git,
+            extra: Default::default(),
             created_at: OffsetDateTime::now_utc(),
             updated_at: OffsetDateTime::now_utc(),
         };
@@ -186,12 +345,12 @@ fn test_article_status_values() {
 
 #[test]
 fn test_all_post_categories() {
-    let categories = vec![
-        PostCategory::Article,
-        PostCategory::Note,
-        PostCategory::Think,
-        PostCategory::Pictures,
-        PostCategory::Talk,
+    let categories = [
+        category("article"),
+        category("note"),
+        category("think"),
+        category("pictures"),
+        category("talk"),
     ];
 
     assert_eq!(categories.len(), 5);
@@ -203,3 +362,2695 @@ fn test_all_post_categories() {
     unique.dedup();
     assert_eq!(unique.len(), str_categories.len());
 }
+
+#[test]
+fn test_category_registry_default() {
+    let registry = CategoryRegistry::default();
+
+    let slugs: Vec<&str> = registry.iter().map(|c| c.slug.as_str()).collect();
+    assert_eq!(slugs, ["article", "note", "think", "pictures", "talk"]);
+    assert!(registry.iter().all(|c| c.visible));
+    assert!(
+        registry
+            .iter()
+            .all(|c| c.page_size == DEFAULT_CATEGORY_PAGE_SIZE)
+    );
+
+    // Only talks may be untitled
+    let untitled: Vec<&str> = registry
+        .iter()
+        .filter(|c| c.optional_title)
+        .map(|c| c.slug.as_str())
+        .collect();
+    assert_eq!(untitled, ["talk"]);
+
+    // An empty configuration falls back to the defaults
+    let empty = CategoryRegistry::new(Vec::new()).unwrap();
+    assert_eq!(empty.iter().count(), 5);
+}
+
+#[test]
+fn test_category_registry_from_config() {
+    let toml = r#"
+        [[categories]]
+        slug = "reading"
+        display_name = "Reading"
+        sort_order = 20
+
+        [[categories]]
+        slug = "article"
+        display_name = "Articles"
+        sort_order = 10
+        page_size = 5
+
+        [[categories]]
+        slug = "drafts"
+        display_name = "Drafts"
+        sort_order = 0
+        visible = false
+    "#;
+
+    #[derive(serde::Deserialize)]
+    struct Wrapper {
+        categories: Vec<Category>,
+    }
+
+    let wrapper: Wrapper = config::Config::builder()
+        .add_source(config::File::from_str(toml, config::FileFormat::Toml))
+        .build()
+        .unwrap()
+        .try_deserialize()
+        .unwrap();
+    let registry = CategoryRegistry::new(wrapper.categories).unwrap();
+
+    // Ordered by sort_order
+    let slugs: Vec<&str> = registry.iter().map(|c| c.slug.as_str()).collect();
+    assert_eq!(slugs, ["drafts", "article", "reading"]);
+
+    // Hidden categories exist but are not visible
+    assert!(registry.contains(&category("drafts")));
+    let visible: Vec<&str> = registry.visible().map(|c| c.slug.as_str()).collect();
+    assert_eq!(visible, ["article", "reading"]);
+
+    // Defaults are applied and built-ins are not implied
+    assert_eq!(registry.get(&category("article")).unwrap().page_size, 5);
+    assert_eq!(
+        registry.get(&category("reading")).unwrap().page_size,
+        DEFAULT_CATEGORY_PAGE_SIZE
+    );
+    assert!(!registry.contains(&category("note")));
+}
+
+#[test]
+fn test_category_registry_rejects_invalid_config() {
+    let entry = |slug: &str, page_size: i64| Category {
+        slug: category(slug),
+        display_name: slug.to_string(),
+        description: None,
+        sort_order: 0,
+        page_size,
+        visible: true,
+        optional_title: false,
+    };
+
+    // Duplicate slugs
+    assert!(CategoryRegistry::new(vec![entry("note", 10), entry("note", 20)]).is_err());
+
+    // Non-positive page size
+    assert!(CategoryRegistry::new(vec![entry("note", 0)]).is_err());
+}
+
+#[test]
+fn test_normalize_tag() {
+    assert_eq!(normalize_tag("Rust").as_deref(), Some("rust"));
+    assert_eq!(normalize_tag("  web   dev\t").as_deref(), Some("web dev"));
+    assert_eq!(normalize_tag("Ｒｕｓｔ").as_deref(), Some("rust"));
+    assert_eq!(normalize_tag("ｗｅｂ　ｄｅｖ").as_deref(), Some("web dev"));
+    assert_eq!(normalize_tag("编程").as_deref(), Some("编程"));
+    assert_eq!(normalize_tag(""), None);
+    assert_eq!(normalize_tag(" \u{3000} "), None);
+}
+
+#[test]
+fn test_tag_normalizer_synonyms() {
+    let normalizer = TagNormalizer::new(vec![TagSynonym {
+        canonical: "Rust".to_string(),
+        aliases: vec!["rust-lang".to_string(), "RustLang".to_string()],
+    }])
+    .unwrap();
+
+    assert_eq!(normalizer.normalize("Rust-Lang").as_deref(), Some("rust"));
+    assert_eq!(
+        normalizer.normalize("ｒｕｓｔｌａｎｇ").as_deref(),
+        Some("rust")
+    );
+    assert_eq!(normalizer.normalize("go").as_deref(), Some("go"));
+
+    // Duplicates after normalization are dropped, first occurrence order kept
+    let tags = ["Web", "rust-lang", " ", "RUST", "web"].map(String::from);
+    assert_eq!(normalizer.normalize_all(&tags), ["web", "rust"]);
+}
+
+#[test]
+fn test_tag_normalizer_rejects_conflicting_aliases() {
+    let groups = vec![
+        TagSynonym {
+            canonical: "rust".to_string(),
+            aliases: vec!["rs".to_string()],
+        },
+        TagSynonym {
+            canonical: "rescript".to_string(),
+            aliases: vec!["RS".to_string()],
+        },
+    ];
+    assert!(TagNormalizer::new(groups).is_err());
+
+    let empty = vec![TagSynonym {
+        canonical: "  ".to_string(),
+        aliases: vec![],
+    }];
+    assert!(TagNormalizer::new(empty).is_err());
+}
+
+#[test]
+fn test_article_id_parse_valid() {
+    let id = ArticleId::from_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    assert_eq!(id.to_string(), "67e55044-10b1-426f-9247-bb680e5fe0c8");
+
+    // Other textual UUID forms are normalized to the hyphenated lowercase form
+    let simple = ArticleId::from_str("67E5504410B1426F9247BB680E5FE0C8").unwrap();
+    assert_eq!(simple, id);
+}
+
+#[test]
+fn test_article_id_parse_invalid() {
+    for input in [
+        "",
+        "my-first-post",
+        "67e55044-10b1-426f-9247",
+        "zze55044-10b1-426f-9247-bb680e5fe0c8",
+    ] {
+        let err = ArticleId::from_str(input).unwrap_err();
+        assert!(err.to_string().contains(&format!("`{}`", input)));
+    }
+}
+
+#[test]
+fn test_article_id_serialization() {
+    let id = ArticleId::from_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!(json, "\"67e55044-10b1-426f-9247-bb680e5fe0c8\"");
+
+    let deserialized: ArticleId = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, id);
+
+    // Invalid ids are rejected during deserialization with the offending value
+    let err = serde_json::from_str::<ArticleId>("\"article-1\"").unwrap_err();
+    assert!(err.to_string().contains("article-1"));
+}
+
+const FULL_FRONT_MATTER: &str = "---
+id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+title: Hello
+tags: [Rust]
+category: article
+status: published
+description: A short description
+cover_image: /images/cover.png
+canonical_url: https://example.com/hello
+language: zh-CN
+license: CC BY-SA 4.0
+author: krteke
+pinned: true
+extra:
+  mood: happy
+---
+Body";
+
+#[test]
+fn test_key_lines_yaml() {
+    let lines = key_lines(FULL_FRONT_MATTER, FrontMatterFormat::Yaml);
+
+    assert_eq!(lines.get("id"), Some(&2));
+    assert_eq!(lines.get("title"), Some(&3));
+    assert_eq!(lines.get("extra"), Some(&14));
+    // Nested keys are not top-level fields
+    assert_eq!(lines.get("mood"), None);
+
+    // No front matter, no lines
+    assert!(key_lines("# Just markdown", FrontMatterFormat::Yaml).is_empty());
+}
+
+#[test]
+fn test_validate_front_matter_full() {
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Hello",
+        "tags": ["Rust"],
+        "category": "article",
+        "status": "published",
+        "description": "A short description",
+        "cover_image": "/images/cover.png",
+        "canonical_url": "https://example.com/hello",
+        "language": "zh-CN",
+        "license": "CC BY-SA 4.0",
+        "author": "krteke",
+        "pinned": true,
+        "extra": { "mood": "happy" }
+    });
+
+    let validation = validate_front_matter(
+        Some(&data),
+        &key_lines(FULL_FRONT_MATTER, FrontMatterFormat::Yaml),
+    );
+    assert!(validation.issues.is_empty(), "{:?}", validation.issues);
+
+    let front_matter = validation.front_matter.unwrap();
+    assert_eq!(front_matter.title, "Hello");
+    assert_eq!(front_matter.language.as_deref(), Some("zh-CN"));
+    assert_eq!(
+        front_matter.canonical_url.as_deref(),
+        Some("https://example.com/hello")
+    );
+    assert!(front_matter.pinned);
+    assert_eq!(front_matter.extra["mood"], "happy");
+    assert_eq!(validation.article_id, Some(front_matter.id));
+}
+
+#[test]
+fn test_validate_front_matter_defaults() {
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Minimal",
+        "category": "note",
+        "status": "draft"
+    });
+
+    let validation = validate_front_matter(Some(&data), &Default::default());
+    let front_matter = validation.front_matter.unwrap();
+
+    assert!(front_matter.tags.is_empty());
+    assert!(!front_matter.pinned);
+    assert!(!front_matter.keep_location);
+    assert!(front_matter.extra.is_empty());
+    assert_eq!(front_matter.description, None);
+}
+
+#[test]
+fn test_validate_front_matter_translation() {
+    let source = "---
+id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+title: Hello
+category: article
+status: published
+lang: en-GB
+translation_key: \" hello-world \"
+---
+";
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Hello",
+        "category": "article",
+        "status": "published",
+        "lang": "en-GB",
+        "translation_key": " hello-world "
+    });
+
+    let validation =
+        validate_front_matter(Some(&data), &key_lines(source, FrontMatterFormat::Yaml));
+    assert!(validation.issues.is_empty(), "{:?}", validation.issues);
+    let front_matter = validation.front_matter.unwrap();
+    assert_eq!(front_matter.language.as_deref(), Some("en-GB"));
+    assert_eq!(front_matter.translation_key.as_deref(), Some("hello-world"));
+
+    // `lang` and `language` are the same field
+    let mut both = data.clone();
+    both["language"] = "en".into();
+    let validation = validate_front_matter(Some(&both), &Default::default());
+    assert!(validation.front_matter.is_none());
+    assert_eq!(validation.issues[0].field.as_deref(), Some("language"));
+
+    let mut invalid = data.clone();
+    invalid["lang"] = "english".into();
+    let validation = validate_front_matter(Some(&invalid), &Default::default());
+    assert_eq!(validation.issues[0].field.as_deref(), Some("lang"));
+
+    // A translation without a language is kept, with a warning
+    let mut untagged = data.clone();
+    untagged.as_object_mut().unwrap().remove("lang");
+    let validation = validate_front_matter(Some(&untagged), &Default::default());
+    assert!(validation.front_matter.is_some());
+    assert_eq!(validation.issues.len(), 1);
+    assert!(!validation.issues[0].is_error());
+    assert_eq!(
+        validation.issues[0].field.as_deref(),
+        Some("translation_key")
+    );
+}
+
+#[test]
+fn test_validate_front_matter_authors() {
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Co-written",
+        "category": "article",
+        "status": "published",
+        "authors": [" jane ", "john", "jane"]
+    });
+
+    // Trimmed and deduplicated, byline order kept
+    let validation = validate_front_matter(Some(&data), &Default::default());
+    assert!(validation.issues.is_empty(), "{:?}", validation.issues);
+    assert_eq!(validation.front_matter.unwrap().authors, ["jane", "john"]);
+
+    let mut invalid = data.clone();
+    invalid["authors"] = serde_json::json!(["Jane Doe"]);
+    let validation = validate_front_matter(Some(&invalid), &Default::default());
+    assert!(validation.front_matter.is_none());
+    assert_eq!(validation.issues[0].field.as_deref(), Some("authors"));
+
+    let mut scalar = data.clone();
+    scalar["authors"] = "jane".into();
+    let validation = validate_front_matter(Some(&scalar), &Default::default());
+    assert!(validation.front_matter.is_none());
+}
+
+#[test]
+fn test_validate_front_matter_missing_title() {
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "category": "talk",
+        "status": "published"
+    });
+
+    // Whether the category needs a title is checked by the service
+    let validation = validate_front_matter(Some(&data), &Default::default());
+    assert!(validation.issues.is_empty(), "{:?}", validation.issues);
+    assert_eq!(validation.front_matter.unwrap().title, "");
+
+    // A title that is present must not be blank
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "  ",
+        "category": "talk",
+        "status": "published"
+    });
+    let validation = validate_front_matter(Some(&data), &Default::default());
+    assert!(validation.front_matter.is_none());
+}
+
+#[test]
+fn test_validate_front_matter_field_errors() {
+    let source = "---
+id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+title: 42
+category: article
+canonical_url: example.com/hello
+language: english!
+pinned: yes please
+---
+";
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": 42,
+        "category": "article",
+        "canonical_url": "example.com/hello",
+        "language": "english!",
+        "pinned": "yes please"
+    });
+
+    let validation =
+        validate_front_matter(Some(&data), &key_lines(source, FrontMatterFormat::Yaml));
+    assert!(validation.front_matter.is_none());
+
+    // The id is still reported so the failure can be tied to the article
+    assert!(validation.article_id.is_some());
+
+    let errors: Vec<(&str, Option<usize>)> = validation
+        .issues
+        .iter()
+        .filter(|i| i.is_error())
+        .map(|i| (i.field.as_deref().unwrap(), i.line))
+        .collect();
+    assert_eq!(
+        errors,
+        [
+            ("title", Some(3)),
+            ("status", Some(1)), // missing: points at the front matter start
+            ("canonical_url", Some(5)),
+            ("language", Some(6)),
+            ("pinned", Some(7)),
+        ]
+    );
+
+    let title = &validation.issues[0];
+    assert!(title.message.contains("expected a string"), "{}", title);
+}
+
+#[test]
+fn test_validate_front_matter_unknown_fields_warn() {
+    let source = "---
+id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+title: Hello
+category: article
+status: published
+mood: happy
+---
+";
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Hello",
+        "category": "article",
+        "status": "published",
+        "mood": "happy"
+    });
+
+    let mut validation =
+        validate_front_matter(Some(&data), &key_lines(source, FrontMatterFormat::Yaml));
+    assert!(validation.front_matter.is_some());
+    assert_eq!(
+        validation.issues,
+        [IngestionIssue::warning(
+            Some("mood"),
+            Some(6),
+            "unknown field ignored; put custom metadata under `extra`"
+        )]
+    );
+
+    // Checks done outside the schema can still reject the file
+    validation.reject("category", "unknown category `article`");
+    assert!(validation.front_matter.is_none());
+    assert_eq!(validation.issues[1].line, Some(4));
+}
+
+#[test]
+fn test_validate_front_matter_order_weights() {
+    let source = "---
+id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+title: Hello
+category: article
+status: published
+pinned: true
+pinned_weight: 2
+featured_weight: 1
+---
+";
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Hello",
+        "category": "article",
+        "status": "published",
+        "pinned": true,
+        "pinned_weight": 2,
+        "featured_weight": 1
+    });
+
+    let validation =
+        validate_front_matter(Some(&data), &key_lines(source, FrontMatterFormat::Yaml));
+    let front_matter = validation.front_matter.unwrap();
+    assert_eq!(front_matter.pinned_weight, Some(2));
+    assert!(!front_matter.featured);
+    assert_eq!(front_matter.featured_weight, None);
+
+    // A weight without its flag is dropped, which is worth a warning
+    assert_eq!(
+        validation.issues,
+        [IngestionIssue::warning(
+            Some("featured_weight"),
+            Some(8),
+            "ignored because `featured` is not true"
+        )]
+    );
+}
+
+#[test]
+fn test_validate_front_matter_gallery_images() {
+    let source = "---
+id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+title: Kyoto
+category: pictures
+status: published
+images:
+  - src: kyoto/fushimi.jpg
+---
+";
+    let data = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Kyoto",
+        "category": "pictures",
+        "status": "published",
+        "keep_location": true,
+        "images": [
+            {
+                "src": "kyoto/fushimi.jpg",
+                "alt": "Torii gates",
+                "caption": "Fushimi Inari",
+                "width": 4000,
+                "height": 3000,
+                "taken_at": "2025-04-02T05:48:00+09:00",
+                "camera": "Nikon FM2",
+                "exposure": { "shutter_speed": "1/125", "aperture": 8.0 }
+            },
+            { "src": "kyoto/gion.jpg", "alt": "Gion at night" }
+        ]
+    });
+
+    let validation =
+        validate_front_matter(Some(&data), &key_lines(source, FrontMatterFormat::Yaml));
+    assert!(validation.issues.is_empty(), "{:?}", validation.issues);
+
+    let front_matter = validation.front_matter.unwrap();
+    assert!(front_matter.keep_location);
+    let images = front_matter.images;
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0].caption.as_deref(), Some("Fushimi Inari"));
+    assert_eq!(images[0].camera.as_deref(), Some("Nikon FM2"));
+    assert_eq!(
+        images[0].exposure.as_ref().and_then(|e| e.aperture),
+        Some(8.0)
+    );
+    assert_eq!(
+        images[0].taken_at,
+        Some(time::macros::datetime!(2025-04-01 20:48 UTC))
+    );
+    assert_eq!(images[1].src, "kyoto/gion.jpg");
+    assert_eq!(images[1].width, None);
+
+    // Every invalid image is reported by position on the `images` line
+    let invalid = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Kyoto",
+        "category": "pictures",
+        "status": "published",
+        "images": [
+            { "src": "https://example.com/a.jpg", "alt": "External" },
+            { "src": "b.jpg", "alt": " " },
+            { "src": "c.jpg", "alt": "Half sized", "width": 800 },
+            {
+                "src": "d.jpg",
+                "alt": "Swapped coordinates",
+                "location": { "latitude": 135.77, "longitude": 34.97 }
+            }
+        ]
+    });
+    let validation =
+        validate_front_matter(Some(&invalid), &key_lines(source, FrontMatterFormat::Yaml));
+    assert!(validation.front_matter.is_none());
+
+    let messages: Vec<_> = validation
+        .issues
+        .iter()
+        .map(|i| (i.line, i.message.as_str()))
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            (
+                Some(6),
+                "image 1: `src` must be a repository path, got `https://example.com/a.jpg`"
+            ),
+            (Some(6), "image 2: `alt` must not be empty"),
+            (
+                Some(6),
+                "image 3: `width` and `height` must be given together"
+            ),
+            (Some(6), "image 4: `location` is out of range"),
+        ]
+    );
+
+    // Missing alt text and unknown keys fail deserialization
+    let untyped = serde_json::json!({
+        "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+        "title": "Kyoto",
+        "category": "pictures",
+        "status": "published",
+        "images": [{ "src": "a.jpg", "title": "no alt" }]
+    });
+    let validation = validate_front_matter(Some(&untyped), &Default::default());
+    assert!(validation.front_matter.is_none());
+    assert_eq!(validation.issues[0].field.as_deref(), Some("images"));
+}
+
+#[test]
+fn test_gallery_image_fill_from_exif() {
+    let exif = ExifMetadata {
+        camera: Some("FUJIFILM X100V".to_string()),
+        lens: None,
+        exposure: Some(Exposure {
+            shutter_speed: Some("1/250".to_string()),
+            aperture: Some(2.8),
+            iso: Some(400),
+            focal_length: Some(23.0),
+        }),
+        taken_at: Some(time::macros::datetime!(2025-04-02 05:48 +09:00)),
+        location: Some(GeoLocation {
+            latitude: 34.967,
+            longitude: 135.773,
+        }),
+    };
+    let mut image: GalleryImage = serde_json::from_value(serde_json::json!({
+        "src": "kyoto/fushimi.jpg",
+        "alt": "Torii gates",
+        "camera": "Nikon FM2"
+    }))
+    .unwrap();
+
+    // Front matter wins, the location needs the post's opt-in
+    image.fill_from_exif(&exif, false);
+    assert_eq!(image.camera.as_deref(), Some("Nikon FM2"));
+    assert_eq!(image.exposure, exif.exposure);
+    assert_eq!(image.taken_at, exif.taken_at);
+    assert_eq!(image.location, None);
+
+    image.fill_from_exif(&exif, true);
+    assert_eq!(image.location, exif.location);
+}
+
+#[test]
+fn test_validate_front_matter_missing_or_malformed() {
+    let missing = validate_front_matter(None, &Default::default());
+    assert!(missing.front_matter.is_none());
+    assert_eq!(missing.issues[0].message, "missing front matter");
+
+    let list = serde_json::json!(["not", "a", "mapping"]);
+    let malformed = validate_front_matter(Some(&list), &Default::default());
+    assert!(malformed.front_matter.is_none());
+    assert_eq!(malformed.issues[0].severity, IssueSeverity::Error);
+}
+
+#[test]
+fn test_ingestion_report_status() {
+    let warning = IngestionIssue::warning(Some("mood"), Some(6), "unknown field");
+    let error = IngestionIssue::error(Some("title"), Some(3), "must not be empty");
+
+    let ok = IngestionReport::new("posts/a.md", None, vec![]);
+    assert_eq!(ok.status, IngestionStatus::Ingested);
+
+    let warned = IngestionReport::new("posts/a.md", None, vec![warning.clone()]);
+    assert_eq!(warned.status, IngestionStatus::IngestedWithWarnings);
+
+    let failed = IngestionReport::new("posts/a.md", None, vec![warning, error.clone()]);
+    assert_eq!(failed.status, IngestionStatus::Failed);
+    assert_eq!(failed.status.as_str(), "failed");
+
+    assert_eq!(error.to_string(), "line 3: `title`: must not be empty");
+
+    for status in [
+        IngestionStatus::Ingested,
+        IngestionStatus::IngestedWithWarnings,
+        IngestionStatus::Failed,
+    ] {
+        assert_eq!(status.as_str().parse::<IngestionStatus>(), Ok(status));
+    }
+    assert!("skipped".parse::<IngestionStatus>().is_err());
+}
+
+#[test]
+fn test_dedupe_reports() {
+    let id = ArticleId::from_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap();
+    let warning = IngestionIssue::warning(Some("mood"), Some(6), "unknown field");
+    let error = IngestionIssue::error(None, None, "image could not be fetched");
+
+    let reports = [
+        IngestionReport::new("posts/a.md", Some(id), vec![warning.clone()]),
+        IngestionReport::new("posts/b.md", None, vec![]),
+        IngestionReport::new("posts/a.md", None, vec![error.clone()]),
+    ];
+
+    let deduped = dedupe_reports(&reports);
+    assert_eq!(deduped.len(), 2);
+    assert_eq!(deduped[0].path, "posts/a.md");
+    assert_eq!(deduped[0].article_id, Some(id));
+    assert_eq!(deduped[0].issues, vec![warning, error]);
+    assert_eq!(deduped[0].status, IngestionStatus::Failed);
+    assert_eq!(deduped[1].path, "posts/b.md");
+    assert_eq!(deduped[1].status, IngestionStatus::Ingested);
+}
+
+#[test]
+fn test_front_matter_format_detect() {
+    assert_eq!(
+        FrontMatterFormat::detect("---\ntitle: a\n---\n"),
+        Some(FrontMatterFormat::Yaml)
+    );
+    assert_eq!(
+        FrontMatterFormat::detect("+++\ntitle = \"a\"\n+++\n"),
+        Some(FrontMatterFormat::Toml)
+    );
+    assert_eq!(
+        FrontMatterFormat::detect(";;;\n{\"title\": \"a\"}\n;;;\n"),
+        Some(FrontMatterFormat::Json)
+    );
+    assert_eq!(FrontMatterFormat::detect("# Heading\n---\n"), None);
+    assert_eq!(FrontMatterFormat::detect(""), None);
+    assert_eq!(FrontMatterFormat::Toml.to_string(), "TOML");
+}
+
+#[test]
+fn test_key_lines_toml() {
+    let source = "+++
+id = \"67e55044-10b1-426f-9247-bb680e5fe0c8\"
+title = \"Hello\"
+# a comment
+tags = [
+  \"rust\",
+]
+series.name = \"ignored\"
+
+[extra]
+title = \"nested\"
++++
+Body";
+    let lines = key_lines(source, FrontMatterFormat::Toml);
+
+    assert_eq!(lines.get("id"), Some(&2));
+    assert_eq!(lines.get("title"), Some(&3));
+    assert_eq!(lines.get("tags"), Some(&5));
+    assert_eq!(lines.get("series"), Some(&8));
+    assert_eq!(lines.get("extra"), Some(&10));
+    assert_eq!(lines.len(), 5);
+}
+
+#[test]
+fn test_key_lines_json() {
+    let source = r#";;;
+{
+  "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+  "title": "Colon: \"quoted\"",
+  "tags": ["a:b"],
+  "extra": {
+    "title": "nested"
+  }
+}
+;;;
+Body"#;
+    let lines = key_lines(source, FrontMatterFormat::Json);
+
+    assert_eq!(lines.get("id"), Some(&3));
+    assert_eq!(lines.get("title"), Some(&4));
+    assert_eq!(lines.get("tags"), Some(&5));
+    assert_eq!(lines.get("extra"), Some(&6));
+    assert_eq!(lines.len(), 4);
+}
+
+#[test]
+fn test_slugify() {
+    assert_eq!(slugify("Getting Started"), "getting-started");
+    assert_eq!(slugify("What's new in 2.0?"), "whats-new-in-20");
+    assert_eq!(slugify("所有权与借用"), "所有权与借用");
+    assert_eq!(slugify("Rust の 所有権"), "rust-の-所有権");
+    assert_eq!(slugify("snake_case -- kebab"), "snake-case-kebab");
+    assert_eq!(slugify("!!!"), "");
+}
+
+#[test]
+fn test_render_markdown_gfm() {
+    let html = render_markdown(
+        "| Left | Right |\n|:-----|------:|\n| a | b |\n\n\
+         - [x] done\n- [ ] todo\n\n\
+         Text[^note].\n\n[^note]: The note.\n\n\
+         ~~old~~\n",
+    );
+
+    assert!(html.contains("<table>"));
+    assert!(html.contains(r#"<th style="text-align:left">Left</th>"#));
+    assert!(html.contains(r#"<td style="text-align:right">b</td>"#));
+    assert!(html.contains(r##"<input disabled="" type="checkbox" checked=""##));
+    assert!(html.contains(r##"<sup class="footnote-reference"><a href="#note""##));
+    assert!(html.contains(r#"<div class="footnote-definition" id="note">"#));
+    assert!(html.contains("<del>old</del>"));
+}
+
+#[test]
+fn test_render_markdown_heading_anchors() {
+    let html = render_markdown(
+        "# Intro\n\n## Intro\n\n## 安装 `cargo`\n\n## Custom {#my-anchor}\n\n## !!!\n",
+    );
+
+    assert!(html.contains(r#"<h1 id="intro">Intro</h1>"#));
+    assert!(html.contains(r#"<h2 id="intro-1">Intro</h2>"#));
+    assert!(html.contains(r#"<h2 id="安装-cargo">"#));
+    assert!(html.contains(r#"<h2 id="my-anchor">Custom</h2>"#));
+    assert!(html.contains(r#"<h2 id="section">!!!</h2>"#));
+}
+
+#[test]
+fn test_render_markdown_sanitizes_raw_html() {
+    let html = render_markdown(
+        "<script>alert(1)</script>\n\n\
+         <img src=\"x.png\" onerror=\"alert(1)\">\n\n\
+         [link](javascript:alert(1))\n\n\
+         <input type=\"text\" value=\"x\">\n\n\
+         <td style=\"background: url(evil)\">x</td>\n\n\
+         ```rust\nfn main() {}\n```\n",
+    );
+
+    assert!(!html.contains("<script"));
+    assert!(!html.contains("onerror"));
+    assert!(!html.contains("javascript:"));
+    assert!(!html.contains(r#"type="text""#));
+    assert!(!html.contains("background"));
+    assert!(html.contains(r#"<img src="x.png">"#));
+    assert!(html.contains(r#"<code class="language-rust">"#));
+}
+
+fn toc_entry(level: u8, anchor: &str) -> TocEntry {
+    TocEntry {
+        level,
+        text: anchor.to_string(),
+        anchor: anchor.to_string(),
+        children: Vec::new(),
+    }
+}
+
+#[test]
+fn test_build_toc_nesting() {
+    let toc = build_toc(vec![
+        toc_entry(2, "a"),
+        toc_entry(3, "a1"),
+        toc_entry(4, "a1x"),
+        toc_entry(3, "a2"),
+        toc_entry(2, "b"),
+        // Skipped level nests under the previous shallower heading
+        toc_entry(4, "b1"),
+        toc_entry(1, "c"),
+    ]);
+
+    let anchors =
+        |entries: &[TocEntry]| entries.iter().map(|e| e.anchor.clone()).collect::<Vec<_>>();
+    assert_eq!(anchors(&toc), ["a", "b", "c"]);
+    assert_eq!(anchors(&toc[0].children), ["a1", "a2"]);
+    assert_eq!(anchors(&toc[0].children[0].children), ["a1x"]);
+    assert_eq!(anchors(&toc[1].children), ["b1"]);
+    assert!(toc[2].children.is_empty());
+}
+
+#[test]
+fn test_extract_toc_matches_rendered_anchors() {
+    let source = "# 入门指南\n\n## 安装 *Rust*\n\n### Linux\n\n## 安装 Rust\n\n\
+                  ## Custom {#pinned}\n\n```\n# not a heading\n```\n";
+    let rendered = render(source);
+    let toc = extract_toc(source);
+
+    assert_eq!(toc, rendered.toc);
+    assert_eq!(toc.len(), 1);
+    assert_eq!(toc[0].text, "入门指南");
+
+    let sections = &toc[0].children;
+    assert_eq!(sections.len(), 3);
+    assert_eq!(
+        (sections[0].level, sections[0].text.as_str()),
+        (2, "安装 Rust")
+    );
+    assert_eq!(sections[0].anchor, "安装-rust");
+    assert_eq!(sections[0].children[0].anchor, "linux");
+    assert_eq!(sections[1].anchor, "安装-rust-1");
+    assert_eq!(sections[2].anchor, "pinned");
+
+    for anchor in ["入门指南", "安装-rust", "linux", "安装-rust-1", "pinned"] {
+        assert!(rendered.html.contains(&format!(r#"id="{}""#, anchor)));
+    }
+}
+
+#[test]
+fn test_content_stats_mixed_text() {
+    let mut stats = ContentStats::default();
+    stats.add_text("Rust 的所有权（ownership）机制 isn't hard, 对吧？");
+
+    // 的 所 有 权 机 制 对 吧
+    assert_eq!(stats.cjk_chars, 8);
+    // Rust, ownership, isn't, hard
+    assert_eq!(stats.words, 4);
+    assert_eq!(stats.word_count(), 12);
+
+    let mut stats = ContentStats::default();
+    stats.add_text("ひらがな カタカナ 한국어 3.14 -- ...");
+    assert_eq!(stats.cjk_chars, 11);
+    assert_eq!(stats.words, 2);
+}
+
+#[test]
+fn test_content_stats_markdown() {
+    let stats = content_stats(
+        "# 标题 Title\n\n\
+         Some `inline code` here, 还有中文。\n\n\
+         ![alt text is not prose](a.png) ![](b.png)\n\n\
+         ```rust\nfn main() {\n\n    println!(\"hi\");\n}\n```\n\n\
+         <div>raw html</div>\n",
+    );
+
+    // 标 题 还 有 中 文
+    assert_eq!(stats.cjk_chars, 6);
+    // Title, Some, inline, code, here
+    assert_eq!(stats.words, 5);
+    // Blank lines in code blocks are not counted
+    assert_eq!(stats.code_lines, 3);
+    assert_eq!(stats.images, 2);
+
+    assert_eq!(render("中文 text").stats, content_stats("中文 text"));
+}
+
+#[test]
+fn test_reading_speeds_minutes() {
+    let speeds = ReadingSpeeds::default();
+    let stats = |cjk_chars, words, code_lines, images| ContentStats {
+        cjk_chars,
+        words,
+        code_lines,
+        images,
+    };
+
+    assert_eq!(speeds.reading_minutes(&stats(0, 0, 0, 0)), 0);
+    // Anything non-empty takes at least a minute
+    assert_eq!(speeds.reading_minutes(&stats(1, 0, 0, 0)), 1);
+    // 300 CJK chars + 200 words = 2 minutes exactly
+    assert_eq!(speeds.reading_minutes(&stats(300, 200, 0, 0)), 2);
+    // 60 code lines = 1 minute, 5 images * 12s = 1 minute
+    assert_eq!(speeds.reading_minutes(&stats(0, 0, 60, 5)), 2);
+    assert_eq!(speeds.reading_minutes(&stats(0, 201, 0, 0)), 2);
+
+    let slow = ReadingSpeeds {
+        cjk_chars_per_minute: 100,
+        ..ReadingSpeeds::default()
+    };
+    assert_eq!(slow.reading_minutes(&stats(300, 0, 0, 0)), 3);
+}
+
+#[test]
+fn test_reading_speeds_from_config() {
+    let toml = r#"
+        [reading]
+        words_per_minute = 250
+        seconds_per_image = 0
+    "#;
+
+    #[derive(serde::Deserialize)]
+    struct Wrapper {
+        reading: ReadingSpeeds,
+    }
+
+    let wrapper: Wrapper = config::Config::builder()
+        .add_source(config::File::from_str(toml, config::FileFormat::Toml))
+        .build()
+        .unwrap()
+        .try_deserialize()
+        .unwrap();
+    let speeds = wrapper.reading.validate().unwrap();
+
+    assert_eq!(speeds.words_per_minute, 250);
+    assert_eq!(speeds.seconds_per_image, 0);
+    // Omitted fields keep their defaults
+    assert_eq!(
+        speeds.cjk_chars_per_minute,
+        ReadingSpeeds::default().cjk_chars_per_minute
+    );
+
+    let invalid = ReadingSpeeds {
+        code_lines_per_minute: 0,
+        ..ReadingSpeeds::default()
+    };
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn test_truncate_summary() {
+    // Fits: unchanged apart from trimming
+    assert_eq!(truncate_summary("  Fits.  ", 10), "Fits.");
+
+    // Cut at the last sentence end in the second half of the limit
+    assert_eq!(
+        truncate_summary("Rust is fast. It is also safe. And fun to write.", 35),
+        "Rust is fast. It is also safe."
+    );
+    assert_eq!(
+        truncate_summary(
+            "所有权是 Rust 的核心概念。它让内存安全无需垃圾回收。后面还有很多内容。",
+            30
+        ),
+        "所有权是 Rust 的核心概念。它让内存安全无需垃圾回收。"
+    );
+
+    // A decimal point is not a sentence end
+    assert_eq!(
+        truncate_summary("Version 3.14 brings many improvements", 14),
+        "Version 3.14…"
+    );
+
+    // Sentence end too early: fall back to a word boundary
+    assert_eq!(
+        truncate_summary("Hi. This sentence goes on and on", 24),
+        "Hi. This sentence goes…"
+    );
+
+    // No boundary at all: hard cut at the character limit
+    assert_eq!(
+        truncate_summary("没有任何标点符号的一长串中文文本", 8),
+        "没有任何标点符号…"
+    );
+}
+
+#[test]
+fn test_generate_summary() {
+    let options = SummaryOptions { max_chars: 40 };
+    let source = "# Heading\n\n\
+                  > A quote that is not the summary.\n\n\
+                  ![cover image](cover.png)\n\n\
+                  ```rust\nfn main() {}\n```\n\n\
+                  This *first* paragraph has `code`, a [link](other.md)[^1] and\n\
+                  <span>html</span> spread over lines. Then more text follows here.\n\n\
+                  [^1]: Footnote.\n";
+
+    assert_eq!(
+        generate_summary(source, &options).as_deref(),
+        Some("This first paragraph has code, a link…")
+    );
+
+    assert_eq!(
+        generate_summary("# Only a heading\n\n```\ncode\n```\n", &options),
+        None
+    );
+    assert_eq!(
+        generate_summary("第一段。\n\n第二段。", &SummaryOptions::default()).as_deref(),
+        Some("第一段。")
+    );
+    assert!(SummaryOptions { max_chars: 0 }.validate().is_err());
+}
+
+fn link_target(n: u128, path: &str, title: &str) -> LinkTarget {
+    LinkTarget {
+        id: ArticleId::from_uuid(sqlx::types::Uuid::from_u128(n)),
+        path: path.to_string(),
+        title: title.to_string(),
+        category: category("note"),
+    }
+}
+
+fn link_index() -> LinkIndex {
+    LinkIndex::new(vec![
+        link_target(1, "notes/rust/ownership.md", "Ownership"),
+        link_target(2, "notes/rust/borrowing.md", "Borrowing"),
+        link_target(3, "posts/intro.md", "Getting Started"),
+        link_target(4, "posts/setup.md", "Getting Started"),
+    ])
+}
+
+#[test]
+fn test_link_index_resolve() {
+    let index = link_index();
+
+    let resolve = |source, dest| index.resolve_path(source, dest).map(|t| t.title.clone());
+    assert_eq!(
+        resolve("notes/rust/borrowing.md", "ownership.md").unwrap(),
+        "Ownership"
+    );
+    assert_eq!(
+        resolve("notes/rust/borrowing.md", "./ownership.md").unwrap(),
+        "Ownership"
+    );
+    assert_eq!(
+        resolve("posts/intro.md", "../notes/rust/ownership.md").unwrap(),
+        "Ownership"
+    );
+    assert_eq!(
+        resolve("posts/intro.md", "/notes/rust/borrowing.md").unwrap(),
+        "Borrowing"
+    );
+    assert_eq!(
+        resolve("posts/intro.md", "missing.md"),
+        Err(BrokenLinkReason::NotFound)
+    );
+    assert_eq!(
+        resolve("intro.md", "../../a.md"),
+        Err(BrokenLinkReason::NotFound)
+    );
+
+    let wiki = |name| index.resolve_wiki(name).map(|t| t.path.clone());
+    assert_eq!(wiki("ownership").unwrap(), "notes/rust/ownership.md");
+    assert_eq!(wiki("  BORROWING ").unwrap(), "notes/rust/borrowing.md");
+    assert_eq!(wiki("setup").unwrap(), "posts/setup.md");
+    assert_eq!(
+        wiki("00000000-0000-0000-0000-000000000003").unwrap(),
+        "posts/intro.md"
+    );
+    // Two articles share the title
+    assert_eq!(wiki("Getting Started"), Err(BrokenLinkReason::Ambiguous));
+    assert_eq!(wiki("Lifetimes"), Err(BrokenLinkReason::NotFound));
+
+    assert!(is_internal_markdown_link("ownership.md#moves"));
+    assert!(is_internal_markdown_link("../notes/emacs.org"));
+    assert!(is_internal_markdown_link("guide.adoc#install"));
+    assert!(!is_internal_markdown_link("mailto:me@example.com"));
+    assert!(!is_internal_markdown_link("//cdn.example.com/a.md"));
+}
+
+#[test]
+fn test_resolve_internal_links() {
+    let index = link_index();
+    let ownership = "/note/00000000-0000-0000-0000-000000000001";
+    let borrowing = "/note/00000000-0000-0000-0000-000000000002";
+    let source = "See [moves](ownership.md#moves) and [[Borrowing|the borrow checker]].\n\n\
+                  Also [[ownership#Drop Order]], [ref][own] and [web](https://example.com/a.md).\n\n\
+                  Broken: [gone](gone.md), [[Getting Started]] and `[[Borrowing]]`.\n\n\
+                  [own]: <./ownership.md>\n";
+
+    let resolved = resolve_internal_links("notes/rust/borrowing.md", source, &index);
+
+    let expected = format!(
+        "See [moves]({ownership}#moves) and [the borrow checker]({borrowing}).\n\n\
+         Also [ownership#Drop Order]({ownership}#drop-order), [ref][own] and [web](https://example.com/a.md).\n\n\
+         Broken: [gone](gone.md), [[Getting Started]] and `[[Borrowing]]`.\n\n\
+         [own]: <{ownership}>\n"
+    );
+    assert_eq!(resolved.content, expected);
+    assert_eq!(
+        resolved.targets,
+        [
+            ArticleId::from_uuid(sqlx::types::Uuid::from_u128(1)),
+            ArticleId::from_uuid(sqlx::types::Uuid::from_u128(2))
+        ]
+    );
+
+    let broken: Vec<_> = resolved
+        .broken
+        .iter()
+        .map(|b| (b.link.as_str(), b.reason))
+        .collect();
+    assert_eq!(
+        broken,
+        [
+            ("gone.md", BrokenLinkReason::NotFound),
+            ("[[Getting Started]]", BrokenLinkReason::Ambiguous)
+        ]
+    );
+}
+
+#[test]
+fn test_image_sources() {
+    let source = "![Map](./map.png \"Route\") and ![again](./map.png)\n\n\
+                  ![Logo][logo] ![Remote](https://example.com/a.png) ![Inline](data:image/png;base64,AA)\n\n\
+                  `![code](code.png)`\n\n\
+                  [logo]: </assets/logo.svg>\n";
+
+    assert_eq!(image_sources(source), ["./map.png", "/assets/logo.svg"]);
+}
+
+#[test]
+fn test_rewrite_image_sources() {
+    let source = "![Map](./map.png \"Route\") and [a link](./map.png)\n\n\
+                  ![Logo][logo] ![Other](other.png)\n\n\
+                  [logo]: </assets/logo.svg>\n";
+    let urls = HashMap::from([
+        ("./map.png".to_string(), "/api/media/aaa".to_string()),
+        ("/assets/logo.svg".to_string(), "/api/media/bbb".to_string()),
+    ]);
+
+    assert_eq!(
+        rewrite_image_sources(source, &urls),
+        "![Map](/api/media/aaa \"Route\") and [a link](./map.png)\n\n\
+         ![Logo][logo] ![Other](other.png)\n\n\
+         [logo]: </api/media/bbb>\n"
+    );
+}
+
+#[test]
+fn test_remove_images() {
+    let plot = "data:image/png;base64,iVBORw==";
+    let source = format!(
+        "Before ![Plot]({plot}) after\n\n![Kept](data:image/png;base64,AAAA)\n\n\
+         `![Code]({plot})`\n"
+    );
+    let sources = HashSet::from([plot.to_string()]);
+
+    // Images in code are left alone
+    assert_eq!(
+        remove_images(&source, &sources),
+        format!("Before  after\n\n![Kept](data:image/png;base64,AAAA)\n\n`![Code]({plot})`\n")
+    );
+    assert_eq!(remove_images(&source, &HashSet::new()), source);
+}
+
+#[test]
+fn test_media_hash_parse() {
+    let hex = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    let hash: MediaHash = hex.parse().unwrap();
+    assert_eq!(hash.as_str(), hex);
+    assert_eq!(serde_json::to_value(&hash).unwrap(), serde_json::json!(hex));
+
+    assert!("".parse::<MediaHash>().is_err());
+    assert!(hex[..63].parse::<MediaHash>().is_err());
+    assert!(hex.to_uppercase().parse::<MediaHash>().is_err());
+    assert!(format!("../{}", &hex[3..]).parse::<MediaHash>().is_err());
+    assert!(serde_json::from_value::<MediaHash>(serde_json::json!("nope")).is_err());
+}
+
+#[test]
+fn test_media_options_validate() {
+    let options = MediaOptions {
+        widths: vec![1600, 480, 960, 480],
+        formats: vec![VariantFormat::Webp, VariantFormat::Webp],
+        public_path: "/api/media/".to_string(),
+        ..MediaOptions::default()
+    }
+    .validate()
+    .unwrap();
+    assert_eq!(options.widths, [480, 960, 1600]);
+    assert_eq!(options.formats, [VariantFormat::Webp]);
+
+    let hash: MediaHash = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        options.url(&hash),
+        "/api/media/9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+    );
+
+    for invalid in [
+        MediaOptions {
+            root: " ".to_string(),
+            ..MediaOptions::default()
+        },
+        MediaOptions {
+            public_path: "/".to_string(),
+            ..MediaOptions::default()
+        },
+        MediaOptions {
+            widths: vec![0, 480],
+            ..MediaOptions::default()
+        },
+        MediaOptions {
+            max_source_bytes: 0,
+            ..MediaOptions::default()
+        },
+    ] {
+        assert!(invalid.clone().validate().is_err(), "{:?}", invalid);
+    }
+}
+
+#[cfg(feature = "webhook")]
+#[test]
+fn test_process_image() {
+    use backend::infrastructure::media::processing::process_image;
+    use image::{ImageFormat, Rgb, RgbImage};
+
+    let mut source = Vec::new();
+    RgbImage::from_fn(640, 480, |x, y| {
+        Rgb([(x % 256) as u8, (y % 256) as u8, 128])
+    })
+    .write_to(&mut std::io::Cursor::new(&mut source), ImageFormat::Png)
+    .unwrap();
+
+    let options = MediaOptions {
+        widths: vec![320, 640, 1280],
+        formats: vec![VariantFormat::Webp],
+        ..MediaOptions::default()
+    };
+    let processed = process_image(source.clone(), &options).unwrap();
+
+    let original = &processed.original.asset;
+    assert_eq!(original.hash, MediaHash::of(&source));
+    assert_eq!(original.content_type, "image/png");
+    assert_eq!((original.width, original.height), (640, 480));
+    assert!(original.blurhash.is_some());
+    assert_eq!(processed.original.bytes, source);
+
+    // Only widths smaller than the original are generated
+    assert_eq!(processed.variants.len(), 1);
+    let variant = &processed.variants[0];
+    assert_eq!(variant.asset.content_type, "image/webp");
+    assert_eq!((variant.asset.width, variant.asset.height), (320, 240));
+    assert_eq!(variant.asset.original.as_ref(), Some(&original.hash));
+    assert_eq!(variant.asset.hash, MediaHash::of(&variant.bytes));
+    // Lossy, so the gradient doesn't cost more than the lossless original
+    assert!(variant.bytes.len() < source.len());
+    assert_eq!(
+        image::load_from_memory_with_format(&variant.bytes, ImageFormat::WebP)
+            .unwrap()
+            .width(),
+        320
+    );
+
+    assert!(process_image(b"not an image".to_vec(), &options).is_err());
+}
+
+#[cfg(feature = "webhook")]
+#[test]
+fn test_process_image_orientation() {
+    use backend::infrastructure::media::processing::process_image;
+    use exif::{Field, In, Tag, Value, experimental::Writer};
+    use image::{ImageFormat, Rgb, RgbImage};
+    use img_parts::{Bytes, ImageEXIF, jpeg::Jpeg};
+    use std::io::Cursor;
+
+    // Portrait photo stored landscape, rotated 90° clockwise when displayed
+    let mut writer = Writer::new();
+    let orientation = Field {
+        tag: Tag::Orientation,
+        ifd_num: In::PRIMARY,
+        value: Value::Short(vec![6]),
+    };
+    writer.push_field(&orientation);
+    let mut raw_exif = Cursor::new(Vec::new());
+    writer.write(&mut raw_exif, false).unwrap();
+
+    let mut encoded = Vec::new();
+    RgbImage::from_fn(64, 48, |x, _| Rgb([(x * 4) as u8, 0, 0]))
+        .write_to(&mut Cursor::new(&mut encoded), ImageFormat::Jpeg)
+        .unwrap();
+    let mut jpeg = Jpeg::from_bytes(Bytes::from(encoded)).unwrap();
+    jpeg.set_exif(Some(Bytes::from(raw_exif.into_inner())));
+    let source: Vec<u8> = jpeg.encoder().bytes().into();
+
+    let options = MediaOptions {
+        widths: vec![24, 60],
+        formats: vec![VariantFormat::Avif],
+        ..MediaOptions::default()
+    };
+    let processed = process_image(source, &options).unwrap();
+
+    // Dimensions are those of the upright image, so 60 is not a downscale
+    let original = &processed.original.asset;
+    assert_eq!((original.width, original.height), (48, 64));
+    assert_eq!(processed.variants.len(), 1);
+    let variant = &processed.variants[0].asset;
+    assert_eq!((variant.width, variant.height), (24, 32));
+}
+
+#[cfg(feature = "webhook")]
+#[test]
+fn test_strip_metadata() {
+    use backend::infrastructure::media::processing::{process_image, strip_metadata};
+    use exif::{Field, In, Rational, Reader, Tag, Value, experimental::Writer};
+    use image::{ImageFormat, Rgb, RgbImage};
+    use img_parts::{
+        Bytes, ImageEXIF,
+        jpeg::{Jpeg, JpegSegment, markers},
+    };
+    use std::io::Cursor;
+
+    let ascii = |tag, text: &str| Field {
+        tag,
+        ifd_num: In::PRIMARY,
+        value: Value::Ascii(vec![text.as_bytes().to_vec()]),
+    };
+    let rationals = |tag, values: &[(u32, u32)]| Field {
+        tag,
+        ifd_num: In::PRIMARY,
+        value: Value::Rational(
+            values
+                .iter()
+                .map(|&(num, denom)| Rational { num, denom })
+                .collect(),
+        ),
+    };
+    let fields = [
+        ascii(Tag::Make, "FUJIFILM"),
+        ascii(Tag::Model, "X100V"),
+        ascii(Tag::BodySerialNumber, "5CB12345"),
+        ascii(Tag::DateTimeOriginal, "2025:04:02 05:48:00"),
+        ascii(Tag::OffsetTimeOriginal, "+09:00"),
+        rationals(Tag::ExposureTime, &[(1, 250)]),
+        rationals(Tag::FNumber, &[(28, 10)]),
+        Field {
+            tag: Tag::PhotographicSensitivity,
+            ifd_num: In::PRIMARY,
+            value: Value::Short(vec![400]),
+        },
+        rationals(Tag::FocalLength, &[(23, 1)]),
+        ascii(Tag::GPSLatitudeRef, "N"),
+        rationals(Tag::GPSLatitude, &[(34, 1), (58, 1), (120, 10)]),
+        ascii(Tag::GPSLongitudeRef, "E"),
+        rationals(Tag::GPSLongitude, &[(135, 1), (46, 1), (120, 10)]),
+    ];
+    let mut writer = Writer::new();
+    for field in &fields {
+        writer.push_field(field);
+    }
+    let mut raw_exif = Cursor::new(Vec::new());
+    writer.write(&mut raw_exif, false).unwrap();
+
+    let mut encoded = Vec::new();
+    RgbImage::from_fn(64, 48, |x, y| Rgb([(x * 4) as u8, (y * 4) as u8, 128]))
+        .write_to(&mut Cursor::new(&mut encoded), ImageFormat::Jpeg)
+        .unwrap();
+    let mut jpeg = Jpeg::from_bytes(Bytes::from(encoded)).unwrap();
+    jpeg.set_exif(Some(Bytes::from(raw_exif.into_inner())));
+    jpeg.segments_mut().insert(
+        1,
+        JpegSegment::new_with_contents(
+            markers::APP1,
+            Bytes::from_static(b"http://ns.adobe.com/xap/1.0/\0<x:xmpmeta/>"),
+        ),
+    );
+    let mut source: Vec<u8> = jpeg.encoder().bytes().into();
+    // Motion photos append a video after the end of the image
+    source.extend_from_slice(b"ftypmp42 trailing video");
+
+    let read = |bytes: &[u8]| {
+        Reader::new()
+            .read_from_container(&mut Cursor::new(bytes))
+            .unwrap()
+    };
+    let contains = |bytes: &[u8], needle: &[u8]| bytes.windows(needle.len()).any(|w| w == needle);
+
+    let stripped = strip_metadata(source.clone(), false).unwrap();
+    let exif = read(&stripped);
+    assert!(exif.get_field(Tag::Model, In::PRIMARY).is_some());
+    assert!(exif.get_field(Tag::BodySerialNumber, In::PRIMARY).is_none());
+    assert!(exif.get_field(Tag::GPSLatitude, In::PRIMARY).is_none());
+    assert!(!contains(&stripped, b"xmpmeta"));
+    assert!(stripped.ends_with(&[0xFF, 0xD9]));
+    assert!(image::load_from_memory(&stripped).is_ok());
+
+    let options = MediaOptions {
+        widths: vec![],
+        ..MediaOptions::default()
+    };
+    let metadata = process_image(stripped, &options)
+        .unwrap()
+        .original
+        .asset
+        .exif
+        .unwrap();
+    assert_eq!(metadata.camera.as_deref(), Some("FUJIFILM X100V"));
+    assert_eq!(metadata.lens, None);
+    assert_eq!(
+        metadata.exposure,
+        Some(Exposure {
+            shutter_speed: Some("1/250".to_string()),
+            aperture: Some(2.8),
+            iso: Some(400),
+            focal_length: Some(23.0),
+        })
+    );
+    assert_eq!(
+        metadata.taken_at,
+        Some(time::macros::datetime!(2025-04-02 05:48 +09:00))
+    );
+    assert_eq!(metadata.location, None);
+
+    // Opting in keeps the GPS position, and nothing else
+    let kept = strip_metadata(source, true).unwrap();
+    assert!(
+        read(&kept)
+            .get_field(Tag::BodySerialNumber, In::PRIMARY)
+            .is_none()
+    );
+    let location = process_image(kept, &options)
+        .unwrap()
+        .original
+        .asset
+        .exif
+        .and_then(|exif| exif.location)
+        .unwrap();
+    assert!((location.latitude - 34.97).abs() < 1e-9);
+    assert!((location.longitude - 135.77).abs() < 1e-9);
+
+    // Images without EXIF pass through unchanged
+    let mut png = Vec::new();
+    RgbImage::new(8, 8)
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .unwrap();
+    assert_eq!(strip_metadata(png.clone(), false).unwrap(), png);
+}
+
+#[test]
+fn test_ingestion_report_push_issue() {
+    let mut report = IngestionReport::new("notes/a.md", None, vec![]);
+    assert_eq!(report.status, IngestionStatus::Ingested);
+
+    report.push_issue(IngestionIssue::warning(
+        None,
+        None,
+        "broken internal link `b.md`: no article matches",
+    ));
+    assert_eq!(report.status, IngestionStatus::IngestedWithWarnings);
+    assert_eq!(report.issues.len(), 1);
+}
+
+fn related_candidate(title: &str, category_slug: &str, tags: &[&str]) -> RelatedCandidate {
+    RelatedCandidate {
+        id: ArticleId::from_uuid(sqlx::types::Uuid::new_v4()),
+        title: title.to_string(),
+        category: category(category_slug),
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        series: None,
+        summary: None,
+        created_at: OffsetDateTime::UNIX_EPOCH,
+    }
+}
+
+#[test]
+fn test_related_score() {
+    let article = related_candidate("Ownership", "article", &["rust", "memory"]);
+
+    let other = related_candidate("Cooking", "note", &["rust"]);
+    assert_eq!(related_score(&article, &other), SHARED_TAG_WEIGHT);
+
+    let mut same_series = related_candidate("Lifetimes", "article", &[]);
+    let mut article_in_series = article.clone();
+    article_in_series.series = Some("Learning Rust".to_string());
+    same_series.series = Some("Learning Rust".to_string());
+    assert_eq!(
+        related_score(&article_in_series, &same_series),
+        SAME_SERIES_WEIGHT + SAME_CATEGORY_WEIGHT
+    );
+    // Articles outside any series don't share a series
+    assert_eq!(
+        related_score(&article, &related_candidate("Lifetimes", "note", &[])),
+        0.0
+    );
+
+    // CJK titles are compared by character pairs
+    let chinese = related_candidate("所有权与借用", "note", &[]);
+    let similar = related_candidate("借用检查器", "think", &[]);
+    let unrelated = related_candidate("今天的晚饭", "think", &[]);
+    assert!(related_score(&chinese, &similar) > 0.0);
+    assert_eq!(related_score(&chinese, &unrelated), 0.0);
+}
+
+fn form(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_micropub_request_from_form() {
+    let request = MicropubRequest::from_form(&form(&[
+        ("h", "entry"),
+        ("content", "Finally tried the new coffee place"),
+        ("category[]", "coffee"),
+        ("category[]", "life"),
+        ("post-status", "draft"),
+        ("mp-syndicate-to", "https://example.org"),
+        ("access_token", "s3cret"),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        request,
+        MicropubRequest::Create(MicropubProperties {
+            content: Some("Finally tried the new coffee place".to_string()),
+            category: vec!["coffee".to_string(), "life".to_string()],
+            post_status: Some("draft".to_string()),
+            ..Default::default()
+        })
+    );
+
+    let request =
+        MicropubRequest::from_form(&form(&[("action", "delete"), ("url", "/talk/1")])).unwrap();
+    assert_eq!(
+        request,
+        MicropubRequest::Delete {
+            url: "/talk/1".to_string()
+        }
+    );
+
+    // Rejected requests
+    for pairs in [
+        &[("h", "event"), ("content", "Party")][..],
+        &[("action", "update"), ("url", "/talk/1")],
+        &[("action", "delete")],
+        &[("action", "undelete"), ("url", "/talk/1")],
+        &[("content", "Hi"), ("post-status", "private")],
+        &[("content", "Hi"), ("published", "yesterday")],
+    ] {
+        assert!(
+            MicropubRequest::from_form(&form(pairs)).is_err(),
+            "{:?}",
+            pairs
+        );
+    }
+}
+
+#[test]
+fn test_micropub_request_from_json() {
+    let request = MicropubRequest::from_json(&serde_json::json!({
+        "type": ["h-entry"],
+        "properties": {
+            "content": [{ "html": "<p>Hello</p>" }],
+            "name": ["Hello"],
+            "published": ["2025-12-09T09:00:00+08:00"]
+        }
+    }))
+    .unwrap();
+
+    let MicropubRequest::Create(properties) = request else {
+        panic!("expected a create request, got {:?}", request);
+    };
+    assert_eq!(properties.content.as_deref(), Some("<p>Hello</p>"));
+    assert_eq!(properties.name.as_deref(), Some("Hello"));
+    assert_eq!(
+        properties.published.unwrap().unix_timestamp(),
+        OffsetDateTime::parse(
+            "2025-12-09T01:00:00Z",
+            &time::format_description::well_known::Rfc3339
+        )
+        .unwrap()
+        .unix_timestamp()
+    );
+
+    let request = MicropubRequest::from_json(&serde_json::json!({
+        "action": "update",
+        "url": "/talk/1",
+        "replace": { "content": ["Edited"] },
+        "delete": ["summary"]
+    }))
+    .unwrap();
+    assert_eq!(
+        request,
+        MicropubRequest::Update {
+            url: "/talk/1".to_string(),
+            update: MicropubUpdate {
+                replace: serde_json::from_value(serde_json::json!({ "content": ["Edited"] }))
+                    .unwrap(),
+                delete: vec!["summary".to_string()],
+                ..Default::default()
+            },
+        }
+    );
+
+    // Rejected requests
+    for body in [
+        serde_json::json!(["h-entry"]),
+        serde_json::json!({ "type": ["h-event"], "properties": {} }),
+        serde_json::json!({ "type": ["h-entry"] }),
+        serde_json::json!({ "type": ["h-entry"], "properties": { "content": "not an array" } }),
+        serde_json::json!({ "action": "update" }),
+        serde_json::json!({ "action": "update", "url": "/talk/1", "delete": "summary" }),
+    ] {
+        assert!(MicropubRequest::from_json(&body).is_err(), "{}", body);
+    }
+}
+
+#[test]
+fn test_micropub_update_apply() {
+    let mut properties = MicropubProperties {
+        content: Some("First".to_string()),
+        category: vec!["coffee".to_string(), "life".to_string()],
+        summary: Some("Short".to_string()),
+        ..Default::default()
+    };
+
+    let update = |body: serde_json::Value| {
+        let MicropubRequest::Update { update, .. } = MicropubRequest::from_json(&body).unwrap()
+        else {
+            panic!("expected an update request");
+        };
+        update
+    };
+
+    update(serde_json::json!({
+        "action": "update",
+        "url": "/talk/1",
+        "replace": { "content": ["Second"] },
+        "add": { "category": ["tea", "coffee"], "name": ["Titled"] },
+        "delete": { "category": ["life"] }
+    }))
+    .apply(&mut properties)
+    .unwrap();
+    assert_eq!(properties.content.as_deref(), Some("Second"));
+    assert_eq!(properties.name.as_deref(), Some("Titled"));
+    assert_eq!(properties.category, ["coffee", "tea"]);
+
+    update(serde_json::json!({
+        "action": "update",
+        "url": "/talk/1",
+        "delete": ["summary", "category"]
+    }))
+    .apply(&mut properties)
+    .unwrap();
+    assert_eq!(properties.summary, None);
+    assert!(properties.category.is_empty());
+
+    // Rejected updates
+    for body in [
+        serde_json::json!({ "replace": { "published": ["2025-12-09T09:00:00Z"] } }),
+        serde_json::json!({ "add": { "content": ["Third"] } }),
+        serde_json::json!({ "delete": ["content"] }),
+        serde_json::json!({ "delete": { "name": ["Titled"] } }),
+    ] {
+        let mut body = body;
+        body["action"] = "update".into();
+        body["url"] = "/talk/1".into();
+        assert!(
+            update(body.clone()).apply(&mut properties).is_err(),
+            "{}",
+            body
+        );
+    }
+}
+
+#[test]
+fn test_micropub_article_id_from_url() {
+    let id = "67e55044-10b1-426f-9247-bb680e5fe0c8";
+
+    for url in [
+        format!("https://example.com/talk/{}", id),
+        format!("/talk/{}/", id),
+        format!("/talk/{}?utm_source=app#top", id),
+    ] {
+        assert_eq!(
+            article_id_from_url(&url).unwrap().to_string(),
+            id,
+            "{}",
+            url
+        );
+    }
+
+    assert!(article_id_from_url("https://example.com/talk/").is_none());
+    assert!(article_id_from_url("").is_none());
+}
+
+#[test]
+fn test_micropub_options_validate() {
+    let registry = CategoryRegistry::default();
+
+    let options = MicropubOptions {
+        site_url: Some(" https://example.com/ ".to_string()),
+        ..Default::default()
+    }
+    .validate(&registry)
+    .unwrap();
+    assert_eq!(options.site_url.as_deref(), Some("https://example.com"));
+    let id: ArticleId = "67e55044-10b1-426f-9247-bb680e5fe0c8".parse().unwrap();
+    assert_eq!(
+        options.post_url(&category("talk"), &id),
+        "https://example.com/talk/67e55044-10b1-426f-9247-bb680e5fe0c8"
+    );
+
+    let unknown_category = MicropubOptions {
+        category: category("reading"),
+        ..Default::default()
+    };
+    assert!(unknown_category.validate(&registry).is_err());
+
+    for site_url in ["example.com", "https://", "ftp://example.com"] {
+        let options = MicropubOptions {
+            site_url: Some(site_url.to_string()),
+            ..Default::default()
+        };
+        assert!(options.validate(&registry).is_err(), "{}", site_url);
+    }
+}
+
+#[test]
+fn test_micropub_token_matches() {
+    assert!(token_matches("s3cret", "s3cret"));
+    assert!(!token_matches("s3cret", "S3cret"));
+    assert!(!token_matches("s3cret", "s3cret "));
+    assert!(!token_matches("s3cret", ""));
+}
+
+#[test]
+fn test_language_tags() {
+    for tag in ["en", "zh-CN", "zh-Hant-TW", "yue", "es-419"] {
+        assert!(is_language_tag(tag), "{}", tag);
+    }
+    for tag in ["", "e", "english", "zh_CN", "en-", "en-toolongsubtag", "*"] {
+        assert!(!is_language_tag(tag), "{}", tag);
+    }
+
+    assert_eq!(primary_language("zh-Hant-TW").as_deref(), Some("zh"));
+    assert_eq!(primary_language(" EN-us ").as_deref(), Some("en"));
+    assert_eq!(primary_language("en_US"), None);
+}
+
+#[test]
+fn test_accept_language() {
+    assert_eq!(
+        accept_language("zh-CN,zh;q=0.9,en-US;q=0.8,en;q=0.7"),
+        ["zh", "en"]
+    );
+    // Sorted by weight, header order for ties, q=0 and junk skipped
+    assert_eq!(
+        accept_language("de;q=0.5, ja, fr;q=0.5, en;q=0, *, x!;q=1, it;q=abc"),
+        ["ja", "de", "fr"]
+    );
+    assert!(accept_language("").is_empty());
+}
+
+#[test]
+fn test_negotiate_language() {
+    let available = ["en".to_string(), "zh".to_string()];
+
+    assert_eq!(
+        negotiate_language(Some("ZH-tw"), Some("en"), &available),
+        Ok(Some("zh".to_string()))
+    );
+    // Explicit languages are used even without content in them
+    assert_eq!(
+        negotiate_language(Some("fr"), None, &available),
+        Ok(Some("fr".to_string()))
+    );
+    assert_eq!(
+        negotiate_language(Some("ALL"), Some("en"), &available),
+        Ok(None)
+    );
+    assert_eq!(
+        negotiate_language(Some("français"), None, &available),
+        Err("français".to_string())
+    );
+
+    assert_eq!(
+        negotiate_language(None, Some("fr, en-GB;q=0.4, zh;q=0.6"), &available),
+        Ok(Some("zh".to_string()))
+    );
+    assert_eq!(negotiate_language(None, Some("fr"), &available), Ok(None));
+    assert_eq!(negotiate_language(None, None, &available), Ok(None));
+}
+
+#[test]
+fn test_article_document_language() {
+    let mut article = Article {
+        id: "00000000-0000-0000-0000-000000000001".parse().unwrap(),
+        path: "posts/hello.md".to_string(),
+        title: "你好".to_string(),
+        tags: vec![],
+        category: category("article"),
+        summary: None,
+        summary_generated: false,
+        content: "Content".to_string(),
+        content_html: String::new(),
+        toc: None,
+        word_count: None,
+        reading_minutes: None,
+        status: "published".to_string(),
+        series: None,
+        series_order: None,
+        description: None,
+        cover_image: None,
+        canonical_url: None,
+        language: Some("zh-CN".to_string()),
+        translation_key: Some("hello".to_string()),
+        license: None,
+        author: None,
+        authors: Vec::new(),
+        pinned: false,
+        pinned_weight: None,
+        featured: false,
+        featured_weight: None,
+        images: Vec::new(),
+        keep_location: false,
+        source: ArticleSource::Git,
+        extra: Default::default(),
+        created_at: OffsetDateTime::now_utc(),
+        updated_at: OffsetDateTime::now_utc(),
+    };
+
+    let document = serde_json::to_value(ArticleDocument::from(&article)).unwrap();
+    assert_eq!(document["lang"], "zh");
+    assert_eq!(document["language"], "zh-CN");
+    assert_eq!(document["translation_key"], "hello");
+
+    article.language = None;
+    let document = serde_json::to_value(ArticleDocument::from(&article)).unwrap();
+    assert!(document["lang"].is_null());
+}
+
+#[test]
+fn test_authors_data_format() {
+    assert_eq!(
+        authors_data_format("data/authors.yaml"),
+        Some(FrontMatterFormat::Yaml)
+    );
+    assert_eq!(
+        authors_data_format("data/authors.toml"),
+        Some(FrontMatterFormat::Toml)
+    );
+    assert_eq!(
+        authors_data_format("data/authors.json"),
+        Some(FrontMatterFormat::Json)
+    );
+    assert_eq!(authors_data_format("data/authors.md"), None);
+    assert_eq!(authors_data_format("authors.yaml"), None);
+}
+
+#[test]
+fn test_is_author_id() {
+    assert!(is_author_id("jane"));
+    assert!(is_author_id("jane_doe-2"));
+    assert!(!is_author_id(""));
+    assert!(!is_author_id("Jane"));
+    assert!(!is_author_id("jane doe"));
+    assert!(!is_author_id(&"a".repeat(65)));
+}
+
+#[test]
+fn test_validate_authors() {
+    let data: AuthorsData = serde_json::from_value(serde_json::json!({
+        "authors": [
+            {
+                "id": "jane",
+                "name": "Jane Doe",
+                "links": [{ "name": "Website", "url": "https://jane.example.com" }]
+            },
+            { "id": "jane", "name": "Jane Again" },
+            {
+                "id": "John",
+                "name": " ",
+                "links": [{ "name": "Mastodon", "url": "mastodon.social/@john" }]
+            }
+        ]
+    }))
+    .unwrap();
+
+    assert!(validate_authors(&data.authors[..1]).is_empty());
+
+    let issues = validate_authors(&data.authors);
+    let messages: Vec<_> = issues.iter().map(|i| i.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "author 2: duplicate id `jane`",
+            "author 3: `John` is not a valid author id",
+            "author 3: `name` must not be empty",
+            "author 3: link `mastodon.social/@john` is not an absolute http(s) URL",
+        ]
+    );
+    assert!(issues.iter().all(|i| i.is_error()));
+    assert!(issues.iter().all(|i| i.field.as_deref() == Some("authors")));
+}
+
+#[test]
+fn test_commit_author_id() {
+    let authors = vec![
+        Author {
+            github: Some("JaneDoe".to_string()),
+            ..Author::placeholder("jane")
+        },
+        Author {
+            emails: vec!["John@Example.com".to_string()],
+            ..Author::placeholder("john")
+        },
+        Author::placeholder("octocat"),
+    ];
+    let commit = |email: Option<&str>, username: Option<&str>| CommitAuthor {
+        name: "Someone".to_string(),
+        email: email.map(str::to_string),
+        username: username.map(str::to_string),
+    };
+
+    let matched = |c: CommitAuthor| c.author_id(&authors);
+    assert_eq!(
+        matched(commit(None, Some("janedoe"))).as_deref(),
+        Some("jane")
+    );
+    assert_eq!(
+        matched(commit(Some("john@example.com"), None)).as_deref(),
+        Some("john")
+    );
+    assert_eq!(
+        matched(commit(None, Some("OctoCat"))).as_deref(),
+        Some("octocat")
+    );
+    assert_eq!(
+        matched(commit(Some("nobody@example.com"), Some("nobody"))),
+        None
+    );
+    assert_eq!(matched(commit(None, None)), None);
+}
+
+#[test]
+fn test_byline() {
+    let profiles = vec![
+        Author {
+            links: vec![AuthorLink {
+                name: "Website".to_string(),
+                url: "https://john.example.com".to_string(),
+            }],
+            ..Author::placeholder("john")
+        },
+        Author::placeholder("jane"),
+    ];
+    let ids = vec!["jane".to_string(), "ghost".to_string(), "john".to_string()];
+
+    let authors = byline(&ids, &profiles);
+    let names: Vec<_> = authors.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, ["jane", "ghost", "john"]);
+    assert_eq!(authors[1], Author::placeholder("ghost"));
+    assert_eq!(authors[2].links.len(), 1);
+}
+
+fn links_schema() -> CollectionSchema {
+    let field = |name: &str, kind: FieldType, required: bool| CollectionField {
+        name: name.to_string(),
+        kind,
+        required,
+    };
+
+    CollectionSchema {
+        name: "links".to_string(),
+        fields: vec![
+            field("name", FieldType::String, true),
+            field("url", FieldType::Url, true),
+            field("since", FieldType::Date, false),
+            field("rank", FieldType::Integer, false),
+            field("tags", FieldType::List, false),
+        ],
+    }
+}
+
+#[test]
+fn test_is_collection_name() {
+    assert!(is_collection_name("links"));
+    assert!(is_collection_name("side_projects-2"));
+    assert!(!is_collection_name(""));
+    assert!(!is_collection_name("Links"));
+    assert!(!is_collection_name("friend links"));
+}
+
+#[test]
+fn test_collection_options_validate() {
+    let options = CollectionOptions {
+        dir: "/content/data/".to_string(),
+        schemas: vec![links_schema()],
+    }
+    .validate()
+    .unwrap();
+    assert_eq!(options.dir, "content/data");
+
+    let mut duplicate_field = links_schema();
+    duplicate_field
+        .fields
+        .push(duplicate_field.fields[0].clone());
+    let mut renamed = links_schema();
+    renamed.name = "Friend Links".to_string();
+
+    for invalid in [
+        CollectionOptions {
+            dir: "/".to_string(),
+            ..CollectionOptions::default()
+        },
+        CollectionOptions {
+            schemas: vec![links_schema(), links_schema()],
+            ..CollectionOptions::default()
+        },
+        CollectionOptions {
+            schemas: vec![duplicate_field],
+            ..CollectionOptions::default()
+        },
+        CollectionOptions {
+            schemas: vec![renamed],
+            ..CollectionOptions::default()
+        },
+    ] {
+        assert!(invalid.clone().validate().is_err(), "{:?}", invalid);
+    }
+}
+
+#[test]
+fn test_collection_file() {
+    let mut authors = links_schema();
+    authors.name = "authors".to_string();
+    let options = CollectionOptions {
+        schemas: vec![links_schema(), authors],
+        ..CollectionOptions::default()
+    };
+
+    for (path, format) in [
+        ("data/links.yaml", FrontMatterFormat::Yaml),
+        ("data/links.yml", FrontMatterFormat::Yaml),
+        ("data/links.toml", FrontMatterFormat::Toml),
+        ("data/links.json", FrontMatterFormat::Json),
+    ] {
+        let (schema, found) = options.collection_file(path).unwrap();
+        assert_eq!((schema.name.as_str(), found), ("links", format), "{path}");
+    }
+
+    // Undeclared, nested, other extensions and the authors data file
+    for path in [
+        "data/projects.yaml",
+        "data/old/links.yaml",
+        "data/links.md",
+        "datalinks.yaml",
+        "posts/links.yaml",
+        "data/authors.yaml",
+    ] {
+        assert!(options.collection_file(path).is_none(), "{path}");
+    }
+}
+
+#[test]
+fn test_collection_schema_validate() {
+    let schema = links_schema();
+
+    let valid = serde_json::json!([
+        { "name": "Jane", "url": "https://jane.example.com", "since": "2024-05-01", "rank": 1 },
+        { "name": "John", "url": "http://john.example.com", "since": "2025-01-02T03:04:05Z", "tags": ["rust"] },
+        { "name": "Nobody", "url": "https://nobody.example.com", "since": null }
+    ]);
+    assert!(schema.validate(&valid).is_empty());
+
+    let invalid = serde_json::json!([
+        { "name": "Jane", "url": "jane.example.com", "rank": 1.5 },
+        { "url": "https://john.example.com", "since": "May 2024", "website": "x" },
+        "just a string"
+    ]);
+    let issues = schema.validate(&invalid);
+    let messages: Vec<(bool, &str)> = issues
+        .iter()
+        .map(|i| (i.is_error(), i.message.as_str()))
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            (true, "entry 1: `url` must be an absolute http(s) URL"),
+            (true, "entry 1: `rank` must be an integer"),
+            (true, "entry 2: missing required field `name`"),
+            (
+                true,
+                "entry 2: `since` must be a YYYY-MM-DD date or RFC 3339 timestamp"
+            ),
+            (
+                false,
+                "entry 2: field `website` is not declared in the schema"
+            ),
+            (true, "entry 3: must be a mapping of fields"),
+        ]
+    );
+    assert_eq!(issues[0].field.as_deref(), Some("url"));
+
+    // A single mapping is one entry
+    let single = serde_json::json!({ "name": "About", "url": "https://example.com" });
+    assert!(schema.validate(&single).is_empty());
+
+    let scalar = serde_json::json!("links");
+    assert!(schema.validate(&scalar)[0].is_error());
+}
+
+#[test]
+fn test_convert_notebook() {
+    let source = serde_json::json!({
+        "nbformat": 4,
+        "nbformat_minor": 5,
+        "metadata": { "language_info": { "name": "python" } },
+        "cells": [
+            {
+                "cell_type": "markdown",
+                "source": ["# Plotting\n", "\n", "![Sketch](attachment:sketch.png)"],
+                "attachments": { "sketch.png": { "image/png": "iVBORw0KGgo=\n" } }
+            },
+            { "cell_type": "raw", "source": "not front matter" },
+            {
+                "cell_type": "code",
+                "source": ["print('```')\n", "1 + 1"],
+                "outputs": [
+                    { "output_type": "stream", "name": "stdout", "text": "\u{1b}[31m```\u{1b}[0m\n" },
+                    { "output_type": "execute_result", "data": { "text/plain": ["2"] } }
+                ]
+            },
+            {
+                "cell_type": "code",
+                "source": "plot()",
+                "outputs": [
+                    {
+                        "output_type": "display_data",
+                        "data": {
+                            "image/png": "iVBORw0KGgo=",
+                            "text/plain": "<Figure size 640x480 with 1 Axes>"
+                        }
+                    },
+                    { "output_type": "display_data", "data": { "text/markdown": "**bold**\n" } },
+                    { "output_type": "error", "ename": "ValueError", "evalue": "bad", "traceback": [] }
+                ]
+            },
+            { "cell_type": "code", "source": "", "outputs": [] }
+        ]
+    });
+
+    let converted = convert_notebook(&source.to_string(), &NotebookOptions::default()).unwrap();
+    assert_eq!(converted.front_matter, None);
+    assert!(converted.issues.is_empty(), "{:?}", converted.issues);
+    assert_eq!(
+        converted.markdown,
+        "# Plotting\n\n\
+         ![Sketch](data:image/png;base64,iVBORw0KGgo=)\n\n\
+         ```python\nprint('```')\n1 + 1\n```\n\n\
+         ````text\n```\n````\n\n\
+         ```text\n2\n```\n\n\
+         ```python\nplot()\n```\n\n\
+         ![output](data:image/png;base64,iVBORw0KGgo=)\n\n\
+         **bold**\n\n\
+         ```text\nValueError: bad\n```\n"
+    );
+}
+
+#[test]
+fn test_convert_notebook_front_matter() {
+    let raw_cell = serde_json::json!({
+        "cell_type": "raw",
+        "source": ["---\n", "title: Notebook\n", "---\n"]
+    });
+    let markdown = serde_json::json!({ "cell_type": "markdown", "source": "Body" });
+    let metadata = serde_json::json!({ "front_matter": { "title": "From metadata" } });
+    let notebook = |metadata: &serde_json::Value, cells: Vec<&serde_json::Value>| {
+        serde_json::json!({ "nbformat": 4, "metadata": metadata, "cells": cells }).to_string()
+    };
+    let options = NotebookOptions::default();
+
+    // A leading raw cell is the front matter, and not part of the body
+    let converted = convert_notebook(
+        &notebook(&serde_json::json!({}), vec![&raw_cell, &markdown]),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(
+        converted.front_matter,
+        Some(NotebookFrontMatter::Cell(
+            "---\ntitle: Notebook\n---\n".to_string()
+        ))
+    );
+    assert_eq!(converted.markdown, "Body\n");
+
+    let converted = convert_notebook(&notebook(&metadata, vec![&markdown]), &options).unwrap();
+    assert_eq!(
+        converted.front_matter,
+        Some(NotebookFrontMatter::Metadata(
+            serde_json::json!({ "title": "From metadata" })
+        ))
+    );
+
+    // The raw cell wins over the metadata
+    let converted =
+        convert_notebook(&notebook(&metadata, vec![&raw_cell, &markdown]), &options).unwrap();
+    assert!(matches!(
+        converted.front_matter,
+        Some(NotebookFrontMatter::Cell(_))
+    ));
+    assert_eq!(converted.issues.len(), 1);
+    assert!(!converted.issues[0].is_error());
+
+    // Only a leading raw cell counts
+    let converted = convert_notebook(
+        &notebook(&serde_json::json!({}), vec![&markdown, &raw_cell]),
+        &options,
+    )
+    .unwrap();
+    assert_eq!(converted.front_matter, None);
+    assert_eq!(converted.markdown, "Body\n");
+}
+
+#[test]
+fn test_convert_notebook_output_limits() {
+    let long_log: Vec<String> = (1..=5).map(|i| format!("epoch {}\n", i)).collect();
+    let source = serde_json::json!({
+        "nbformat": 4,
+        "metadata": {},
+        "cells": [{
+            "cell_type": "code",
+            "source": "train()",
+            "outputs": [
+                { "output_type": "stream", "name": "stdout", "text": long_log },
+                { "output_type": "stream", "name": "stdout", "text": "x".repeat(30) },
+                { "output_type": "display_data", "data": { "image/png": "A".repeat(200) } }
+            ]
+        }]
+    });
+    let options = NotebookOptions {
+        max_output_lines: 2,
+        max_output_chars: 20,
+        max_image_bytes: 100,
+    };
+
+    let converted = convert_notebook(&source.to_string(), &options).unwrap();
+    assert_eq!(
+        converted.markdown,
+        format!(
+            "```\ntrain()\n```\n\n```text\nepoch 1\nepoch 2\n…\n```\n\n```text\n{}\n…\n```\n",
+            "x".repeat(20)
+        )
+    );
+
+    let messages: Vec<_> = converted
+        .issues
+        .iter()
+        .map(|i| i.message.as_str())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "cell 1: output cut to 2 lines or 20 characters",
+            "cell 1: output cut to 2 lines or 20 characters",
+            "cell 1: image/png image of 150 bytes dropped, the limit is 100 bytes",
+        ]
+    );
+    assert!(converted.issues.iter().all(|i| !i.is_error()));
+}
+
+#[test]
+fn test_convert_notebook_invalid() {
+    let options = NotebookOptions::default();
+
+    assert!(convert_notebook("not json", &options).is_err());
+    assert!(convert_notebook(r#"{"nbformat": 3, "worksheets": []}"#, &options).is_err());
+    assert!(
+        convert_notebook(
+            r#"{"nbformat": 4, "cells": [{"cell_type": "heading", "source": ""}]}"#,
+            &options
+        )
+        .is_err()
+    );
+
+    let empty = convert_notebook(r#"{"nbformat": 4, "cells": []}"#, &options).unwrap();
+    assert_eq!(empty.markdown, "");
+
+    assert!(
+        NotebookOptions {
+            max_output_lines: 0,
+            ..NotebookOptions::default()
+        }
+        .validate()
+        .is_err()
+    );
+    assert!(NotebookOptions::default().validate().is_ok());
+}
+
+#[test]
+fn test_source_format_from_path() {
+    assert_eq!(
+        SourceFormat::from_path("posts/intro.md"),
+        Some(SourceFormat::Markdown)
+    );
+    assert_eq!(
+        SourceFormat::from_path("posts/intro.mdx"),
+        Some(SourceFormat::Markdown)
+    );
+    assert_eq!(
+        SourceFormat::from_path("posts/analysis.ipynb"),
+        Some(SourceFormat::Notebook)
+    );
+    assert_eq!(
+        SourceFormat::from_path("posts/emacs.ORG"),
+        Some(SourceFormat::Org)
+    );
+    assert_eq!(
+        SourceFormat::from_path("posts/guide.adoc"),
+        Some(SourceFormat::AsciiDoc)
+    );
+    assert_eq!(
+        SourceFormat::from_path("posts/guide.asciidoc"),
+        Some(SourceFormat::AsciiDoc)
+    );
+
+    assert_eq!(SourceFormat::from_path("data/links.yaml"), None);
+    assert_eq!(SourceFormat::from_path("posts.d/README"), None);
+    assert_eq!(SourceFormat::from_path("Makefile"), None);
+}
+
+#[test]
+fn test_header_value() {
+    assert_eq!(
+        header_value("tags", " rust, web ,, async "),
+        serde_json::json!(["rust", "web", "async"])
+    );
+    assert_eq!(header_value("authors", ""), serde_json::json!([]));
+    assert_eq!(header_value("pinned_weight", "-3"), serde_json::json!(-3));
+    // Left as text for validation to report
+    assert_eq!(
+        header_value("series_order", "two"),
+        serde_json::json!("two")
+    );
+    assert_eq!(header_value("pinned", ""), serde_json::json!(true));
+    assert_eq!(header_value("featured", "false"), serde_json::json!(false));
+    assert_eq!(
+        header_value("keep_location", "yes"),
+        serde_json::json!("yes")
+    );
+    assert_eq!(
+        header_value("extra", r#"{"mood": "calm"}"#),
+        serde_json::json!({ "mood": "calm" })
+    );
+    assert_eq!(header_value("summary", "true"), serde_json::json!("true"));
+}
+
+#[test]
+fn test_convert_org_header() {
+    let source = "\
+# -*- mode: org -*-
+#+TITLE: Emacs for Rust
+#+OPTIONS: toc:nil
+#+AUTHOR: Jane Doe, Kim Lee
+#+FILETAGS: :emacs:rust:
+#+PROPERTY: id 67e55044-10b1-426f-9247-bb680e5fe0c8
+#+PROPERTY: category article
+#+PROPERTY: status published
+#+PROPERTY: series_order 2
+#+PROPERTY: pinned
+#+title: Repeated
+
+Body with #+KEYWORD lines after it.
+#+PROPERTY: summary not front matter
+";
+
+    let converted = convert_org(source);
+    assert_eq!(
+        converted.front_matter,
+        Some(serde_json::json!({
+            "title": "Emacs for Rust",
+            "authors": ["Jane Doe", "Kim Lee"],
+            "tags": ["emacs", "rust"],
+            "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "category": "article",
+            "status": "published",
+            "series_order": 2,
+            "pinned": true,
+        }))
+    );
+    assert_eq!(converted.key_lines.get("title"), Some(&2));
+    assert_eq!(converted.key_lines.get("authors"), Some(&4));
+    assert_eq!(converted.key_lines.get("status"), Some(&8));
+    assert_eq!(converted.markdown, "Body with #+KEYWORD lines after it.\n");
+
+    assert_eq!(converted.issues.len(), 1);
+    assert_eq!(converted.issues[0].line, Some(11));
+    assert!(!converted.issues[0].is_error());
+
+    // Once the author names are resolved, the header validates like markdown
+    // front matter
+    let mut converted = converted;
+    converted.resolve_authors(&[
+        Author {
+            name: "Jane Doe".to_string(),
+            ..Author::placeholder("jane")
+        },
+        Author {
+            name: "Kim Lee".to_string(),
+            ..Author::placeholder("kim")
+        },
+    ]);
+    let validation = validate_front_matter(converted.front_matter.as_ref(), &converted.key_lines);
+    let front_matter = validation.front_matter.unwrap();
+    assert_eq!(front_matter.title, "Emacs for Rust");
+    assert_eq!(front_matter.tags, vec!["emacs", "rust"]);
+    assert_eq!(front_matter.series_order, Some(2));
+    assert!(front_matter.pinned);
+    assert_eq!(front_matter.authors, ["jane", "kim"]);
+    assert_eq!(front_matter.author, None);
+
+    let untitled = convert_org("Just text\n");
+    assert_eq!(untitled.front_matter, None);
+    assert_eq!(untitled.markdown, "Just text\n");
+}
+
+#[test]
+fn test_convert_org_body() {
+    let source = "\
+#+TITLE: Body
+
+* Setup :emacs:
+:PROPERTIES:
+:CUSTOM_ID: setup
+:END:
+Install it with =cargo install= or ~make~, see [[https://example.com][the /docs/]].
+Paths like /usr/bin and a+b=c stay as they are.
+# A comment
+** TODO Notes
+- one
++ two
+  1) nested
+- term :: meaning
+#+BEGIN_SRC rust :exports both
+fn main() {}
+,* not a headline
+#+END_SRC
+#+begin_quote
+Be *bold*.
+#+end_quote
+: $ cargo run
+: done
+| Name | Value |
+|------+-------|
+| a    | +gone+ |
+-----
+[[file:img/crab.png]] [[../notes/ownership.org][Ownership]] [[Lifetimes]] [[*Setup]]
+";
+
+    let converted = convert_org(source);
+    assert_eq!(
+        converted.markdown,
+        "\
+## Setup
+Install it with `cargo install` or `make`, see [the *docs*](https://example.com).
+Paths like /usr/bin and a+b=c stay as they are.
+### TODO Notes
+- one
+- two
+  1. nested
+- **term**: meaning
+```rust
+fn main() {}
+* not a headline
+```
+> Be **bold**.
+```text
+$ cargo run
+done
+```
+| Name | Value |
+| --- | --- |
+| a | ~~gone~~ |
+---
+![](img/crab.png) [Ownership](../notes/ownership.org) [[Lifetimes]] Setup
+"
+    );
+}
+
+#[test]
+fn test_convert_asciidoc_header() {
+    let source = "\
+// Draft of the guide
+= Writing AsciiDoc
+Jane Doe <jane@example.com>; Kim Lee
+v1.0, 2024-03-01
+:id: 67e55044-10b1-426f-9247-bb680e5fe0c8
+:category: article
+:status: published
+:keywords: docs, asciidoc
+:description: How to write \\
+  posts in AsciiDoc
+:lang: en
+:toc: left
+:product: Mizu
+:title: Repeated
+:status!:
+
+Welcome to {product}, not {unknown}.
+";
+
+    let converted = convert_asciidoc(source);
+    assert_eq!(
+        converted.front_matter,
+        Some(serde_json::json!({
+            "title": "Writing AsciiDoc",
+            "authors": ["Jane Doe", "Kim Lee"],
+            "id": "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "category": "article",
+            "status": "published",
+            "tags": ["docs", "asciidoc"],
+            "description": "How to write posts in AsciiDoc",
+            "lang": "en",
+        }))
+    );
+    assert_eq!(converted.key_lines.get("title"), Some(&2));
+    assert_eq!(converted.key_lines.get("tags"), Some(&8));
+    assert_eq!(converted.key_lines.get("lang"), Some(&11));
+    assert_eq!(converted.markdown, "Welcome to Mizu, not {unknown}.\n");
+
+    assert_eq!(converted.issues.len(), 1);
+    assert_eq!(converted.issues[0].line, Some(14));
+
+    // Kim Lee has no profile
+    let mut converted = converted;
+    converted.resolve_authors(&[Author {
+        name: "Jane Doe".to_string(),
+        ..Author::placeholder("jane")
+    }]);
+    assert_eq!(converted.issues.len(), 2);
+    assert_eq!(converted.issues[1].line, Some(3));
+
+    let validation = validate_front_matter(converted.front_matter.as_ref(), &converted.key_lines);
+    let front_matter = validation.front_matter.unwrap();
+    assert_eq!(front_matter.language.as_deref(), Some("en"));
+    assert_eq!(front_matter.authors, ["jane"]);
+    assert_eq!(front_matter.author, None);
+
+    // Attributes without a title are a header too
+    let untitled = convert_asciidoc(":status: draft\n\nText\n");
+    assert_eq!(
+        untitled.front_matter,
+        Some(serde_json::json!({ "status": "draft" }))
+    );
+    assert_eq!(untitled.markdown, "Text\n");
+}
+
+#[test]
+fn test_convert_asciidoc_body() {
+    let source = "\
+= Body
+
+== Install
+Run `+cargo *install*+` from https://example.com[the site^] or https://example.com.
+See link:../notes/ownership.adoc[Ownership], xref:guide.adoc#setup[setup] and <<usage,usage>>.
+// A comment
+
+[[usage]]
+=== Usage
+* one
+** nested
+. first
+.. second
+CLI:: command line
+
+NOTE: Back up first.
+
+.Example
+[source,rust]
+----
+fn main() {}
+----
+
+[quote, Ferris]
+____
+Be *bold*.
+____
+
+[WARNING]
+====
+Careful.
+====
+
+image::img/crab.png[Crab, 200]
+'''
+|===
+|Name |Value
+
+|a
+|b
+|===
+////
+Hidden
+////
+";
+
+    let converted = convert_asciidoc(source);
+    assert_eq!(
+        converted.markdown,
+        "\
+## Install
+Run `cargo *install*` from [the site](https://example.com) or https://example.com.
+See [Ownership](../notes/ownership.adoc), [setup](guide.adoc#setup) and [usage](#usage).
+
+### Usage
+- one
+  - nested
+1. first
+   1. second
+**CLI**: command line
+
+> **Note:** Back up first.
+
+*Example*
+```rust
+fn main() {}
+```
+
+> Be **bold**.
+>
+> — Ferris
+
+> **Warning:**
+>
+> Careful.
+
+![Crab](img/crab.png)
+---
+| Name | Value |
+| --- | --- |
+| a | b |
+"
+    );
+}
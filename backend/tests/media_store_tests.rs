@@ -0,0 +1,209 @@
+//! Media store tests
+//! Runs both stores through the same put/get/delete cycle; the S3 store talks
+//! to an in-process stand-in for an S3-compatible service such as MinIO
+
+use axum::{
+    Router,
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, Method, StatusCode, Uri, header},
+    response::{IntoResponse, Response},
+};
+use backend::domain::media::{MediaContent, MediaHash, MediaStore, S3Options};
+use backend::infrastructure::media::{local::LocalMediaStore, s3::S3MediaStore};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+/// Object stored by the stand-in, with the headers it was uploaded with
+#[derive(Clone)]
+struct StoredObject {
+    bytes: Vec<u8>,
+    content_type: Option<String>,
+    cache_control: Option<String>,
+}
+
+type Objects = Arc<Mutex<HashMap<String, StoredObject>>>;
+
+/// Minimal S3-compatible service: PUT, GET and DELETE of objects addressed
+/// path-style as `/{bucket}/{key}`. Requests must carry a SigV4 signature,
+/// which is not verified.
+async fn s3_stand_in(
+    State(objects): State<Objects>,
+    method: Method,
+    uri: Uri,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let signed = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("AWS4-HMAC-SHA256"));
+    if !signed {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    let key = uri.path().to_string();
+    let header = |name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let mut objects = objects.lock().unwrap();
+
+    match method {
+        Method::PUT => {
+            objects.insert(
+                key,
+                StoredObject {
+                    bytes: body.to_vec(),
+                    content_type: header(header::CONTENT_TYPE),
+                    cache_control: header(header::CACHE_CONTROL),
+                },
+            );
+            (StatusCode::OK, [(header::ETAG, "\"stand-in\"")]).into_response()
+        }
+        Method::GET => match objects.get(&key) {
+            Some(object) => object.bytes.clone().into_response(),
+            None => (
+                StatusCode::NOT_FOUND,
+                "<Error><Code>NoSuchKey</Code></Error>",
+            )
+                .into_response(),
+        },
+        Method::DELETE => {
+            objects.remove(&key);
+            StatusCode::NO_CONTENT.into_response()
+        }
+        _ => StatusCode::METHOD_NOT_ALLOWED.into_response(),
+    }
+}
+
+/// Start the stand-in on a free local port
+///
+/// # Returns
+///
+/// The endpoint URL and the objects it holds
+async fn spawn_s3_stand_in() -> (String, Objects) {
+    let objects = Objects::default();
+    let app = Router::new()
+        .fallback(s3_stand_in)
+        .with_state(objects.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+    (endpoint, objects)
+}
+
+fn s3_options(endpoint: &str, presign_seconds: u32) -> S3Options {
+    S3Options {
+        bucket: "mizu-media".to_string(),
+        region: "us-east-1".to_string(),
+        endpoint: Some(endpoint.to_string()),
+        path_style: true,
+        access_key: Some("minioadmin".to_string()),
+        secret_key: Some("minioadmin".to_string()),
+        prefix: "/media/".to_string(),
+        presign_seconds,
+    }
+}
+
+fn media_hash(hex: &str) -> MediaHash {
+    hex.parse().unwrap()
+}
+
+/// Put, read and delete a blob through any store
+async fn exercise_store(store: &dyn MediaStore) {
+    let hash = media_hash("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08");
+    let missing = media_hash("60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752");
+
+    store.put(&hash, b"webp bytes", "image/webp").await.unwrap();
+    // Blobs are content addressed, writing one twice is harmless
+    store.put(&hash, b"webp bytes", "image/webp").await.unwrap();
+
+    assert_eq!(
+        store.get(&hash).await.unwrap().as_deref(),
+        Some(&b"webp bytes"[..])
+    );
+    assert_eq!(store.get(&missing).await.unwrap(), None);
+
+    store.delete(&hash).await.unwrap();
+    assert_eq!(store.get(&hash).await.unwrap(), None);
+
+    // Deleting a missing blob is not an error
+    store.delete(&missing).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_local_media_store() {
+    let root = std::env::temp_dir().join("mizu-test-media-store");
+    let store = LocalMediaStore::new(&root);
+
+    exercise_store(&store).await;
+
+    let hash = media_hash("fd61a03af4f77d870fc21e05e7e80678095c92d808cfb3b5c279ee04c74aca13");
+    store.put(&hash, b"avif", "image/avif").await.unwrap();
+    assert!(root.join("fd").join(hash.as_str()).exists());
+
+    // Local blobs are always proxied
+    assert_eq!(store.presigned_read(&hash).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_s3_media_store() {
+    let (endpoint, objects) = spawn_s3_stand_in().await;
+    let store = S3MediaStore::new(&s3_options(&endpoint, 0)).unwrap();
+
+    exercise_store(&store).await;
+
+    let hash = media_hash("fd61a03af4f77d870fc21e05e7e80678095c92d808cfb3b5c279ee04c74aca13");
+    store.put(&hash, b"avif", "image/avif").await.unwrap();
+
+    let key = format!("/mizu-media/media/fd/{}", hash);
+    let object = objects.lock().unwrap()[&key].clone();
+    assert_eq!(object.bytes, b"avif");
+    assert_eq!(object.content_type.as_deref(), Some("image/avif"));
+    assert_eq!(
+        object.cache_control.as_deref(),
+        Some("public, max-age=31536000, immutable")
+    );
+
+    // Without a presign lifetime reads are proxied
+    assert_eq!(store.presigned_read(&hash).await.unwrap(), None);
+}
+
+#[tokio::test]
+async fn test_s3_media_store_presigned_read() {
+    let (endpoint, _) = spawn_s3_stand_in().await;
+    let store = S3MediaStore::new(&s3_options(&endpoint, 3600)).unwrap();
+    let hash = media_hash("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08");
+
+    let Some(MediaContent::Redirect { url, expires_in }) =
+        store.presigned_read(&hash).await.unwrap()
+    else {
+        panic!("expected a presigned URL");
+    };
+
+    assert_eq!(expires_in, 3600);
+    assert!(url.starts_with(&format!("{}/mizu-media/media/9f/{}?", endpoint, hash)));
+    assert!(url.contains("X-Amz-Expires=3600"));
+    assert!(url.contains("X-Amz-Signature="));
+}
+
+#[test]
+fn test_s3_options_validate() {
+    assert!(s3_options("http://localhost:9000", 3600).validate().is_ok());
+
+    let mut options = s3_options("http://localhost:9000", 0);
+    options.bucket = " ".to_string();
+    assert!(options.validate().is_err());
+
+    let mut options = s3_options("http://localhost:9000", 0);
+    options.secret_key = None;
+    assert!(options.validate().is_err());
+
+    let options = s3_options("http://localhost:9000", 8 * 24 * 60 * 60);
+    assert!(options.validate().is_err());
+}
@@ -5,9 +5,27 @@ mod common;
 
 use backend::application::article_service::ArticleService;
 use backend::config::AppConfig;
-use backend::domain::articles::PostCategory;
+use backend::domain::archives::ArchivePeriod;
+use backend::domain::articles::{Article, ArticleSource};
+use backend::domain::authors::Author;
+use backend::domain::categories::{Category, CategoryRegistry};
+use backend::domain::collections::{Collection, CollectionOptions, CollectionSchema};
+use backend::domain::exif::ExifMetadata;
+use backend::domain::gallery::GalleryImage;
+use backend::domain::ingestion::{IngestionIssue, IngestionReport, IngestionStatus};
+use backend::domain::media::{MediaAsset, MediaContent, MediaHash, MediaOptions, MediaStore};
+use backend::domain::micropub::{MicropubOptions, MicropubOutcome, MicropubRequest};
+use backend::domain::notebooks::NotebookOptions;
+use backend::domain::reading::ReadingSpeeds;
+use backend::domain::summary::SummaryOptions;
+use backend::domain::tags::{TagNormalizer, TagSynonym};
+use backend::domain::toc::TocEntry;
+use backend::errors::{GetPostsError, MediaError, MicropubError, SomeError};
+use backend::infrastructure::media::local::LocalMediaStore;
+use backend::interfaces::http::dtos::{ArchiveMonth, ContentFormat, PostResponse};
 use common::{
-    MockArticleRepository, MockSearchService, create_test_article, create_test_search_hit,
+    ArticleBuilder, MockArticleRepository, MockSearchService, create_service, create_test_article,
+    create_test_media_store, create_test_search_hit, test_article_id, test_category,
 };
 use std::sync::Arc;
 
@@ -17,8 +35,41 @@ use common::MockGithubClient;
 use std::collections::HashSet;
 
 fn create_test_config() -> Arc<AppConfig> {
+    create_test_config_with_categories(CategoryRegistry::default())
+}
+
+fn create_test_config_with_categories(categories: CategoryRegistry) -> Arc<AppConfig> {
+    create_test_config_with(categories, TagNormalizer::default())
+}
+
+fn create_test_config_with(categories: CategoryRegistry, tags: TagNormalizer) -> Arc<AppConfig> {
+    create_test_config_with_micropub(
+        categories,
+        tags,
+        MicropubOptions::default(),
+        Some(TEST_MICROPUB_TOKEN),
+    )
+}
+
+const TEST_MICROPUB_TOKEN: &str = "test_micropub_token";
+
+fn create_test_config_with_micropub(
+    categories: CategoryRegistry,
+    tags: TagNormalizer,
+    micropub: MicropubOptions,
+    micropub_token: Option<&str>,
+) -> Arc<AppConfig> {
     Arc::new(AppConfig::new(
         "test_jwt_secret",
+        categories,
+        tags,
+        ReadingSpeeds::default(),
+        SummaryOptions::default(),
+        MediaOptions::default(),
+        micropub,
+        micropub_token,
+        CollectionOptions::default(),
+        NotebookOptions::default(),
         #[cfg(feature = "webhook")]
         "test_webhook_secret",
         #[cfg(feature = "webhook")]
@@ -48,7 +99,7 @@ fn create_test_config() -> Arc<AppConfig> {
 
 // #[tokio::test]
 // async fn test_article_service_is_valid_id_existing() {
-//     let article = create_test_article("existing-id", "Existing Article", PostCategory::Article);
+//     let article = create_test_article("existing-id", "Existing Article", test_category("article"));
 //     let repo = Arc::new(MockArticleRepository::with_articles(vec![article]));
 //     let search = Arc::new(MockSearchService::new());
 //     let config = create_test_config();
@@ -67,9 +118,9 @@ fn create_test_config() -> Arc<AppConfig> {
 
 #[tokio::test]
 async fn test_article_service_get_posts_by_category() {
-    let article1 = create_test_article("1", "Article 1", PostCategory::Article);
-    let article2 = create_test_article("2", "Article 2", PostCategory::Article);
-    let note = create_test_article("3", "Note 1", PostCategory::Note);
+    let article1 = create_test_article(test_article_id(1), "Article 1", test_category("article"));
+    let article2 = create_test_article(test_article_id(2), "Article 2", test_category("article"));
+    let note = create_test_article(test_article_id(3), "Note 1", test_category("note"));
 
     let repo = Arc::new(MockArticleRepository::with_articles(vec![
         article1, article2, note,
@@ -82,11 +133,12 @@ async fn test_article_service_get_posts_by_category() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
     let results = service
-        .get_posts_by_category("article", 10, 0)
+        .get_posts_by_category("article", None, 10, 0)
         .await
         .unwrap();
     assert_eq!(results.len(), 2);
@@ -97,9 +149,9 @@ async fn test_article_service_get_posts_by_category_pagination() {
     let articles: Vec<_> = (0..25)
         .map(|i| {
             create_test_article(
-                &format!("article-{}", i),
+                test_article_id(i),
                 &format!("Article {}", i),
-                PostCategory::Article,
+                test_category("article"),
             )
         })
         .collect();
@@ -113,26 +165,27 @@ async fn test_article_service_get_posts_by_category_pagination() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
     // 第一页：10 条
     let page1 = service
-        .get_posts_by_category("article", 10, 0)
+        .get_posts_by_category("article", None, 10, 0)
         .await
         .unwrap();
     assert_eq!(page1.len(), 10);
 
     // 第二页：10 条
     let page2 = service
-        .get_posts_by_category("article", 10, 10)
+        .get_posts_by_category("article", None, 10, 10)
         .await
         .unwrap();
     assert_eq!(page2.len(), 10);
 
     // 第三页：5 条
     let page3 = service
-        .get_posts_by_category("article", 10, 20)
+        .get_posts_by_category("article", None, 10, 20)
         .await
         .unwrap();
     assert_eq!(page3.len(), 5);
@@ -149,11 +202,12 @@ async fn test_article_service_get_posts_empty_category() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
     let results = service
-        .get_posts_by_category("article", 10, 0)
+        .get_posts_by_category("article", None, 10, 0)
         .await
         .unwrap();
     assert_eq!(results.len(), 0);
@@ -161,7 +215,11 @@ async fn test_article_service_get_posts_empty_category() {
 
 #[tokio::test]
 async fn test_article_service_get_article_by_id() {
-    let article = create_test_article("test-123", "Test Article", PostCategory::Article);
+    let article = create_test_article(
+        test_article_id(123),
+        "Test Article",
+        test_category("article"),
+    );
     let repo = Arc::new(MockArticleRepository::with_articles(vec![article]));
     let search = Arc::new(MockSearchService::new());
     let config = create_test_config();
@@ -171,12 +229,16 @@ async fn test_article_service_get_article_by_id() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
-    let found = service.get_article_by_id("test-123").await.unwrap();
+    let found = service
+        .get_article_by_id(&test_article_id(123))
+        .await
+        .unwrap();
     assert_eq!(found.title, "Test Article");
-    assert_eq!(found.id, "test-123");
+    assert_eq!(found.id, test_article_id(123));
 }
 
 #[tokio::test]
@@ -190,10 +252,11 @@ async fn test_article_service_get_article_by_id_not_found() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
-    let result = service.get_article_by_id("non-existent").await;
+    let result = service.get_article_by_id(&test_article_id(999)).await;
     assert!(result.is_err());
 }
 
@@ -204,8 +267,8 @@ async fn test_article_service_search() {
     let config = create_test_config();
 
     // 设置搜索结果
-    let hit1 = create_test_search_hit("1", "Rust Programming");
-    let hit2 = create_test_search_hit("2", "Rust Web Development");
+    let hit1 = create_test_search_hit(test_article_id(1), "Rust Programming");
+    let hit2 = create_test_search_hit(test_article_id(2), "Rust Web Development");
     search.set_search_result("rust", vec![hit1, hit2]);
 
     let service = ArticleService::new(
@@ -213,11 +276,14 @@ async fn test_article_service_search() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
-    let (results, total_hits, total_pages, current_page) =
-        service.search("articles", "rust", 1, 10).await.unwrap();
+    let (results, total_hits, total_pages, current_page) = service
+        .search("articles", "rust", None, 1, 10)
+        .await
+        .unwrap();
 
     assert_eq!(results.len(), 2);
     assert_eq!(total_hits, 2);
@@ -233,7 +299,7 @@ async fn test_article_service_search_pagination() {
 
     // 设置 15 个搜索结果
     let hits: Vec<_> = (0..15)
-        .map(|i| create_test_search_hit(&format!("id-{}", i), &format!("Article {}", i)))
+        .map(|i| create_test_search_hit(test_article_id(i), &format!("Article {}", i)))
         .collect();
     search.set_search_result("test", hits);
 
@@ -242,25 +308,33 @@ async fn test_article_service_search_pagination() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
     // 第一页：5 条
-    let (results1, total1, pages1, page1) = service.search("articles", "test", 1, 5).await.unwrap();
+    let (results1, total1, pages1, page1) = service
+        .search("articles", "test", None, 1, 5)
+        .await
+        .unwrap();
     assert_eq!(results1.len(), 5);
     assert_eq!(total1, 15);
     assert_eq!(pages1, 3);
     assert_eq!(page1, 1);
 
     // 第二页：5 条
-    let (results2, _total2, _pages2, page2) =
-        service.search("articles", "test", 2, 5).await.unwrap();
+    let (results2, _total2, _pages2, page2) = service
+        .search("articles", "test", None, 2, 5)
+        .await
+        .unwrap();
     assert_eq!(results2.len(), 5);
     assert_eq!(page2, 2);
 
     // 第三页：5 条
-    let (results3, _total3, _pages3, page3) =
-        service.search("articles", "test", 3, 5).await.unwrap();
+    let (results3, _total3, _pages3, page3) = service
+        .search("articles", "test", None, 3, 5)
+        .await
+        .unwrap();
     assert_eq!(results3.len(), 5);
     assert_eq!(page3, 3);
 }
@@ -276,11 +350,12 @@ async fn test_article_service_search_empty_query() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
     let (results, total_hits, _, _) = service
-        .search("articles", "nonexistent", 1, 10)
+        .search("articles", "nonexistent", None, 1, 10)
         .await
         .unwrap();
 
@@ -296,7 +371,7 @@ async fn test_article_service_search_with_different_page_sizes() {
 
     // 设置 20 个搜索结果
     let hits: Vec<_> = (0..20)
-        .map(|i| create_test_search_hit(&format!("id-{}", i), &format!("Article {}", i)))
+        .map(|i| create_test_search_hit(test_article_id(i), &format!("Article {}", i)))
         .collect();
     search.set_search_result("test", hits);
 
@@ -305,19 +380,29 @@ async fn test_article_service_search_with_different_page_sizes() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
     // 测试不同的页面大小
-    let (results_10, _, pages_10, _) = service.search("articles", "test", 1, 10).await.unwrap();
+    let (results_10, _, pages_10, _) = service
+        .search("articles", "test", None, 1, 10)
+        .await
+        .unwrap();
     assert_eq!(results_10.len(), 10);
     assert_eq!(pages_10, 2);
 
-    let (results_5, _, pages_5, _) = service.search("articles", "test", 1, 5).await.unwrap();
+    let (results_5, _, pages_5, _) = service
+        .search("articles", "test", None, 1, 5)
+        .await
+        .unwrap();
     assert_eq!(results_5.len(), 5);
     assert_eq!(pages_5, 4);
 
-    let (results_20, _, pages_20, _) = service.search("articles", "test", 1, 20).await.unwrap();
+    let (results_20, _, pages_20, _) = service
+        .search("articles", "test", None, 1, 20)
+        .await
+        .unwrap();
     assert_eq!(results_20.len(), 20);
     assert_eq!(pages_20, 1);
 }
@@ -325,11 +410,11 @@ async fn test_article_service_search_with_different_page_sizes() {
 #[tokio::test]
 async fn test_article_service_multiple_categories() {
     let articles = vec![
-        create_test_article("a1", "Article 1", PostCategory::Article),
-        create_test_article("a2", "Article 2", PostCategory::Article),
-        create_test_article("n1", "Note 1", PostCategory::Note),
-        create_test_article("n2", "Note 2", PostCategory::Note),
-        create_test_article("t1", "Think 1", PostCategory::Think),
+        create_test_article(test_article_id(1), "Article 1", test_category("article")),
+        create_test_article(test_article_id(2), "Article 2", test_category("article")),
+        create_test_article(test_article_id(3), "Note 1", test_category("note")),
+        create_test_article(test_article_id(4), "Note 2", test_category("note")),
+        create_test_article(test_article_id(5), "Think 1", test_category("think")),
     ];
 
     let repo = Arc::new(MockArticleRepository::with_articles(articles));
@@ -341,19 +426,1757 @@ async fn test_article_service_multiple_categories() {
         #[cfg(feature = "webhook")]
         Arc::new(MockGithubClient::new()),
         search,
+        create_test_media_store(),
         config,
     );
 
     // 测试不同分类
     let articles = service
-        .get_posts_by_category("article", 10, 0)
+        .get_posts_by_category("article", None, 10, 0)
         .await
         .unwrap();
     assert_eq!(articles.len(), 2);
 
-    let notes = service.get_posts_by_category("note", 10, 0).await.unwrap();
+    let notes = service
+        .get_posts_by_category("note", None, 10, 0)
+        .await
+        .unwrap();
     assert_eq!(notes.len(), 2);
 
-    let thinks = service.get_posts_by_category("think", 10, 0).await.unwrap();
+    let thinks = service
+        .get_posts_by_category("think", None, 10, 0)
+        .await
+        .unwrap();
     assert_eq!(thinks.len(), 1);
 }
+
+fn test_category_config(slug: &str, sort_order: i32, visible: bool) -> Category {
+    Category {
+        slug: test_category(slug),
+        display_name: slug.to_uppercase(),
+        description: None,
+        sort_order,
+        page_size: 12,
+        visible,
+        optional_title: false,
+    }
+}
+
+#[tokio::test]
+async fn test_article_service_get_categories_default() {
+    let service = create_service(
+        Arc::new(MockArticleRepository::new()),
+        create_test_config_with_categories(CategoryRegistry::default()),
+    );
+
+    let slugs: Vec<String> = service
+        .get_categories()
+        .await
+        .into_iter()
+        .map(|c| c.slug.to_string())
+        .collect();
+    assert_eq!(slugs, ["article", "note", "think", "pictures", "talk"]);
+}
+
+#[tokio::test]
+async fn test_article_service_get_categories_hides_invisible() {
+    let registry = CategoryRegistry::new(vec![
+        test_category_config("reading", 20, true),
+        test_category_config("drafts", 0, false),
+        test_category_config("article", 10, true),
+    ])
+    .unwrap();
+    let service = create_service(
+        Arc::new(MockArticleRepository::new()),
+        create_test_config_with_categories(registry),
+    );
+
+    let slugs: Vec<String> = service
+        .get_categories()
+        .await
+        .into_iter()
+        .map(|c| c.slug.to_string())
+        .collect();
+    assert_eq!(slugs, ["article", "reading"]);
+}
+
+#[tokio::test]
+async fn test_article_service_get_listed_category() {
+    let registry = CategoryRegistry::new(vec![
+        test_category_config("reading", 0, true),
+        test_category_config("drafts", 10, false),
+    ])
+    .unwrap();
+    let service = create_service(
+        Arc::new(MockArticleRepository::new()),
+        create_test_config_with_categories(registry),
+    );
+
+    let reading = service
+        .get_listed_category(&test_category("reading"))
+        .await
+        .unwrap();
+    assert_eq!(reading.page_size, 12);
+
+    // Hidden categories cannot be listed
+    let hidden = service.get_listed_category(&test_category("drafts")).await;
+    assert!(matches!(
+        hidden,
+        Err(SomeError::GetPosts(GetPostsError::UnknownCategory(_)))
+    ));
+
+    // Built-in slugs are not implied once categories are configured
+    let unknown = service.get_listed_category(&test_category("article")).await;
+    assert!(matches!(
+        unknown,
+        Err(SomeError::GetPosts(GetPostsError::UnknownCategory(_)))
+    ));
+}
+
+fn rust_synonyms() -> TagNormalizer {
+    TagNormalizer::new(vec![TagSynonym {
+        canonical: "rust".to_string(),
+        aliases: vec!["rust-lang".to_string()],
+    }])
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_article_service_get_tags_counts() {
+    let draft = ArticleBuilder::new(4)
+        .tags(&["rust", "draft-only"])
+        .draft()
+        .build();
+
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1).tags(&["rust", "web"]).build(),
+            ArticleBuilder::new(2).tags(&["rust"]).build(),
+            ArticleBuilder::new(3).tags(&["async"]).build(),
+            draft,
+        ])),
+        create_test_config_with(CategoryRegistry::default(), rust_synonyms()),
+    );
+
+    let tags = service.get_tags().await.unwrap();
+    let pairs: Vec<(&str, i64)> = tags.iter().map(|t| (t.name.as_str(), t.count)).collect();
+    assert_eq!(pairs, [("rust", 2), ("async", 1), ("web", 1)]);
+}
+
+#[tokio::test]
+async fn test_article_service_get_posts_by_tag_resolves_synonyms() {
+    let draft = ArticleBuilder::new(4).tags(&["rust"]).draft().build();
+
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1).tags(&["rust"]).build(),
+            ArticleBuilder::new(2).tags(&["rust", "web"]).build(),
+            ArticleBuilder::new(3).tags(&["web"]).build(),
+            draft,
+        ])),
+        create_test_config_with(CategoryRegistry::default(), rust_synonyms()),
+    );
+
+    // Case, full-width and synonym variants all resolve to "rust"
+    for tag in ["rust", "RUST", "Ｒｕｓｔ", "Rust-Lang"] {
+        let posts = service.get_posts_by_tag(tag, None, 10, 0).await.unwrap();
+        assert_eq!(posts.len(), 2, "tag {tag}");
+    }
+
+    // Pagination
+    let page2 = service.get_posts_by_tag("rust", None, 1, 1).await.unwrap();
+    assert_eq!(page2.len(), 1);
+}
+
+#[tokio::test]
+async fn test_article_service_get_posts_by_tag_invalid() {
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(Vec::new())),
+        create_test_config_with(CategoryRegistry::default(), rust_synonyms()),
+    );
+
+    let result = service.get_posts_by_tag("   ", None, 10, 0).await;
+    assert!(matches!(
+        result,
+        Err(SomeError::GetPosts(GetPostsError::InvalidTag(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_get_series_order() {
+    let draft = ArticleBuilder::new(6)
+        .series("Learning Rust", Some(5))
+        .day(5)
+        .draft()
+        .build();
+
+    // Ingested out of order: part 3 first, then an unnumbered part, then 1 and 2
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(3)
+                .series("Learning Rust", Some(3))
+                .day(1)
+                .build(),
+            ArticleBuilder::new(4)
+                .series("Learning Rust", None)
+                .day(2)
+                .build(),
+            ArticleBuilder::new(1)
+                .series("Learning Rust", Some(1))
+                .day(3)
+                .build(),
+            ArticleBuilder::new(2)
+                .series("Learning Rust", Some(2))
+                .day(4)
+                .build(),
+            ArticleBuilder::new(5)
+                .series("Other", Some(1))
+                .day(0)
+                .build(),
+            draft,
+        ])),
+        create_test_config(),
+    );
+
+    let series = service.get_series("Learning Rust").await.unwrap();
+    assert_eq!(series.name, "Learning Rust");
+    assert_eq!(series.total, 4);
+
+    let ids: Vec<_> = series.parts.iter().map(|p| p.id).collect();
+    assert_eq!(
+        ids,
+        [
+            test_article_id(1),
+            test_article_id(2),
+            test_article_id(3),
+            test_article_id(4)
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_article_service_get_series_not_found() {
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(Vec::new())),
+        create_test_config(),
+    );
+
+    let result = service.get_series("Missing").await;
+    assert!(matches!(
+        result,
+        Err(SomeError::GetPosts(GetPostsError::SeriesNotFound(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_get_article_detail_series_navigation() {
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1)
+                .series("Learning Rust", Some(1))
+                .day(0)
+                .build(),
+            ArticleBuilder::new(2)
+                .series("Learning Rust", Some(2))
+                .day(0)
+                .build(),
+            ArticleBuilder::new(3)
+                .series("Learning Rust", Some(3))
+                .day(0)
+                .build(),
+        ])),
+        create_test_config(),
+    );
+
+    let middle = service
+        .get_article_detail(&test_article_id(2), ContentFormat::None)
+        .await
+        .unwrap();
+    let nav = middle.series_navigation.unwrap();
+    assert_eq!(nav.name, "Learning Rust");
+    assert_eq!((nav.position, nav.total), (2, 3));
+    assert_eq!(nav.previous.unwrap().id, test_article_id(1));
+    assert_eq!(nav.next.unwrap().id, test_article_id(3));
+
+    let first = service
+        .get_article_detail(&test_article_id(1), ContentFormat::None)
+        .await
+        .unwrap();
+    let nav = first.series_navigation.unwrap();
+    assert_eq!(nav.position, 1);
+    assert!(nav.previous.is_none());
+    assert_eq!(nav.next.unwrap().id, test_article_id(2));
+
+    let last = service
+        .get_article_detail(&test_article_id(3), ContentFormat::None)
+        .await
+        .unwrap();
+    let nav = last.series_navigation.unwrap();
+    assert_eq!(nav.previous.unwrap().id, test_article_id(2));
+    assert!(nav.next.is_none());
+}
+
+#[tokio::test]
+async fn test_article_service_get_article_detail_without_series() {
+    let article = create_test_article(test_article_id(1), "Standalone", test_category("note"));
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![article])),
+        create_test_config(),
+    );
+
+    let detail = service
+        .get_article_detail(&test_article_id(1), ContentFormat::None)
+        .await
+        .unwrap();
+    assert_eq!(detail.article.title, "Standalone");
+    assert!(detail.series_navigation.is_none());
+
+    // Navigation block sits next to the flattened article fields
+    let json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(json["title"], "Standalone");
+    assert!(json["series_navigation"].is_null());
+}
+
+#[tokio::test]
+async fn test_article_service_get_article_detail_content_formats() {
+    let mut stored = create_test_article(test_article_id(1), "Formats", test_category("note"));
+    stored.content = "# Heading\n\nBody".to_string();
+    stored.content_html = "<h1 id=\"heading\">Heading</h1>\n<p>Body</p>\n".to_string();
+    // Stored before HTML rendering existed: rendered on the fly
+    let mut legacy = create_test_article(test_article_id(2), "Legacy", test_category("note"));
+    legacy.content = "## Old *post*".to_string();
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![stored, legacy])),
+        create_test_config(),
+    );
+
+    let plain = service
+        .get_article_detail(&test_article_id(1), ContentFormat::None)
+        .await
+        .unwrap();
+    let json = serde_json::to_value(&plain).unwrap();
+    assert_eq!(json["content"], "# Heading\n\nBody");
+    assert!(json.get("content_html").is_none());
+    assert!(json.get("content_markdown").is_none());
+
+    let both = service
+        .get_article_detail(&test_article_id(1), ContentFormat::Both)
+        .await
+        .unwrap();
+    let json = serde_json::to_value(&both).unwrap();
+    assert_eq!(json["content_markdown"], "# Heading\n\nBody");
+    assert_eq!(
+        json["content_html"],
+        "<h1 id=\"heading\">Heading</h1>\n<p>Body</p>\n"
+    );
+
+    let legacy = service
+        .get_article_detail(&test_article_id(2), ContentFormat::Html)
+        .await
+        .unwrap();
+    assert!(legacy.content_markdown.is_none());
+    assert_eq!(
+        legacy.content_html.as_deref(),
+        Some("<h2 id=\"old-post\">Old <em>post</em></h2>\n")
+    );
+}
+
+#[tokio::test]
+async fn test_article_service_get_toc() {
+    let mut stored = create_test_article(test_article_id(1), "Stored", test_category("note"));
+    stored.content = "## Outdated".to_string();
+    stored.toc = Some(vec![TocEntry {
+        level: 2,
+        text: "Stored".to_string(),
+        anchor: "stored".to_string(),
+        children: Vec::new(),
+    }]);
+    // Stored before TOC extraction existed: extracted on the fly
+    let mut legacy = create_test_article(test_article_id(2), "Legacy", test_category("note"));
+    legacy.content = "## 第一节\n\n### 细节\n".to_string();
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![stored, legacy])),
+        create_test_config(),
+    );
+
+    let toc = service.get_toc(&test_article_id(1)).await.unwrap();
+    assert_eq!(toc.len(), 1);
+    assert_eq!(toc[0].anchor, "stored");
+
+    let toc = service.get_toc(&test_article_id(2)).await.unwrap();
+    assert_eq!(toc[0].anchor, "第一节");
+    assert_eq!(toc[0].children[0].anchor, "细节");
+
+    let detail = service
+        .get_article_detail(&test_article_id(2), ContentFormat::None)
+        .await
+        .unwrap();
+    assert_eq!(detail.toc, toc);
+    let json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(json["toc"][0]["children"][0]["text"], "细节");
+
+    let result = service.get_toc(&test_article_id(9)).await;
+    assert!(matches!(
+        result,
+        Err(SomeError::GetPosts(GetPostsError::ArticleNotFound))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_reading_stats() {
+    let mut stored = create_test_article(test_article_id(1), "Stored", test_category("note"));
+    stored.word_count = Some(1200);
+    stored.reading_minutes = Some(5);
+    // Stored before reading statistics existed: computed on the fly
+    let mut legacy = create_test_article(test_article_id(2), "Legacy", test_category("note"));
+    legacy.content = "中".repeat(450) + " and some words";
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![stored, legacy])),
+        create_test_config(),
+    );
+
+    let posts = service
+        .get_posts_by_category("note", None, 10, 0)
+        .await
+        .unwrap();
+    let stored_post = posts.iter().find(|p| p.id == test_article_id(1)).unwrap();
+    assert_eq!(stored_post.word_count, Some(1200));
+    assert_eq!(stored_post.reading_minutes, Some(5));
+    let legacy_post = posts.iter().find(|p| p.id == test_article_id(2)).unwrap();
+    assert_eq!(legacy_post.word_count, None);
+
+    let detail = service
+        .get_article_detail(&test_article_id(2), ContentFormat::None)
+        .await
+        .unwrap();
+    assert_eq!(detail.article.word_count, Some(453));
+    assert_eq!(detail.article.reading_minutes, Some(2));
+
+    let json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(json["word_count"], 453);
+    assert_eq!(json["reading_minutes"], 2);
+}
+
+#[tokio::test]
+async fn test_article_service_generated_summary() {
+    let mut authored = create_test_article(test_article_id(1), "Authored", test_category("note"));
+    authored.summary = Some("Written by the author".to_string());
+    let mut generated = create_test_article(test_article_id(2), "Generated", test_category("note"));
+    generated.summary = Some("From the first paragraph".to_string());
+    generated.summary_generated = true;
+    // Stored before summary generation existed: generated on the fly
+    let mut legacy = create_test_article(test_article_id(3), "Legacy", test_category("note"));
+    legacy.summary = None;
+    legacy.content = "## Intro\n\nThe **first** paragraph.\n\nThe second one.".to_string();
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            authored, generated, legacy,
+        ])),
+        create_test_config(),
+    );
+
+    let posts = service
+        .get_posts_by_category("note", None, 10, 0)
+        .await
+        .unwrap();
+    let flag = |n| {
+        posts
+            .iter()
+            .find(|p| p.id == test_article_id(n))
+            .unwrap()
+            .summary_generated
+    };
+    assert!(!flag(1));
+    assert!(flag(2));
+
+    let detail = service
+        .get_article_detail(&test_article_id(3), ContentFormat::None)
+        .await
+        .unwrap();
+    let json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(json["summary"], "The first paragraph.");
+    assert_eq!(json["summary_generated"], true);
+}
+
+#[tokio::test]
+async fn test_article_service_get_backlinks() {
+    let draft = ArticleBuilder::new(4)
+        .series("-", None)
+        .day(4)
+        .draft()
+        .build();
+
+    let repo = MockArticleRepository::with_articles(vec![
+        ArticleBuilder::new(1).series("-", None).day(1).build(),
+        ArticleBuilder::new(2).series("-", None).day(2).build(),
+        ArticleBuilder::new(3).series("-", None).day(3).build(),
+        draft,
+    ]);
+    repo.add_link(test_article_id(2), test_article_id(1));
+    repo.add_link(test_article_id(3), test_article_id(1));
+    repo.add_link(test_article_id(1), test_article_id(2));
+    // Links from and to a draft stay hidden until it is published
+    repo.add_link(test_article_id(4), test_article_id(1));
+    repo.add_link(test_article_id(1), test_article_id(4));
+    let service = create_service(Arc::new(repo), create_test_config());
+
+    // Newest first
+    let backlinks = service.get_backlinks(&test_article_id(1)).await.unwrap();
+    let ids: Vec<_> = backlinks.iter().map(|b| b.id).collect();
+    assert_eq!(ids, [test_article_id(3), test_article_id(2)]);
+    assert_eq!(backlinks[0].url, format!("/article/{}", test_article_id(3)));
+
+    assert!(
+        service
+            .get_backlinks(&test_article_id(3))
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    assert!(matches!(
+        service.get_backlinks(&test_article_id(9)).await,
+        Err(SomeError::GetPosts(GetPostsError::ArticleNotFound))
+    ));
+
+    let graph = service.get_link_graph().await.unwrap();
+    assert_eq!(graph.nodes.len(), 3);
+    assert_eq!(graph.edges.len(), 3);
+    assert_eq!(graph.edges[0].source, test_article_id(2));
+}
+
+#[tokio::test]
+async fn test_article_service_get_related_posts() {
+    let mut article = ArticleBuilder::new(1)
+        .title("Async Rust")
+        .tags(&["rust", "async"])
+        .build();
+    let mut same_tags = ArticleBuilder::new(2)
+        .title("Tokio in practice")
+        .tags(&["rust", "async"])
+        .build();
+    let mut similar_title = ArticleBuilder::new(3)
+        .title("Async Rust patterns")
+        .tags(&["rust"])
+        .build();
+    let mut unrelated = ArticleBuilder::new(4).category("note").tags(&[]).build();
+    let mut draft = ArticleBuilder::new(5)
+        .tags(&["rust", "async"])
+        .draft()
+        .build();
+    for a in [
+        &mut article,
+        &mut same_tags,
+        &mut similar_title,
+        &mut unrelated,
+        &mut draft,
+    ] {
+        a.summary = None;
+    }
+
+    let repo = Arc::new(MockArticleRepository::with_articles(vec![
+        article,
+        same_tags,
+        similar_title,
+        unrelated,
+        draft,
+    ]));
+    let service = create_service(repo.clone(), create_test_config());
+
+    let related = service
+        .get_related_posts(&test_article_id(1), 5)
+        .await
+        .unwrap();
+    let ids: Vec<_> = related.iter().map(|p| p.id).collect();
+    // Two shared tags beat one shared tag with a similar title; the draft
+    // and the unrelated note are left out
+    assert_eq!(ids, [test_article_id(2), test_article_id(3)]);
+    assert!(related[0].score > related[1].score);
+
+    let limited = service
+        .get_related_posts(&test_article_id(1), 1)
+        .await
+        .unwrap();
+    assert_eq!(limited.len(), 1);
+
+    // Served from the cache while the published articles are unchanged
+    repo.articles
+        .lock()
+        .unwrap()
+        .get_mut(&test_article_id(2))
+        .unwrap()
+        .tags
+        .clear();
+    let cached = service
+        .get_related_posts(&test_article_id(1), 5)
+        .await
+        .unwrap();
+    assert_eq!(cached, related);
+
+    // Recomputed once an article is updated
+    repo.articles
+        .lock()
+        .unwrap()
+        .get_mut(&test_article_id(2))
+        .unwrap()
+        .updated_at += time::Duration::days(1);
+    let recomputed = service
+        .get_related_posts(&test_article_id(1), 5)
+        .await
+        .unwrap();
+    assert_eq!(recomputed[0].id, test_article_id(3));
+
+    // Drafts aren't part of the content version, so theirs aren't cached
+    let for_draft = service
+        .get_related_posts(&test_article_id(5), 5)
+        .await
+        .unwrap();
+    assert_eq!(for_draft[0].id, test_article_id(1));
+    repo.articles
+        .lock()
+        .unwrap()
+        .get_mut(&test_article_id(5))
+        .unwrap()
+        .tags
+        .clear();
+    let edited_draft = service
+        .get_related_posts(&test_article_id(5), 5)
+        .await
+        .unwrap();
+    assert_ne!(edited_draft, for_draft);
+
+    assert!(matches!(
+        service.get_related_posts(&test_article_id(9), 5).await,
+        Err(SomeError::GetPosts(GetPostsError::ArticleNotFound))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_get_article_detail_category_navigation() {
+    let draft = ArticleBuilder::new(3)
+        .series("-", None)
+        .day(3)
+        .draft()
+        .build();
+    let other_category = ArticleBuilder::new(5)
+        .category("note")
+        .series("-", None)
+        .day(5)
+        .build();
+    // Same timestamp as part 2, ordered after it by id
+    let same_time = ArticleBuilder::new(6).series("-", None).day(2).build();
+
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1).series("-", None).day(1).build(),
+            ArticleBuilder::new(2).series("-", None).day(2).build(),
+            draft,
+            ArticleBuilder::new(4).series("-", None).day(4).build(),
+            other_category,
+            same_time,
+        ])),
+        create_test_config(),
+    );
+
+    let detail = service
+        .get_article_detail(&test_article_id(6), ContentFormat::None)
+        .await
+        .unwrap();
+    let navigation = detail.category_navigation;
+    assert_eq!(navigation.previous.unwrap().id, test_article_id(2));
+    // Skips the draft and the article in another category
+    assert_eq!(navigation.next.unwrap().id, test_article_id(4));
+
+    let first = service
+        .get_article_detail(&test_article_id(1), ContentFormat::None)
+        .await
+        .unwrap();
+    assert!(first.category_navigation.previous.is_none());
+    assert_eq!(
+        first.category_navigation.next.unwrap().id,
+        test_article_id(2)
+    );
+
+    let last = service
+        .get_article_detail(&test_article_id(4), ContentFormat::None)
+        .await
+        .unwrap();
+    assert!(last.category_navigation.next.is_none());
+}
+
+#[tokio::test]
+async fn test_article_service_get_archives() {
+    use time::macros::datetime;
+
+    let draft = ArticleBuilder::new(5)
+        .created_at(datetime!(2025-11-20 0:00 UTC))
+        .draft()
+        .build();
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1)
+                .created_at(datetime!(2025-11-02 10:00 UTC))
+                .build(),
+            ArticleBuilder::new(2)
+                .category("note")
+                .created_at(datetime!(2025-11-30 23:59 UTC))
+                .build(),
+            ArticleBuilder::new(3)
+                .created_at(datetime!(2025-03-15 8:00 UTC))
+                .build(),
+            ArticleBuilder::new(4)
+                .created_at(datetime!(2024-12-31 12:00 UTC))
+                .build(),
+            draft,
+            // Late on Nov 30 in UTC+8 is still November in UTC
+            ArticleBuilder::new(6)
+                .created_at(datetime!(2025-12-01 1:00 +8))
+                .build(),
+        ])),
+        create_test_config(),
+    );
+
+    let archives = service.get_archives(None).await.unwrap();
+    let summary: Vec<_> = archives
+        .iter()
+        .map(|y| {
+            let months: Vec<_> = y.months.iter().map(|m| (m.month, m.count)).collect();
+            (y.year, y.count, months)
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [(2025, 4, vec![(11, 3), (3, 1)]), (2024, 1, vec![(12, 1)])]
+    );
+
+    let notes = service
+        .get_archives(Some(&test_category("note")))
+        .await
+        .unwrap();
+    assert_eq!(notes.len(), 1);
+    assert_eq!(
+        notes[0].months,
+        [ArchiveMonth {
+            month: 11,
+            count: 1
+        }]
+    );
+
+    let period = ArchivePeriod::new(2025, 11).unwrap();
+    let posts = service
+        .get_archive_posts(period, None, 20, 0)
+        .await
+        .unwrap();
+    let ids: Vec<_> = posts.iter().map(|p| p.id).collect();
+    assert_eq!(
+        ids,
+        [test_article_id(2), test_article_id(6), test_article_id(1)]
+    );
+
+    let articles_only = service
+        .get_archive_posts(period, Some(&test_category("article")), 1, 1)
+        .await
+        .unwrap();
+    assert_eq!(articles_only.len(), 1);
+    assert_eq!(articles_only[0].id, test_article_id(1));
+
+    assert!(matches!(
+        ArchivePeriod::new(2025, 0),
+        Err(SomeError::GetPosts(
+            GetPostsError::InvalidArchivePeriod { .. }
+        ))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_pinned_and_featured() {
+    use time::macros::datetime;
+
+    let pinned = |n, weight| {
+        let mut article = ArticleBuilder::new(n)
+            .created_at(datetime!(2025-01-01 0:00 UTC))
+            .build();
+        article.pinned = true;
+        article.pinned_weight = weight;
+        article
+    };
+    let featured = |n, weight, date| {
+        let mut article = ArticleBuilder::new(n).created_at(date).build();
+        article.featured = true;
+        article.featured_weight = weight;
+        article
+    };
+    let mut draft = featured(7, Some(0), datetime!(2025-06-01 0:00 UTC));
+    draft.status = "draft".to_string();
+
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1)
+                .created_at(datetime!(2025-05-01 0:00 UTC))
+                .build(),
+            pinned(2, None),
+            pinned(3, Some(2)),
+            pinned(4, Some(1)),
+            featured(5, None, datetime!(2025-04-01 0:00 UTC)),
+            featured(6, Some(1), datetime!(2025-02-01 0:00 UTC)),
+            draft,
+        ])),
+        create_test_config(),
+    );
+
+    // Pinned posts come first by weight, unweighted ones after, then newest first
+    let posts = service
+        .get_posts_by_category("article", None, 10, 0)
+        .await
+        .unwrap();
+    let ids: Vec<_> = posts.iter().map(|p| p.id).collect();
+    assert_eq!(ids, [4, 3, 2, 7, 1, 5, 6].map(test_article_id));
+    assert!(posts[0].pinned);
+    assert!(!posts[3].pinned);
+
+    // Drafts are never featured
+    let hero = service.get_featured(5).await.unwrap();
+    let ids: Vec<_> = hero.iter().map(|p| p.id).collect();
+    assert_eq!(ids, [test_article_id(6), test_article_id(5)]);
+    assert_eq!(hero[0].featured_weight, Some(1));
+
+    assert_eq!(service.get_featured(1).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_article_service_get_gallery() {
+    let mut article = create_test_article(
+        test_article_id(1),
+        "Kyoto in spring",
+        test_category("pictures"),
+    );
+    article.images = vec![
+        GalleryImage {
+            src: "pictures/kyoto/fushimi.jpg".to_string(),
+            alt: "Torii gates".to_string(),
+            caption: Some("Fushimi Inari at dawn".to_string()),
+            width: Some(4000),
+            height: Some(3000),
+            taken_at: None,
+            camera: None,
+            lens: None,
+            exposure: None,
+            location: None,
+        },
+        GalleryImage {
+            src: "pictures/kyoto/gion.jpg".to_string(),
+            alt: "Gion at night".to_string(),
+            caption: None,
+            width: None,
+            height: None,
+            taken_at: None,
+            camera: None,
+            lens: None,
+            exposure: None,
+            location: None,
+        },
+    ];
+    let plain = create_test_article(test_article_id(2), "Notes", test_category("note"));
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![article, plain])),
+        create_test_config(),
+    );
+
+    let gallery = service.get_gallery(&test_article_id(1)).await.unwrap();
+    assert_eq!(gallery.title, "Kyoto in spring");
+    let sources: Vec<_> = gallery.images.iter().map(|i| i.src.as_str()).collect();
+    assert_eq!(
+        sources,
+        ["pictures/kyoto/fushimi.jpg", "pictures/kyoto/gion.jpg"]
+    );
+
+    // Posts without images have an empty gallery
+    let empty = service.get_gallery(&test_article_id(2)).await.unwrap();
+    assert!(empty.images.is_empty());
+
+    assert!(matches!(
+        service.get_gallery(&test_article_id(3)).await,
+        Err(SomeError::GetPosts(GetPostsError::ArticleNotFound))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_get_media() {
+    let original: MediaHash = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
+        .parse()
+        .unwrap();
+    let avif: MediaHash = "60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752"
+        .parse()
+        .unwrap();
+    let webp: MediaHash = "fd61a03af4f77d870fc21e05e7e80678095c92d808cfb3b5c279ee04c74aca13"
+        .parse()
+        .unwrap();
+    let asset =
+        |hash: &MediaHash, content_type: &str, width, parent: Option<&MediaHash>| MediaAsset {
+            hash: hash.clone(),
+            content_type: content_type.to_string(),
+            byte_size: 4,
+            width,
+            height: width * 3 / 4,
+            blurhash: parent
+                .is_none()
+                .then(|| "LEHV6nWB2yk8pyo0adR*.7kCMdnj".to_string()),
+            original: parent.cloned(),
+            exif: None,
+        };
+
+    let mut photo = asset(&original, "image/jpeg", 1600, None);
+    photo.exif = Some(ExifMetadata {
+        camera: Some("FUJIFILM X100V".to_string()),
+        ..ExifMetadata::default()
+    });
+
+    let repo = MockArticleRepository::new();
+    repo.add_media(photo);
+    repo.add_media(asset(&webp, "image/webp", 480, Some(&original)));
+    repo.add_media(asset(&avif, "image/avif", 480, Some(&original)));
+
+    let store = Arc::new(LocalMediaStore::new(
+        std::env::temp_dir().join("mizu-test-media-get"),
+    ));
+    store.put(&original, b"jpeg", "image/jpeg").await.unwrap();
+    store.put(&webp, b"webp", "image/webp").await.unwrap();
+
+    let service = ArticleService::new(
+        Arc::new(repo),
+        #[cfg(feature = "webhook")]
+        Arc::new(MockGithubClient::new()),
+        Arc::new(MockSearchService::new()),
+        store,
+        create_test_config(),
+    );
+
+    let (served, content) = service.get_media(&webp).await.unwrap();
+    assert_eq!(served.content_type, "image/webp");
+    assert_eq!(content, MediaContent::Bytes(b"webp".to_vec()));
+
+    // Recorded but missing from the store
+    assert!(matches!(
+        service.get_media(&avif).await,
+        Err(SomeError::Media(MediaError::NotFound(_)))
+    ));
+
+    let info = service.get_media_info(&original).await.unwrap();
+    assert_eq!(info.url, format!("/api/media/{}", original));
+    assert_eq!((info.width, info.height), (1600, 1200));
+    assert!(info.blurhash.is_some());
+    assert_eq!(
+        info.exif.and_then(|exif| exif.camera).as_deref(),
+        Some("FUJIFILM X100V")
+    );
+    let variants: Vec<_> = info
+        .variants
+        .iter()
+        .map(|v| (v.content_type.as_str(), v.width))
+        .collect();
+    assert_eq!(variants, [("image/avif", 480), ("image/webp", 480)]);
+
+    // Variants have no info of their own
+    assert!(matches!(
+        service.get_media_info(&webp).await,
+        Err(SomeError::Media(MediaError::NotFound(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_collect_media_garbage() {
+    let hashes: Vec<MediaHash> = [
+        "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+        "60303ae22b998861bce3b28f33eec1be758a213c86c93c076dbe9f558c11c752",
+        "fd61a03af4f77d870fc21e05e7e80678095c92d808cfb3b5c279ee04c74aca13",
+        "a4e624d686e03ed2767c0abd85c14426b0b1157d2ce81d27bb4fe4f6f01d688a",
+    ]
+    .iter()
+    .map(|hex| hex.parse().unwrap())
+    .collect();
+    let (kept, kept_variant, dropped, dropped_variant) =
+        (&hashes[0], &hashes[1], &hashes[2], &hashes[3]);
+    let asset = |hash: &MediaHash, original: Option<&MediaHash>| MediaAsset {
+        hash: hash.clone(),
+        content_type: "image/webp".to_string(),
+        byte_size: 4,
+        width: 480,
+        height: 360,
+        blurhash: None,
+        original: original.cloned(),
+        exif: None,
+    };
+
+    let repo = Arc::new(MockArticleRepository::new());
+    repo.add_media(asset(kept, None));
+    repo.add_media(asset(kept_variant, Some(kept)));
+    repo.add_media(asset(dropped, None));
+    repo.add_media(asset(dropped_variant, Some(dropped)));
+    repo.add_media_reference(test_article_id(1), kept.clone());
+
+    let store = Arc::new(LocalMediaStore::new(
+        std::env::temp_dir().join("mizu-test-media-gc"),
+    ));
+    for hash in &hashes {
+        store.put(hash, b"blob", "image/webp").await.unwrap();
+    }
+
+    let service = ArticleService::new(
+        repo.clone(),
+        #[cfg(feature = "webhook")]
+        Arc::new(MockGithubClient::new()),
+        Arc::new(MockSearchService::new()),
+        store.clone(),
+        create_test_config(),
+    );
+
+    assert_eq!(service.collect_media_garbage().await.unwrap(), 2);
+
+    // The unreferenced original goes with its variants, rows and blobs
+    let media = repo.media.lock().unwrap().clone();
+    assert!(media.contains_key(kept) && media.contains_key(kept_variant));
+    assert!(!media.contains_key(dropped) && !media.contains_key(dropped_variant));
+    assert!(store.get(kept_variant).await.unwrap().is_some());
+    assert!(store.get(dropped).await.unwrap().is_none());
+    assert!(store.get(dropped_variant).await.unwrap().is_none());
+
+    // Nothing left to collect
+    assert_eq!(service.collect_media_garbage().await.unwrap(), 0);
+}
+
+fn micropub_form(pairs: &[(&str, &str)]) -> MicropubRequest {
+    let pairs: Vec<(String, String)> = pairs
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    MicropubRequest::from_form(&pairs).unwrap()
+}
+
+#[tokio::test]
+async fn test_article_service_authorize_micropub() {
+    let repo = Arc::new(MockArticleRepository::new());
+    let service = create_service(repo.clone(), create_test_config());
+
+    assert!(
+        service
+            .authorize_micropub(Some(TEST_MICROPUB_TOKEN))
+            .is_ok()
+    );
+    assert!(matches!(
+        service.authorize_micropub(None),
+        Err(SomeError::Micropub(MicropubError::Unauthorized))
+    ));
+    assert!(matches!(
+        service.authorize_micropub(Some("wrong")),
+        Err(SomeError::Micropub(MicropubError::Forbidden(_)))
+    ));
+
+    // Without a configured token the endpoint is disabled
+    let disabled = create_service(
+        repo,
+        create_test_config_with_micropub(
+            CategoryRegistry::default(),
+            TagNormalizer::default(),
+            MicropubOptions::default(),
+            None,
+        ),
+    );
+    assert!(matches!(
+        disabled.authorize_micropub(Some(TEST_MICROPUB_TOKEN)),
+        Err(SomeError::Micropub(MicropubError::Forbidden(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_micropub_create() {
+    let repo = Arc::new(MockArticleRepository::new());
+    let tags = TagNormalizer::new(vec![TagSynonym {
+        canonical: "coffee".to_string(),
+        aliases: vec!["kaffee".to_string()],
+    }])
+    .unwrap();
+    let micropub = MicropubOptions {
+        site_url: Some("https://example.com".to_string()),
+        ..Default::default()
+    };
+    let service = create_service(
+        repo.clone(),
+        create_test_config_with_micropub(
+            CategoryRegistry::default(),
+            tags,
+            micropub,
+            Some(TEST_MICROPUB_TOKEN),
+        ),
+    );
+
+    let outcome = service
+        .handle_micropub(micropub_form(&[
+            ("h", "entry"),
+            ("content", "Finally tried the new coffee place"),
+            ("category[]", "Kaffee"),
+        ]))
+        .await
+        .unwrap();
+
+    let articles: Vec<Article> = repo.articles.lock().unwrap().values().cloned().collect();
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+
+    let MicropubOutcome::Created { url } = outcome else {
+        panic!("expected a created post, got {:?}", outcome);
+    };
+    assert_eq!(url, format!("https://example.com/talk/{}", article.id));
+
+    // An untitled post in the configured category, marked as API-authored
+    assert_eq!(article.title, "");
+    assert_eq!(article.category.as_str(), "talk");
+    assert_eq!(article.source, ArticleSource::Micropub);
+    assert_eq!(article.path, format!("micropub:{}", article.id));
+    assert_eq!(article.tags, ["coffee"]);
+    assert_eq!(article.status, "published");
+    assert_eq!(article.content, "Finally tried the new coffee place");
+}
+
+#[tokio::test]
+async fn test_article_service_micropub_create_requires_name() {
+    let repo = Arc::new(MockArticleRepository::new());
+    let micropub = MicropubOptions {
+        category: test_category("article"),
+        ..Default::default()
+    };
+    let service = create_service(
+        repo.clone(),
+        create_test_config_with_micropub(
+            CategoryRegistry::default(),
+            TagNormalizer::default(),
+            micropub,
+            Some(TEST_MICROPUB_TOKEN),
+        ),
+    );
+
+    // Articles need a title
+    let result = service
+        .handle_micropub(micropub_form(&[("h", "entry"), ("content", "Untitled")]))
+        .await;
+    assert!(matches!(
+        result,
+        Err(SomeError::Micropub(MicropubError::InvalidRequest(_)))
+    ));
+    assert!(repo.articles.lock().unwrap().is_empty());
+
+    // Content is always required
+    let result = service
+        .handle_micropub(micropub_form(&[("h", "entry"), ("name", "Hello")]))
+        .await;
+    assert!(matches!(
+        result,
+        Err(SomeError::Micropub(MicropubError::InvalidRequest(_)))
+    ));
+
+    let result = service
+        .handle_micropub(micropub_form(&[
+            ("h", "entry"),
+            ("name", "Hello"),
+            ("content", "World"),
+        ]))
+        .await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_article_service_micropub_update_and_delete() {
+    let repo = Arc::new(MockArticleRepository::new());
+    let search = Arc::new(MockSearchService::new());
+    let service = ArticleService::new(
+        repo.clone(),
+        #[cfg(feature = "webhook")]
+        Arc::new(MockGithubClient::new()),
+        search.clone(),
+        create_test_media_store(),
+        create_test_config(),
+    );
+
+    let MicropubOutcome::Created { url } = service
+        .handle_micropub(micropub_form(&[
+            ("h", "entry"),
+            ("content", "First draft"),
+            ("category[]", "life"),
+        ]))
+        .await
+        .unwrap()
+    else {
+        panic!("expected a created post");
+    };
+    let id = *repo.articles.lock().unwrap().keys().next().unwrap();
+    let created_at = repo.articles.lock().unwrap()[&id].created_at;
+    assert_eq!(search.documents.lock().unwrap()[&id].content, "First draft");
+
+    let update = MicropubRequest::from_json(&serde_json::json!({
+        "action": "update",
+        "url": url,
+        "replace": { "content": ["Second draft"] },
+        "add": { "category": ["coffee"] }
+    }))
+    .unwrap();
+    assert!(matches!(
+        service.handle_micropub(update).await,
+        Ok(MicropubOutcome::Updated)
+    ));
+
+    let article = repo.articles.lock().unwrap()[&id].clone();
+    assert_eq!(article.content, "Second draft");
+    assert_eq!(article.tags, ["life", "coffee"]);
+    assert_eq!(article.created_at, created_at);
+    assert_eq!(article.source, ArticleSource::Micropub);
+
+    // Only the changed post is indexed again
+    assert_eq!(
+        search.documents.lock().unwrap()[&id].content,
+        "Second draft"
+    );
+
+    // The source query returns the updated properties
+    let source = service.get_micropub_source(&url).await.unwrap();
+    assert_eq!(
+        source.properties["content"],
+        serde_json::json!(["Second draft"])
+    );
+    assert_eq!(
+        source.properties["category"],
+        serde_json::json!(["life", "coffee"])
+    );
+
+    let delete = micropub_form(&[("action", "delete"), ("url", &url)]);
+    assert!(matches!(
+        service.handle_micropub(delete).await,
+        Ok(MicropubOutcome::Deleted)
+    ));
+    assert!(repo.articles.lock().unwrap().is_empty());
+    assert!(search.documents.lock().unwrap().is_empty());
+
+    // The post is gone
+    let delete = micropub_form(&[("action", "delete"), ("url", &url)]);
+    assert!(matches!(
+        service.handle_micropub(delete).await,
+        Err(SomeError::Micropub(MicropubError::InvalidRequest(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_micropub_leaves_repository_posts_alone() {
+    let article = create_test_article(test_article_id(1), "From git", test_category("talk"));
+    let repo = Arc::new(MockArticleRepository::with_articles(vec![article]));
+    let service = create_service(repo.clone(), create_test_config());
+
+    let url = format!("/talk/{}", test_article_id(1));
+
+    // Repository posts can be read for editing but not changed
+    assert!(service.get_micropub_source(&url).await.is_ok());
+
+    let delete = micropub_form(&[("action", "delete"), ("url", &url)]);
+    assert!(matches!(
+        service.handle_micropub(delete).await,
+        Err(SomeError::Micropub(MicropubError::Forbidden(_)))
+    ));
+    assert_eq!(repo.articles.lock().unwrap().len(), 1);
+
+    let delete = micropub_form(&[("action", "delete"), ("url", "/talk/not-a-post")]);
+    assert!(matches!(
+        service.handle_micropub(delete).await,
+        Err(SomeError::Micropub(MicropubError::InvalidRequest(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_get_micropub_config() {
+    let service = create_service(Arc::new(MockArticleRepository::new()), create_test_config());
+
+    let config = serde_json::to_value(service.get_micropub_config().await).unwrap();
+    assert_eq!(
+        config,
+        serde_json::json!({
+            "syndicate-to": [],
+            "post-types": [{ "type": "note", "name": "Talks" }]
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_article_service_posts_by_language() {
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1)
+                .language("en")
+                .translation_key("hello")
+                .day(1)
+                .build(),
+            ArticleBuilder::new(2)
+                .language("zh-CN")
+                .translation_key("hello")
+                .day(2)
+                .build(),
+            ArticleBuilder::new(3).language("zh-TW").day(3).build(),
+            ArticleBuilder::new(4).day(4).build(),
+        ])),
+        create_test_config(),
+    );
+
+    let ids = |posts: Vec<PostResponse>| -> Vec<u128> {
+        posts.iter().map(|p| p.id.as_uuid().as_u128()).collect()
+    };
+
+    // Regional variants share their primary language; untagged posts are
+    // listed for every language
+    let zh = service
+        .get_posts_by_category("article", Some("zh"), 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(ids(zh), [4, 3, 2]);
+
+    let en = service
+        .get_posts_by_category("article", Some("en"), 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(ids(en), [4, 1]);
+
+    let all = service
+        .get_posts_by_category("article", None, 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(all.len(), 4);
+}
+
+#[tokio::test]
+async fn test_article_service_resolve_language() {
+    let draft = ArticleBuilder::new(3).language("fr").day(3).draft().build();
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1).language("en-US").day(1).build(),
+            ArticleBuilder::new(2).language("zh-CN").day(2).build(),
+            draft,
+        ])),
+        create_test_config(),
+    );
+
+    // An explicit language wins over the header
+    assert_eq!(
+        service
+            .resolve_language(Some("zh-TW"), Some("en"))
+            .await
+            .unwrap()
+            .as_deref(),
+        Some("zh")
+    );
+    assert_eq!(
+        service
+            .resolve_language(Some("all"), Some("en"))
+            .await
+            .unwrap(),
+        None
+    );
+
+    // The header is matched against the languages with published content
+    assert_eq!(
+        service
+            .resolve_language(None, Some("fr-FR, zh;q=0.5, en;q=0.8"))
+            .await
+            .unwrap()
+            .as_deref(),
+        Some("en")
+    );
+    assert_eq!(
+        service
+            .resolve_language(None, Some("fr, de"))
+            .await
+            .unwrap(),
+        None
+    );
+    assert_eq!(service.resolve_language(None, None).await.unwrap(), None);
+
+    assert!(matches!(
+        service.resolve_language(Some("english!"), None).await,
+        Err(SomeError::GetPosts(GetPostsError::InvalidLanguage(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_get_article_detail_translations() {
+    let draft = ArticleBuilder::new(4)
+        .language("ja")
+        .translation_key("hello")
+        .day(4)
+        .draft()
+        .build();
+    let service = create_service(
+        Arc::new(MockArticleRepository::with_articles(vec![
+            ArticleBuilder::new(1)
+                .language("zh-CN")
+                .translation_key("hello")
+                .day(1)
+                .build(),
+            ArticleBuilder::new(2)
+                .language("en")
+                .translation_key("hello")
+                .day(2)
+                .build(),
+            ArticleBuilder::new(3)
+                .language("de")
+                .translation_key("hello")
+                .day(3)
+                .build(),
+            draft,
+            ArticleBuilder::new(5)
+                .language("en")
+                .translation_key("other")
+                .day(5)
+                .build(),
+            ArticleBuilder::new(6).language("en").day(6).build(),
+        ])),
+        create_test_config(),
+    );
+
+    let detail = service
+        .get_article_detail(&test_article_id(1), ContentFormat::default())
+        .await
+        .unwrap();
+
+    // Other published versions, by language
+    let translations: Vec<(u128, Option<&str>)> = detail
+        .translations
+        .iter()
+        .map(|t| (t.id.as_uuid().as_u128(), t.language.as_deref()))
+        .collect();
+    assert_eq!(translations, [(3, Some("de")), (2, Some("en"))]);
+    assert_eq!(detail.translations[0].title, "Post 3");
+
+    let detail = service
+        .get_article_detail(&test_article_id(6), ContentFormat::default())
+        .await
+        .unwrap();
+    assert!(detail.translations.is_empty());
+}
+
+fn create_test_author(id: &str, name: &str) -> Author {
+    Author {
+        name: name.to_string(),
+        avatar: Some(format!("/media/{}.png", id)),
+        github: Some(id.to_string()),
+        emails: vec![format!("{}@example.com", id)],
+        ..Author::placeholder(id)
+    }
+}
+
+#[tokio::test]
+async fn test_article_service_get_author_posts() {
+    let repo = MockArticleRepository::with_articles(vec![
+        ArticleBuilder::new(1).authors(&["jane"]).day(1).build(),
+        ArticleBuilder::new(2)
+            .authors(&["john", "jane"])
+            .day(2)
+            .build(),
+        ArticleBuilder::new(3).authors(&["john"]).day(3).build(),
+        ArticleBuilder::new(4).authors(&["jane"]).day(4).build(),
+    ]);
+    repo.add_author(create_test_author("jane", "Jane Doe"));
+    repo.add_author(create_test_author("john", "John Roe"));
+    let service = create_service(Arc::new(repo), create_test_config());
+
+    let author = service.get_author("jane", None, 10, 0).await.unwrap();
+    assert_eq!(author.author.name, "Jane Doe");
+
+    // Newest first, co-authored posts included
+    let ids: Vec<_> = author.posts.iter().map(|p| p.id).collect();
+    assert_eq!(
+        ids,
+        [test_article_id(4), test_article_id(2), test_article_id(1)]
+    );
+
+    // Pagination
+    let page2 = service.get_author("jane", None, 2, 2).await.unwrap();
+    assert_eq!(page2.posts.len(), 1);
+    assert_eq!(page2.posts[0].id, test_article_id(1));
+
+    // Private attribution fields are never exposed
+    let json = serde_json::to_value(&author).unwrap();
+    assert_eq!(json["id"], "jane");
+    assert_eq!(json["avatar"], "/media/jane.png");
+    assert!(json.get("github").is_none());
+    assert!(json.get("emails").is_none());
+}
+
+#[cfg(feature = "webhook")]
+#[tokio::test]
+async fn test_article_service_modified_post_keeps_byline() {
+    use backend::domain::authors::CommitAuthor;
+    use backend::infrastructure::github::webhook::FileChange;
+
+    let post = |n: u128| {
+        format!(
+            "---\nid: {}\ntitle: Post {}\ncategory: article\nstatus: published\n---\nEdited",
+            test_article_id(n),
+            n
+        )
+    };
+    let change = |path: &str| FileChange {
+        file_path: path.to_string(),
+        timestamp: chrono::Utc::now(),
+        author: CommitAuthor {
+            name: "John Roe".to_string(),
+            email: None,
+            username: Some("john".to_string()),
+        },
+        row_url: None,
+    };
+
+    let repo = MockArticleRepository::with_articles(vec![
+        ArticleBuilder::new(1).authors(&["jane"]).day(1).build(),
+    ]);
+    let github = MockGithubClient::new();
+    github.set_file_content("posts/one.md", post(1));
+    github.set_file_content("posts/two.md", post(2));
+
+    let service = ArticleService::new(
+        Arc::new(repo),
+        Arc::new(github),
+        Arc::new(MockSearchService::new()),
+        create_test_media_store(),
+        create_test_config(),
+    );
+
+    let authors = [
+        create_test_author("jane", "Jane Doe"),
+        create_test_author("john", "John Roe"),
+    ];
+    let articles = service
+        .process_modified_event(
+            "owner",
+            "repo",
+            "0000000000000000000000000000000000000000",
+            &[change("posts/one.md"), change("posts/two.md")],
+            &authors,
+            &mut Vec::new(),
+        )
+        .await
+        .unwrap();
+
+    // Editing a post doesn't hand it to the last committer
+    assert_eq!(articles[0].authors, ["jane"]);
+    // A post not stored yet is credited like a new one
+    assert_eq!(articles[1].authors, ["john"]);
+}
+
+#[cfg(feature = "webhook")]
+#[tokio::test]
+async fn test_article_service_gallery_images_checked_at_commit() {
+    use backend::domain::authors::CommitAuthor;
+    use backend::infrastructure::github::webhook::FileChange;
+
+    let post = |n: u128, images: &str| {
+        format!(
+            "---\nid: {}\ntitle: Photos {}\ncategory: pictures\nstatus: published\nimages:\n{}---\n",
+            test_article_id(n),
+            n,
+            images
+        )
+    };
+    let change = |path: &str| FileChange {
+        file_path: path.to_string(),
+        timestamp: chrono::Utc::now(),
+        author: CommitAuthor {
+            name: "Jane Doe".to_string(),
+            email: None,
+            username: None,
+        },
+        row_url: None,
+    };
+
+    let github = MockGithubClient::new();
+    github.set_file_bytes("photos/a.jpg", vec![0xFF, 0xD8]);
+    github.set_unreachable("photos/b.jpg");
+    github.set_file_content(
+        "posts/one.md",
+        post(
+            1,
+            "  - src: ../photos/a.jpg\n    alt: A\n  - src: /photos/b.jpg\n    alt: B\n",
+        ),
+    );
+    github.set_file_content(
+        "posts/two.md",
+        post(2, "  - src: /photos/missing.jpg\n    alt: Missing\n"),
+    );
+
+    let service = ArticleService::new(
+        Arc::new(MockArticleRepository::new()),
+        Arc::new(github),
+        Arc::new(MockSearchService::new()),
+        create_test_media_store(),
+        create_test_config(),
+    );
+
+    let mut reports = Vec::new();
+    let articles = service
+        .process_modified_event(
+            "owner",
+            "repo",
+            "0000000000000000000000000000000000000000",
+            &[change("posts/one.md"), change("posts/two.md")],
+            &[],
+            &mut reports,
+        )
+        .await
+        .unwrap();
+
+    // An image that couldn't be looked up is kept, with a warning
+    assert_eq!(articles.len(), 1);
+    let sources: Vec<_> = articles[0].images.iter().map(|i| i.src.as_str()).collect();
+    assert_eq!(sources, ["photos/a.jpg", "photos/b.jpg"]);
+    let one = reports.iter().find(|r| r.path == "posts/one.md").unwrap();
+    assert_eq!(one.status, IngestionStatus::IngestedWithWarnings);
+
+    // A missing image rejects the post
+    let two = reports.iter().find(|r| r.path == "posts/two.md").unwrap();
+    assert_eq!(two.status, IngestionStatus::Failed);
+}
+
+#[tokio::test]
+async fn test_article_service_get_author_not_found() {
+    // Posts may reference an id without a profile, but it has no page
+    let repo = MockArticleRepository::with_articles(vec![
+        ArticleBuilder::new(1).authors(&["ghost"]).day(1).build(),
+    ]);
+    let service = create_service(Arc::new(repo), create_test_config());
+
+    let result = service.get_author("ghost", None, 10, 0).await;
+    assert!(matches!(
+        result,
+        Err(SomeError::GetPosts(GetPostsError::AuthorNotFound(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_article_service_post_bylines() {
+    let repo = MockArticleRepository::with_articles(vec![
+        ArticleBuilder::new(1)
+            .authors(&["john", "ghost"])
+            .day(1)
+            .build(),
+    ]);
+    repo.add_author(create_test_author("john", "John Roe"));
+    let service = create_service(Arc::new(repo), create_test_config());
+
+    // Listings embed id, name and avatar in byline order
+    let posts = service
+        .get_posts_by_category("article", None, 10, 0)
+        .await
+        .unwrap();
+    let byline: Vec<(&str, &str, Option<&str>)> = posts[0]
+        .authors
+        .iter()
+        .map(|a| (a.id.as_str(), a.name.as_str(), a.avatar.as_deref()))
+        .collect();
+    assert_eq!(
+        byline,
+        [
+            ("john", "John Roe", Some("/media/john.png")),
+            ("ghost", "ghost", None)
+        ]
+    );
+
+    // The detail embeds full profiles, falling back to the id as name
+    let detail = service
+        .get_article_detail(&test_article_id(1), ContentFormat::None)
+        .await
+        .unwrap();
+    let names: Vec<_> = detail.authors.iter().map(|a| a.name.as_str()).collect();
+    assert_eq!(names, ["John Roe", "ghost"]);
+
+    let json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(json["authors"][0]["id"], "john");
+    assert!(json["authors"][0].get("emails").is_none());
+}
+
+fn create_test_collection_config() -> Arc<AppConfig> {
+    let config = create_test_config();
+    config.collections.try_write().unwrap().schemas = ["links", "projects"]
+        .into_iter()
+        .map(|name| CollectionSchema {
+            name: name.to_string(),
+            fields: Vec::new(),
+        })
+        .collect();
+    config
+}
+
+fn create_test_collection(name: &str, data: serde_json::Value) -> Collection {
+    Collection {
+        name: name.to_string(),
+        path: format!("data/{}.yaml", name),
+        data,
+        updated_at: time::OffsetDateTime::UNIX_EPOCH,
+    }
+}
+
+#[tokio::test]
+async fn test_article_service_get_collection() {
+    let links = serde_json::json!([{ "name": "Jane", "url": "https://jane.example.com" }]);
+    let repo = MockArticleRepository::new();
+    repo.add_collection(create_test_collection("links", links.clone()));
+    // Stored before the collection was removed from the config
+    repo.add_collection(create_test_collection("friends", serde_json::json!([])));
+    let service = create_service(Arc::new(repo), create_test_collection_config());
+
+    let collection = service.get_collection("links").await.unwrap();
+    assert_eq!(collection.name, "links");
+    assert_eq!(collection.data, links);
+
+    // Undeclared, and declared but never pushed
+    for name in ["friends", "projects", "Links"] {
+        let result = service.get_collection(name).await;
+        assert!(
+            matches!(
+                result,
+                Err(SomeError::GetPosts(GetPostsError::CollectionNotFound(_)))
+            ),
+            "{name}"
+        );
+    }
+}
+
+#[tokio::test]
+async fn test_article_service_get_reports() {
+    let repo = MockArticleRepository::new();
+    for (i, path) in ["posts/a.md", "posts/b.md", "posts/c.md"]
+        .iter()
+        .enumerate()
+    {
+        let issues = if *path == "posts/b.md" {
+            vec![IngestionIssue::error(
+                Some("title"),
+                Some(2),
+                "must not be empty",
+            )]
+        } else {
+            vec![]
+        };
+        let mut report = IngestionReport::new(path, None, issues);
+        report.ingested_at = time::OffsetDateTime::UNIX_EPOCH + time::Duration::days(i as i64);
+        repo.add_report(report);
+    }
+
+    let service = create_service(Arc::new(repo), create_test_config());
+
+    // Most recently ingested first
+    let all = service.get_reports(None, 10, 0).await.unwrap();
+    let paths: Vec<&str> = all.iter().map(|r| r.path.as_str()).collect();
+    assert_eq!(paths, ["posts/c.md", "posts/b.md", "posts/a.md"]);
+
+    let failed = service
+        .get_reports(Some(IngestionStatus::Failed), 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(failed.len(), 1);
+    assert_eq!(failed[0].path, "posts/b.md");
+
+    let page2 = service.get_reports(None, 2, 2).await.unwrap();
+    assert_eq!(page2.len(), 1);
+    assert_eq!(page2[0].path, "posts/a.md");
+}